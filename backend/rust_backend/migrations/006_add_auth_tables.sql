@@ -0,0 +1,34 @@
+-- 添加钱包签名登录相关表
+-- 说明: 服务端签发 nonce，客户端用 Sui 钱包签名 personal message，验证通过后签发会话 token
+
+USE resume_vault_sui;
+
+-- 1. 登录挑战 nonce 表
+CREATE TABLE IF NOT EXISTS auth_nonces (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    wallet_address VARCHAR(100) NOT NULL COMMENT '请求登录的钱包地址',
+    nonce VARCHAR(64) UNIQUE NOT NULL COMMENT '随机 nonce',
+    message TEXT NOT NULL COMMENT '待签名的完整消息',
+    used BOOLEAN NOT NULL DEFAULT FALSE COMMENT '是否已使用',
+    expires_at DATETIME NOT NULL COMMENT '过期时间',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_wallet (wallet_address),
+    INDEX idx_expires_at (expires_at)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='登录挑战 nonce 表';
+
+-- 2. 会话表（只存储 token 的 SHA-256 哈希）
+CREATE TABLE IF NOT EXISTS auth_sessions (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    token_hash VARCHAR(64) UNIQUE NOT NULL COMMENT '会话 token 的 SHA-256 哈希',
+    user_id BIGINT NOT NULL COMMENT '用户 ID',
+    wallet_address VARCHAR(100) NOT NULL COMMENT '已验证的钱包地址',
+    expires_at DATETIME NOT NULL COMMENT '过期时间',
+    revoked_at DATETIME NULL COMMENT '注销时间',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_user_id (user_id),
+    INDEX idx_wallet (wallet_address),
+    INDEX idx_expires_at (expires_at),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='登录会话表';
+
+SELECT '✅ Migration 006: 登录认证表已创建' AS status;
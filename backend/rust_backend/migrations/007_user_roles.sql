@@ -0,0 +1,9 @@
+-- 用户角色: 支持同时拥有求职者和招聘者身份
+-- user_type 取值: job_seeker(求职者), recruiter(招聘者), both(两者兼有)
+
+USE resume_vault_sui;
+
+ALTER TABLE users
+    MODIFY COLUMN user_type VARCHAR(20) NOT NULL DEFAULT 'job_seeker' COMMENT '用户类型: job_seeker, recruiter 或 both';
+
+SELECT '✅ Migration 007: 用户角色已更新' AS status;
@@ -0,0 +1,38 @@
+-- 招聘组织：多个钱包共享解锁权益
+-- 说明: 组织成员购买的简历对整个组织可见，unlock_records 记录购买时所属组织
+
+USE resume_vault_sui;
+
+-- 1. 组织表
+CREATE TABLE IF NOT EXISTS organizations (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    name VARCHAR(100) NOT NULL COMMENT '组织名称',
+    created_by BIGINT NOT NULL COMMENT '创建者用户 ID',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    INDEX idx_created_by (created_by),
+    FOREIGN KEY (created_by) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='招聘组织表';
+
+-- 2. 组织成员表
+CREATE TABLE IF NOT EXISTS organization_members (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    organization_id BIGINT NOT NULL COMMENT '组织 ID',
+    user_id BIGINT NOT NULL COMMENT '成员用户 ID',
+    wallet_address VARCHAR(100) NOT NULL COMMENT '成员钱包地址',
+    role VARCHAR(20) NOT NULL DEFAULT 'member' COMMENT '成员角色: admin, member',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE KEY uk_org_user (organization_id, user_id),
+    INDEX idx_user_id (user_id),
+    FOREIGN KEY (organization_id) REFERENCES organizations(id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='组织成员表';
+
+-- 3. 解锁记录关联购买时的组织
+ALTER TABLE unlock_records
+    ADD COLUMN organization_id BIGINT NULL COMMENT '购买时所属组织 ID' AFTER buyer_wallet,
+    ADD INDEX idx_organization (organization_id),
+    ADD CONSTRAINT unlock_records_organization_fk
+        FOREIGN KEY (organization_id) REFERENCES organizations(id) ON DELETE SET NULL;
+
+SELECT '✅ Migration 008: 组织表已创建' AS status;
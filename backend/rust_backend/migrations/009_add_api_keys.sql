@@ -0,0 +1,38 @@
+-- 程序化访问的 API Key（供招聘方 ATS 服务端调用）
+-- 说明: 明文 key 只在创建/轮换时返回一次，库中只存 SHA-256 哈希
+
+USE resume_vault_sui;
+
+-- 1. API Key 表
+CREATE TABLE IF NOT EXISTS api_keys (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    name VARCHAR(100) NOT NULL COMMENT 'Key 名称',
+    key_prefix VARCHAR(16) NOT NULL COMMENT 'Key 前缀（用于识别，不可用于认证）',
+    key_hash VARCHAR(64) UNIQUE NOT NULL COMMENT 'Key 的 SHA-256 哈希',
+    user_id BIGINT NOT NULL COMMENT '创建者用户 ID',
+    organization_id BIGINT NULL COMMENT '所属组织 ID（组织 Key）',
+    scopes VARCHAR(255) NOT NULL COMMENT '授权范围，逗号分隔',
+    last_used_at DATETIME NULL COMMENT '最后使用时间',
+    revoked_at DATETIME NULL COMMENT '吊销时间',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    INDEX idx_user_id (user_id),
+    INDEX idx_organization (organization_id),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (organization_id) REFERENCES organizations(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='API Key 表';
+
+-- 2. API Key 审计日志
+CREATE TABLE IF NOT EXISTS api_key_audit_logs (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    api_key_id BIGINT NOT NULL COMMENT 'API Key ID',
+    method VARCHAR(10) NOT NULL COMMENT 'HTTP 方法',
+    path VARCHAR(255) NOT NULL COMMENT '请求路径',
+    status_code INT NOT NULL COMMENT '响应状态码',
+    ip_address VARCHAR(45) NULL COMMENT 'IP 地址',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_api_key_created (api_key_id, created_at),
+    FOREIGN KEY (api_key_id) REFERENCES api_keys(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='API Key 审计日志';
+
+SELECT '✅ Migration 009: API Key 表已创建' AS status;
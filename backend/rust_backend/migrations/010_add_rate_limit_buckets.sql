@@ -0,0 +1,13 @@
+-- 限流令牌桶状态表（RATE_LIMIT_BACKEND=database 时使用）
+-- 说明: 多实例部署时共享限流状态；空闲超过最长补满窗口的行可以直接删除
+
+USE resume_vault_sui;
+
+CREATE TABLE IF NOT EXISTS rate_limit_buckets (
+    bucket_key VARCHAR(191) PRIMARY KEY COMMENT '桶标识: 路由组 + IP / 钱包 / API Key',
+    tokens DOUBLE NOT NULL COMMENT '剩余令牌数',
+    updated_at_ms BIGINT NOT NULL COMMENT '上次更新时间（毫秒时间戳）',
+    INDEX idx_updated_at (updated_at_ms)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='限流令牌桶表';
+
+SELECT '✅ Migration 010: 限流令牌桶表已创建' AS status;
@@ -0,0 +1,32 @@
+-- 钱包地址规范化
+-- 说明: Sui 地址统一为小写、0x 前缀、64 位十六进制（左侧补零），与服务端 Validator::normalize_sui_address 一致
+
+USE resume_vault_sui;
+
+-- 1. 规范化已有的 Sui 地址
+UPDATE users
+SET wallet_address = CONCAT('0x', LPAD(LOWER(SUBSTRING(wallet_address, 3)), 64, '0'))
+WHERE wallet_address REGEXP '^0[xX][0-9a-fA-F]{1,64}$';
+
+UPDATE resumes
+SET owner_wallet = CONCAT('0x', LPAD(LOWER(SUBSTRING(owner_wallet, 3)), 64, '0'))
+WHERE owner_wallet REGEXP '^0[xX][0-9a-fA-F]{1,64}$';
+
+UPDATE unlock_records
+SET buyer_wallet = CONCAT('0x', LPAD(LOWER(SUBSTRING(buyer_wallet, 3)), 64, '0'))
+WHERE buyer_wallet REGEXP '^0[xX][0-9a-fA-F]{1,64}$';
+
+UPDATE unlock_records
+SET seller_wallet = CONCAT('0x', LPAD(LOWER(SUBSTRING(seller_wallet, 3)), 64, '0'))
+WHERE seller_wallet REGEXP '^0[xX][0-9a-fA-F]{1,64}$';
+
+UPDATE access_logs
+SET accessor_address = CONCAT('0x', LPAD(LOWER(SUBSTRING(accessor_address, 3)), 64, '0'))
+WHERE accessor_address REGEXP '^0[xX][0-9a-fA-F]{1,64}$';
+
+-- 2. 列出无法规范化的用户（历史上由任意字符串自动创建），需人工确认后清理
+SELECT id, wallet_address, created_at
+FROM users
+WHERE wallet_address NOT REGEXP '^0x[0-9a-f]{64}$';
+
+SELECT '✅ Migration 011: 钱包地址已规范化' AS status;
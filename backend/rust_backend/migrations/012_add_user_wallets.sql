@@ -0,0 +1,33 @@
+-- 一个用户关联多条链上的多个钱包
+-- 说明: users.wallet_address 保留为主钱包（登录用的 Sui 地址），其余地址通过签名证明后关联
+
+USE resume_vault_sui;
+
+-- 1. 用户钱包表
+CREATE TABLE IF NOT EXISTS user_wallets (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    user_id BIGINT NOT NULL COMMENT '用户 ID',
+    chain VARCHAR(20) NOT NULL COMMENT '链: sui / solana',
+    address VARCHAR(100) NOT NULL COMMENT '规范化后的钱包地址',
+    is_primary BOOLEAN NOT NULL DEFAULT FALSE COMMENT '是否为主钱包（users.wallet_address）',
+    verified_at DATETIME NOT NULL COMMENT '签名验证时间',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE KEY uk_chain_address (chain, address),
+    INDEX idx_user_id (user_id),
+    INDEX idx_address (address),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='用户钱包表';
+
+-- 2. 已有用户的主钱包
+INSERT IGNORE INTO user_wallets (user_id, chain, address, is_primary, verified_at, created_at)
+SELECT id, 'sui', wallet_address, TRUE, created_at, created_at
+FROM users
+WHERE wallet_address REGEXP '^0x[0-9a-f]{64}$';
+
+-- 3. 签名挑战区分用途（登录 / 关联钱包）
+ALTER TABLE auth_nonces
+    ADD COLUMN purpose VARCHAR(20) NOT NULL DEFAULT 'login' COMMENT '用途: login / link_wallet' AFTER message,
+    ADD COLUMN chain VARCHAR(20) NOT NULL DEFAULT 'sui' COMMENT '签名钱包所在链' AFTER purpose,
+    ADD COLUMN user_id BIGINT NULL COMMENT '关联钱包时发起请求的用户 ID' AFTER chain;
+
+SELECT '✅ Migration 012: 用户多钱包关联已创建' AS status;
@@ -0,0 +1,13 @@
+-- 会话设备信息与滑动续期
+-- 说明: 登录时记录设备标签、IP、User-Agent；每次请求刷新 last_seen_at 并顺延过期时间（不超过最长有效期）
+
+USE resume_vault_sui;
+
+ALTER TABLE auth_sessions
+    ADD COLUMN device_label VARCHAR(100) NULL COMMENT '设备标签（客户端登录时提供）' AFTER wallet_address,
+    ADD COLUMN ip_address VARCHAR(45) NULL COMMENT '登录 IP' AFTER device_label,
+    ADD COLUMN user_agent VARCHAR(255) NULL COMMENT '登录 User-Agent' AFTER ip_address,
+    ADD COLUMN last_seen_at DATETIME NULL COMMENT '最后活跃时间' AFTER user_agent,
+    ADD INDEX idx_user_active (user_id, revoked_at, expires_at);
+
+SELECT '✅ Migration 013: 会话设备信息已添加' AS status;
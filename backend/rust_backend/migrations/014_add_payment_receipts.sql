@@ -0,0 +1,30 @@
+-- 付费解锁的服务端签名收据
+-- 说明: payload 为签名时的原始 JSON 文本，signature 为 Ed25519 签名（base64），
+--       可用 GET /api/receipts/public-key 公布的公钥离线验证
+
+USE resume_vault_sui;
+
+CREATE TABLE IF NOT EXISTS payment_receipts (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    receipt_id VARCHAR(36) UNIQUE NOT NULL COMMENT '收据 ID (UUID)',
+    unlock_record_id BIGINT UNIQUE NOT NULL COMMENT '对应的解锁记录',
+    resume_id VARCHAR(64) NOT NULL COMMENT '简历 ID',
+    buyer_id BIGINT NOT NULL COMMENT '购买者用户 ID',
+    seller_id BIGINT NOT NULL COMMENT '卖家（简历所有者）用户 ID',
+    buyer_wallet VARCHAR(100) NOT NULL COMMENT '购买者钱包地址',
+    seller_wallet VARCHAR(100) NOT NULL COMMENT '卖家钱包地址',
+    amount BIGINT NOT NULL COMMENT '支付金额（代币最小单位）',
+    asset VARCHAR(100) NOT NULL COMMENT '支付代币',
+    network VARCHAR(50) NOT NULL COMMENT '支付网络',
+    transaction_digest VARCHAR(150) NOT NULL COMMENT '结算交易摘要',
+    payload TEXT NOT NULL COMMENT '被签名的收据 JSON',
+    signature VARCHAR(128) NOT NULL COMMENT 'Ed25519 签名 (base64)',
+    key_id VARCHAR(16) NOT NULL COMMENT '签名公钥 ID',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_buyer_id (buyer_id),
+    INDEX idx_seller_id (seller_id),
+    INDEX idx_transaction (transaction_digest),
+    FOREIGN KEY (unlock_record_id) REFERENCES unlock_records(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='付费解锁收据表';
+
+SELECT '✅ Migration 014: 付费解锁收据表已创建' AS status;
@@ -0,0 +1,41 @@
+-- 平台佣金与卖家结算账本
+-- 说明: x402 付费解锁的款项先进入平台收款地址，每笔解锁按佣金规则记录 gross / fee / net，
+--       管理员把未结算的账目按卖家打包成结算批次，链上打款后标记为已支付
+
+USE resume_vault_sui;
+
+-- 1. 结算批次
+CREATE TABLE IF NOT EXISTS seller_payouts (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    seller_id BIGINT NOT NULL COMMENT '卖家用户 ID',
+    seller_wallet VARCHAR(100) NOT NULL COMMENT '打款钱包地址',
+    amount BIGINT NOT NULL COMMENT '应付金额（net 合计）',
+    entry_count INT NOT NULL COMMENT '包含的账目数',
+    status VARCHAR(20) NOT NULL DEFAULT 'pending' COMMENT 'pending / paid',
+    transaction_digest VARCHAR(150) NULL COMMENT '打款交易摘要',
+    paid_at DATETIME NULL COMMENT '打款时间',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_seller_status (seller_id, status),
+    FOREIGN KEY (seller_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='卖家结算批次表';
+
+-- 2. 每笔解锁的佣金账目
+CREATE TABLE IF NOT EXISTS seller_ledger_entries (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    unlock_record_id BIGINT UNIQUE NOT NULL COMMENT '对应的解锁记录',
+    seller_id BIGINT NOT NULL COMMENT '卖家用户 ID',
+    seller_wallet VARCHAR(100) NOT NULL COMMENT '卖家钱包地址',
+    resume_id BIGINT NOT NULL COMMENT '简历 ID',
+    gross_amount BIGINT NOT NULL COMMENT '买家支付金额',
+    fee_amount BIGINT NOT NULL COMMENT '平台佣金',
+    net_amount BIGINT NOT NULL COMMENT '卖家应得',
+    payout_id BIGINT NULL COMMENT '所属结算批次，NULL 表示未结算',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_seller_payout (seller_id, payout_id),
+    INDEX idx_payout_id (payout_id),
+    FOREIGN KEY (unlock_record_id) REFERENCES unlock_records(id) ON DELETE CASCADE,
+    FOREIGN KEY (seller_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (payout_id) REFERENCES seller_payouts(id) ON DELETE SET NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='卖家佣金账本';
+
+SELECT '✅ Migration 015: 佣金账本和结算批次表已创建' AS status;
@@ -0,0 +1,38 @@
+-- 金额统一记录资产
+-- 说明: 所有金额均为对应资产的最小单位整数（USDC 6 位精度，SUI / SOL 9 位精度），
+--       资产以符号记录（USDC / SUI / SOL），精度、所在链和链上标识由后端资产注册表提供
+--
+-- 历史数据:
+--   * 简历价格此前由前端以 SUI 的 MIST 设置（price * 1e9），价格大于 0 的旧简历记为 SUI，
+--     需要所有者以 USDC 重新定价后才能通过 x402 解锁
+--   * 带收据的解锁记录经 x402 结算，记为 USDC；免费解锁记为 USDC；
+--     其余旧解锁记录由前端在 Sui 上支付后上报，记为 SUI
+--   * 佣金账本和结算批次只来自 x402 结算，记为 USDC
+
+USE resume_vault_sui;
+
+-- 1. 简历价格
+ALTER TABLE resumes
+    MODIFY COLUMN price BIGINT NOT NULL DEFAULT 0 COMMENT '解锁价格（price_asset 的最小单位）',
+    ADD COLUMN price_asset VARCHAR(20) NOT NULL DEFAULT 'USDC' COMMENT '价格资产符号' AFTER price;
+
+UPDATE resumes SET price_asset = 'SUI' WHERE price > 0;
+
+-- 2. 解锁记录金额
+ALTER TABLE unlock_records
+    MODIFY COLUMN amount BIGINT NOT NULL COMMENT '支付金额（asset 的最小单位）',
+    ADD COLUMN asset VARCHAR(20) NOT NULL DEFAULT 'USDC' COMMENT '支付资产符号' AFTER amount;
+
+UPDATE unlock_records u
+SET u.asset = 'SUI'
+WHERE u.transaction_signature NOT LIKE 'free:%'
+  AND NOT EXISTS (SELECT 1 FROM payment_receipts r WHERE r.unlock_record_id = u.id);
+
+-- 3. 佣金账本和结算批次
+ALTER TABLE seller_ledger_entries
+    ADD COLUMN asset VARCHAR(20) NOT NULL DEFAULT 'USDC' COMMENT '资产符号' AFTER resume_id;
+
+ALTER TABLE seller_payouts
+    ADD COLUMN asset VARCHAR(20) NOT NULL DEFAULT 'USDC' COMMENT '资产符号' AFTER seller_wallet;
+
+SELECT '✅ Migration 016: 金额资产字段已添加' AS status;
@@ -0,0 +1,33 @@
+-- 简历分级定价
+-- 说明: 简历可以定义多个价格档位（如 profile / contact），每个档位对应独立加密的 blob 或 Seal 身份；
+--       简历本身（resumes.price / blob_id）始终作为最高档位 full。
+--       档位按 level 从低到高包含，买家从低档升级时只需支付差价，每次购买（含升级）一条解锁记录
+
+USE resume_vault_sui;
+
+-- 1. 档位表
+CREATE TABLE IF NOT EXISTS resume_tiers (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    resume_id BIGINT NOT NULL COMMENT '简历主键',
+    tier VARCHAR(32) NOT NULL COMMENT '档位名称，如 profile / contact',
+    level INT NOT NULL COMMENT '档位等级，越高包含的内容越多',
+    price BIGINT NOT NULL COMMENT '档位价格（price_asset 的最小单位）',
+    price_asset VARCHAR(20) NOT NULL COMMENT '价格资产符号，与简历价格一致',
+    blob_id VARCHAR(150) NOT NULL COMMENT '该档位内容的 Walrus Blob ID',
+    encryption_type VARCHAR(20) NOT NULL COMMENT 'simple / seal',
+    encryption_id VARCHAR(150) NULL COMMENT 'Seal 加密 ID',
+    policy_object_id VARCHAR(100) NULL COMMENT 'Seal Policy Object ID',
+    encryption_key TEXT NULL COMMENT '简单加密模式的密钥',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    UNIQUE KEY uk_resume_tier (resume_id, tier),
+    UNIQUE KEY uk_resume_level (resume_id, level),
+    FOREIGN KEY (resume_id) REFERENCES resumes(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='简历价格档位表';
+
+-- 2. 解锁记录记录购买的档位，历史记录均为完整简历
+ALTER TABLE unlock_records
+    ADD COLUMN tier VARCHAR(32) NOT NULL DEFAULT 'full' COMMENT '购买的档位' AFTER asset,
+    ADD INDEX idx_resume_buyer (resume_id, buyer_id);
+
+SELECT '✅ Migration 017: 简历价格档位已添加' AS status;
@@ -0,0 +1,33 @@
+-- 解锁记录的争议与退款
+-- 说明: 解锁记录状态机 pending → confirmed / failed，confirmed → disputed → refunded / rejected。
+--       买家对已确认的解锁发起争议，管理员裁决退款或驳回；退款后收回解锁权益，
+--       并在卖家账本中写入一笔冲正账目（同一解锁记录可以有 sale 和 refund 两条账目）
+
+USE resume_vault_sui;
+
+-- 1. 争议表
+CREATE TABLE IF NOT EXISTS unlock_disputes (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    unlock_record_id BIGINT UNIQUE NOT NULL COMMENT '争议的解锁记录',
+    opened_by BIGINT NOT NULL COMMENT '发起争议的用户 ID',
+    reason TEXT NOT NULL COMMENT '争议原因',
+    evidence TEXT NOT NULL COMMENT '证据（JSON 字符串数组）',
+    status VARCHAR(20) NOT NULL DEFAULT 'open' COMMENT 'open / refunded / rejected',
+    resolution_note TEXT NULL COMMENT '裁决说明',
+    resolved_by BIGINT NULL COMMENT '裁决的管理员用户 ID',
+    refund_transaction VARCHAR(150) NULL COMMENT '退款交易摘要',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    resolved_at DATETIME NULL,
+    INDEX idx_status (status),
+    FOREIGN KEY (unlock_record_id) REFERENCES unlock_records(id) ON DELETE CASCADE,
+    FOREIGN KEY (opened_by) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='解锁争议表';
+
+-- 2. 账目类型：sale(解锁收入) / refund(退款冲正)
+ALTER TABLE seller_ledger_entries
+    ADD COLUMN entry_type VARCHAR(20) NOT NULL DEFAULT 'sale' COMMENT 'sale / refund' AFTER unlock_record_id,
+    ADD UNIQUE KEY uk_unlock_entry_type (unlock_record_id, entry_type);
+
+ALTER TABLE seller_ledger_entries DROP INDEX unlock_record_id;
+
+SELECT '✅ Migration 018: 解锁争议表已创建，卖家账本支持退款冲正' AS status;
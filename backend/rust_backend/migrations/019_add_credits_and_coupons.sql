@@ -0,0 +1,81 @@
+-- 预付额度、额度套餐和优惠码
+-- 说明: 招聘方通过 x402 购买额度套餐充值到个人或组织的额度账户，解锁简历时可以用额度支付；
+--       每笔充值、扣减和退回都记入额度流水。优惠码（按百分比或固定金额减免）在结算时使用，
+--       支持总使用次数、每人使用次数和过期时间限制
+
+USE resume_vault_sui;
+
+-- 1. 额度账户（每个用户或组织每种资产一个）
+CREATE TABLE IF NOT EXISTS credit_accounts (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    owner_type VARCHAR(20) NOT NULL COMMENT 'user / organization',
+    owner_id BIGINT NOT NULL COMMENT '用户 ID 或组织 ID',
+    asset VARCHAR(20) NOT NULL COMMENT '资产符号',
+    balance BIGINT NOT NULL DEFAULT 0 COMMENT '余额（资产最小单位）',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    UNIQUE KEY uk_owner_asset (owner_type, owner_id, asset)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='额度账户表';
+
+-- 2. 额度套餐（管理员维护）
+CREATE TABLE IF NOT EXISTS credit_bundles (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    name VARCHAR(100) NOT NULL COMMENT '套餐名称',
+    price BIGINT NOT NULL COMMENT '售价（资产最小单位）',
+    credits BIGINT NOT NULL COMMENT '到账额度（不少于售价，差额为赠送）',
+    asset VARCHAR(20) NOT NULL COMMENT '资产符号',
+    active BOOLEAN NOT NULL DEFAULT TRUE COMMENT '是否在售',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='额度套餐表';
+
+-- 3. 额度流水
+CREATE TABLE IF NOT EXISTS credit_transactions (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    account_id BIGINT NOT NULL COMMENT '额度账户',
+    kind VARCHAR(20) NOT NULL COMMENT 'topup(充值) / unlock(解锁扣减) / refund(退款退回)',
+    amount BIGINT NOT NULL COMMENT '变动金额，扣减为负',
+    balance_after BIGINT NOT NULL COMMENT '变动后余额',
+    bundle_id BIGINT NULL COMMENT '充值的套餐',
+    unlock_record_id BIGINT NULL COMMENT '关联的解锁记录',
+    reference VARCHAR(150) NULL COMMENT '充值的结算交易摘要',
+    created_by BIGINT NOT NULL COMMENT '操作用户 ID',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_account_created (account_id, created_at),
+    INDEX idx_unlock_record (unlock_record_id),
+    UNIQUE KEY uk_kind_reference (kind, reference),
+    FOREIGN KEY (account_id) REFERENCES credit_accounts(id) ON DELETE CASCADE,
+    FOREIGN KEY (bundle_id) REFERENCES credit_bundles(id) ON DELETE SET NULL,
+    FOREIGN KEY (unlock_record_id) REFERENCES unlock_records(id) ON DELETE SET NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='额度流水表';
+
+-- 4. 优惠码
+CREATE TABLE IF NOT EXISTS coupons (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    code VARCHAR(50) UNIQUE NOT NULL COMMENT '优惠码（大写）',
+    discount_type VARCHAR(20) NOT NULL COMMENT 'percent / fixed',
+    discount_value BIGINT NOT NULL COMMENT '百分比 (1-100) 或固定减免金额（资产最小单位）',
+    asset VARCHAR(20) NULL COMMENT '固定减免的资产，percent 为 NULL',
+    max_redemptions INT NULL COMMENT '总使用次数上限，NULL 表示不限',
+    max_per_user INT NULL COMMENT '每人使用次数上限，NULL 表示不限',
+    redeemed_count INT NOT NULL DEFAULT 0 COMMENT '已使用次数',
+    expires_at DATETIME NULL COMMENT '过期时间',
+    active BOOLEAN NOT NULL DEFAULT TRUE COMMENT '是否可用',
+    created_by BIGINT NOT NULL COMMENT '创建的管理员用户 ID',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='优惠码表';
+
+-- 5. 优惠码使用记录
+CREATE TABLE IF NOT EXISTS coupon_redemptions (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    coupon_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    unlock_record_id BIGINT NOT NULL,
+    discount BIGINT NOT NULL COMMENT '减免金额',
+    asset VARCHAR(20) NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_coupon_user (coupon_id, user_id),
+    FOREIGN KEY (coupon_id) REFERENCES coupons(id) ON DELETE CASCADE,
+    FOREIGN KEY (unlock_record_id) REFERENCES unlock_records(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='优惠码使用记录表';
+
+SELECT '✅ Migration 019: 额度账户、套餐、流水和优惠码表已创建' AS status;
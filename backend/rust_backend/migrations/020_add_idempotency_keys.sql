@@ -0,0 +1,24 @@
+-- 幂等键
+-- 说明: 支付相关的 POST 可以带 Idempotency-Key 请求头。同一用户同一个键的第一次响应被保存，
+--       相同请求的重试直接重放该响应；同一个键用于不同请求体时返回 409。超过保留期的键可以直接删除
+
+USE resume_vault_sui;
+
+CREATE TABLE IF NOT EXISTS idempotency_keys (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    user_id BIGINT NOT NULL COMMENT '发起请求的用户 ID',
+    idempotency_key VARCHAR(100) NOT NULL COMMENT '客户端提供的幂等键',
+    method VARCHAR(10) NOT NULL COMMENT '请求方法',
+    path VARCHAR(255) NOT NULL COMMENT '请求路径（含查询参数）',
+    request_hash CHAR(64) NOT NULL COMMENT '方法、路径和请求体的 SHA-256 哈希',
+    status VARCHAR(20) NOT NULL DEFAULT 'processing' COMMENT 'processing(处理中) / completed(已保存响应)',
+    response_status INT NULL COMMENT '保存的响应状态码',
+    response_headers TEXT NULL COMMENT '需要重放的响应头（JSON）',
+    response_body LONGTEXT NULL COMMENT '保存的响应体',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    completed_at DATETIME NULL,
+    UNIQUE KEY uk_user_key (user_id, idempotency_key),
+    INDEX idx_created_at (created_at)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='幂等键表';
+
+SELECT '✅ Migration 020: 幂等键表已创建' AS status;
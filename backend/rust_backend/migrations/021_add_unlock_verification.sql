@@ -0,0 +1,12 @@
+-- 解锁记录的链上校验
+-- 说明: 客户端提交的解锁记录先以 pending 写入，后端按交易摘要从 Sui RPC 查询交易，
+--       确认交易成功且买家向卖家转账不少于简历价格（或为简历的服务创建了 Subscription）后转为 confirmed，
+--       校验不通过时转为 failed 并记录原因
+
+USE resume_vault_sui;
+
+ALTER TABLE unlock_records
+    ADD COLUMN failure_reason VARCHAR(500) NULL COMMENT '链上校验失败的原因' AFTER status,
+    ADD COLUMN verified_at DATETIME NULL COMMENT '链上校验完成时间' AFTER failure_reason;
+
+SELECT '✅ Migration 021: 解锁记录链上校验字段已添加' AS status;
@@ -0,0 +1,41 @@
+-- 链上 allowlist 事件索引
+-- 说明: 后台索引任务轮询 allowlist 合约事件：AllowlistCreated 记录创建者，AccountAdded / AccountRemoved
+--       同步 allowlist_members，BlobPublished 按 blob ID 把 allowlist 关联到简历。
+--       成员可能在 allowlist 关联简历之前加入，因此 allowlist_members.resume_id 改为可空，关联后回填
+
+USE resume_vault_sui;
+
+-- 1. 链上创建的 allowlist
+CREATE TABLE IF NOT EXISTS allowlists (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    allowlist_id VARCHAR(100) UNIQUE NOT NULL COMMENT 'Allowlist 对象 ID',
+    name VARCHAR(255) NOT NULL COMMENT '链上名称',
+    creator VARCHAR(100) NOT NULL COMMENT '创建者地址',
+    resume_id BIGINT NULL COMMENT '关联的简历 ID',
+    tx_digest VARCHAR(150) NOT NULL COMMENT '创建交易摘要',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    INDEX idx_creator (creator),
+    INDEX idx_resume_id (resume_id),
+    FOREIGN KEY (resume_id) REFERENCES resumes(id) ON DELETE SET NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='Allowlist 表';
+
+-- 2. 发布到 allowlist 的 blob
+CREATE TABLE IF NOT EXISTS allowlist_blobs (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    allowlist_id VARCHAR(100) NOT NULL COMMENT 'Allowlist 对象 ID',
+    blob_id VARCHAR(150) NOT NULL COMMENT 'Walrus blob ID',
+    publisher VARCHAR(100) NOT NULL COMMENT '发布者地址',
+    resume_id BIGINT NULL COMMENT '按 blob ID 匹配到的简历 ID',
+    tx_digest VARCHAR(150) NOT NULL COMMENT '发布交易摘要',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE KEY uk_allowlist_blob (allowlist_id, blob_id),
+    INDEX idx_blob_id (blob_id),
+    FOREIGN KEY (resume_id) REFERENCES resumes(id) ON DELETE SET NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='Allowlist blob 表';
+
+-- 3. 成员在 allowlist 关联简历之前可能已经加入
+ALTER TABLE allowlist_members
+    MODIFY COLUMN resume_id BIGINT NULL COMMENT '关联的简历 ID（allowlist 尚未关联简历时为 NULL）';
+
+SELECT '✅ Migration 022: allowlist 索引表已创建' AS status;
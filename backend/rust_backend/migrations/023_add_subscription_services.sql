@@ -0,0 +1,24 @@
+-- 链上订阅事件索引
+-- 说明: 后台索引任务轮询 subscription 合约事件：ServiceCreated 同步服务的订阅费和有效期，
+--       SubscriptionCreated 为 policy_object_id 匹配该服务的简历自动写入 confirmed 解锁记录
+--       （以交易摘要去重，买家浏览器在调用 POST /api/unlock-records 之前崩溃也不会漏记）
+
+USE resume_vault_sui;
+
+CREATE TABLE IF NOT EXISTS subscription_services (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    service_id VARCHAR(100) UNIQUE NOT NULL COMMENT '订阅服务对象 ID',
+    name VARCHAR(255) NOT NULL COMMENT '链上名称',
+    fee BIGINT NOT NULL COMMENT '订阅费（MIST）',
+    ttl_ms BIGINT NOT NULL COMMENT '订阅有效期（毫秒）',
+    owner VARCHAR(100) NOT NULL COMMENT '服务所有者（收款）地址',
+    resume_id BIGINT NULL COMMENT '以该服务为策略的简历 ID',
+    tx_digest VARCHAR(150) NOT NULL COMMENT '创建交易摘要',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    INDEX idx_owner (owner),
+    INDEX idx_resume_id (resume_id),
+    FOREIGN KEY (resume_id) REFERENCES resumes(id) ON DELETE SET NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='订阅服务表';
+
+SELECT '✅ Migration 023: subscription_services 表已创建' AS status;
@@ -0,0 +1,11 @@
+-- Allowlist 成员管理
+-- 说明: 简历所有者提交 allowlist::add 交易后先以 pending 记录成员（附交易摘要），
+--       索引到 AccountAdded 事件后转为 active；移除时记录移除交易摘要并标记为 removed
+
+USE resume_vault_sui;
+
+ALTER TABLE allowlist_members
+    MODIFY COLUMN tx_digest VARCHAR(150) NOT NULL COMMENT '最近一次添加 / 移除交易摘要',
+    MODIFY COLUMN status VARCHAR(20) NOT NULL DEFAULT 'active' COMMENT '状态: pending(已提交添加交易), active, removed';
+
+SELECT '✅ Migration 024: allowlist_members 支持 pending 状态' AS status;
@@ -0,0 +1,33 @@
+-- 链上事件索引检查点
+-- 说明: 索引器按 (合约包, 事件类型) 持久化最后处理的事件游标，重启后从游标继续；
+--       已处理的事件按 (交易摘要, 事件序号) 记录，重放或回填时跳过，保证每个事件只生效一次。
+--       事件类型按模块订阅（如 allowlist），以保证同一模块内的事件按链上顺序处理
+
+USE resume_vault_sui;
+
+-- 1. 每个事件流的游标
+CREATE TABLE IF NOT EXISTS indexer_cursors (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    package_id VARCHAR(100) NOT NULL COMMENT '合约包 ID',
+    event_type VARCHAR(100) NOT NULL COMMENT '事件类型（模块名）',
+    tx_digest VARCHAR(150) NULL COMMENT '最后处理的事件所在交易',
+    event_seq VARCHAR(20) NULL COMMENT '最后处理的事件在交易中的序号',
+    last_event_timestamp_ms BIGINT NULL COMMENT '最后处理的事件的链上时间（毫秒）',
+    caught_up_at DATETIME NULL COMMENT '最近一次追上链上最新事件的时间',
+    last_error VARCHAR(500) NULL COMMENT '最近一次轮询的错误',
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    UNIQUE KEY uk_package_event_type (package_id, event_type)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='索引器游标表';
+
+-- 2. 已处理的事件
+CREATE TABLE IF NOT EXISTS indexer_events (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    package_id VARCHAR(100) NOT NULL COMMENT '合约包 ID',
+    event_type VARCHAR(100) NOT NULL COMMENT '事件类型（模块名）',
+    tx_digest VARCHAR(150) NOT NULL COMMENT '交易摘要',
+    event_seq VARCHAR(20) NOT NULL COMMENT '事件在交易中的序号',
+    processed_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE KEY uk_tx_event (tx_digest, event_seq)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='索引器已处理事件表';
+
+SELECT '✅ Migration 025: 索引器游标表已创建' AS status;
@@ -0,0 +1,36 @@
+-- x402 结算意向
+-- 说明: 付费解锁先在短事务中写入 pending 的结算意向，再在不持有行锁的情况下调用 facilitator 结算，
+--       结算成功后转为 settled 并记录交易摘要，写入解锁记录后转为 completed；结算失败转为 failed。
+--       结算成功但写入解锁记录失败的意向停留在 settled，由后台对账任务重试，支付不会丢失
+
+USE resume_vault_sui;
+
+CREATE TABLE IF NOT EXISTS payment_settlements (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    purpose VARCHAR(20) NOT NULL COMMENT '用途: unlock(付费解锁)',
+    user_id BIGINT NOT NULL COMMENT '付款用户 ID',
+    wallet VARCHAR(100) NOT NULL COMMENT '付款用户钱包',
+    organization_id BIGINT NULL COMMENT '代表组织购买时的组织 ID',
+    resume_id BIGINT NULL COMMENT '解锁的简历',
+    tier VARCHAR(50) NULL COMMENT '解锁的档位',
+    upgrade BOOLEAN NOT NULL DEFAULT FALSE COMMENT '是否为从低档升级（不增加解锁次数）',
+    coupon_id BIGINT NULL COMMENT '使用的优惠码',
+    discount BIGINT NOT NULL DEFAULT 0 COMMENT '优惠码减免金额',
+    amount BIGINT NOT NULL COMMENT '应付金额（资产最小单位）',
+    asset VARCHAR(20) NOT NULL COMMENT '资产符号',
+    pay_asset VARCHAR(100) NOT NULL COMMENT 'x402 支付代币地址',
+    network VARCHAR(50) NOT NULL COMMENT 'x402 网络',
+    status VARCHAR(20) NOT NULL DEFAULT 'pending' COMMENT 'pending / settled / completed / failed',
+    transaction VARCHAR(150) NULL COMMENT '结算交易摘要',
+    payer VARCHAR(100) NULL COMMENT 'facilitator 识别出的付款地址',
+    unlock_record_id BIGINT NULL COMMENT '完成后写入的解锁记录',
+    error VARCHAR(500) NULL COMMENT '结算或写入失败的原因',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    UNIQUE KEY uk_transaction (transaction),
+    INDEX idx_status_updated (status, updated_at),
+    FOREIGN KEY (resume_id) REFERENCES resumes(id) ON DELETE SET NULL,
+    FOREIGN KEY (unlock_record_id) REFERENCES unlock_records(id) ON DELETE SET NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='x402 结算意向表';
+
+SELECT '✅ Migration 026: 结算意向表已创建' AS status;
@@ -0,0 +1,19 @@
+-- 额度套餐购买的结算意向
+-- 说明: x402 购买额度套餐与付费解锁一样，先写入 pending 的结算意向再结算，
+--       结算成功后在同一事务中给额度账户加额度并把意向转为 completed；
+--       加额度失败的意向停留在 settled，由后台对账任务重试
+
+USE resume_vault_sui;
+
+ALTER TABLE payment_settlements
+    MODIFY COLUMN purpose VARCHAR(20) NOT NULL COMMENT '用途: unlock(付费解锁) / credit_topup(购买额度套餐)',
+    ADD COLUMN bundle_id BIGINT NULL COMMENT '购买的额度套餐' AFTER discount,
+    ADD COLUMN credits BIGINT NULL COMMENT '购买时套餐的到账额度' AFTER bundle_id,
+    ADD COLUMN credit_owner_type VARCHAR(20) NULL COMMENT '充值的额度账户所有者类型: user / organization' AFTER credits,
+    ADD COLUMN credit_owner_id BIGINT NULL COMMENT '充值的额度账户所有者 ID' AFTER credit_owner_type,
+    ADD COLUMN credit_transaction_id BIGINT NULL COMMENT '完成后写入的额度流水' AFTER unlock_record_id,
+    ADD CONSTRAINT fk_payment_settlements_bundle FOREIGN KEY (bundle_id) REFERENCES credit_bundles(id) ON DELETE SET NULL,
+    ADD CONSTRAINT fk_payment_settlements_credit_transaction
+        FOREIGN KEY (credit_transaction_id) REFERENCES credit_transactions(id) ON DELETE SET NULL;
+
+SELECT '✅ Migration 027: 结算意向支持额度套餐购买' AS status;
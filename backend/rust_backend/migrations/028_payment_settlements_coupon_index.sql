@@ -0,0 +1,10 @@
+-- 结算意向按优惠码统计
+-- 说明: x402 支付的优惠码在结算完成后才写入使用记录，检查优惠码使用次数时
+--       需要统计使用该优惠码、仍为 pending / settled 的结算意向
+
+USE resume_vault_sui;
+
+ALTER TABLE payment_settlements
+    ADD INDEX idx_payment_settlements_coupon (coupon_id, status, user_id);
+
+SELECT '✅ Migration 028: 结算意向优惠码索引' AS status;
@@ -0,0 +1,32 @@
+use actix_web::{http::StatusCode, HttpResponse, ResponseError};
+use crate::models::ApiResponse;
+
+/// 认证/鉴权错误，统一以 `ApiResponse` 格式返回
+#[derive(Debug, thiserror::Error)]
+pub enum AuthError {
+    /// 未登录或会话无效 (401)
+    #[error("{0}")]
+    Unauthorized(String),
+
+    /// 已登录但无权限 (403)
+    #[error("{0}")]
+    Forbidden(String),
+
+    /// 内部错误 (500)
+    #[error("{0}")]
+    Internal(String),
+}
+
+impl ResponseError for AuthError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            AuthError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AuthError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AuthError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(ApiResponse::<()>::error(self.to_string()))
+    }
+}
@@ -0,0 +1,153 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use actix_web::{dev::Payload, web, FromRequest, HttpMessage, HttpRequest};
+use sea_orm::DatabaseConnection;
+
+use crate::auth::AuthError;
+use crate::dao::UserDao;
+use crate::entities::{api_key, auth_session, user};
+use crate::models::AuthConfig;
+use crate::services::{ApiKeyService, AuthService, OrganizationService};
+
+/// API Key 请求头
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// 调用者使用的凭证
+#[derive(Debug, Clone)]
+pub enum Credential {
+    /// 钱包签名登录得到的会话
+    Session(auth_session::Model),
+    /// 服务端调用使用的 API Key
+    ApiKey(api_key::Model),
+}
+
+/// 已认证的调用者：由 `Authorization: Bearer <token>` 会话或 `X-API-Key` 解析出的凭证和用户
+///
+/// 作为 handler 参数使用时，未登录的请求会直接返回 401。
+#[derive(Debug, Clone)]
+pub struct AuthenticatedWallet {
+    pub credential: Credential,
+    pub user: user::Model,
+    /// 调用钱包是否在 `ADMIN_WALLETS` 中
+    pub is_admin: bool,
+}
+
+impl AuthenticatedWallet {
+    /// 已验证的钱包地址（API Key 使用创建者的钱包）
+    pub fn wallet(&self) -> &str {
+        match &self.credential {
+            Credential::Session(session) => &session.wallet_address,
+            Credential::ApiKey(_) => &self.user.wallet_address,
+        }
+    }
+
+    /// 钱包会话（API Key 调用时为 None）
+    pub fn session(&self) -> Option<&auth_session::Model> {
+        match &self.credential {
+            Credential::Session(session) => Some(session),
+            Credential::ApiKey(_) => None,
+        }
+    }
+
+    /// API Key（会话调用时为 None）
+    pub fn api_key(&self) -> Option<&api_key::Model> {
+        match &self.credential {
+            Credential::ApiKey(key) => Some(key),
+            Credential::Session(_) => None,
+        }
+    }
+
+    /// 组织 Key 所属的组织
+    pub fn organization_id(&self) -> Option<i64> {
+        self.api_key().and_then(|key| key.organization_id)
+    }
+
+    /// 用户 ID
+    pub fn user_id(&self) -> i64 {
+        self.user.id
+    }
+
+    /// 读取 `Authorization: Bearer <token>` 头
+    pub fn bearer_token(req: &HttpRequest) -> Option<String> {
+        req.headers()
+            .get("authorization")
+            .and_then(|h| h.to_str().ok())
+            .and_then(|s| s.strip_prefix("Bearer "))
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+    }
+
+    /// 读取 `X-API-Key` 头
+    pub fn api_key_header(req: &HttpRequest) -> Option<String> {
+        req.headers()
+            .get(API_KEY_HEADER)
+            .and_then(|h| h.to_str().ok())
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+    }
+
+    async fn resolve_session(db: &DatabaseConnection, config: &AuthConfig, token: &str) -> Result<Self, AuthError> {
+        let session = AuthService::authenticate(db, config, token)
+            .await
+            .map_err(AuthError::Unauthorized)?;
+
+        let user = Self::find_user(db, session.user_id).await?;
+        let is_admin = config.admin_wallets.contains(&session.wallet_address);
+
+        Ok(Self { credential: Credential::Session(session), user, is_admin })
+    }
+
+    async fn resolve_api_key(db: &DatabaseConnection, config: &AuthConfig, raw_key: &str) -> Result<Self, AuthError> {
+        let key = ApiKeyService::authenticate(db, raw_key)
+            .await
+            .map_err(AuthError::Unauthorized)?;
+
+        // 组织 Key：创建者离开组织后立即失效
+        if let Some(organization_id) = key.organization_id {
+            OrganizationService::require_member(db, organization_id, key.user_id, false).await?;
+        }
+
+        let user = Self::find_user(db, key.user_id).await?;
+        let is_admin = config.admin_wallets.contains(&user.wallet_address);
+
+        Ok(Self { credential: Credential::ApiKey(key), user, is_admin })
+    }
+
+    async fn find_user(db: &DatabaseConnection, user_id: i64) -> Result<user::Model, AuthError> {
+        UserDao::find_by_id(db, user_id)
+            .await
+            .map_err(|e| AuthError::Internal(format!("Failed to fetch user: {}", e)))?
+            .ok_or_else(|| AuthError::Unauthorized("User not found".to_string()))
+    }
+}
+
+impl FromRequest for AuthenticatedWallet {
+    type Error = AuthError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        // 路由中间件已经解析过的直接复用
+        if let Some(wallet) = req.extensions().get::<AuthenticatedWallet>() {
+            let wallet = wallet.clone();
+            return Box::pin(async move { Ok(wallet) });
+        }
+
+        let db = req.app_data::<web::Data<DatabaseConnection>>().cloned();
+        let config = req
+            .app_data::<web::Data<AuthConfig>>()
+            .map(|c| c.get_ref().clone())
+            .unwrap_or_default();
+        let token = Self::bearer_token(req);
+        let api_key = Self::api_key_header(req);
+
+        Box::pin(async move {
+            let missing_db = || AuthError::Internal("Database not configured".to_string());
+            match (token, api_key) {
+                (Some(token), _) => Self::resolve_session(&*db.ok_or_else(missing_db)?, &config, &token).await,
+                (None, Some(api_key)) => Self::resolve_api_key(&*db.ok_or_else(missing_db)?, &config, &api_key).await,
+                (None, None) => Err(AuthError::Unauthorized("Missing bearer token or API key".to_string())),
+            }
+        })
+    }
+}
@@ -0,0 +1,8 @@
+pub mod error;
+pub mod extractor;
+pub mod permissions;
+pub mod policy;
+
+pub use error::AuthError;
+pub use extractor::AuthenticatedWallet;
+pub use permissions::Permission;
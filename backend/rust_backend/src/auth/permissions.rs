@@ -0,0 +1,133 @@
+use crate::auth::policy::SCOPE_ADMIN_READ;
+use crate::auth::{AuthError, AuthenticatedWallet};
+
+/// 用户角色
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    /// 求职者
+    JobSeeker,
+    /// 招聘者
+    Recruiter,
+}
+
+/// `users.user_type` 的取值
+pub const USER_TYPE_JOB_SEEKER: &str = "job_seeker";
+pub const USER_TYPE_RECRUITER: &str = "recruiter";
+pub const USER_TYPE_BOTH: &str = "both";
+
+/// 需要角色授权的操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    /// 创建简历
+    CreateResume,
+    /// 修改/删除自己的简历、设置价格
+    ManageResume,
+    /// 创建解锁记录（购买简历）
+    CreateUnlockRecord,
+    /// 记录解密/查看行为
+    CreateAccessLog,
+    /// 创建招聘组织
+    CreateOrganization,
+}
+
+/// 权限矩阵：每个操作允许的角色
+const PERMISSION_MATRIX: &[(Permission, &[Role])] = &[
+    (Permission::CreateResume, &[Role::JobSeeker]),
+    (Permission::ManageResume, &[Role::JobSeeker]),
+    (Permission::CreateUnlockRecord, &[Role::Recruiter]),
+    (Permission::CreateAccessLog, &[Role::JobSeeker, Role::Recruiter]),
+    (Permission::CreateOrganization, &[Role::Recruiter]),
+];
+
+/// 解析 `user_type` 为角色列表，未知取值不授予任何角色
+pub fn roles_of(user_type: &str) -> &'static [Role] {
+    match user_type {
+        USER_TYPE_JOB_SEEKER => &[Role::JobSeeker],
+        USER_TYPE_RECRUITER => &[Role::Recruiter],
+        USER_TYPE_BOTH => &[Role::JobSeeker, Role::Recruiter],
+        _ => &[],
+    }
+}
+
+/// 是否为合法的 `user_type`
+pub fn is_valid_user_type(user_type: &str) -> bool {
+    !roles_of(user_type).is_empty()
+}
+
+/// 查询某个 `user_type` 是否拥有权限
+pub fn is_allowed(user_type: &str, permission: Permission) -> bool {
+    let allowed = PERMISSION_MATRIX
+        .iter()
+        .find(|(p, _)| *p == permission)
+        .map(|(_, roles)| *roles)
+        .unwrap_or(&[]);
+
+    roles_of(user_type).iter().any(|role| allowed.contains(role))
+}
+
+impl AuthenticatedWallet {
+    /// 要求当前用户拥有权限，否则返回 403
+    pub fn require(&self, permission: Permission) -> Result<(), AuthError> {
+        if is_allowed(&self.user.user_type, permission) {
+            Ok(())
+        } else {
+            Err(AuthError::Forbidden(format!(
+                "Forbidden: user type '{}' is not allowed to {:?}",
+                self.user.user_type, permission
+            )))
+        }
+    }
+
+    /// 是否可以使用管理员 override
+    ///
+    /// 管理员钱包的会话直接拥有；API Key 还必须显式带有 `admin:read` scope。
+    pub fn has_admin_override(&self) -> bool {
+        self.is_admin && self.api_key().is_none_or(|key| key.has_scope(SCOPE_ADMIN_READ))
+    }
+
+    /// 要求记录属于当前用户（`owner_id` 为记录所有者，None 表示不存在），
+    /// 管理员 override 放行并记录日志，否则返回 403
+    pub fn require_owner(&self, owner_id: Option<i64>, resource: &str) -> Result<(), AuthError> {
+        if owner_id == Some(self.user_id()) {
+            return Ok(());
+        }
+
+        if self.has_admin_override() {
+            log::warn!(
+                "Admin override: user {} ({}) read {} owned by {:?}",
+                self.user_id(),
+                self.wallet(),
+                resource,
+                owner_id
+            );
+            return Ok(());
+        }
+
+        Err(AuthError::Forbidden(format!("Forbidden: You don't own {}", resource)))
+    }
+
+    /// 要求当前用户是管理员钱包，否则返回 403
+    pub fn require_admin(&self) -> Result<(), AuthError> {
+        if self.is_admin {
+            Ok(())
+        } else {
+            Err(AuthError::Forbidden("Forbidden: admin only".to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permission_matrix() {
+        assert!(is_allowed(USER_TYPE_JOB_SEEKER, Permission::CreateResume));
+        assert!(!is_allowed(USER_TYPE_JOB_SEEKER, Permission::CreateUnlockRecord));
+        assert!(is_allowed(USER_TYPE_RECRUITER, Permission::CreateUnlockRecord));
+        assert!(!is_allowed(USER_TYPE_RECRUITER, Permission::CreateResume));
+        assert!(is_allowed(USER_TYPE_BOTH, Permission::CreateResume));
+        assert!(is_allowed(USER_TYPE_BOTH, Permission::CreateUnlockRecord));
+        assert!(!is_allowed("admin", Permission::CreateAccessLog));
+    }
+}
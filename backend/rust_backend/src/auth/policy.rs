@@ -0,0 +1,326 @@
+use actix_web::{
+    body::MessageBody,
+    dev::{HttpServiceFactory, ServiceRequest, ServiceResponse},
+    guard,
+    http::Method,
+    middleware::{from_fn, Condition, Next},
+    web, Error, FromRequest, Handler, HttpMessage, Responder,
+};
+use sea_orm::DatabaseConnection;
+
+use crate::auth::{AuthError, AuthenticatedWallet};
+use crate::idempotency;
+use crate::rate_limit::{self, RateLimitGroup};
+use crate::services::ApiKeyService;
+use crate::x402::{self, Pricing};
+
+/// 路由访问级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    /// 公开读取，只允许 GET
+    Public,
+    /// 登录握手本身（申请挑战、提交签名），无需会话
+    LoginHandshake,
+    /// 需要有效的钱包会话（或带对应 scope 的 API Key）
+    Authenticated,
+}
+
+/// API Key 授权范围
+pub const SCOPE_RESUMES_READ: &str = "resumes:read";
+pub const SCOPE_UNLOCKS_READ: &str = "unlocks:read";
+pub const SCOPE_UNLOCKS_WRITE: &str = "unlocks:write";
+pub const SCOPE_ACCESS_LOGS_READ: &str = "access_logs:read";
+pub const SCOPE_ACCESS_LOGS_WRITE: &str = "access_logs:write";
+pub const SCOPE_ORGANIZATIONS_READ: &str = "organizations:read";
+/// 管理员 override：可读取他人的访问/解锁记录，只有管理员钱包能授予
+pub const SCOPE_ADMIN_READ: &str = "admin:read";
+
+/// 全部可授予的 API Key 授权范围
+pub const API_KEY_SCOPES: &[&str] = &[
+    SCOPE_RESUMES_READ,
+    SCOPE_UNLOCKS_READ,
+    SCOPE_UNLOCKS_WRITE,
+    SCOPE_ACCESS_LOGS_READ,
+    SCOPE_ACCESS_LOGS_WRITE,
+    SCOPE_ORGANIZATIONS_READ,
+    SCOPE_ADMIN_READ,
+];
+
+/// 单条路由策略
+#[derive(Debug, Clone, Copy)]
+pub struct RoutePolicy {
+    pub method: &'static str,
+    /// 完整路径模式（scope + path）
+    pub path: &'static str,
+    pub access: Access,
+    /// API Key 调用该路由所需的 scope，为 None 时只接受钱包会话
+    pub api_key_scope: Option<&'static str>,
+    /// 限流组
+    pub rate_limit: RateLimitGroup,
+    /// x402 定价，为 None 时免费
+    pub price: Option<Pricing>,
+    /// 支持 `Idempotency-Key` 请求头，重试时重放第一次的响应
+    pub idempotent: bool,
+}
+
+const fn policy(method: &'static str, path: &'static str, access: Access) -> RoutePolicy {
+    RoutePolicy { method, path, access, api_key_scope: None, rate_limit: RateLimitGroup::Default, price: None, idempotent: false }
+}
+
+impl RoutePolicy {
+    /// 允许持有 `scope` 的 API Key 调用
+    const fn api_key(mut self, scope: &'static str) -> Self {
+        self.api_key_scope = Some(scope);
+        self
+    }
+
+    /// 使用指定的限流组
+    const fn rate_limit(mut self, group: RateLimitGroup) -> Self {
+        self.rate_limit = group;
+        self
+    }
+
+    /// 需要通过 x402 支付才能访问
+    const fn paid(mut self, pricing: Pricing) -> Self {
+        self.price = Some(pricing);
+        self
+    }
+
+    /// 支持 `Idempotency-Key` 请求头（支付相关的写操作）
+    const fn idempotent(mut self) -> Self {
+        self.idempotent = true;
+        self
+    }
+}
+
+/// 全部路由的访问策略表
+///
+/// 每个通过 [`route`] 注册的路由都必须在这里有对应条目，否则启动时 panic；
+/// 非 GET 路由不允许声明为 `Public`，由 [`validate`] 在启动时检查。
+/// 未声明 `api_key` scope 的路由不接受 API Key（如 Key 管理本身）。
+pub const ROUTE_POLICIES: &[RoutePolicy] = &[
+    // 示例
+    policy("GET", "/api/weather", Access::Public),
+    policy("GET", "/api/premium/content", Access::Public).paid(Pricing::Fixed(10_000)),
+    // 登录认证
+    policy("POST", "/api/auth/challenge", Access::LoginHandshake).rate_limit(RateLimitGroup::Login),
+    policy("POST", "/api/auth/login", Access::LoginHandshake).rate_limit(RateLimitGroup::Login),
+    policy("POST", "/api/auth/logout", Access::Authenticated),
+    policy("GET", "/api/auth/sessions", Access::Authenticated),
+    policy("DELETE", "/api/auth/sessions/{session_id}", Access::Authenticated),
+    policy("POST", "/api/auth/sessions/revoke-others", Access::Authenticated),
+    // 用户
+    policy("POST", "/api/users/register", Access::Authenticated).rate_limit(RateLimitGroup::Register),
+    policy("PUT", "/api/users/role", Access::Authenticated),
+    policy("GET", "/api/users/wallet/{wallet}", Access::Public),
+    policy("GET", "/api/users/id/{id}", Access::Public),
+    policy("POST", "/api/users/wallet/{wallet}/nickname", Access::Authenticated),
+    policy("POST", "/api/users/wallets/challenge", Access::Authenticated),
+    policy("POST", "/api/users/wallets", Access::Authenticated),
+    policy("GET", "/api/users/wallets", Access::Authenticated),
+    policy("DELETE", "/api/users/wallets/{wallet_id}", Access::Authenticated),
+    // 简历
+    policy("POST", "/api/resumes", Access::Authenticated),
+    policy("GET", "/api/resumes/summaries", Access::Public).rate_limit(RateLimitGroup::PublicRead),
+    policy("GET", "/api/resumes/my/{owner}", Access::Public),
+    policy("GET", "/api/resumes/detail/{resume_id}", Access::Authenticated).api_key(SCOPE_RESUMES_READ),
+    policy("PUT", "/api/resumes/price", Access::Authenticated),
+    policy("PUT", "/api/resumes/name", Access::Authenticated),
+    policy("GET", "/api/resumes/{resume_id}/tiers", Access::Public),
+    policy("PUT", "/api/resumes/{resume_id}/tiers", Access::Authenticated),
+    policy("GET", "/api/resumes/{resume_id}/allowlist-members", Access::Authenticated),
+    policy("POST", "/api/resumes/{resume_id}/allowlist-members", Access::Authenticated),
+    policy("DELETE", "/api/resumes/{resume_id}/allowlist-members/{member_address}", Access::Authenticated),
+    policy("POST", "/api/resumes/{resume_id}/unlock", Access::Authenticated)
+        .api_key(SCOPE_UNLOCKS_WRITE)
+        .paid(Pricing::Resume { param: "resume_id" })
+        .idempotent(),
+    policy("POST", "/api/resumes/{resume_id}/unlock/credits", Access::Authenticated)
+        .api_key(SCOPE_UNLOCKS_WRITE)
+        .idempotent(),
+    policy("PUT", "/api/resumes/{resume_id}", Access::Authenticated),
+    policy("DELETE", "/api/resumes/{resume_id}", Access::Authenticated),
+    // 解锁记录
+    policy("POST", "/api/unlock-records", Access::Authenticated)
+        .api_key(SCOPE_UNLOCKS_WRITE)
+        .idempotent(),
+    policy("GET", "/api/unlock-records/check/{resume_id}/{buyer_id}", Access::Authenticated).api_key(SCOPE_UNLOCKS_READ),
+    policy("GET", "/api/unlock-records/buyer/{buyer_wallet}", Access::Authenticated).api_key(SCOPE_UNLOCKS_READ),
+    policy("GET", "/api/unlock-records/resume/{resume_id}", Access::Authenticated).api_key(SCOPE_UNLOCKS_READ),
+    policy("POST", "/api/unlock-records/{record_id}/verify", Access::Authenticated).api_key(SCOPE_UNLOCKS_WRITE),
+    // 解锁争议
+    policy("GET", "/api/unlock-records/disputes", Access::Authenticated),
+    policy("POST", "/api/unlock-records/disputes/{dispute_id}/resolve", Access::Authenticated).idempotent(),
+    policy("POST", "/api/unlock-records/{record_id}/dispute", Access::Authenticated).api_key(SCOPE_UNLOCKS_WRITE),
+    policy("GET", "/api/unlock-records/{record_id}/dispute", Access::Authenticated).api_key(SCOPE_UNLOCKS_READ),
+    // 招聘组织
+    policy("POST", "/api/organizations", Access::Authenticated),
+    policy("GET", "/api/organizations/my", Access::Authenticated).api_key(SCOPE_ORGANIZATIONS_READ),
+    policy("GET", "/api/organizations/{org_id}/members", Access::Authenticated).api_key(SCOPE_ORGANIZATIONS_READ),
+    policy("POST", "/api/organizations/{org_id}/members", Access::Authenticated),
+    policy("DELETE", "/api/organizations/{org_id}/members/{user_id}", Access::Authenticated),
+    policy("GET", "/api/organizations/{org_id}/unlocks", Access::Authenticated).api_key(SCOPE_UNLOCKS_READ),
+    policy("GET", "/api/organizations/{org_id}/spend", Access::Authenticated).api_key(SCOPE_UNLOCKS_READ),
+    // API Key 管理（只接受钱包会话）
+    policy("POST", "/api/api-keys", Access::Authenticated),
+    policy("GET", "/api/api-keys", Access::Authenticated),
+    policy("POST", "/api/api-keys/{key_id}/rotate", Access::Authenticated),
+    policy("DELETE", "/api/api-keys/{key_id}", Access::Authenticated),
+    policy("GET", "/api/api-keys/{key_id}/audit", Access::Authenticated),
+    // 付费收据
+    policy("GET", "/api/receipts/public-key", Access::Public),
+    policy("GET", "/api/receipts/{receipt_id}", Access::Authenticated).api_key(SCOPE_UNLOCKS_READ),
+    // 卖家结算（批次管理只接受管理员钱包会话）
+    policy("GET", "/api/payouts/balance", Access::Authenticated).api_key(SCOPE_UNLOCKS_READ),
+    policy("GET", "/api/payouts/ledger", Access::Authenticated).api_key(SCOPE_UNLOCKS_READ),
+    policy("GET", "/api/payouts", Access::Authenticated).api_key(SCOPE_UNLOCKS_READ),
+    policy("POST", "/api/payouts/batches", Access::Authenticated).idempotent(),
+    policy("POST", "/api/payouts/{payout_id}/paid", Access::Authenticated).idempotent(),
+    // 预付额度
+    policy("GET", "/api/credits/balance", Access::Authenticated).api_key(SCOPE_UNLOCKS_READ),
+    policy("GET", "/api/credits/ledger", Access::Authenticated).api_key(SCOPE_UNLOCKS_READ),
+    policy("GET", "/api/credits/bundles", Access::Public),
+    policy("POST", "/api/credits/bundles", Access::Authenticated),
+    policy("DELETE", "/api/credits/bundles/{bundle_id}", Access::Authenticated),
+    policy("POST", "/api/credits/bundles/{bundle_id}/purchase", Access::Authenticated)
+        .api_key(SCOPE_UNLOCKS_WRITE)
+        .paid(Pricing::CreditBundle { param: "bundle_id" })
+        .idempotent(),
+    // 优惠码（管理员）
+    policy("POST", "/api/coupons", Access::Authenticated),
+    policy("GET", "/api/coupons", Access::Authenticated),
+    policy("DELETE", "/api/coupons/{code}", Access::Authenticated),
+    // 链上事件索引进度
+    policy("GET", "/api/indexer/status", Access::Public),
+    // 访问记录
+    policy("POST", "/api/access-logs", Access::Authenticated)
+        .api_key(SCOPE_ACCESS_LOGS_WRITE)
+        .rate_limit(RateLimitGroup::AccessLog),
+    policy("GET", "/api/access-logs/resume/{resume_id}", Access::Authenticated).api_key(SCOPE_ACCESS_LOGS_READ),
+    policy("GET", "/api/access-logs/accessor/{accessor}", Access::Authenticated).api_key(SCOPE_ACCESS_LOGS_READ),
+    policy("GET", "/api/access-logs/count/{resume_id}", Access::Public),
+];
+
+/// 查询路由策略
+pub fn find_policy(method: &Method, path: &str) -> Option<&'static RoutePolicy> {
+    ROUTE_POLICIES
+        .iter()
+        .find(|p| p.method == method.as_str() && p.path == path)
+}
+
+/// 检查策略表本身：写操作不能是公开的，API Key scope 和幂等键只能用在需认证的路由上，
+/// 按简历或额度套餐定价的路由必须带对应路径参数，且不能有重复条目
+pub fn validate() -> Result<(), String> {
+    for (i, p) in ROUTE_POLICIES.iter().enumerate() {
+        if p.method != "GET" && p.access == Access::Public {
+            return Err(format!("Write route {} {} must not be public", p.method, p.path));
+        }
+        if let Some(scope) = p.api_key_scope {
+            if p.access != Access::Authenticated {
+                return Err(format!("Route {} {} declares an API key scope but is not authenticated", p.method, p.path));
+            }
+            if !API_KEY_SCOPES.contains(&scope) {
+                return Err(format!("Unknown API key scope '{}' on {} {}", scope, p.method, p.path));
+            }
+        }
+        if p.idempotent && (p.method == "GET" || p.access != Access::Authenticated) {
+            return Err(format!("Idempotent route {} {} must be an authenticated write", p.method, p.path));
+        }
+        if let Some(Pricing::Resume { param } | Pricing::CreditBundle { param }) = p.price
+            && !p.path.contains(&format!("{{{}}}", param))
+        {
+            return Err(format!("Route {} {} prices by missing path parameter '{}'", p.method, p.path, param));
+        }
+        if ROUTE_POLICIES[..i].iter().any(|q| q.method == p.method && q.path == p.path) {
+            return Err(format!("Duplicate route policy for {} {}", p.method, p.path));
+        }
+    }
+    Ok(())
+}
+
+/// 按策略表注册路由：先按 IP 限流，`Authenticated` 路由再强制校验会话并按钱包或 API Key 限流，
+/// 幂等路由重放已保存的响应，付费路由最后校验 x402 支付
+///
+/// `scope` 为所在 scope 的前缀，`path` 为 scope 内的相对路径。
+pub fn route<F, Args>(scope: &str, method: Method, path: &str, handler: F) -> impl HttpServiceFactory + use<F, Args>
+where
+    F: Handler<Args>,
+    Args: FromRequest + 'static,
+    F::Output: Responder + 'static,
+{
+    let full_path = format!("{}{}", scope, path);
+    let policy = find_policy(&method, &full_path)
+        .unwrap_or_else(|| panic!("No access policy registered for {} {}", method, full_path));
+
+    // 后注册的中间件先执行：先按 IP 限流（未认证的请求也计数，认证失败前不查询会话），
+    // 再认证并按钱包 / API Key 限流，然后重放幂等响应，最后收费
+    web::resource(path)
+        .guard(guard::Method(method.clone()))
+        .wrap(Condition::new(
+            policy.price.is_some(),
+            from_fn(x402::middleware::require_payment),
+        ))
+        .wrap(Condition::new(
+            policy.idempotent,
+            from_fn(idempotency::middleware::replay),
+        ))
+        .wrap(from_fn(rate_limit::middleware::enforce_identity))
+        .wrap(Condition::new(
+            policy.access == Access::Authenticated,
+            from_fn(require_authenticated),
+        ))
+        .wrap(from_fn(rate_limit::middleware::enforce_ip))
+        .route(web::method(method).to(handler))
+}
+
+/// 强制要求有效会话，解析结果存入请求扩展供 handler 复用
+///
+/// API Key 调用还要求路由声明的 scope，并且每次调用（包括被拒绝的）都写入该 Key 的审计日志。
+async fn require_authenticated(
+    mut req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let wallet = req.extract::<AuthenticatedWallet>().await?;
+    let api_key = wallet.api_key().cloned();
+    req.extensions_mut().insert(wallet);
+
+    let Some(api_key) = api_key else {
+        return next.call(req).await;
+    };
+
+    let db = req.app_data::<web::Data<DatabaseConnection>>().cloned();
+    let method = req.method().to_string();
+    let path = req.path().to_string();
+    let ip_address = req.connection_info().realip_remote_addr().map(|s| s.to_string());
+
+    let scope = req
+        .match_pattern()
+        .and_then(|pattern| find_policy(req.method(), &pattern))
+        .and_then(|p| p.api_key_scope);
+
+    let result = match scope {
+        Some(scope) if api_key.has_scope(scope) => next.call(req).await,
+        Some(scope) => Err(AuthError::Forbidden(format!("Forbidden: API key is missing scope '{}'", scope)).into()),
+        None => Err(AuthError::Forbidden("Forbidden: This route does not accept API keys".to_string()).into()),
+    };
+
+    if let Some(db) = db {
+        let status = match &result {
+            Ok(res) => res.status(),
+            Err(e) => e.as_response_error().status_code(),
+        };
+        ApiKeyService::record_usage(&db, api_key.id, method, path, status, ip_address).await;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_table_is_valid() {
+        assert!(validate().is_ok());
+    }
+}
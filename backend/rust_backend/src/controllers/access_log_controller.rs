@@ -1,21 +1,28 @@
-use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use actix_web::{web, HttpRequest, HttpResponse, Responder, ResponseError};
 use crate::models::{ApiResponse, CreateAccessLogRequest, AccessLogResponse};
-use crate::services::AccessLogService;
+use crate::auth::{AuthenticatedWallet, Permission};
+use crate::services::{AccessLogService, ResumeService, UserService};
+use crate::utils::validator::Validator;
 use sea_orm::DatabaseConnection;
 
 /// 访问记录控制器
 pub struct AccessLogController;
 
 impl AccessLogController {
-    /// 创建访问记录
+    /// 创建访问记录（访问者为当前登录钱包）
     pub async fn create(
         req: web::Json<CreateAccessLogRequest>,
+        wallet: AuthenticatedWallet,
         http_req: HttpRequest,
         db: web::Data<DatabaseConnection>,
     ) -> impl Responder {
         println!("=== Create access log endpoint ===");
+
+        if let Err(e) = wallet.require(Permission::CreateAccessLog) {
+            return e.error_response();
+        }
         println!("Resume ID: {}", req.resume_id);
-        println!("Accessor: {}", req.accessor_address);
+        println!("Accessor: {}", wallet.wallet());
         println!("Access Type: {}", req.access_type);
         println!("Encryption Type: {}", req.encryption_type);
 
@@ -36,7 +43,7 @@ impl AccessLogController {
         match AccessLogService::create_access_log(
             &db,
             request.resume_id,
-            request.accessor_address,
+            wallet.wallet().to_string(),
             request.access_type,
             request.encryption_type,
             request.success,
@@ -60,10 +67,11 @@ impl AccessLogController {
         }
     }
 
-    /// 获取简历的访问记录
+    /// 获取简历的访问记录（仅简历所有者）
     pub async fn get_resume_logs(
         path: web::Path<String>,
         query: web::Query<std::collections::HashMap<String, String>>,
+        wallet: AuthenticatedWallet,
         db: web::Data<DatabaseConnection>,
     ) -> impl Responder {
         let resume_id = path.into_inner();
@@ -75,6 +83,14 @@ impl AccessLogController {
         println!("=== Get resume access logs endpoint ===");
         println!("Resume ID: {}, Limit: {}", resume_id, limit);
 
+        let owner_id = match ResumeService::owner_id_of(&db, &resume_id).await {
+            Ok(owner_id) => owner_id,
+            Err(e) => return e.error_response(),
+        };
+        if let Err(e) = wallet.require_owner(owner_id, "this resume") {
+            return e.error_response();
+        }
+
         match AccessLogService::get_resume_access_logs(&db, &resume_id, limit).await {
             Ok(logs) => {
                 let responses: Vec<AccessLogResponse> = logs
@@ -91,10 +107,11 @@ impl AccessLogController {
         }
     }
 
-    /// 获取访问者的访问记录
+    /// 获取访问者的访问记录（只能查看自己关联钱包的记录）
     pub async fn get_accessor_logs(
         accessor: web::Path<String>,
         query: web::Query<std::collections::HashMap<String, String>>,
+        wallet: AuthenticatedWallet,
         db: web::Data<DatabaseConnection>,
     ) -> impl Responder {
         let limit = query
@@ -105,6 +122,19 @@ impl AccessLogController {
         println!("=== Get accessor logs endpoint ===");
         println!("Accessor: {}, Limit: {}", accessor, limit);
 
+        let accessor = match Validator::detect_wallet_address(&accessor) {
+            Ok((_, accessor)) => accessor,
+            Err(e) => return e.for_field("accessor").error_response(),
+        };
+
+        let owner_id = match UserService::user_id_of_wallet(&db, &accessor).await {
+            Ok(owner_id) => owner_id,
+            Err(e) => return e.error_response(),
+        };
+        if let Err(e) = wallet.require_owner(owner_id, "this wallet's access logs") {
+            return e.error_response();
+        }
+
         match AccessLogService::get_accessor_logs(&db, &accessor, limit).await {
             Ok(logs) => {
                 let responses: Vec<AccessLogResponse> = logs
@@ -0,0 +1,91 @@
+use actix_web::{web, HttpResponse, Responder, ResponseError};
+use sea_orm::DatabaseConnection;
+use crate::auth::{AuthenticatedWallet, Permission};
+use crate::models::{AddAllowlistMemberRequest, ApiResponse, RemoveAllowlistMemberQuery};
+use crate::services::AllowlistService;
+
+/// 简历 allowlist 成员控制器（仅限简历所有者）
+pub struct AllowlistController;
+
+impl AllowlistController {
+    /// 列出简历 allowlist 的成员
+    pub async fn list_members(
+        resume_id: web::Path<String>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== List allowlist members endpoint ===");
+        println!("Resume ID: {}", resume_id);
+
+        let resume = match AllowlistService::get_resume_for_owner(&db, &resume_id, &wallet).await {
+            Ok(Some(resume)) => resume,
+            Ok(None) => return HttpResponse::NotFound().json(ApiResponse::<()>::error("Resume not found".to_string())),
+            Err(e) => return e.error_response(),
+        };
+
+        match AllowlistService::list_members(&db, &resume).await {
+            Ok(members) => HttpResponse::Ok().json(ApiResponse::success(members)),
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 记录已提交的添加交易（成员在链上确认前为 pending）
+    pub async fn add_member(
+        resume_id: web::Path<String>,
+        request: web::Json<AddAllowlistMemberRequest>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Add allowlist member endpoint ===");
+
+        if let Err(e) = wallet.require(Permission::ManageResume) {
+            return e.error_response();
+        }
+        println!("Resume ID: {}, Member: {}, Tx: {}", resume_id, request.member_address, request.tx_digest);
+
+        let resume = match AllowlistService::get_resume_for_owner(&db, &resume_id, &wallet).await {
+            Ok(Some(resume)) => resume,
+            Ok(None) => return HttpResponse::NotFound().json(ApiResponse::<()>::error("Resume not found".to_string())),
+            Err(e) => return e.error_response(),
+        };
+
+        match AllowlistService::record_pending_member(&db, &resume, wallet.wallet(), request.into_inner()).await {
+            Ok(member) => HttpResponse::Ok().json(ApiResponse::success_with_message(
+                member,
+                "Allowlist member recorded, pending on-chain confirmation".to_string(),
+            )),
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 标记成员已移除
+    pub async fn remove_member(
+        path: web::Path<(String, String)>, // (resume_id, member_address)
+        query: web::Query<RemoveAllowlistMemberQuery>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        let (resume_id, member_address) = path.into_inner();
+
+        println!("=== Remove allowlist member endpoint ===");
+
+        if let Err(e) = wallet.require(Permission::ManageResume) {
+            return e.error_response();
+        }
+        println!("Resume ID: {}, Member: {}", resume_id, member_address);
+
+        let resume = match AllowlistService::get_resume_for_owner(&db, &resume_id, &wallet).await {
+            Ok(Some(resume)) => resume,
+            Ok(None) => return HttpResponse::NotFound().json(ApiResponse::<()>::error("Resume not found".to_string())),
+            Err(e) => return e.error_response(),
+        };
+
+        match AllowlistService::record_removal(&db, &resume, &member_address, query.tx_digest.as_deref()).await {
+            Ok(removed) => HttpResponse::Ok().json(ApiResponse::success_with_message(
+                serde_json::json!({ "removed": removed }),
+                "Allowlist member removed".to_string(),
+            )),
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use actix_web::{web, HttpResponse, Responder, ResponseError};
+use crate::auth::{AuthError, AuthenticatedWallet};
+use crate::auth::policy::SCOPE_ADMIN_READ;
+use crate::models::{
+    ApiKeyAuditLogResponse, ApiKeyResponse, ApiKeySecretResponse, ApiResponse, CreateApiKeyRequest,
+};
+use crate::services::{ApiKeyService, OrganizationService};
+use sea_orm::DatabaseConnection;
+
+/// API Key 管理控制器（只接受钱包会话）
+pub struct ApiKeyController;
+
+impl ApiKeyController {
+    /// 创建 API Key，明文 key 只在响应中返回一次
+    pub async fn create(
+        req: web::Json<CreateApiKeyRequest>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Create API key endpoint ===");
+        println!("Name: {}, Owner: {}, Scopes: {:?}", req.name, wallet.wallet(), req.scopes);
+
+        // 管理员 override scope 只能由管理员钱包授予
+        if req.scopes.iter().any(|s| s.trim() == SCOPE_ADMIN_READ) && !wallet.is_admin {
+            return AuthError::Forbidden(format!("Forbidden: Only admin wallets can grant '{}'", SCOPE_ADMIN_READ))
+                .error_response();
+        }
+
+        // 组织 Key 只能由组织管理员创建
+        if let Some(organization_id) = req.organization_id
+            && let Err(e) = OrganizationService::require_member(&db, organization_id, wallet.user_id(), true).await
+        {
+            return e.error_response();
+        }
+
+        match ApiKeyService::create_key(&db, wallet.user_id(), &req.name, &req.scopes, req.organization_id).await {
+            Ok((key, raw_key)) => {
+                let response = ApiResponse::success_with_message(
+                    ApiKeySecretResponse {
+                        key: raw_key,
+                        api_key: ApiKeyResponse::from(key),
+                    },
+                    "API key created, store it now as it will not be shown again".to_string(),
+                );
+                HttpResponse::Ok().json(response)
+            }
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 获取我创建的 API Key
+    pub async fn list(
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== List API keys endpoint ===");
+
+        match ApiKeyService::list_keys(&db, wallet.user_id()).await {
+            Ok(keys) => {
+                let responses: Vec<ApiKeyResponse> = keys
+                    .into_iter()
+                    .map(ApiKeyResponse::from)
+                    .collect();
+                HttpResponse::Ok().json(ApiResponse::success(responses))
+            }
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 轮换 API Key
+    pub async fn rotate(
+        key_id: web::Path<i64>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Rotate API key endpoint ===");
+        println!("Key ID: {}", key_id);
+
+        let key = match ApiKeyService::get_owned_key(&db, *key_id, wallet.user_id()).await {
+            Ok(key) => key,
+            Err(e) => return e.error_response(),
+        };
+
+        match ApiKeyService::rotate_key(&db, key).await {
+            Ok((key, raw_key)) => {
+                let response = ApiResponse::success_with_message(
+                    ApiKeySecretResponse {
+                        key: raw_key,
+                        api_key: ApiKeyResponse::from(key),
+                    },
+                    "API key rotated, the previous key no longer works".to_string(),
+                );
+                HttpResponse::Ok().json(response)
+            }
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 吊销 API Key
+    pub async fn revoke(
+        key_id: web::Path<i64>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Revoke API key endpoint ===");
+        println!("Key ID: {}", key_id);
+
+        if let Err(e) = ApiKeyService::get_owned_key(&db, *key_id, wallet.user_id()).await {
+            return e.error_response();
+        }
+
+        match ApiKeyService::revoke_key(&db, *key_id).await {
+            Ok(_) => HttpResponse::Ok().json(ApiResponse::<()>::success_with_message(
+                (),
+                "API key revoked successfully".to_string(),
+            )),
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 获取 API Key 的审计日志
+    pub async fn get_audit_logs(
+        key_id: web::Path<i64>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Get API key audit logs endpoint ===");
+        println!("Key ID: {}", key_id);
+
+        if let Err(e) = ApiKeyService::get_owned_key(&db, *key_id, wallet.user_id()).await {
+            return e.error_response();
+        }
+
+        match ApiKeyService::get_audit_logs(&db, *key_id).await {
+            Ok(logs) => {
+                let responses: Vec<ApiKeyAuditLogResponse> = logs
+                    .into_iter()
+                    .map(ApiKeyAuditLogResponse::from)
+                    .collect();
+                HttpResponse::Ok().json(ApiResponse::success(responses))
+            }
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+}
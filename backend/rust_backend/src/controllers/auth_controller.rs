@@ -0,0 +1,149 @@
+use actix_web::{web, HttpRequest, HttpResponse, Responder, ResponseError};
+use crate::auth::AuthenticatedWallet;
+use crate::models::{ApiResponse, AuthConfig, ChallengeRequest, LoginRequest, SessionClientInfo, SessionResponse};
+use crate::services::AuthService;
+use crate::utils::validator::Validator;
+use sea_orm::DatabaseConnection;
+
+/// 登录认证控制器
+pub struct AuthController;
+
+impl AuthController {
+    /// 申请登录挑战
+    pub async fn challenge(
+        req: web::Json<ChallengeRequest>,
+        db: web::Data<DatabaseConnection>,
+        config: web::Data<AuthConfig>,
+    ) -> impl Responder {
+        println!("=== Auth challenge endpoint ===");
+        println!("Wallet: {}", req.wallet_address);
+
+        let wallet_address = match Validator::normalize_sui_address(&req.wallet_address) {
+            Ok(address) => address,
+            Err(e) => return e.error_response(),
+        };
+
+        match AuthService::issue_challenge(&db, &config, wallet_address).await {
+            Ok(challenge) => HttpResponse::Ok().json(ApiResponse::success(challenge)),
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 提交钱包签名，换取会话 token
+    pub async fn login(
+        req: web::Json<LoginRequest>,
+        http_req: HttpRequest,
+        db: web::Data<DatabaseConnection>,
+        config: web::Data<AuthConfig>,
+    ) -> impl Responder {
+        println!("=== Auth login endpoint ===");
+        println!("Nonce: {}", req.nonce);
+
+        let req = req.into_inner();
+        let client = SessionClientInfo {
+            device_label: req.device_label.map(|s| s.trim().chars().take(100).collect()),
+            ip_address: http_req.connection_info().realip_remote_addr().map(|s| s.to_string()),
+            user_agent: http_req
+                .headers()
+                .get("User-Agent")
+                .and_then(|h| h.to_str().ok())
+                .map(|s| s.chars().take(255).collect()),
+        };
+
+        match AuthService::login(&db, &config, &req.nonce, &req.signature, client).await {
+            Ok(login) => {
+                let response = ApiResponse::success_with_message(
+                    login,
+                    "Login successful".to_string(),
+                );
+                HttpResponse::Ok().json(response)
+            }
+            Err(e) => HttpResponse::Unauthorized().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 注销当前会话
+    pub async fn logout(
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Auth logout endpoint ===");
+        println!("Wallet: {}", wallet.wallet());
+
+        let Some(session) = wallet.session() else {
+            return HttpResponse::BadRequest().json(ApiResponse::<()>::error(
+                "API keys cannot log out, revoke the key instead".to_string(),
+            ));
+        };
+
+        match AuthService::logout(&db, session.id).await {
+            Ok(_) => HttpResponse::Ok().json(ApiResponse::<()>::success_with_message(
+                (),
+                "Logged out successfully".to_string(),
+            )),
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 列出当前用户的所有有效会话（设备）
+    pub async fn list_sessions(
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== List sessions endpoint ===");
+        println!("User ID: {}", wallet.user_id());
+
+        let current_session_id = wallet.session().map(|s| s.id);
+        match AuthService::list_sessions(&db, wallet.user_id()).await {
+            Ok(sessions) => {
+                let responses: Vec<SessionResponse> = sessions
+                    .into_iter()
+                    .map(|s| SessionResponse::new(s, current_session_id))
+                    .collect();
+                HttpResponse::Ok().json(ApiResponse::success(responses))
+            }
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 注销指定会话（只能注销自己的）
+    pub async fn revoke_session(
+        session_id: web::Path<i64>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Revoke session endpoint ===");
+        println!("User ID: {}, Session ID: {}", wallet.user_id(), session_id);
+
+        match AuthService::revoke_session(&db, wallet.user_id(), *session_id).await {
+            Ok(_) => HttpResponse::Ok().json(ApiResponse::<()>::success_with_message(
+                (),
+                "Session revoked successfully".to_string(),
+            )),
+            Err(e) => HttpResponse::NotFound().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 注销除当前会话外的所有会话
+    pub async fn revoke_other_sessions(
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Revoke other sessions endpoint ===");
+        println!("User ID: {}", wallet.user_id());
+
+        let Some(session) = wallet.session() else {
+            return HttpResponse::BadRequest().json(ApiResponse::<()>::error(
+                "A wallet session is required".to_string(),
+            ));
+        };
+
+        match AuthService::revoke_other_sessions(&db, wallet.user_id(), session.id).await {
+            Ok(count) => HttpResponse::Ok().json(ApiResponse::success_with_message(
+                count,
+                format!("Revoked {} other session(s)", count),
+            )),
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+}
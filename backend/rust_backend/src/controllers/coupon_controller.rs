@@ -0,0 +1,76 @@
+use actix_web::{web, HttpResponse, Responder, ResponseError};
+use crate::auth::AuthenticatedWallet;
+use crate::models::{ApiResponse, CouponResponse, CreateCouponRequest};
+use crate::services::CouponService;
+use sea_orm::DatabaseConnection;
+
+/// 优惠码控制器（管理员）
+pub struct CouponController;
+
+impl CouponController {
+    /// 创建优惠码
+    pub async fn create(
+        request: web::Json<CreateCouponRequest>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Create coupon endpoint ===");
+        println!("Admin: {}, Code: {}", wallet.wallet(), request.code);
+
+        if let Err(e) = wallet.require_admin() {
+            return e.error_response();
+        }
+
+        match CouponService::create(&db, wallet.user_id(), request.into_inner()).await {
+            Ok(coupon) => HttpResponse::Ok().json(ApiResponse::success_with_message(
+                CouponResponse::from(coupon),
+                "Coupon created".to_string(),
+            )),
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 全部优惠码
+    pub async fn list(
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== List coupons endpoint ===");
+        println!("Admin: {}", wallet.wallet());
+
+        if let Err(e) = wallet.require_admin() {
+            return e.error_response();
+        }
+
+        match CouponService::list(&db).await {
+            Ok(coupons) => {
+                let coupons: Vec<CouponResponse> = coupons.into_iter().map(Into::into).collect();
+                HttpResponse::Ok().json(ApiResponse::success(coupons))
+            }
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 停用优惠码
+    pub async fn deactivate(
+        code: web::Path<String>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Deactivate coupon endpoint ===");
+        println!("Admin: {}, Code: {}", wallet.wallet(), code);
+
+        if let Err(e) = wallet.require_admin() {
+            return e.error_response();
+        }
+
+        match CouponService::deactivate(&db, &code).await {
+            Ok(true) => HttpResponse::Ok().json(ApiResponse::<()>::success_with_message(
+                (),
+                "Coupon deactivated".to_string(),
+            )),
+            Ok(false) => HttpResponse::NotFound().json(ApiResponse::<()>::error("Coupon not found".to_string())),
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use actix_web::{web, HttpResponse, Responder, ResponseError};
+use crate::auth::AuthenticatedWallet;
+use crate::models::{
+    ApiResponse, CreateCreditBundleRequest, CreditAccountQuery, CreditBundleResponse, CreditTopupResponse,
+};
+use crate::money;
+use crate::services::CreditService;
+use crate::x402::types::PAYMENT_RESPONSE_HEADER;
+use crate::x402::{Facilitator, VerifiedPayment};
+use sea_orm::DatabaseConnection;
+
+/// 预付额度控制器
+pub struct CreditController;
+
+impl CreditController {
+    /// 个人（或 `?organization_id=` 指定组织）的额度余额
+    pub async fn get_balance(
+        query: web::Query<CreditAccountQuery>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Get credit balance endpoint ===");
+        println!("User: {} (ID: {}), Organization: {:?}", wallet.wallet(), wallet.user_id(), query.organization_id);
+
+        let owner = match CreditService::resolve_owner(&db, wallet.user_id(), query.organization_id).await {
+            Ok(owner) => owner,
+            Err(e) => return e.error_response(),
+        };
+
+        match CreditService::get_balance(&db, owner).await {
+            Ok(balance) => HttpResponse::Ok().json(ApiResponse::success(balance)),
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 个人（或 `?organization_id=` 指定组织）的额度流水
+    pub async fn get_ledger(
+        query: web::Query<CreditAccountQuery>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Get credit ledger endpoint ===");
+        println!("User: {} (ID: {}), Organization: {:?}", wallet.wallet(), wallet.user_id(), query.organization_id);
+
+        let owner = match CreditService::resolve_owner(&db, wallet.user_id(), query.organization_id).await {
+            Ok(owner) => owner,
+            Err(e) => return e.error_response(),
+        };
+
+        match CreditService::get_ledger(&db, owner).await {
+            Ok(transactions) => HttpResponse::Ok().json(ApiResponse::success(transactions)),
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 在售的额度套餐（公开）
+    pub async fn get_bundles(db: web::Data<DatabaseConnection>) -> impl Responder {
+        println!("=== Get credit bundles endpoint ===");
+
+        match CreditService::list_bundles(&db).await {
+            Ok(bundles) => {
+                let bundles: Vec<CreditBundleResponse> = bundles.into_iter().map(Into::into).collect();
+                HttpResponse::Ok().json(ApiResponse::success(bundles))
+            }
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 创建额度套餐（管理员）
+    pub async fn create_bundle(
+        request: web::Json<CreateCreditBundleRequest>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Create credit bundle endpoint ===");
+        println!("Admin: {}, Name: {}", wallet.wallet(), request.name);
+
+        if let Err(e) = wallet.require_admin() {
+            return e.error_response();
+        }
+
+        match CreditService::create_bundle(&db, request.into_inner()).await {
+            Ok(bundle) => HttpResponse::Ok().json(ApiResponse::success_with_message(
+                CreditBundleResponse::from(bundle),
+                "Credit bundle created".to_string(),
+            )),
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 下架额度套餐（管理员）
+    pub async fn deactivate_bundle(
+        bundle_id: web::Path<i64>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Deactivate credit bundle endpoint ===");
+        println!("Admin: {}, Bundle ID: {}", wallet.wallet(), bundle_id);
+
+        if let Err(e) = wallet.require_admin() {
+            return e.error_response();
+        }
+
+        match CreditService::deactivate_bundle(&db, *bundle_id).await {
+            Ok(true) => HttpResponse::Ok().json(ApiResponse::<()>::success_with_message(
+                (),
+                "Credit bundle deactivated".to_string(),
+            )),
+            Ok(false) => HttpResponse::NotFound().json(ApiResponse::<()>::error("Credit bundle not found".to_string())),
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// x402 购买额度套餐，充值到个人（或 `?organization_id=` 指定组织）的额度账户
+    pub async fn purchase_bundle(
+        bundle_id: web::Path<i64>,
+        query: web::Query<CreditAccountQuery>,
+        payment: Option<web::ReqData<VerifiedPayment>>,
+        wallet: AuthenticatedWallet,
+        facilitator: web::Data<dyn Facilitator>,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Purchase credit bundle endpoint ===");
+        println!("Bundle ID: {}, Buyer: {} (ID: {}), Organization: {:?}", bundle_id, wallet.wallet(), wallet.user_id(), query.organization_id);
+
+        let owner = match CreditService::resolve_owner(&db, wallet.user_id(), query.organization_id).await {
+            Ok(owner) => owner,
+            Err(e) => return e.error_response(),
+        };
+
+        let topup = match CreditService::purchase_bundle(
+            &db,
+            facilitator.get_ref(),
+            payment.as_deref(),
+            *bundle_id,
+            owner,
+            wallet.user_id(),
+            wallet.wallet(),
+        )
+        .await
+        {
+            Ok(topup) => topup,
+            Err(e) => return e.error_response(),
+        };
+
+        let registry = money::registry();
+        let response = CreditTopupResponse {
+            bundle_id: topup.bundle.id,
+            credited: registry.stored(topup.transaction.amount, &topup.bundle.asset),
+            balance: registry.stored(topup.transaction.balance_after, &topup.bundle.asset),
+            transaction: topup.settlement.transaction.clone(),
+            network: topup.settlement.network.clone(),
+        };
+
+        HttpResponse::Ok()
+            .insert_header((PAYMENT_RESPONSE_HEADER, topup.settlement.to_header()))
+            .json(ApiResponse::success_with_message(response, "Credits added".to_string()))
+    }
+}
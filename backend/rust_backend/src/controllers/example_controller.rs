@@ -1,8 +1,10 @@
-use actix_web::{HttpResponse, Responder};
+use actix_web::{web, HttpResponse, Responder, ResponseError};
 use crate::models::{ApiResponse, WeatherResponse, WeatherReport, PremiumContent};
+use crate::x402::middleware::PaymentError;
+use crate::x402::types::PAYMENT_RESPONSE_HEADER;
+use crate::x402::{Facilitator, VerifiedPayment};
 
 /// 天气端点（示例）
-#[allow(dead_code)]
 pub async fn weather_handler() -> impl Responder {
     println!("=== Weather endpoint called ===");
     
@@ -15,12 +17,33 @@ pub async fn weather_handler() -> impl Responder {
     HttpResponse::Ok().json(response)
 }
 
-/// 高级内容端点（示例）
-pub async fn premium_content_handler() -> impl Responder {
+/// 高级内容端点（示例，x402 付费，返回内容前先结算）
+pub async fn premium_content_handler(
+    payment: Option<web::ReqData<VerifiedPayment>>,
+    facilitator: web::Data<dyn Facilitator>,
+) -> impl Responder {
     println!("=== Premium content endpoint called ===");
+
+    let mut builder = HttpResponse::Ok();
+    if let Some(payment) = payment {
+        match facilitator.settle(&payment.payment, &payment.requirements).await {
+            Ok(settlement) => {
+                println!(
+                    "Paid: {} {} by {:?}, tx {} on {}",
+                    payment.requirements.max_amount_required,
+                    payment.requirements.asset,
+                    settlement.payer.as_ref().or(payment.payer.as_ref()),
+                    settlement.transaction,
+                    settlement.network
+                );
+                builder.insert_header((PAYMENT_RESPONSE_HEADER, settlement.to_header()));
+            }
+            Err(e) => return PaymentError::from_facilitator(e, &payment.requirements).error_response(),
+        }
+    }
     
     let response = ApiResponse::success(PremiumContent {
         content: "This is premium content".to_string(),
     });
-    HttpResponse::Ok().json(response)
+    builder.json(response)
 }
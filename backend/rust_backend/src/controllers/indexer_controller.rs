@@ -0,0 +1,26 @@
+use actix_web::{web, HttpResponse, Responder};
+use crate::models::{ApiResponse, SuiConfig};
+use crate::services::IndexerService;
+use sea_orm::DatabaseConnection;
+
+/// 链上事件索引控制器
+pub struct IndexerController;
+
+impl IndexerController {
+    /// 获取各事件流的索引进度和延迟
+    pub async fn status(
+        db: web::Data<DatabaseConnection>,
+        sui_config: web::Data<SuiConfig>,
+    ) -> impl Responder {
+        println!("=== Get indexer status endpoint ===");
+
+        let Some(package_id) = &sui_config.package_id else {
+            return HttpResponse::NotFound().json(ApiResponse::<()>::error("Chain indexer is not configured".to_string()));
+        };
+
+        match IndexerService::status(&db, package_id).await {
+            Ok(status) => HttpResponse::Ok().json(ApiResponse::success(status)),
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+}
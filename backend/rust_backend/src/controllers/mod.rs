@@ -3,8 +3,28 @@ pub mod resume_controller;
 pub mod user_controller;
 pub mod unlock_record_controller;
 pub mod access_log_controller;
+pub mod auth_controller;
+pub mod organization_controller;
+pub mod api_key_controller;
+pub mod user_wallet_controller;
+pub mod receipt_controller;
+pub mod payout_controller;
+pub mod credit_controller;
+pub mod coupon_controller;
+pub mod allowlist_controller;
+pub mod indexer_controller;
 
 pub use example_controller::*;
 pub use resume_controller::ResumeController;
 pub use unlock_record_controller::UnlockRecordController;
 pub use access_log_controller::AccessLogController;
+pub use auth_controller::AuthController;
+pub use organization_controller::OrganizationController;
+pub use api_key_controller::ApiKeyController;
+pub use user_wallet_controller::UserWalletController;
+pub use receipt_controller::ReceiptController;
+pub use payout_controller::PayoutController;
+pub use credit_controller::CreditController;
+pub use coupon_controller::CouponController;
+pub use allowlist_controller::AllowlistController;
+pub use indexer_controller::IndexerController;
@@ -0,0 +1,196 @@
+use actix_web::{web, HttpResponse, Responder, ResponseError};
+use crate::auth::{AuthenticatedWallet, Permission};
+use crate::models::{
+    AddOrganizationMemberRequest, ApiResponse, CreateOrganizationRequest,
+    OrganizationMemberResponse, OrganizationResponse, UnlockRecordResponse,
+};
+use crate::services::OrganizationService;
+use crate::services::organization_service::ORG_ROLE_ADMIN;
+use crate::utils::validator::Validator;
+use sea_orm::DatabaseConnection;
+
+/// 招聘组织控制器
+pub struct OrganizationController;
+
+impl OrganizationController {
+    /// 创建组织（创建者成为管理员）
+    pub async fn create(
+        req: web::Json<CreateOrganizationRequest>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Create organization endpoint ===");
+        println!("Name: {}, Creator: {}", req.name, wallet.wallet());
+
+        if let Err(e) = wallet.require(Permission::CreateOrganization) {
+            return e.error_response();
+        }
+
+        match OrganizationService::create_organization(&db, wallet.user_id(), wallet.wallet(), &req.name).await {
+            Ok(org) => {
+                let response = ApiResponse::success_with_message(
+                    OrganizationResponse {
+                        id: org.id,
+                        name: org.name,
+                        created_by: org.created_by,
+                        role: ORG_ROLE_ADMIN.to_string(),
+                        created_at: org.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    },
+                    "Organization created successfully".to_string(),
+                );
+                HttpResponse::Ok().json(response)
+            }
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 获取我加入的组织
+    pub async fn get_my_organizations(
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Get my organizations endpoint ===");
+
+        match OrganizationService::get_my_organizations(&db, wallet.user_id()).await {
+            Ok(orgs) => {
+                let responses: Vec<OrganizationResponse> = orgs
+                    .into_iter()
+                    .map(|(org, role)| OrganizationResponse {
+                        id: org.id,
+                        name: org.name,
+                        created_by: org.created_by,
+                        role,
+                        created_at: org.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    })
+                    .collect();
+                HttpResponse::Ok().json(ApiResponse::success(responses))
+            }
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 获取组织成员（组织成员可查看）
+    pub async fn get_members(
+        org_id: web::Path<i64>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Get organization members endpoint ===");
+        println!("Organization ID: {}", org_id);
+
+        if let Err(e) = OrganizationService::require_member(&db, *org_id, wallet.user_id(), false).await {
+            return e.error_response();
+        }
+
+        match OrganizationService::get_members(&db, *org_id).await {
+            Ok(members) => {
+                let responses: Vec<OrganizationMemberResponse> = members
+                    .into_iter()
+                    .map(OrganizationMemberResponse::from)
+                    .collect();
+                HttpResponse::Ok().json(ApiResponse::success(responses))
+            }
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 添加组织成员（仅管理员）
+    pub async fn add_member(
+        org_id: web::Path<i64>,
+        req: web::Json<AddOrganizationMemberRequest>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Add organization member endpoint ===");
+        println!("Organization ID: {}, Member: {}", org_id, req.wallet_address);
+
+        if let Err(e) = OrganizationService::require_member(&db, *org_id, wallet.user_id(), true).await {
+            return e.error_response();
+        }
+
+        let member_wallet = match Validator::normalize_sui_address(&req.wallet_address) {
+            Ok(address) => address,
+            Err(e) => return e.error_response(),
+        };
+
+        match OrganizationService::add_member(&db, *org_id, &member_wallet, req.role.as_deref()).await {
+            Ok(member) => {
+                let response = ApiResponse::success_with_message(
+                    OrganizationMemberResponse::from(member),
+                    "Member added successfully".to_string(),
+                );
+                HttpResponse::Ok().json(response)
+            }
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 移除组织成员（管理员可移除任何人，成员可以退出）
+    pub async fn remove_member(
+        path: web::Path<(i64, i64)>, // (org_id, user_id)
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        let (org_id, user_id) = path.into_inner();
+
+        println!("=== Remove organization member endpoint ===");
+        println!("Organization ID: {}, User ID: {}", org_id, user_id);
+
+        let admin_only = user_id != wallet.user_id();
+        if let Err(e) = OrganizationService::require_member(&db, org_id, wallet.user_id(), admin_only).await {
+            return e.error_response();
+        }
+
+        match OrganizationService::remove_member(&db, org_id, user_id).await {
+            Ok(_) => HttpResponse::Ok().json(ApiResponse::<()>::success_with_message(
+                (),
+                "Member removed successfully".to_string(),
+            )),
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 获取组织已解锁的简历（组织成员可查看）
+    pub async fn get_unlocks(
+        org_id: web::Path<i64>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Get organization unlocks endpoint ===");
+        println!("Organization ID: {}", org_id);
+
+        if let Err(e) = OrganizationService::require_member(&db, *org_id, wallet.user_id(), false).await {
+            return e.error_response();
+        }
+
+        match OrganizationService::get_unlocks(&db, *org_id).await {
+            Ok(records) => {
+                let responses: Vec<UnlockRecordResponse> = records
+                    .into_iter()
+                    .map(UnlockRecordResponse::from)
+                    .collect();
+                HttpResponse::Ok().json(ApiResponse::success(responses))
+            }
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 获取组织消费统计（组织成员可查看）
+    pub async fn get_spend(
+        org_id: web::Path<i64>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Get organization spend endpoint ===");
+        println!("Organization ID: {}", org_id);
+
+        if let Err(e) = OrganizationService::require_member(&db, *org_id, wallet.user_id(), false).await {
+            return e.error_response();
+        }
+
+        match OrganizationService::get_spend(&db, *org_id).await {
+            Ok(spend) => HttpResponse::Ok().json(ApiResponse::success(spend)),
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+}
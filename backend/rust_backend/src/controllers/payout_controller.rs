@@ -0,0 +1,104 @@
+use actix_web::{web, HttpResponse, Responder, ResponseError};
+use crate::auth::AuthenticatedWallet;
+use crate::models::{ApiResponse, MarkPayoutPaidRequest, SellerLedgerEntryResponse, SellerPayoutResponse};
+use crate::services::PayoutService;
+use sea_orm::DatabaseConnection;
+
+/// 卖家结算控制器
+pub struct PayoutController;
+
+impl PayoutController {
+    /// 当前卖家的余额汇总
+    pub async fn get_balance(
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Get seller balance endpoint ===");
+        println!("Seller: {} (ID: {})", wallet.wallet(), wallet.user_id());
+
+        match PayoutService::get_balance(&db, wallet.user_id()).await {
+            Ok(balance) => HttpResponse::Ok().json(ApiResponse::success(balance)),
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 当前卖家的账目明细
+    pub async fn get_ledger(
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Get seller ledger endpoint ===");
+        println!("Seller: {} (ID: {})", wallet.wallet(), wallet.user_id());
+
+        match PayoutService::get_ledger(&db, wallet.user_id()).await {
+            Ok(entries) => {
+                let entries: Vec<SellerLedgerEntryResponse> = entries.into_iter().map(Into::into).collect();
+                HttpResponse::Ok().json(ApiResponse::success(entries))
+            }
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 当前卖家的结算批次
+    pub async fn get_payouts(
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Get seller payouts endpoint ===");
+        println!("Seller: {} (ID: {})", wallet.wallet(), wallet.user_id());
+
+        match PayoutService::get_payouts(&db, wallet.user_id()).await {
+            Ok(payouts) => {
+                let payouts: Vec<SellerPayoutResponse> = payouts.into_iter().map(Into::into).collect();
+                HttpResponse::Ok().json(ApiResponse::success(payouts))
+            }
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 把未结算账目打包成结算批次（管理员）
+    pub async fn create_batches(
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Create payout batches endpoint ===");
+        println!("Admin: {}", wallet.wallet());
+
+        if let Err(e) = wallet.require_admin() {
+            return e.error_response();
+        }
+
+        match PayoutService::create_batches(&db).await {
+            Ok(payouts) => {
+                println!("✅ Created {} payout batches", payouts.len());
+                let payouts: Vec<SellerPayoutResponse> = payouts.into_iter().map(Into::into).collect();
+                HttpResponse::Ok().json(ApiResponse::success(payouts))
+            }
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 标记结算批次已打款（管理员）
+    pub async fn mark_paid(
+        payout_id: web::Path<i64>,
+        request: web::Json<MarkPayoutPaidRequest>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Mark payout paid endpoint ===");
+        println!("Payout ID: {}, Tx: {}", payout_id, request.transaction_digest);
+
+        if let Err(e) = wallet.require_admin() {
+            return e.error_response();
+        }
+
+        match PayoutService::mark_paid(&db, *payout_id, &request.transaction_digest).await {
+            Ok(Some(payout)) => HttpResponse::Ok().json(ApiResponse::success_with_message(
+                SellerPayoutResponse::from(payout),
+                "Payout marked as paid".to_string(),
+            )),
+            Ok(None) => HttpResponse::NotFound().json(ApiResponse::<()>::error("Payout not found".to_string())),
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use actix_web::{web, HttpResponse, Responder, ResponseError};
+use crate::auth::AuthenticatedWallet;
+use crate::models::{ApiResponse, ReceiptPublicKeyQuery, ReceiptPublicKeyResponse};
+use crate::services::ReceiptService;
+use crate::utils::receipt_signer::{ReceiptSigner, RECEIPT_ALGORITHM};
+use sea_orm::DatabaseConnection;
+
+/// 付费收据控制器
+pub struct ReceiptController;
+
+impl ReceiptController {
+    /// 获取收据签名公钥，用于离线验证收据；`key_id` 可以查询轮换前的公钥
+    pub async fn get_public_key(
+        query: web::Query<ReceiptPublicKeyQuery>,
+        signer: web::Data<ReceiptSigner>,
+    ) -> impl Responder {
+        println!("=== Get receipt public key endpoint ===");
+
+        let key_id = query.key_id.as_deref().unwrap_or(signer.key_id());
+        match signer.public_key(key_id) {
+            Some(public_key) => HttpResponse::Ok().json(ApiResponse::success(ReceiptPublicKeyResponse {
+                algorithm: RECEIPT_ALGORITHM.to_string(),
+                key_id: key_id.to_string(),
+                public_key,
+                current: key_id == signer.key_id(),
+            })),
+            None => HttpResponse::NotFound().json(ApiResponse::<()>::error("Receipt signing key not found".to_string())),
+        }
+    }
+
+    /// 获取收据（买家或卖家）
+    pub async fn get_receipt(
+        receipt_id: web::Path<String>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Get receipt endpoint ===");
+        println!("Receipt ID: {}", receipt_id);
+
+        let receipt = match ReceiptService::get_receipt(&db, &receipt_id, &wallet).await {
+            Ok(Some(receipt)) => receipt,
+            Ok(None) => return HttpResponse::NotFound().json(ApiResponse::<()>::error("Receipt not found".to_string())),
+            Err(e) => return e.error_response(),
+        };
+
+        match ReceiptService::to_response(receipt) {
+            Ok(response) => HttpResponse::Ok().json(ApiResponse::success(response)),
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+}
@@ -1,21 +1,36 @@
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::{web, HttpResponse, Responder, ResponseError};
 use crate::models::{
-    ApiResponse, CreateResumeRequest, SetPriceRequest, UpdateResumeNameRequest,
+    ApiResponse, CommissionConfig, CreateResumeRequest, ResumeUnlockResponse, SetPriceRequest, SetResumeTiersRequest,
+    UnlockResumeQuery, UnlockWithCreditsQuery, UpdateResumeNameRequest,
 };
-use crate::services::ResumeService;
+use crate::auth::{AuthenticatedWallet, Permission};
+use crate::services::resume_tier_service::FULL_TIER;
+use crate::services::credit_service::CreditOwner;
+use crate::services::unlock_record_service::{PaymentSource, ResumePurchase};
+use crate::services::{CreditService, OrganizationService, ReceiptService, ResumeService, ResumeTierService, UnlockRecordService};
+use crate::money::{self, Money};
+use crate::utils::receipt_signer::ReceiptSigner;
+use crate::utils::validator::Validator;
+use crate::x402::types::PAYMENT_RESPONSE_HEADER;
+use crate::x402::{Facilitator, VerifiedPayment};
 use sea_orm::DatabaseConnection;
 
 /// 简历控制器
 pub struct ResumeController;
 
 impl ResumeController {
-    /// 创建简历
+    /// 创建简历（所有者为当前登录钱包）
     pub async fn create(
         req: web::Json<CreateResumeRequest>,
+        wallet: AuthenticatedWallet,
         db: web::Data<DatabaseConnection>,
     ) -> impl Responder {
         println!("=== Create resume endpoint ===");
 
+        if let Err(e) = wallet.require(Permission::CreateResume) {
+            return e.error_response();
+        }
+
         let request = req.into_inner();
         
         // 检查是否提供了 blob_id（Walrus 存储）
@@ -38,7 +53,7 @@ impl ResumeController {
         println!("Creating resume with blob_id: {}, encryption_type: {:?}", 
                  blob_id, request.encryption_type);
 
-        match ResumeService::create_resume(&db, request, blob_id).await {
+        match ResumeService::create_resume(&db, wallet.user_id(), wallet.wallet(), request, blob_id).await {
             Ok(resume_id) => {
                 let response = ApiResponse::success_with_message(
                     resume_id,
@@ -78,6 +93,11 @@ impl ResumeController {
     ) -> impl Responder {
         println!("=== Get my resumes endpoint ===");
 
+        let owner = match Validator::detect_wallet_address(&owner) {
+            Ok((_, owner)) => owner,
+            Err(e) => return e.for_field("owner").error_response(),
+        };
+
         match ResumeService::get_my_resumes(&db, &owner).await {
             Ok(resumes) => {
                 let response = ApiResponse::success(resumes);
@@ -90,16 +110,15 @@ impl ResumeController {
         }
     }
 
-    /// 根据 owner 和简历 ID 获取简历详情
+    /// 获取简历详情（仅限已登录的所有者）
     pub async fn get_resume_detail(
-        path: web::Path<(String, String)>, // (resume_id, owner)
+        resume_id: web::Path<String>,
+        wallet: AuthenticatedWallet,
         db: web::Data<DatabaseConnection>,
     ) -> impl Responder {
         println!("=== Get resume detail endpoint ===");
 
-        let (resume_id, owner) = path.into_inner();
-
-        match ResumeService::get_resume_detail(&db, &resume_id, &owner).await {
+        match ResumeService::get_resume_detail(&db, &resume_id, wallet.user_id()).await {
             Ok(resume) => {
                 let response = ApiResponse::success(resume);
                 HttpResponse::Ok().json(response)
@@ -111,15 +130,207 @@ impl ResumeController {
         }
     }
 
-    /// 更新简历
+    /// x402 付费解锁简历的档位（`?tier=`，默认 `full`），结算成功后由服务端创建解锁记录并返回该档位的解密凭据和签名收据
+    ///
+    /// 已拥有低档位时只需支付差价。结算结果同时通过 `X-PAYMENT-RESPONSE` 头返回。
+    #[allow(clippy::too_many_arguments)]
+    pub async fn unlock(
+        resume_id: web::Path<String>,
+        query: web::Query<UnlockResumeQuery>,
+        payment: Option<web::ReqData<VerifiedPayment>>,
+        wallet: AuthenticatedWallet,
+        facilitator: web::Data<dyn Facilitator>,
+        signer: web::Data<ReceiptSigner>,
+        commission: web::Data<CommissionConfig>,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        let tier = query.tier.as_deref().unwrap_or(FULL_TIER);
+        println!("=== Unlock resume endpoint ===");
+        println!("Resume ID: {}, Tier: {}, Buyer: {} (ID: {})", resume_id, tier, wallet.wallet(), wallet.user_id());
+
+        if let Err(e) = wallet.require(Permission::CreateUnlockRecord) {
+            return e.error_response();
+        }
+
+        // 组织成员购买的简历对整个组织生效，组织 Key 默认归属其组织
+        let organization_id = match OrganizationService::resolve_purchase_organization(
+            &db,
+            wallet.user_id(),
+            wallet.organization_id(),
+        )
+        .await
+        {
+            Ok(organization_id) => organization_id,
+            Err(e) => return e.error_response(),
+        };
+
+        let purchase = match UnlockRecordService::purchase_resume(
+            &db,
+            facilitator.get_ref(),
+            &signer,
+            &commission,
+            PaymentSource::X402(payment.as_deref()),
+            &resume_id,
+            tier,
+            query.coupon.as_deref(),
+            wallet.user_id(),
+            wallet.wallet(),
+            organization_id,
+        )
+        .await
+        {
+            Ok(purchase) => purchase,
+            Err(e) => return e.error_response(),
+        };
+
+        Self::unlock_response(purchase)
+    }
+
+    /// 用预付额度解锁简历的档位（`?tier=`、`?coupon=` 同 x402 解锁）
+    ///
+    /// 从 `?organization_id=` 指定组织的额度账户扣减，不传时从个人额度账户扣减，购买归属同一组织。
+    pub async fn unlock_with_credits(
+        resume_id: web::Path<String>,
+        query: web::Query<UnlockWithCreditsQuery>,
+        wallet: AuthenticatedWallet,
+        facilitator: web::Data<dyn Facilitator>,
+        signer: web::Data<ReceiptSigner>,
+        commission: web::Data<CommissionConfig>,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        let tier = query.tier.as_deref().unwrap_or(FULL_TIER);
+        println!("=== Unlock resume with credits endpoint ===");
+        println!("Resume ID: {}, Tier: {}, Buyer: {} (ID: {})", resume_id, tier, wallet.wallet(), wallet.user_id());
+
+        if let Err(e) = wallet.require(Permission::CreateUnlockRecord) {
+            return e.error_response();
+        }
+
+        let owner = match CreditService::resolve_owner(&db, wallet.user_id(), query.organization_id).await {
+            Ok(owner) => owner,
+            Err(e) => return e.error_response(),
+        };
+        let organization_id = match owner {
+            CreditOwner::Organization(organization_id) => Some(organization_id),
+            CreditOwner::User(_) => None,
+        };
+
+        let purchase = match UnlockRecordService::purchase_resume(
+            &db,
+            facilitator.get_ref(),
+            &signer,
+            &commission,
+            PaymentSource::Credits(owner),
+            &resume_id,
+            tier,
+            query.coupon.as_deref(),
+            wallet.user_id(),
+            wallet.wallet(),
+            organization_id,
+        )
+        .await
+        {
+            Ok(purchase) => purchase,
+            Err(e) => return e.error_response(),
+        };
+
+        Self::unlock_response(purchase)
+    }
+
+    /// 解锁响应：该档位的解密凭据、本次支付信息和收据
+    fn unlock_response(purchase: ResumePurchase) -> HttpResponse {
+        let receipt = match purchase.receipt.map(ReceiptService::to_response).transpose() {
+            Ok(receipt) => receipt,
+            Err(e) => return HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        };
+        let payment_response = purchase.settlement.as_ref().map(|s| s.to_header());
+
+        let credentials = purchase.tier.credentials;
+        let response = ResumeUnlockResponse {
+            resume_id: purchase.resume.resume_id,
+            tier: purchase.tier.name,
+            unlock_record_id: purchase.record.as_ref().map(|r| r.id),
+            already_unlocked: purchase.record.is_none(),
+            amount: purchase
+                .record
+                .as_ref()
+                .map(|r| money::registry().stored(r.amount, &r.asset))
+                .unwrap_or(Money { amount: 0, ..purchase.tier.price }),
+            payment_method: purchase.payment_method.map(str::to_string),
+            discount: purchase.discount,
+            transaction: purchase.settlement.as_ref().map(|s| s.transaction.clone()),
+            network: purchase.settlement.map(|s| s.network),
+            blob_id: credentials.blob_id,
+            encryption_type: credentials.encryption_type,
+            encryption_mode: credentials.encryption_mode,
+            encryption_id: credentials.encryption_id,
+            policy_object_id: credentials.policy_object_id,
+            encryption_key: credentials.encryption_key,
+            receipt,
+        };
+
+        let mut builder = HttpResponse::Ok();
+        if let Some(header) = payment_response {
+            builder.insert_header((PAYMENT_RESPONSE_HEADER, header));
+        }
+        builder.json(ApiResponse::success_with_message(
+            response,
+            "Resume unlocked successfully".to_string(),
+        ))
+    }
+
+    /// 获取简历的价格档位（公开，不包含解密凭据）
+    pub async fn get_tiers(
+        resume_id: web::Path<String>,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Get resume tiers endpoint ===");
+        println!("Resume ID: {}", resume_id);
+
+        match ResumeTierService::list(&db, &resume_id).await {
+            Ok(Some(tiers)) => HttpResponse::Ok().json(ApiResponse::success(tiers)),
+            Ok(None) => HttpResponse::NotFound().json(ApiResponse::<()>::error("Resume not found".to_string())),
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 设置简历的价格档位（仅限已登录的所有者，整体替换）
+    pub async fn set_tiers(
+        resume_id: web::Path<String>,
+        request: web::Json<SetResumeTiersRequest>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Set resume tiers endpoint ===");
+
+        if let Err(e) = wallet.require(Permission::ManageResume) {
+            return e.error_response();
+        }
+        println!("Resume ID: {}, Tiers: {}", resume_id, request.tiers.len());
+
+        match ResumeTierService::set_tiers(&db, &resume_id, wallet.user_id(), request.into_inner().tiers).await {
+            Ok(tiers) => HttpResponse::Ok().json(ApiResponse::success_with_message(
+                tiers,
+                "Resume tiers updated successfully".to_string(),
+            )),
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 更新简历（仅限已登录的所有者）
     pub async fn update(
         resume_id: web::Path<String>,
         request: web::Json<CreateResumeRequest>,
+        wallet: AuthenticatedWallet,
         db: web::Data<DatabaseConnection>,
     ) -> impl Responder {
         println!("=== Update resume endpoint ===");
 
-        match ResumeService::update_resume(&db, &resume_id, request.into_inner()).await {
+        if let Err(e) = wallet.require(Permission::ManageResume) {
+            return e.error_response();
+        }
+
+        match ResumeService::update_resume(&db, &resume_id, wallet.user_id(), request.into_inner()).await {
             Ok(_) => {
                 let response = ApiResponse::<()>::success_with_message(
                     (),
@@ -134,16 +345,19 @@ impl ResumeController {
         }
     }
 
-    /// 删除简历
+    /// 删除简历（仅限已登录的所有者）
     pub async fn delete(
-        path: web::Path<(String, String)>, // (resume_id, owner)
+        resume_id: web::Path<String>,
+        wallet: AuthenticatedWallet,
         db: web::Data<DatabaseConnection>,
     ) -> impl Responder {
         println!("=== Delete resume endpoint ===");
 
-        let (resume_id, owner) = path.into_inner();
+        if let Err(e) = wallet.require(Permission::ManageResume) {
+            return e.error_response();
+        }
 
-        match ResumeService::delete_resume(&db, &resume_id, &owner).await {
+        match ResumeService::delete_resume(&db, &resume_id, wallet.user_id()).await {
             Ok(_) => {
                 let response = ApiResponse::<()>::success_with_message(
                     (),
@@ -158,26 +372,40 @@ impl ResumeController {
         }
     }
 
-    /// 设置简历价格
+    /// 设置简历价格（仅限已登录的所有者）
     pub async fn set_price(
         request: web::Json<SetPriceRequest>,
+        wallet: AuthenticatedWallet,
         db: web::Data<DatabaseConnection>,
     ) -> impl Responder {
         println!("=== Set resume price endpoint ===");
+
+        if let Err(e) = wallet.require(Permission::ManageResume) {
+            return e.error_response();
+        }
+
+        let price = match money::registry()
+            .resolve(request.asset.as_deref())
+            .and_then(|asset| asset.parse(&request.price))
+        {
+            Ok(price) => price,
+            Err(e) => return HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        };
+
         println!("Resume ID: {}", request.resume_id);
-        println!("Owner: {}", request.owner);
-        println!("Price: {} lamports ({} SOL)", request.price, request.price as f64 / 1_000_000_000.0);
+        println!("Owner: {}", wallet.wallet());
+        println!("Price: {} ({} minor units)", price, price.amount);
 
         match ResumeService::set_resume_price(
             &db,
             &request.resume_id,
-            &request.owner,
-            request.price,
+            wallet.user_id(),
+            &price,
         ).await {
             Ok(_) => {
                 let response = ApiResponse::<()>::success_with_message(
                     (),
-                    format!("Resume price set to {} lamports successfully", request.price),
+                    format!("Resume price set to {} successfully", price),
                 );
                 HttpResponse::Ok().json(response)
             }
@@ -188,20 +416,26 @@ impl ResumeController {
         }
     }
 
-    /// 更新简历名称
+    /// 更新简历名称（仅限已登录的所有者）
     pub async fn update_name(
         request: web::Json<UpdateResumeNameRequest>,
+        wallet: AuthenticatedWallet,
         db: web::Data<DatabaseConnection>,
     ) -> impl Responder {
         println!("=== Update resume name endpoint ===");
+
+        if let Err(e) = wallet.require(Permission::ManageResume) {
+            return e.error_response();
+        }
+
         println!("Resume ID: {}", request.resume_id);
-        println!("Owner: {}", request.owner);
+        println!("Owner: {}", wallet.wallet());
         println!("Name: {}", request.name);
 
         match ResumeService::update_resume_name(
             &db,
             &request.resume_id,
-            &request.owner,
+            wallet.user_id(),
             request.name.clone(),
         ).await {
             Ok(_) => {
@@ -1,45 +1,84 @@
-use actix_web::{web, HttpResponse, Responder};
-use crate::models::{ApiResponse, CreateUnlockRecordRequest, UnlockRecordResponse};
-use crate::services::UnlockRecordService;
+use actix_web::{web, HttpResponse, Responder, ResponseError};
+use crate::models::{
+    ApiResponse, CreateUnlockRecordRequest, DisputeListQuery, OpenDisputeRequest, ResolveDisputeRequest, SuiConfig,
+    UnlockDisputeResponse, UnlockRecordResponse,
+};
+use crate::auth::{AuthenticatedWallet, Permission};
+use crate::entities::unlock_record;
+use crate::services::unlock_verification_service::VerificationOutcome;
+use crate::services::{
+    DisputeService, OrganizationService, ResumeService, UnlockRecordService, UnlockVerificationService, UserService,
+};
+use crate::money;
+use crate::sui::SuiRpcClient;
+use crate::utils::validator::Validator;
 use sea_orm::DatabaseConnection;
 
 /// 解锁记录控制器
 pub struct UnlockRecordController;
 
 impl UnlockRecordController {
-    /// 创建解锁记录（支付成功后调用）
+    /// 创建解锁记录（支付成功后调用，购买者为当前登录钱包），交易通过链上校验后才生效
     pub async fn create(
         req: web::Json<CreateUnlockRecordRequest>,
+        wallet: AuthenticatedWallet,
         db: web::Data<DatabaseConnection>,
+        sui: web::Data<dyn SuiRpcClient>,
+        sui_config: web::Data<SuiConfig>,
     ) -> impl Responder {
         println!("=== Create unlock record endpoint ===");
+
+        if let Err(e) = wallet.require(Permission::CreateUnlockRecord) {
+            return e.error_response();
+        }
         println!("Resume ID: {}", req.resume_id);
-        println!("Buyer: {} (ID: {})", req.buyer_wallet, req.buyer_id);
+        println!("Buyer: {} (ID: {})", wallet.wallet(), wallet.user_id());
         println!("Seller: {}", req.seller_wallet);
-        println!("Amount: {}", req.amount);
+        println!("Amount: {} {}", req.amount, req.asset.as_deref().unwrap_or("(default asset)"));
         println!("Transaction: {}", req.transaction_signature);
 
-        let request = req.into_inner();
+        let mut request = req.into_inner();
+        request.seller_wallet = match Validator::normalize_sui_address(&request.seller_wallet) {
+            Ok(seller_wallet) => seller_wallet,
+            Err(e) => return e.for_field("seller_wallet").error_response(),
+        };
+
+        if request.amount < 0 {
+            return HttpResponse::BadRequest().json(ApiResponse::<()>::error("amount must not be negative".to_string()));
+        }
+        let amount = match money::registry().resolve(request.asset.as_deref()) {
+            Ok(asset) => asset.money(request.amount),
+            Err(e) => return HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        };
+
+        // 组织成员购买的简历对整个组织生效，组织 Key 默认归属其组织
+        let organization_id = match OrganizationService::resolve_purchase_organization(
+            &db,
+            wallet.user_id(),
+            request.organization_id.or(wallet.organization_id()),
+        )
+        .await
+        {
+            Ok(organization_id) => organization_id,
+            Err(e) => return e.error_response(),
+        };
 
         match UnlockRecordService::create_unlock_record(
             &db,
+            sui.get_ref(),
+            sui_config.package_id.as_deref(),
             request.resume_id,
-            request.buyer_id,
-            request.buyer_wallet,
+            wallet.user_id(),
+            wallet.wallet().to_string(),
+            organization_id,
             request.seller_wallet,
-            request.amount,
+            amount,
             request.transaction_signature,
             request.block_time,
         )
         .await
         {
-            Ok(record) => {
-                let response = ApiResponse::success_with_message(
-                    UnlockRecordResponse::from(record),
-                    "Unlock record created successfully".to_string(),
-                );
-                HttpResponse::Ok().json(response)
-            }
+            Ok((record, outcome)) => verification_response(record, outcome, "Unlock record created successfully"),
             Err(e) => {
                 let response = ApiResponse::<()>::error(e);
                 HttpResponse::BadRequest().json(response)
@@ -47,9 +86,33 @@ impl UnlockRecordController {
         }
     }
 
-    /// 检查用户是否已解锁某简历
+    /// 重新校验仍为 pending 的解锁记录（买家或卖家）
+    pub async fn verify(
+        record_id: web::Path<i64>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+        sui: web::Data<dyn SuiRpcClient>,
+        sui_config: web::Data<SuiConfig>,
+    ) -> impl Responder {
+        println!("=== Verify unlock record endpoint ===");
+        println!("Unlock record ID: {}", record_id);
+
+        match DisputeService::get_record_for_party(&db, *record_id, &wallet).await {
+            Ok(Some(_)) => {}
+            Ok(None) => return HttpResponse::NotFound().json(ApiResponse::<()>::error("Unlock record not found".to_string())),
+            Err(e) => return e.error_response(),
+        }
+
+        match UnlockVerificationService::verify_record(&db, sui.get_ref(), sui_config.package_id.as_deref(), *record_id).await {
+            Ok((record, outcome)) => verification_response(record, outcome, "Unlock record verified"),
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 检查用户是否已解锁某简历（只有买家本人和简历所有者可以查询）
     pub async fn check_unlock(
         path: web::Path<(i64, i64)>, // (resume_id, buyer_id)
+        wallet: AuthenticatedWallet,
         db: web::Data<DatabaseConnection>,
     ) -> impl Responder {
         let (resume_id, buyer_id) = path.into_inner();
@@ -57,6 +120,16 @@ impl UnlockRecordController {
         println!("=== Check unlock status endpoint ===");
         println!("Resume ID: {}, Buyer ID: {}", resume_id, buyer_id);
 
+        if buyer_id != wallet.user_id() {
+            let owner_id = match ResumeService::owner_id_by_id(&db, resume_id).await {
+                Ok(owner_id) => owner_id,
+                Err(e) => return e.error_response(),
+            };
+            if let Err(e) = wallet.require_owner(owner_id, "this resume") {
+                return e.error_response();
+            }
+        }
+
         match UnlockRecordService::has_unlocked(&db, resume_id, buyer_id).await {
             Ok(unlocked) => {
                 let response = ApiResponse::success(serde_json::json!({
@@ -71,14 +144,28 @@ impl UnlockRecordController {
         }
     }
 
-    /// 获取用户已解锁的所有简历
+    /// 获取用户已解锁的所有简历（只能查看自己的）
     pub async fn get_unlocked_resumes(
         buyer_wallet: web::Path<String>,
+        wallet: AuthenticatedWallet,
         db: web::Data<DatabaseConnection>,
     ) -> impl Responder {
         println!("=== Get unlocked resumes endpoint ===");
         println!("Buyer wallet: {}", buyer_wallet);
 
+        let buyer_wallet = match Validator::detect_wallet_address(&buyer_wallet) {
+            Ok((_, buyer_wallet)) => buyer_wallet,
+            Err(e) => return e.for_field("buyer_wallet").error_response(),
+        };
+
+        let buyer_id = match UserService::user_id_of_wallet(&db, &buyer_wallet).await {
+            Ok(buyer_id) => buyer_id,
+            Err(e) => return e.error_response(),
+        };
+        if let Err(e) = wallet.require_owner(buyer_id, "this wallet's unlock records") {
+            return e.error_response();
+        }
+
         match UnlockRecordService::get_unlocked_resumes(&db, &buyer_wallet).await {
             Ok(records) => {
                 let responses: Vec<UnlockRecordResponse> = records
@@ -98,11 +185,20 @@ impl UnlockRecordController {
     /// 获取简历的所有解锁记录（简历所有者查看）
     pub async fn get_resume_unlock_records(
         resume_id: web::Path<i64>,
+        wallet: AuthenticatedWallet,
         db: web::Data<DatabaseConnection>,
     ) -> impl Responder {
         println!("=== Get resume unlock records endpoint ===");
         println!("Resume ID: {}", resume_id);
 
+        let owner_id = match ResumeService::owner_id_by_id(&db, *resume_id).await {
+            Ok(owner_id) => owner_id,
+            Err(e) => return e.error_response(),
+        };
+        if let Err(e) = wallet.require_owner(owner_id, "this resume") {
+            return e.error_response();
+        }
+
         match UnlockRecordService::get_resume_unlock_records(&db, *resume_id).await {
             Ok(records) => {
                 let responses: Vec<UnlockRecordResponse> = records
@@ -118,4 +214,125 @@ impl UnlockRecordController {
             }
         }
     }
+
+    /// 买家对解锁记录发起争议
+    pub async fn open_dispute(
+        record_id: web::Path<i64>,
+        req: web::Json<OpenDisputeRequest>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Open dispute endpoint ===");
+        println!("Unlock record ID: {}, Buyer ID: {}", record_id, wallet.user_id());
+
+        match DisputeService::get_record_for_party(&db, *record_id, &wallet).await {
+            Ok(Some(_)) => {}
+            Ok(None) => return HttpResponse::NotFound().json(ApiResponse::<()>::error("Unlock record not found".to_string())),
+            Err(e) => return e.error_response(),
+        }
+
+        match DisputeService::open_dispute(&db, *record_id, wallet.user_id(), req.into_inner()).await {
+            Ok(dispute) => HttpResponse::Ok().json(ApiResponse::success_with_message(
+                UnlockDisputeResponse::from(dispute),
+                "Dispute opened".to_string(),
+            )),
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 查看解锁记录的争议（买家或卖家）
+    pub async fn get_dispute(
+        record_id: web::Path<i64>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Get dispute endpoint ===");
+        println!("Unlock record ID: {}", record_id);
+
+        match DisputeService::get_record_for_party(&db, *record_id, &wallet).await {
+            Ok(Some(_)) => {}
+            Ok(None) => return HttpResponse::NotFound().json(ApiResponse::<()>::error("Unlock record not found".to_string())),
+            Err(e) => return e.error_response(),
+        }
+
+        match DisputeService::get_by_record(&db, *record_id).await {
+            Ok(Some(dispute)) => HttpResponse::Ok().json(ApiResponse::success(UnlockDisputeResponse::from(dispute))),
+            Ok(None) => HttpResponse::NotFound().json(ApiResponse::<()>::error("Dispute not found".to_string())),
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 列出争议（管理员，可按状态过滤）
+    pub async fn list_disputes(
+        query: web::Query<DisputeListQuery>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== List disputes endpoint ===");
+        println!("Admin: {}, Status: {:?}", wallet.wallet(), query.status);
+
+        if let Err(e) = wallet.require_admin() {
+            return e.error_response();
+        }
+
+        match DisputeService::list(&db, query.status.as_deref()).await {
+            Ok(disputes) => {
+                let disputes: Vec<UnlockDisputeResponse> = disputes.into_iter().map(Into::into).collect();
+                HttpResponse::Ok().json(ApiResponse::success(disputes))
+            }
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 裁决争议：退款（收回解锁权益）或驳回（管理员）
+    pub async fn resolve_dispute(
+        dispute_id: web::Path<i64>,
+        req: web::Json<ResolveDisputeRequest>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Resolve dispute endpoint ===");
+        println!("Dispute ID: {}, Resolution: {}", dispute_id, req.resolution);
+
+        if let Err(e) = wallet.require_admin() {
+            return e.error_response();
+        }
+
+        match DisputeService::resolve(&db, *dispute_id, wallet.user_id(), req.into_inner()).await {
+            Ok(Some(dispute)) => {
+                println!("✅ Dispute {} resolved as {}", dispute.id, dispute.status);
+                HttpResponse::Ok().json(ApiResponse::success_with_message(
+                    UnlockDisputeResponse::from(dispute),
+                    "Dispute resolved".to_string(),
+                ))
+            }
+            Ok(None) => HttpResponse::NotFound().json(ApiResponse::<()>::error("Dispute not found".to_string())),
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+}
+
+/// 按链上校验结果返回解锁记录：通过为 200，暂未确认为 202，不通过为 400（附带失败的记录）
+fn verification_response(record: unlock_record::Model, outcome: VerificationOutcome, confirmed: &str) -> HttpResponse {
+    let record = UnlockRecordResponse::from(record);
+    match outcome {
+        VerificationOutcome::Confirmed => {
+            println!("✅ Unlock record {} confirmed on chain", record.id);
+            HttpResponse::Ok().json(ApiResponse::success_with_message(record, confirmed.to_string()))
+        }
+        VerificationOutcome::Pending(reason) => {
+            println!("⏳ Unlock record {} is pending: {}", record.id, reason);
+            let message = format!("Transaction is not verified yet ({}), retry verification later", reason);
+            HttpResponse::Accepted().json(ApiResponse::success_with_message(record, message))
+        }
+        VerificationOutcome::Failed(reason) => {
+            println!("❌ Unlock record {} failed verification: {}", record.id, reason);
+            HttpResponse::BadRequest().json(ApiResponse {
+                success: false,
+                data: Some(record),
+                message: None,
+                error: Some(format!("Transaction verification failed: {}", reason)),
+            })
+        }
+    }
 }
@@ -1,8 +1,11 @@
-use actix_web::{web, HttpResponse, Result};
+use actix_web::{http::Method, web, HttpResponse, Result};
 use sea_orm::DatabaseConnection;
 use serde::{Deserialize, Serialize};
 
+use crate::auth::AuthenticatedWallet;
+use crate::auth::policy::route;
 use crate::services::UserService;
+use crate::utils::validator::Validator;
 
 #[derive(Debug, Serialize)]
 struct ApiResponse<T> {
@@ -24,7 +27,13 @@ struct UserResponse {
 
 #[derive(Debug, Deserialize)]
 pub struct RegisterRequest {
-    wallet_address: String,
+    /// 角色: job_seeker / recruiter / both，不传则保持当前角色
+    user_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateRoleRequest {
+    user_type: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -32,54 +41,78 @@ pub struct UpdateNicknameRequest {
     nickname: String,
 }
 
-/// POST /api/users/register - 创建或获取用户
+/// POST /api/users/register - 注册：为当前登录钱包选择角色
+///
+/// 用户在钱包签名登录时创建（默认 job_seeker），注册时可指定 user_type
 pub async fn register_user(
     db: web::Data<DatabaseConnection>,
+    wallet: AuthenticatedWallet,
     request: web::Json<RegisterRequest>,
 ) -> Result<HttpResponse> {
-    match UserService::create_or_get_user(db.get_ref(), request.wallet_address.clone()).await {
-        Ok(user_id) => {
-            // 获取完整用户信息
-            match UserService::get_user_by_id(db.get_ref(), user_id).await {
-                Ok(Some(user)) => {
-                    let response = ApiResponse {
-                        success: true,
-                        data: Some(UserResponse {
-                            id: user.id,
-                            wallet_address: user.wallet_address,
-                            nickname: user.nickname,
-                            user_type: user.user_type,
-                            created_at: user.created_at,
-                        }),
-                        error: None,
-                    };
-                    Ok(HttpResponse::Ok().json(response))
-                }
-                Ok(None) => Ok(HttpResponse::InternalServerError().json(ApiResponse::<()> {
-                    success: false,
-                    data: None,
-                    error: Some("User created but not found".to_string()),
-                })),
-                Err(e) => Ok(HttpResponse::InternalServerError().json(ApiResponse::<()> {
+    let user = match request.user_type.as_deref() {
+        Some(user_type) if user_type != wallet.user.user_type => {
+            match UserService::set_user_type(db.get_ref(), wallet.user_id(), user_type).await {
+                Ok(user) => user,
+                Err(e) => return Ok(HttpResponse::BadRequest().json(ApiResponse::<()> {
                     success: false,
                     data: None,
-                    error: Some(format!("Failed to fetch user: {}", e)),
+                    error: Some(format!("Failed to register user: {}", e)),
                 })),
             }
         }
+        _ => wallet.user,
+    };
+
+    let response = ApiResponse {
+        success: true,
+        data: Some(UserResponse {
+            id: user.id,
+            wallet_address: user.wallet_address,
+            nickname: user.nickname,
+            user_type: user.user_type,
+            created_at: user.created_at,
+        }),
+        error: None,
+    };
+    Ok(HttpResponse::Ok().json(response))
+}
+
+/// PUT /api/users/role - 切换当前用户角色（job_seeker / recruiter / both）
+pub async fn update_role(
+    db: web::Data<DatabaseConnection>,
+    wallet: AuthenticatedWallet,
+    request: web::Json<UpdateRoleRequest>,
+) -> Result<HttpResponse> {
+    match UserService::set_user_type(db.get_ref(), wallet.user_id(), &request.user_type).await {
+        Ok(user) => {
+            let response = ApiResponse {
+                success: true,
+                data: Some(UserResponse {
+                    id: user.id,
+                    wallet_address: user.wallet_address,
+                    nickname: user.nickname,
+                    user_type: user.user_type,
+                    created_at: user.created_at,
+                }),
+                error: None,
+            };
+            Ok(HttpResponse::Ok().json(response))
+        }
         Err(e) => Ok(HttpResponse::BadRequest().json(ApiResponse::<()> {
             success: false,
             data: None,
-            error: Some(format!("Failed to register user: {}", e)),
+            error: Some(format!("Failed to update role: {}", e)),
         })),
     }
 }
 
-/// GET /api/users/wallet/:wallet - 通过钱包地址获取用户
+/// GET /api/users/wallet/:wallet - 通过钱包地址获取用户（主钱包或任意关联钱包）
 pub async fn get_user_by_wallet(
     db: web::Data<DatabaseConnection>,
     wallet: web::Path<String>,
 ) -> Result<HttpResponse> {
+    let (_, wallet) = Validator::detect_wallet_address(&wallet)?;
+
     match UserService::get_user_by_wallet(db.get_ref(), &wallet).await {
         Ok(Some(user)) => {
             let response = ApiResponse {
@@ -141,12 +174,27 @@ pub async fn get_user_by_id(
     }
 }
 
-/// POST /api/users/wallet/:wallet/nickname - 更新用户昵称
+/// POST /api/users/wallet/:wallet/nickname - 更新用户昵称（仅限本人）
 pub async fn update_nickname(
     db: web::Data<DatabaseConnection>,
     wallet: web::Path<String>,
+    caller: AuthenticatedWallet,
     request: web::Json<UpdateNicknameRequest>,
 ) -> Result<HttpResponse> {
+    // 路径中的钱包可以是本人的任一关联钱包
+    let (_, wallet) = Validator::detect_wallet_address(&wallet)?;
+    let is_own_wallet = matches!(
+        UserService::get_user_by_wallet(db.get_ref(), &wallet).await,
+        Ok(Some(ref user)) if user.id == caller.user_id()
+    );
+    if !is_own_wallet {
+        return Ok(HttpResponse::Forbidden().json(ApiResponse::<()> {
+            success: false,
+            data: None,
+            error: Some("Unauthorized: You can only update your own nickname".to_string()),
+        }));
+    }
+
     match UserService::update_user_nickname(db.get_ref(), &wallet, request.nickname.clone()).await
     {
         Ok(_) => {
@@ -187,11 +235,13 @@ pub async fn update_nickname(
 }
 
 pub fn config(cfg: &mut web::ServiceConfig) {
+    const SCOPE: &str = "/api/users";
     cfg.service(
-        web::scope("/api/users")
-            .route("/register", web::post().to(register_user))
-            .route("/wallet/{wallet}", web::get().to(get_user_by_wallet))
-            .route("/id/{id}", web::get().to(get_user_by_id))
-            .route("/wallet/{wallet}/nickname", web::post().to(update_nickname)),
+        web::scope(SCOPE)
+            .service(route(SCOPE, Method::POST, "/register", register_user))
+            .service(route(SCOPE, Method::PUT, "/role", update_role))
+            .service(route(SCOPE, Method::GET, "/wallet/{wallet}", get_user_by_wallet))
+            .service(route(SCOPE, Method::GET, "/id/{id}", get_user_by_id))
+            .service(route(SCOPE, Method::POST, "/wallet/{wallet}/nickname", update_nickname)),
     );
 }
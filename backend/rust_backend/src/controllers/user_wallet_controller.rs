@@ -0,0 +1,96 @@
+use actix_web::{web, HttpResponse, Responder, ResponseError};
+use crate::auth::AuthenticatedWallet;
+use crate::models::{
+    ApiResponse, AuthConfig, LinkWalletChallengeRequest, LinkWalletRequest, UserWalletResponse,
+};
+use crate::services::UserWalletService;
+use crate::utils::validator::{Chain, Validator};
+use sea_orm::DatabaseConnection;
+
+/// 多链钱包关联控制器
+pub struct UserWalletController;
+
+impl UserWalletController {
+    /// 申请关联钱包挑战
+    pub async fn challenge(
+        req: web::Json<LinkWalletChallengeRequest>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+        config: web::Data<AuthConfig>,
+    ) -> impl Responder {
+        println!("=== Link wallet challenge endpoint ===");
+        println!("User ID: {}, Chain: {}, Address: {}", wallet.user_id(), req.chain, req.address);
+
+        let chain = match Chain::parse(&req.chain) {
+            Ok(chain) => chain,
+            Err(e) => return e.error_response(),
+        };
+        let address = match Validator::normalize_wallet_address(chain, &req.address) {
+            Ok(address) => address,
+            Err(e) => return e.for_field("address").error_response(),
+        };
+
+        match UserWalletService::issue_link_challenge(&db, &config, wallet.user_id(), chain, address).await {
+            Ok(challenge) => HttpResponse::Ok().json(ApiResponse::success(challenge)),
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 提交待关联钱包的签名
+    pub async fn link(
+        req: web::Json<LinkWalletRequest>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Link wallet endpoint ===");
+        println!("User ID: {}, Nonce: {}", wallet.user_id(), req.nonce);
+
+        match UserWalletService::link_wallet(&db, wallet.user_id(), &req.nonce, &req.signature).await {
+            Ok(linked) => {
+                let response = ApiResponse::success_with_message(
+                    UserWalletResponse::from(linked),
+                    "Wallet linked successfully".to_string(),
+                );
+                HttpResponse::Ok().json(response)
+            }
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 获取我关联的所有钱包
+    pub async fn list(
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== List linked wallets endpoint ===");
+
+        match UserWalletService::list_wallets(&db, wallet.user_id()).await {
+            Ok(wallets) => {
+                let responses: Vec<UserWalletResponse> = wallets
+                    .into_iter()
+                    .map(UserWalletResponse::from)
+                    .collect();
+                HttpResponse::Ok().json(ApiResponse::success(responses))
+            }
+            Err(e) => HttpResponse::InternalServerError().json(ApiResponse::<()>::error(e)),
+        }
+    }
+
+    /// 取消关联钱包
+    pub async fn unlink(
+        wallet_id: web::Path<i64>,
+        wallet: AuthenticatedWallet,
+        db: web::Data<DatabaseConnection>,
+    ) -> impl Responder {
+        println!("=== Unlink wallet endpoint ===");
+        println!("User ID: {}, Wallet ID: {}", wallet.user_id(), wallet_id);
+
+        match UserWalletService::unlink_wallet(&db, wallet.user_id(), *wallet_id).await {
+            Ok(_) => HttpResponse::Ok().json(ApiResponse::<()>::success_with_message(
+                (),
+                "Wallet unlinked successfully".to_string(),
+            )),
+            Err(e) => HttpResponse::BadRequest().json(ApiResponse::<()>::error(e)),
+        }
+    }
+}
@@ -6,6 +6,7 @@ pub struct AccessLogDao;
 impl AccessLogDao {
     /// 创建访问记录
     #[allow(dead_code)]
+    #[allow(clippy::too_many_arguments)]
     pub async fn create(
         db: &DatabaseConnection,
         resume_id: String,
@@ -0,0 +1,94 @@
+use sea_orm::*;
+use sea_orm::sea_query::Expr;
+use crate::entities::{allowlist, allowlist_blob};
+
+pub struct AllowlistDao;
+
+impl AllowlistDao {
+    /// 记录链上创建的 allowlist，已存在时返回已有记录
+    pub async fn create_if_missing(
+        db: &DatabaseConnection,
+        allowlist_id: String,
+        name: String,
+        creator: String,
+        resume_id: Option<i64>,
+        tx_digest: String,
+    ) -> Result<allowlist::Model, DbErr> {
+        if let Some(existing) = Self::find_by_allowlist_id(db, &allowlist_id).await? {
+            return Ok(existing);
+        }
+
+        let now = chrono::Utc::now().naive_utc();
+        let allowlist = allowlist::ActiveModel {
+            allowlist_id: Set(allowlist_id),
+            name: Set(name),
+            creator: Set(creator),
+            resume_id: Set(resume_id),
+            tx_digest: Set(tx_digest),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        allowlist.insert(db).await
+    }
+
+    /// 根据 allowlist 对象 ID 查询
+    pub async fn find_by_allowlist_id(
+        db: &DatabaseConnection,
+        allowlist_id: &str,
+    ) -> Result<Option<allowlist::Model>, DbErr> {
+        allowlist::Entity::find()
+            .filter(allowlist::Column::AllowlistId.eq(allowlist_id))
+            .one(db)
+            .await
+    }
+
+    /// 关联简历（只在尚未关联时设置）
+    pub async fn link_resume(
+        db: &DatabaseConnection,
+        allowlist_id: &str,
+        resume_id: i64,
+    ) -> Result<(), DbErr> {
+        allowlist::Entity::update_many()
+            .col_expr(allowlist::Column::ResumeId, Expr::value(resume_id))
+            .col_expr(allowlist::Column::UpdatedAt, Expr::value(chrono::Utc::now().naive_utc()))
+            .filter(allowlist::Column::AllowlistId.eq(allowlist_id))
+            .filter(allowlist::Column::ResumeId.is_null())
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+
+    /// 记录发布到 allowlist 的 blob，已存在时不重复记录
+    pub async fn create_blob_if_missing(
+        db: &DatabaseConnection,
+        allowlist_id: String,
+        blob_id: String,
+        publisher: String,
+        resume_id: Option<i64>,
+        tx_digest: String,
+    ) -> Result<(), DbErr> {
+        let exists = allowlist_blob::Entity::find()
+            .filter(allowlist_blob::Column::AllowlistId.eq(allowlist_id.as_str()))
+            .filter(allowlist_blob::Column::BlobId.eq(blob_id.as_str()))
+            .count(db)
+            .await?
+            > 0;
+        if exists {
+            return Ok(());
+        }
+
+        let blob = allowlist_blob::ActiveModel {
+            allowlist_id: Set(allowlist_id),
+            blob_id: Set(blob_id),
+            publisher: Set(publisher),
+            resume_id: Set(resume_id),
+            tx_digest: Set(tx_digest),
+            created_at: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        };
+        blob.insert(db).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+use sea_orm::*;
+use sea_orm::sea_query::Expr;
+use crate::entities::allowlist_member;
+
+/// 成员状态：pending 为所有者已提交、尚未被链上事件确认的添加
+pub const MEMBER_STATUS_PENDING: &str = "pending";
+pub const MEMBER_STATUS_ACTIVE: &str = "active";
+pub const MEMBER_STATUS_REMOVED: &str = "removed";
+
+pub struct AllowlistMemberDao;
+
+impl AllowlistMemberDao {
+    /// 查询 allowlist 中的成员（任意状态）
+    pub async fn find(
+        db: &DatabaseConnection,
+        policy_object_id: &str,
+        member_address: &str,
+    ) -> Result<Option<allowlist_member::Model>, DbErr> {
+        allowlist_member::Entity::find()
+            .filter(allowlist_member::Column::PolicyObjectId.eq(policy_object_id))
+            .filter(allowlist_member::Column::MemberAddress.eq(member_address))
+            .one(db)
+            .await
+    }
+
+    /// 查询若干 allowlist 的全部成员（任意状态），最近变更的在前
+    pub async fn find_by_policies(
+        db: &DatabaseConnection,
+        policy_object_ids: &[String],
+    ) -> Result<Vec<allowlist_member::Model>, DbErr> {
+        allowlist_member::Entity::find()
+            .filter(allowlist_member::Column::PolicyObjectId.is_in(policy_object_ids.iter().map(String::as_str)))
+            .order_by_desc(allowlist_member::Column::UpdatedAt)
+            .all(db)
+            .await
+    }
+
+    /// 记录所有者提交的添加交易，成员状态置为 pending；已是 active 的成员返回 None
+    pub async fn record_pending(
+        db: &DatabaseConnection,
+        policy_object_id: String,
+        member_address: String,
+        resume_id: i64,
+        added_by: String,
+        tx_digest: String,
+    ) -> Result<Option<allowlist_member::Model>, DbErr> {
+        let now = chrono::Utc::now().naive_utc();
+
+        match Self::find(db, &policy_object_id, &member_address).await? {
+            Some(existing) if existing.status == MEMBER_STATUS_ACTIVE => Ok(None),
+            Some(existing) => {
+                let mut member: allowlist_member::ActiveModel = existing.into();
+                member.resume_id = Set(Some(resume_id));
+                member.added_by = Set(added_by);
+                member.tx_digest = Set(tx_digest);
+                member.status = Set(MEMBER_STATUS_PENDING.to_string());
+                member.updated_at = Set(now);
+                member.update(db).await.map(Some)
+            }
+            None => {
+                let member = allowlist_member::ActiveModel {
+                    policy_object_id: Set(policy_object_id),
+                    member_address: Set(member_address),
+                    resume_id: Set(Some(resume_id)),
+                    added_by: Set(added_by),
+                    tx_digest: Set(tx_digest),
+                    status: Set(MEMBER_STATUS_PENDING.to_string()),
+                    created_at: Set(now),
+                    updated_at: Set(now),
+                    ..Default::default()
+                };
+                member.insert(db).await.map(Some)
+            }
+        }
+    }
+
+    /// 成员加入（或重新加入）allowlist，状态置为 active
+    pub async fn upsert_active(
+        db: &DatabaseConnection,
+        policy_object_id: String,
+        member_address: String,
+        resume_id: Option<i64>,
+        added_by: String,
+        tx_digest: String,
+    ) -> Result<allowlist_member::Model, DbErr> {
+        let now = chrono::Utc::now().naive_utc();
+
+        match Self::find(db, &policy_object_id, &member_address).await? {
+            Some(existing) => {
+                let resume_id = resume_id.or(existing.resume_id);
+                let mut member: allowlist_member::ActiveModel = existing.into();
+                member.resume_id = Set(resume_id);
+                member.added_by = Set(added_by);
+                member.tx_digest = Set(tx_digest);
+                member.status = Set(MEMBER_STATUS_ACTIVE.to_string());
+                member.updated_at = Set(now);
+                member.update(db).await
+            }
+            None => {
+                let member = allowlist_member::ActiveModel {
+                    policy_object_id: Set(policy_object_id),
+                    member_address: Set(member_address),
+                    resume_id: Set(resume_id),
+                    added_by: Set(added_by),
+                    tx_digest: Set(tx_digest),
+                    status: Set(MEMBER_STATUS_ACTIVE.to_string()),
+                    created_at: Set(now),
+                    updated_at: Set(now),
+                    ..Default::default()
+                };
+                member.insert(db).await
+            }
+        }
+    }
+
+    /// 标记成员已移除（同时记录移除交易），成员不存在或已移除时返回 false
+    pub async fn mark_removed(
+        db: &DatabaseConnection,
+        policy_object_id: &str,
+        member_address: &str,
+        tx_digest: Option<&str>,
+    ) -> Result<bool, DbErr> {
+        let mut update = allowlist_member::Entity::update_many();
+        if let Some(tx_digest) = tx_digest {
+            update = update.col_expr(allowlist_member::Column::TxDigest, Expr::value(tx_digest));
+        }
+        let result = update
+            .col_expr(allowlist_member::Column::Status, Expr::value(MEMBER_STATUS_REMOVED))
+            .col_expr(allowlist_member::Column::UpdatedAt, Expr::value(chrono::Utc::now().naive_utc()))
+            .filter(allowlist_member::Column::PolicyObjectId.eq(policy_object_id))
+            .filter(allowlist_member::Column::MemberAddress.eq(member_address))
+            .filter(allowlist_member::Column::Status.ne(MEMBER_STATUS_REMOVED))
+            .exec(db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// allowlist 关联简历后回填成员的简历 ID
+    pub async fn link_resume(
+        db: &DatabaseConnection,
+        policy_object_id: &str,
+        resume_id: i64,
+    ) -> Result<u64, DbErr> {
+        let result = allowlist_member::Entity::update_many()
+            .col_expr(allowlist_member::Column::ResumeId, Expr::value(resume_id))
+            .filter(allowlist_member::Column::PolicyObjectId.eq(policy_object_id))
+            .filter(allowlist_member::Column::ResumeId.is_null())
+            .exec(db)
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,42 @@
+use sea_orm::*;
+use crate::entities::api_key_audit_log;
+
+pub struct ApiKeyAuditLogDao;
+
+impl ApiKeyAuditLogDao {
+    /// 记录一次 API Key 调用
+    pub async fn create(
+        db: &DatabaseConnection,
+        api_key_id: i64,
+        method: String,
+        path: String,
+        status_code: i32,
+        ip_address: Option<String>,
+    ) -> Result<api_key_audit_log::Model, DbErr> {
+        let new_log = api_key_audit_log::ActiveModel {
+            api_key_id: Set(api_key_id),
+            method: Set(method),
+            path: Set(path),
+            status_code: Set(status_code),
+            ip_address: Set(ip_address),
+            created_at: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        new_log.insert(db).await
+    }
+
+    /// 获取 Key 的审计日志
+    pub async fn find_by_api_key(
+        db: &DatabaseConnection,
+        api_key_id: i64,
+        limit: u64,
+    ) -> Result<Vec<api_key_audit_log::Model>, DbErr> {
+        api_key_audit_log::Entity::find()
+            .filter(api_key_audit_log::Column::ApiKeyId.eq(api_key_id))
+            .order_by_desc(api_key_audit_log::Column::CreatedAt)
+            .limit(limit)
+            .all(db)
+            .await
+    }
+}
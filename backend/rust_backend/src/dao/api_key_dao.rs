@@ -0,0 +1,116 @@
+use sea_orm::*;
+use sea_orm::sea_query::Expr;
+use crate::entities::api_key;
+
+pub struct ApiKeyDao;
+
+impl ApiKeyDao {
+    /// 创建 API Key
+    pub async fn create(
+        db: &DatabaseConnection,
+        name: String,
+        key_prefix: String,
+        key_hash: String,
+        user_id: i64,
+        organization_id: Option<i64>,
+        scopes: String,
+    ) -> Result<api_key::Model, DbErr> {
+        let now = chrono::Utc::now().naive_utc();
+
+        let new_key = api_key::ActiveModel {
+            name: Set(name),
+            key_prefix: Set(key_prefix),
+            key_hash: Set(key_hash),
+            user_id: Set(user_id),
+            organization_id: Set(organization_id),
+            scopes: Set(scopes),
+            last_used_at: Set(None),
+            revoked_at: Set(None),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        new_key.insert(db).await
+    }
+
+    /// 根据 ID 查询
+    pub async fn find_by_id(
+        db: &DatabaseConnection,
+        api_key_id: i64,
+    ) -> Result<Option<api_key::Model>, DbErr> {
+        api_key::Entity::find_by_id(api_key_id).one(db).await
+    }
+
+    /// 根据哈希查询未吊销的 Key
+    pub async fn find_active_by_hash(
+        db: &DatabaseConnection,
+        key_hash: &str,
+    ) -> Result<Option<api_key::Model>, DbErr> {
+        api_key::Entity::find()
+            .filter(api_key::Column::KeyHash.eq(key_hash))
+            .filter(api_key::Column::RevokedAt.is_null())
+            .one(db)
+            .await
+    }
+
+    /// 查询用户创建的所有 Key
+    pub async fn find_by_user(
+        db: &DatabaseConnection,
+        user_id: i64,
+    ) -> Result<Vec<api_key::Model>, DbErr> {
+        api_key::Entity::find()
+            .filter(api_key::Column::UserId.eq(user_id))
+            .order_by_desc(api_key::Column::CreatedAt)
+            .all(db)
+            .await
+    }
+
+    /// 轮换：替换 Key 的哈希和前缀，旧 Key 立即失效
+    pub async fn rotate(
+        db: &DatabaseConnection,
+        api_key_id: i64,
+        key_prefix: String,
+        key_hash: String,
+    ) -> Result<(), DbErr> {
+        api_key::Entity::update_many()
+            .col_expr(api_key::Column::KeyPrefix, Expr::value(key_prefix))
+            .col_expr(api_key::Column::KeyHash, Expr::value(key_hash))
+            .col_expr(api_key::Column::UpdatedAt, Expr::value(chrono::Utc::now().naive_utc()))
+            .filter(api_key::Column::Id.eq(api_key_id))
+            .filter(api_key::Column::RevokedAt.is_null())
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+
+    /// 吊销 Key
+    pub async fn revoke(
+        db: &DatabaseConnection,
+        api_key_id: i64,
+    ) -> Result<(), DbErr> {
+        let now = chrono::Utc::now().naive_utc();
+
+        api_key::Entity::update_many()
+            .col_expr(api_key::Column::RevokedAt, Expr::value(now))
+            .col_expr(api_key::Column::UpdatedAt, Expr::value(now))
+            .filter(api_key::Column::Id.eq(api_key_id))
+            .filter(api_key::Column::RevokedAt.is_null())
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+
+    /// 更新最后使用时间
+    pub async fn touch_last_used(
+        db: &DatabaseConnection,
+        api_key_id: i64,
+    ) -> Result<(), DbErr> {
+        api_key::Entity::update_many()
+            .col_expr(api_key::Column::LastUsedAt, Expr::value(chrono::Utc::now().naive_utc()))
+            .filter(api_key::Column::Id.eq(api_key_id))
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+}
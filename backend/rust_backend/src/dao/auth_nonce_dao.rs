@@ -0,0 +1,79 @@
+use sea_orm::*;
+use sea_orm::sea_query::Expr;
+use crate::entities::auth_nonce;
+
+pub struct AuthNonceDao;
+
+impl AuthNonceDao {
+    /// 创建签名挑战
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        db: &DatabaseConnection,
+        wallet_address: String,
+        nonce: String,
+        message: String,
+        purpose: &str,
+        chain: &str,
+        user_id: Option<i64>,
+        expires_at: chrono::NaiveDateTime,
+    ) -> Result<auth_nonce::Model, DbErr> {
+        let now = chrono::Utc::now();
+
+        let new_nonce = auth_nonce::ActiveModel {
+            wallet_address: Set(wallet_address),
+            nonce: Set(nonce),
+            message: Set(message),
+            purpose: Set(purpose.to_string()),
+            chain: Set(chain.to_string()),
+            user_id: Set(user_id),
+            used: Set(false),
+            expires_at: Set(expires_at),
+            created_at: Set(now.naive_utc()),
+            ..Default::default()
+        };
+
+        new_nonce.insert(db).await
+    }
+
+    /// 根据 nonce 查询
+    pub async fn find_by_nonce(
+        db: &DatabaseConnection,
+        nonce: &str,
+    ) -> Result<Option<auth_nonce::Model>, DbErr> {
+        auth_nonce::Entity::find()
+            .filter(auth_nonce::Column::Nonce.eq(nonce))
+            .one(db)
+            .await
+    }
+
+    /// 原子地消费 nonce，只有未使用且未过期的 nonce 才会成功
+    pub async fn consume(
+        db: &DatabaseConnection,
+        nonce: &str,
+    ) -> Result<bool, DbErr> {
+        let now = chrono::Utc::now().naive_utc();
+
+        let result = auth_nonce::Entity::update_many()
+            .col_expr(auth_nonce::Column::Used, Expr::value(true))
+            .filter(auth_nonce::Column::Nonce.eq(nonce))
+            .filter(auth_nonce::Column::Used.eq(false))
+            .filter(auth_nonce::Column::ExpiresAt.gt(now))
+            .exec(db)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    /// 清理已过期的 nonce
+    #[allow(dead_code)]
+    pub async fn delete_expired(db: &DatabaseConnection) -> Result<u64, DbErr> {
+        let now = chrono::Utc::now().naive_utc();
+
+        let result = auth_nonce::Entity::delete_many()
+            .filter(auth_nonce::Column::ExpiresAt.lt(now))
+            .exec(db)
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,176 @@
+use sea_orm::*;
+use sea_orm::sea_query::Expr;
+use crate::entities::auth_session;
+use crate::models::SessionClientInfo;
+
+pub struct AuthSessionDao;
+
+impl AuthSessionDao {
+    /// 创建会话
+    pub async fn create(
+        db: &DatabaseConnection,
+        token_hash: String,
+        user_id: i64,
+        wallet_address: String,
+        client: SessionClientInfo,
+        expires_at: chrono::NaiveDateTime,
+    ) -> Result<auth_session::Model, DbErr> {
+        let now = chrono::Utc::now();
+
+        let new_session = auth_session::ActiveModel {
+            token_hash: Set(token_hash),
+            user_id: Set(user_id),
+            wallet_address: Set(wallet_address),
+            device_label: Set(client.device_label),
+            ip_address: Set(client.ip_address),
+            user_agent: Set(client.user_agent),
+            last_seen_at: Set(Some(now.naive_utc())),
+            expires_at: Set(expires_at),
+            revoked_at: Set(None),
+            created_at: Set(now.naive_utc()),
+            ..Default::default()
+        };
+
+        new_session.insert(db).await
+    }
+
+    /// 根据 token 哈希查询有效会话（未注销且未过期）
+    pub async fn find_active_by_token_hash(
+        db: &DatabaseConnection,
+        token_hash: &str,
+    ) -> Result<Option<auth_session::Model>, DbErr> {
+        let now = chrono::Utc::now().naive_utc();
+
+        auth_session::Entity::find()
+            .filter(auth_session::Column::TokenHash.eq(token_hash))
+            .filter(auth_session::Column::RevokedAt.is_null())
+            .filter(auth_session::Column::ExpiresAt.gt(now))
+            .one(db)
+            .await
+    }
+
+    /// 查询用户的所有有效会话（最近活跃的在前）
+    pub async fn find_active_by_user(
+        db: &DatabaseConnection,
+        user_id: i64,
+    ) -> Result<Vec<auth_session::Model>, DbErr> {
+        let now = chrono::Utc::now().naive_utc();
+
+        auth_session::Entity::find()
+            .filter(auth_session::Column::UserId.eq(user_id))
+            .filter(auth_session::Column::RevokedAt.is_null())
+            .filter(auth_session::Column::ExpiresAt.gt(now))
+            .order_by_desc(auth_session::Column::LastSeenAt)
+            .all(db)
+            .await
+    }
+
+    /// 刷新最后活跃时间并顺延过期时间
+    pub async fn touch(
+        db: &DatabaseConnection,
+        session_id: i64,
+        last_seen_at: chrono::NaiveDateTime,
+        expires_at: chrono::NaiveDateTime,
+    ) -> Result<(), DbErr> {
+        auth_session::Entity::update_many()
+            .col_expr(auth_session::Column::LastSeenAt, Expr::value(last_seen_at))
+            .col_expr(auth_session::Column::ExpiresAt, Expr::value(expires_at))
+            .filter(auth_session::Column::Id.eq(session_id))
+            .filter(auth_session::Column::RevokedAt.is_null())
+            .exec(db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// 注销用户的某个会话，返回是否有会话被注销
+    pub async fn revoke_for_user(
+        db: &DatabaseConnection,
+        user_id: i64,
+        session_id: i64,
+    ) -> Result<bool, DbErr> {
+        let now = chrono::Utc::now().naive_utc();
+
+        let result = auth_session::Entity::update_many()
+            .col_expr(auth_session::Column::RevokedAt, Expr::value(now))
+            .filter(auth_session::Column::Id.eq(session_id))
+            .filter(auth_session::Column::UserId.eq(user_id))
+            .filter(auth_session::Column::RevokedAt.is_null())
+            .exec(db)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    /// 注销用户除 `keep_session_id` 外的所有会话，返回注销数量
+    pub async fn revoke_others(
+        db: &DatabaseConnection,
+        user_id: i64,
+        keep_session_id: i64,
+    ) -> Result<u64, DbErr> {
+        let now = chrono::Utc::now().naive_utc();
+
+        let result = auth_session::Entity::update_many()
+            .col_expr(auth_session::Column::RevokedAt, Expr::value(now))
+            .filter(auth_session::Column::UserId.eq(user_id))
+            .filter(auth_session::Column::Id.ne(keep_session_id))
+            .filter(auth_session::Column::RevokedAt.is_null())
+            .exec(db)
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+
+    /// 注销用户用某个钱包登录的所有会话（取消关联钱包时调用），返回注销数量
+    pub async fn revoke_for_wallet<C: ConnectionTrait>(
+        db: &C,
+        user_id: i64,
+        wallet_address: &str,
+    ) -> Result<u64, DbErr> {
+        let now = chrono::Utc::now().naive_utc();
+
+        let result = auth_session::Entity::update_many()
+            .col_expr(auth_session::Column::RevokedAt, Expr::value(now))
+            .filter(auth_session::Column::UserId.eq(user_id))
+            .filter(auth_session::Column::WalletAddress.eq(wallet_address))
+            .filter(auth_session::Column::RevokedAt.is_null())
+            .exec(db)
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+
+    /// 清理已过期或已注销的会话
+    pub async fn delete_expired(
+        db: &DatabaseConnection,
+        before: chrono::NaiveDateTime,
+    ) -> Result<u64, DbErr> {
+        let result = auth_session::Entity::delete_many()
+            .filter(
+                Condition::any()
+                    .add(auth_session::Column::ExpiresAt.lt(before))
+                    .add(auth_session::Column::RevokedAt.lt(before)),
+            )
+            .exec(db)
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+
+    /// 注销会话
+    pub async fn revoke(
+        db: &DatabaseConnection,
+        session_id: i64,
+    ) -> Result<(), DbErr> {
+        let now = chrono::Utc::now().naive_utc();
+
+        auth_session::Entity::update_many()
+            .col_expr(auth_session::Column::RevokedAt, Expr::value(now))
+            .filter(auth_session::Column::Id.eq(session_id))
+            .filter(auth_session::Column::RevokedAt.is_null())
+            .exec(db)
+            .await?;
+
+        Ok(())
+    }
+}
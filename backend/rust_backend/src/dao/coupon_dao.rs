@@ -0,0 +1,71 @@
+use sea_orm::*;
+use sea_orm::sea_query::Expr;
+use crate::entities::coupon;
+
+/// 优惠码减免方式
+pub const COUPON_PERCENT: &str = "percent";
+pub const COUPON_FIXED: &str = "fixed";
+
+pub struct CouponDao;
+
+impl CouponDao {
+    /// 创建优惠码
+    pub async fn create(
+        db: &DatabaseConnection,
+        coupon: coupon::ActiveModel,
+    ) -> Result<coupon::Model, DbErr> {
+        coupon.insert(db).await
+    }
+
+    /// 根据优惠码查询
+    pub async fn find_by_code(
+        db: &DatabaseConnection,
+        code: &str,
+    ) -> Result<Option<coupon::Model>, DbErr> {
+        coupon::Entity::find()
+            .filter(coupon::Column::Code.eq(code))
+            .one(db)
+            .await
+    }
+
+    /// 根据优惠码加锁查询，需在事务中调用
+    pub async fn find_by_code_for_update<C: ConnectionTrait>(
+        db: &C,
+        code: &str,
+    ) -> Result<Option<coupon::Model>, DbErr> {
+        coupon::Entity::find()
+            .filter(coupon::Column::Code.eq(code))
+            .lock_exclusive()
+            .one(db)
+            .await
+    }
+
+    /// 全部优惠码，最新的在前
+    pub async fn find_all(db: &DatabaseConnection) -> Result<Vec<coupon::Model>, DbErr> {
+        coupon::Entity::find()
+            .order_by_desc(coupon::Column::Id)
+            .all(db)
+            .await
+    }
+
+    /// 使用次数加一
+    pub async fn increment_redeemed<C: ConnectionTrait>(db: &C, id: i64) -> Result<(), DbErr> {
+        coupon::Entity::update_many()
+            .col_expr(coupon::Column::RedeemedCount, Expr::col(coupon::Column::RedeemedCount).add(1))
+            .filter(coupon::Column::Id.eq(id))
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+
+    /// 停用优惠码，返回是否找到可用的优惠码
+    pub async fn deactivate(db: &DatabaseConnection, code: &str) -> Result<bool, DbErr> {
+        let result = coupon::Entity::update_many()
+            .col_expr(coupon::Column::Active, Expr::value(false))
+            .filter(coupon::Column::Code.eq(code))
+            .filter(coupon::Column::Active.eq(true))
+            .exec(db)
+            .await?;
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -0,0 +1,41 @@
+use sea_orm::*;
+use crate::entities::coupon_redemption;
+use crate::money::Money;
+
+pub struct CouponRedemptionDao;
+
+impl CouponRedemptionDao {
+    /// 记录一次优惠码使用
+    pub async fn create<C: ConnectionTrait>(
+        db: &C,
+        coupon_id: i64,
+        user_id: i64,
+        unlock_record_id: i64,
+        discount: Money,
+    ) -> Result<coupon_redemption::Model, DbErr> {
+        let redemption = coupon_redemption::ActiveModel {
+            coupon_id: Set(coupon_id),
+            user_id: Set(user_id),
+            unlock_record_id: Set(unlock_record_id),
+            discount: Set(discount.amount),
+            asset: Set(discount.asset),
+            created_at: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        redemption.insert(db).await
+    }
+
+    /// 用户使用某优惠码的次数
+    pub async fn count_by_user<C: ConnectionTrait>(
+        db: &C,
+        coupon_id: i64,
+        user_id: i64,
+    ) -> Result<u64, DbErr> {
+        coupon_redemption::Entity::find()
+            .filter(coupon_redemption::Column::CouponId.eq(coupon_id))
+            .filter(coupon_redemption::Column::UserId.eq(user_id))
+            .count(db)
+            .await
+    }
+}
@@ -0,0 +1,98 @@
+use sea_orm::*;
+use sea_orm::sea_query::{Expr, OnConflict};
+use crate::entities::credit_account;
+
+/// 额度账户所有者类型
+pub const CREDIT_OWNER_USER: &str = "user";
+pub const CREDIT_OWNER_ORGANIZATION: &str = "organization";
+
+pub struct CreditAccountDao;
+
+impl CreditAccountDao {
+    /// 账户不存在时创建余额为 0 的账户（已存在时不变），并发的首次创建不会因为重复插入而失败
+    pub async fn create_if_missing<C: ConnectionTrait>(
+        db: &C,
+        owner_type: &str,
+        owner_id: i64,
+        asset: &str,
+    ) -> Result<(), DbErr> {
+        let now = chrono::Utc::now().naive_utc();
+        let account = credit_account::ActiveModel {
+            owner_type: Set(owner_type.to_string()),
+            owner_id: Set(owner_id),
+            asset: Set(asset.to_string()),
+            balance: Set(0),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        credit_account::Entity::insert(account)
+            .on_conflict(
+                OnConflict::columns([
+                    credit_account::Column::OwnerType,
+                    credit_account::Column::OwnerId,
+                    credit_account::Column::Asset,
+                ])
+                .do_nothing_on([credit_account::Column::OwnerId])
+                .to_owned(),
+            )
+            .do_nothing()
+            .exec_without_returning(db)
+            .await?;
+        Ok(())
+    }
+
+    /// 加锁读取额度账户（SELECT ... FOR UPDATE），需在事务中调用
+    pub async fn find_for_update<C: ConnectionTrait>(
+        db: &C,
+        owner_type: &str,
+        owner_id: i64,
+        asset: &str,
+    ) -> Result<Option<credit_account::Model>, DbErr> {
+        credit_account::Entity::find()
+            .filter(credit_account::Column::OwnerType.eq(owner_type))
+            .filter(credit_account::Column::OwnerId.eq(owner_id))
+            .filter(credit_account::Column::Asset.eq(asset))
+            .lock_exclusive()
+            .one(db)
+            .await
+    }
+
+    /// 根据 ID 加锁读取额度账户，需在事务中调用
+    pub async fn find_by_id_for_update<C: ConnectionTrait>(
+        db: &C,
+        id: i64,
+    ) -> Result<Option<credit_account::Model>, DbErr> {
+        credit_account::Entity::find_by_id(id).lock_exclusive().one(db).await
+    }
+
+    /// 所有者的全部额度账户
+    pub async fn find_by_owner(
+        db: &DatabaseConnection,
+        owner_type: &str,
+        owner_id: i64,
+    ) -> Result<Vec<credit_account::Model>, DbErr> {
+        credit_account::Entity::find()
+            .filter(credit_account::Column::OwnerType.eq(owner_type))
+            .filter(credit_account::Column::OwnerId.eq(owner_id))
+            .order_by_asc(credit_account::Column::Asset)
+            .all(db)
+            .await
+    }
+
+    /// 更新余额
+    pub async fn update_balance<C: ConnectionTrait>(
+        db: &C,
+        id: i64,
+        balance: i64,
+    ) -> Result<(), DbErr> {
+        credit_account::Entity::update_many()
+            .col_expr(credit_account::Column::Balance, Expr::value(balance))
+            .col_expr(credit_account::Column::UpdatedAt, Expr::value(chrono::Utc::now().naive_utc()))
+            .filter(credit_account::Column::Id.eq(id))
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use sea_orm::*;
+use sea_orm::sea_query::Expr;
+use crate::entities::credit_bundle;
+use crate::money::Money;
+
+pub struct CreditBundleDao;
+
+impl CreditBundleDao {
+    /// 创建额度套餐
+    pub async fn create(
+        db: &DatabaseConnection,
+        name: String,
+        price: Money,
+        credits: i64,
+    ) -> Result<credit_bundle::Model, DbErr> {
+        let bundle = credit_bundle::ActiveModel {
+            name: Set(name),
+            price: Set(price.amount),
+            credits: Set(credits),
+            asset: Set(price.asset),
+            active: Set(true),
+            created_at: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        bundle.insert(db).await
+    }
+
+    /// 根据 ID 查询套餐
+    pub async fn find_by_id<C: ConnectionTrait>(
+        db: &C,
+        id: i64,
+    ) -> Result<Option<credit_bundle::Model>, DbErr> {
+        credit_bundle::Entity::find_by_id(id).one(db).await
+    }
+
+    /// 在售的套餐，按售价排序
+    pub async fn find_active(db: &DatabaseConnection) -> Result<Vec<credit_bundle::Model>, DbErr> {
+        credit_bundle::Entity::find()
+            .filter(credit_bundle::Column::Active.eq(true))
+            .order_by_asc(credit_bundle::Column::Price)
+            .all(db)
+            .await
+    }
+
+    /// 下架套餐，返回是否找到在售的套餐
+    pub async fn deactivate(db: &DatabaseConnection, id: i64) -> Result<bool, DbErr> {
+        let result = credit_bundle::Entity::update_many()
+            .col_expr(credit_bundle::Column::Active, Expr::value(false))
+            .filter(credit_bundle::Column::Id.eq(id))
+            .filter(credit_bundle::Column::Active.eq(true))
+            .exec(db)
+            .await?;
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -0,0 +1,65 @@
+use sea_orm::*;
+use crate::entities::credit_transaction;
+
+/// 额度流水类型
+pub const CREDIT_KIND_TOPUP: &str = "topup";
+pub const CREDIT_KIND_UNLOCK: &str = "unlock";
+pub const CREDIT_KIND_REFUND: &str = "refund";
+
+pub struct CreditTransactionDao;
+
+impl CreditTransactionDao {
+    /// 记录一笔额度流水
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create<C: ConnectionTrait>(
+        db: &C,
+        account_id: i64,
+        kind: &str,
+        amount: i64,
+        balance_after: i64,
+        bundle_id: Option<i64>,
+        unlock_record_id: Option<i64>,
+        reference: Option<String>,
+        created_by: i64,
+    ) -> Result<credit_transaction::Model, DbErr> {
+        let transaction = credit_transaction::ActiveModel {
+            account_id: Set(account_id),
+            kind: Set(kind.to_string()),
+            amount: Set(amount),
+            balance_after: Set(balance_after),
+            bundle_id: Set(bundle_id),
+            unlock_record_id: Set(unlock_record_id),
+            reference: Set(reference),
+            created_by: Set(created_by),
+            created_at: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        transaction.insert(db).await
+    }
+
+    /// 账户的流水，最新的在前
+    pub async fn find_by_accounts(
+        db: &DatabaseConnection,
+        account_ids: Vec<i64>,
+    ) -> Result<Vec<credit_transaction::Model>, DbErr> {
+        credit_transaction::Entity::find()
+            .filter(credit_transaction::Column::AccountId.is_in(account_ids))
+            .order_by_desc(credit_transaction::Column::Id)
+            .all(db)
+            .await
+    }
+
+    /// 解锁记录对应的某类流水
+    pub async fn find_by_unlock_record<C: ConnectionTrait>(
+        db: &C,
+        unlock_record_id: i64,
+        kind: &str,
+    ) -> Result<Option<credit_transaction::Model>, DbErr> {
+        credit_transaction::Entity::find()
+            .filter(credit_transaction::Column::UnlockRecordId.eq(unlock_record_id))
+            .filter(credit_transaction::Column::Kind.eq(kind))
+            .one(db)
+            .await
+    }
+}
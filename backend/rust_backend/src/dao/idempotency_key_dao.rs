@@ -0,0 +1,89 @@
+use sea_orm::*;
+use sea_orm::sea_query::Expr;
+use crate::entities::idempotency_key;
+
+/// 幂等键状态
+pub const IDEMPOTENCY_STATUS_PROCESSING: &str = "processing";
+pub const IDEMPOTENCY_STATUS_COMPLETED: &str = "completed";
+
+pub struct IdempotencyKeyDao;
+
+impl IdempotencyKeyDao {
+    /// 占用幂等键（状态为 processing），同一用户的键已存在时返回 None
+    pub async fn create(
+        db: &DatabaseConnection,
+        user_id: i64,
+        idempotency_key: String,
+        method: String,
+        path: String,
+        request_hash: String,
+    ) -> Result<Option<idempotency_key::Model>, DbErr> {
+        let key = idempotency_key::ActiveModel {
+            user_id: Set(user_id),
+            idempotency_key: Set(idempotency_key),
+            method: Set(method),
+            path: Set(path),
+            request_hash: Set(request_hash),
+            status: Set(IDEMPOTENCY_STATUS_PROCESSING.to_string()),
+            created_at: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        match key.insert(db).await {
+            Ok(key) => Ok(Some(key)),
+            Err(e) if matches!(e.sql_err(), Some(SqlErr::UniqueConstraintViolation(_))) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 查询用户的幂等键
+    pub async fn find(
+        db: &DatabaseConnection,
+        user_id: i64,
+        idempotency_key: &str,
+    ) -> Result<Option<idempotency_key::Model>, DbErr> {
+        idempotency_key::Entity::find()
+            .filter(idempotency_key::Column::UserId.eq(user_id))
+            .filter(idempotency_key::Column::IdempotencyKey.eq(idempotency_key))
+            .one(db)
+            .await
+    }
+
+    /// 保存响应，幂等键转为 completed
+    pub async fn complete(
+        db: &DatabaseConnection,
+        id: i64,
+        response_status: i32,
+        response_headers: String,
+        response_body: String,
+    ) -> Result<(), DbErr> {
+        idempotency_key::Entity::update_many()
+            .col_expr(idempotency_key::Column::Status, Expr::value(IDEMPOTENCY_STATUS_COMPLETED))
+            .col_expr(idempotency_key::Column::ResponseStatus, Expr::value(response_status))
+            .col_expr(idempotency_key::Column::ResponseHeaders, Expr::value(response_headers))
+            .col_expr(idempotency_key::Column::ResponseBody, Expr::value(response_body))
+            .col_expr(idempotency_key::Column::CompletedAt, Expr::value(chrono::Utc::now().naive_utc()))
+            .filter(idempotency_key::Column::Id.eq(id))
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+
+    /// 释放幂等键（请求未产生可重放的响应，允许用同一个键重试）
+    pub async fn delete(db: &DatabaseConnection, id: i64) -> Result<(), DbErr> {
+        idempotency_key::Entity::delete_by_id(id).exec(db).await?;
+        Ok(())
+    }
+
+    /// 删除早于 `before` 创建的幂等键
+    pub async fn delete_expired(
+        db: &DatabaseConnection,
+        before: chrono::NaiveDateTime,
+    ) -> Result<u64, DbErr> {
+        let result = idempotency_key::Entity::delete_many()
+            .filter(idempotency_key::Column::CreatedAt.lt(before))
+            .exec(db)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
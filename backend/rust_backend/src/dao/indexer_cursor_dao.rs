@@ -0,0 +1,128 @@
+use sea_orm::*;
+use crate::entities::{indexer_cursor, indexer_event};
+
+pub struct IndexerCursorDao;
+
+impl IndexerCursorDao {
+    /// 查询事件流的游标
+    pub async fn find(
+        db: &DatabaseConnection,
+        package_id: &str,
+        event_type: &str,
+    ) -> Result<Option<indexer_cursor::Model>, DbErr> {
+        indexer_cursor::Entity::find()
+            .filter(indexer_cursor::Column::PackageId.eq(package_id))
+            .filter(indexer_cursor::Column::EventType.eq(event_type))
+            .one(db)
+            .await
+    }
+
+    /// 查询合约包所有事件流的游标
+    pub async fn find_by_package(
+        db: &DatabaseConnection,
+        package_id: &str,
+    ) -> Result<Vec<indexer_cursor::Model>, DbErr> {
+        indexer_cursor::Entity::find()
+            .filter(indexer_cursor::Column::PackageId.eq(package_id))
+            .order_by_asc(indexer_cursor::Column::EventType)
+            .all(db)
+            .await
+    }
+
+    /// 更新事件流的游标，游标行不存在时创建
+    ///
+    /// `position` 为 None 时清空游标（回填时从头开始）；`caught_up` 为 true 时记录追上最新事件的时间。
+    pub async fn save(
+        db: &DatabaseConnection,
+        package_id: &str,
+        event_type: &str,
+        position: Option<(String, String, Option<i64>)>,
+        caught_up: bool,
+        last_error: Option<String>,
+    ) -> Result<(), DbErr> {
+        let now = chrono::Utc::now().naive_utc();
+        let existing = Self::find(db, package_id, event_type).await?;
+
+        let mut cursor = match existing {
+            Some(existing) => existing.into(),
+            None => indexer_cursor::ActiveModel {
+                package_id: Set(package_id.to_string()),
+                event_type: Set(event_type.to_string()),
+                ..Default::default()
+            },
+        };
+        if let Some((tx_digest, event_seq, timestamp_ms)) = position {
+            cursor.tx_digest = Set(Some(tx_digest));
+            cursor.event_seq = Set(Some(event_seq));
+            if timestamp_ms.is_some() {
+                cursor.last_event_timestamp_ms = Set(timestamp_ms);
+            }
+        }
+        if caught_up {
+            cursor.caught_up_at = Set(Some(now));
+        }
+        cursor.last_error = Set(last_error);
+        cursor.updated_at = Set(now);
+
+        cursor.save(db).await?;
+        Ok(())
+    }
+
+    /// 清空事件流的游标（回填时从头开始）
+    pub async fn reset(
+        db: &DatabaseConnection,
+        package_id: &str,
+        event_type: &str,
+    ) -> Result<(), DbErr> {
+        let Some(existing) = Self::find(db, package_id, event_type).await? else {
+            return Ok(());
+        };
+
+        let mut cursor: indexer_cursor::ActiveModel = existing.into();
+        cursor.tx_digest = Set(None);
+        cursor.event_seq = Set(None);
+        cursor.last_event_timestamp_ms = Set(None);
+        cursor.updated_at = Set(chrono::Utc::now().naive_utc());
+        cursor.update(db).await?;
+        Ok(())
+    }
+
+    /// 事件是否已经处理过
+    pub async fn is_processed(
+        db: &DatabaseConnection,
+        tx_digest: &str,
+        event_seq: &str,
+    ) -> Result<bool, DbErr> {
+        let count = indexer_event::Entity::find()
+            .filter(indexer_event::Column::TxDigest.eq(tx_digest))
+            .filter(indexer_event::Column::EventSeq.eq(event_seq))
+            .count(db)
+            .await?;
+
+        Ok(count > 0)
+    }
+
+    /// 记录事件已处理（重复记录忽略）
+    pub async fn mark_processed(
+        db: &DatabaseConnection,
+        package_id: &str,
+        event_type: &str,
+        tx_digest: &str,
+        event_seq: &str,
+    ) -> Result<(), DbErr> {
+        let event = indexer_event::ActiveModel {
+            package_id: Set(package_id.to_string()),
+            event_type: Set(event_type.to_string()),
+            tx_digest: Set(tx_digest.to_string()),
+            event_seq: Set(event_seq.to_string()),
+            processed_at: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        match event.insert(db).await {
+            Ok(_) => Ok(()),
+            Err(e) if matches!(e.sql_err(), Some(SqlErr::UniqueConstraintViolation(_))) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -2,8 +2,56 @@ pub mod resume_dao;
 pub mod user_dao;
 pub mod unlock_record_dao;
 pub mod access_log_dao;
+pub mod auth_nonce_dao;
+pub mod auth_session_dao;
+pub mod organization_dao;
+pub mod organization_member_dao;
+pub mod api_key_dao;
+pub mod api_key_audit_log_dao;
+pub mod rate_limit_bucket_dao;
+pub mod user_wallet_dao;
+pub mod payment_receipt_dao;
+pub mod seller_ledger_dao;
+pub mod seller_payout_dao;
+pub mod resume_tier_dao;
+pub mod unlock_dispute_dao;
+pub mod credit_account_dao;
+pub mod credit_transaction_dao;
+pub mod credit_bundle_dao;
+pub mod coupon_dao;
+pub mod coupon_redemption_dao;
+pub mod idempotency_key_dao;
+pub mod allowlist_dao;
+pub mod allowlist_member_dao;
+pub mod subscription_service_dao;
+pub mod indexer_cursor_dao;
+pub mod payment_settlement_dao;
 
 pub use resume_dao::ResumeDao;
 pub use user_dao::UserDao;
 pub use unlock_record_dao::UnlockRecordDao;
 pub use access_log_dao::AccessLogDao;
+pub use auth_nonce_dao::AuthNonceDao;
+pub use auth_session_dao::AuthSessionDao;
+pub use organization_dao::OrganizationDao;
+pub use organization_member_dao::OrganizationMemberDao;
+pub use api_key_dao::ApiKeyDao;
+pub use api_key_audit_log_dao::ApiKeyAuditLogDao;
+pub use rate_limit_bucket_dao::RateLimitBucketDao;
+pub use user_wallet_dao::UserWalletDao;
+pub use payment_receipt_dao::PaymentReceiptDao;
+pub use seller_ledger_dao::SellerLedgerDao;
+pub use seller_payout_dao::SellerPayoutDao;
+pub use resume_tier_dao::ResumeTierDao;
+pub use unlock_dispute_dao::UnlockDisputeDao;
+pub use credit_account_dao::CreditAccountDao;
+pub use credit_transaction_dao::CreditTransactionDao;
+pub use credit_bundle_dao::CreditBundleDao;
+pub use coupon_dao::CouponDao;
+pub use coupon_redemption_dao::CouponRedemptionDao;
+pub use idempotency_key_dao::IdempotencyKeyDao;
+pub use allowlist_dao::AllowlistDao;
+pub use allowlist_member_dao::AllowlistMemberDao;
+pub use subscription_service_dao::SubscriptionServiceDao;
+pub use indexer_cursor_dao::IndexerCursorDao;
+pub use payment_settlement_dao::PaymentSettlementDao;
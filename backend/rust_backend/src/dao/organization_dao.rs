@@ -0,0 +1,46 @@
+use sea_orm::*;
+use crate::entities::organization;
+
+pub struct OrganizationDao;
+
+impl OrganizationDao {
+    /// 创建组织
+    pub async fn create<C: ConnectionTrait>(
+        db: &C,
+        name: String,
+        created_by: i64,
+    ) -> Result<organization::Model, DbErr> {
+        let now = chrono::Utc::now().naive_utc();
+
+        let new_org = organization::ActiveModel {
+            name: Set(name),
+            created_by: Set(created_by),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        new_org.insert(db).await
+    }
+
+    /// 根据 ID 查询组织
+    #[allow(dead_code)]
+    pub async fn find_by_id(
+        db: &DatabaseConnection,
+        organization_id: i64,
+    ) -> Result<Option<organization::Model>, DbErr> {
+        organization::Entity::find_by_id(organization_id).one(db).await
+    }
+
+    /// 批量查询组织
+    pub async fn find_by_ids(
+        db: &DatabaseConnection,
+        organization_ids: Vec<i64>,
+    ) -> Result<Vec<organization::Model>, DbErr> {
+        organization::Entity::find()
+            .filter(organization::Column::Id.is_in(organization_ids))
+            .order_by_asc(organization::Column::Id)
+            .all(db)
+            .await
+    }
+}
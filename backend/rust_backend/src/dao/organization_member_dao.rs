@@ -0,0 +1,89 @@
+use sea_orm::*;
+use crate::entities::organization_member;
+
+pub struct OrganizationMemberDao;
+
+impl OrganizationMemberDao {
+    /// 添加组织成员
+    pub async fn add<C: ConnectionTrait>(
+        db: &C,
+        organization_id: i64,
+        user_id: i64,
+        wallet_address: String,
+        role: &str,
+    ) -> Result<organization_member::Model, DbErr> {
+        let new_member = organization_member::ActiveModel {
+            organization_id: Set(organization_id),
+            user_id: Set(user_id),
+            wallet_address: Set(wallet_address),
+            role: Set(role.to_string()),
+            created_at: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        new_member.insert(db).await
+    }
+
+    /// 查询用户在组织中的成员记录
+    pub async fn find(
+        db: &DatabaseConnection,
+        organization_id: i64,
+        user_id: i64,
+    ) -> Result<Option<organization_member::Model>, DbErr> {
+        organization_member::Entity::find()
+            .filter(organization_member::Column::OrganizationId.eq(organization_id))
+            .filter(organization_member::Column::UserId.eq(user_id))
+            .one(db)
+            .await
+    }
+
+    /// 查询组织的所有成员
+    pub async fn find_by_organization(
+        db: &DatabaseConnection,
+        organization_id: i64,
+    ) -> Result<Vec<organization_member::Model>, DbErr> {
+        organization_member::Entity::find()
+            .filter(organization_member::Column::OrganizationId.eq(organization_id))
+            .order_by_asc(organization_member::Column::CreatedAt)
+            .all(db)
+            .await
+    }
+
+    /// 查询用户加入的所有组织成员记录
+    pub async fn find_by_user(
+        db: &DatabaseConnection,
+        user_id: i64,
+    ) -> Result<Vec<organization_member::Model>, DbErr> {
+        organization_member::Entity::find()
+            .filter(organization_member::Column::UserId.eq(user_id))
+            .all(db)
+            .await
+    }
+
+    /// 统计组织管理员数量
+    pub async fn count_admins(
+        db: &DatabaseConnection,
+        organization_id: i64,
+    ) -> Result<u64, DbErr> {
+        organization_member::Entity::find()
+            .filter(organization_member::Column::OrganizationId.eq(organization_id))
+            .filter(organization_member::Column::Role.eq("admin"))
+            .count(db)
+            .await
+    }
+
+    /// 移除组织成员
+    pub async fn remove(
+        db: &DatabaseConnection,
+        organization_id: i64,
+        user_id: i64,
+    ) -> Result<bool, DbErr> {
+        let result = organization_member::Entity::delete_many()
+            .filter(organization_member::Column::OrganizationId.eq(organization_id))
+            .filter(organization_member::Column::UserId.eq(user_id))
+            .exec(db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
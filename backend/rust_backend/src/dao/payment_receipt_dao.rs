@@ -0,0 +1,25 @@
+use sea_orm::*;
+use crate::entities::payment_receipt;
+
+pub struct PaymentReceiptDao;
+
+impl PaymentReceiptDao {
+    /// 保存收据
+    pub async fn create<C: ConnectionTrait>(
+        db: &C,
+        receipt: payment_receipt::ActiveModel,
+    ) -> Result<payment_receipt::Model, DbErr> {
+        receipt.insert(db).await
+    }
+
+    /// 根据收据 ID 查询
+    pub async fn find_by_receipt_id(
+        db: &DatabaseConnection,
+        receipt_id: &str,
+    ) -> Result<Option<payment_receipt::Model>, DbErr> {
+        payment_receipt::Entity::find()
+            .filter(payment_receipt::Column::ReceiptId.eq(receipt_id))
+            .one(db)
+            .await
+    }
+}
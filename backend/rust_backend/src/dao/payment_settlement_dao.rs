@@ -0,0 +1,169 @@
+use sea_orm::*;
+use sea_orm::sea_query::Expr;
+use crate::entities::payment_settlement;
+
+/// 结算意向用途
+pub const SETTLEMENT_PURPOSE_UNLOCK: &str = "unlock";
+pub const SETTLEMENT_PURPOSE_CREDIT_TOPUP: &str = "credit_topup";
+
+/// 结算意向状态
+pub const SETTLEMENT_STATUS_PENDING: &str = "pending";
+pub const SETTLEMENT_STATUS_SETTLED: &str = "settled";
+pub const SETTLEMENT_STATUS_COMPLETED: &str = "completed";
+pub const SETTLEMENT_STATUS_FAILED: &str = "failed";
+
+/// 错误信息最大长度（与 payment_settlements.error 一致）
+const MAX_ERROR_LEN: usize = 500;
+
+pub struct PaymentSettlementDao;
+
+impl PaymentSettlementDao {
+    /// 写入 pending 的结算意向
+    pub async fn create<C: ConnectionTrait>(
+        db: &C,
+        settlement: payment_settlement::ActiveModel,
+    ) -> Result<payment_settlement::Model, DbErr> {
+        let now = chrono::Utc::now().naive_utc();
+        let settlement = payment_settlement::ActiveModel {
+            status: Set(SETTLEMENT_STATUS_PENDING.to_string()),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..settlement
+        };
+
+        settlement.insert(db).await
+    }
+
+    /// 根据 ID 加锁读取结算意向，需在事务中调用
+    pub async fn find_by_id_for_update<C: ConnectionTrait>(
+        db: &C,
+        id: i64,
+    ) -> Result<Option<payment_settlement::Model>, DbErr> {
+        payment_settlement::Entity::find_by_id(id).lock_exclusive().one(db).await
+    }
+
+    /// pending → settled，记录结算交易；返回是否更新成功
+    pub async fn mark_settled(
+        db: &DatabaseConnection,
+        id: i64,
+        transaction: &str,
+        payer: Option<String>,
+    ) -> Result<bool, DbErr> {
+        let result = payment_settlement::Entity::update_many()
+            .col_expr(payment_settlement::Column::Status, Expr::value(SETTLEMENT_STATUS_SETTLED))
+            .col_expr(payment_settlement::Column::Transaction, Expr::value(transaction))
+            .col_expr(payment_settlement::Column::Payer, Expr::value(payer))
+            .col_expr(payment_settlement::Column::UpdatedAt, Expr::value(chrono::Utc::now().naive_utc()))
+            .filter(payment_settlement::Column::Id.eq(id))
+            .filter(payment_settlement::Column::Status.eq(SETTLEMENT_STATUS_PENDING))
+            .exec(db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// pending → failed（结算失败，没有扣款）
+    pub async fn mark_failed(
+        db: &DatabaseConnection,
+        id: i64,
+        error: &str,
+    ) -> Result<(), DbErr> {
+        payment_settlement::Entity::update_many()
+            .col_expr(payment_settlement::Column::Status, Expr::value(SETTLEMENT_STATUS_FAILED))
+            .col_expr(payment_settlement::Column::Error, Expr::value(Self::truncate(error)))
+            .col_expr(payment_settlement::Column::UpdatedAt, Expr::value(chrono::Utc::now().naive_utc()))
+            .filter(payment_settlement::Column::Id.eq(id))
+            .filter(payment_settlement::Column::Status.eq(SETTLEMENT_STATUS_PENDING))
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+
+    /// settled → completed，关联写入的解锁记录或额度流水（与其在同一事务中调用）
+    pub async fn mark_completed<C: ConnectionTrait>(
+        db: &C,
+        id: i64,
+        unlock_record_id: Option<i64>,
+        credit_transaction_id: Option<i64>,
+    ) -> Result<(), DbErr> {
+        payment_settlement::Entity::update_many()
+            .col_expr(payment_settlement::Column::Status, Expr::value(SETTLEMENT_STATUS_COMPLETED))
+            .col_expr(payment_settlement::Column::UnlockRecordId, Expr::value(unlock_record_id))
+            .col_expr(payment_settlement::Column::CreditTransactionId, Expr::value(credit_transaction_id))
+            .col_expr(payment_settlement::Column::Error, Expr::value(Option::<String>::None))
+            .col_expr(payment_settlement::Column::UpdatedAt, Expr::value(chrono::Utc::now().naive_utc()))
+            .filter(payment_settlement::Column::Id.eq(id))
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+
+    /// 记录已结算意向写入失败的原因，状态保持 settled 等待对账
+    pub async fn record_error(
+        db: &DatabaseConnection,
+        id: i64,
+        error: &str,
+    ) -> Result<(), DbErr> {
+        payment_settlement::Entity::update_many()
+            .col_expr(payment_settlement::Column::Error, Expr::value(Self::truncate(error)))
+            .col_expr(payment_settlement::Column::UpdatedAt, Expr::value(chrono::Utc::now().naive_utc()))
+            .filter(payment_settlement::Column::Id.eq(id))
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+
+    /// 在 `before` 之前结算、仍未完成的意向（按时间升序）
+    pub async fn find_settled_before(
+        db: &DatabaseConnection,
+        before: chrono::NaiveDateTime,
+        limit: u64,
+    ) -> Result<Vec<payment_settlement::Model>, DbErr> {
+        payment_settlement::Entity::find()
+            .filter(payment_settlement::Column::Status.eq(SETTLEMENT_STATUS_SETTLED))
+            .filter(payment_settlement::Column::UpdatedAt.lt(before))
+            .order_by_asc(payment_settlement::Column::UpdatedAt)
+            .limit(limit)
+            .all(db)
+            .await
+    }
+
+    /// 买家是否有已结算、尚未写入解锁记录的同一简历同一档位的解锁意向
+    pub async fn has_settled_unlock<C: ConnectionTrait>(
+        db: &C,
+        user_id: i64,
+        resume_id: i64,
+        tier: &str,
+    ) -> Result<bool, DbErr> {
+        let count = payment_settlement::Entity::find()
+            .filter(payment_settlement::Column::Purpose.eq(SETTLEMENT_PURPOSE_UNLOCK))
+            .filter(payment_settlement::Column::UserId.eq(user_id))
+            .filter(payment_settlement::Column::ResumeId.eq(resume_id))
+            .filter(payment_settlement::Column::Tier.eq(tier))
+            .filter(payment_settlement::Column::Status.eq(SETTLEMENT_STATUS_SETTLED))
+            .count(db)
+            .await?;
+        Ok(count > 0)
+    }
+
+    /// 使用某优惠码、尚未完成也未失败的意向数（`user_id` 指定时只统计该用户）
+    ///
+    /// 这些意向完成时才写入优惠码使用记录，检查使用次数时需要一并计入。
+    pub async fn count_open_with_coupon<C: ConnectionTrait>(
+        db: &C,
+        coupon_id: i64,
+        user_id: Option<i64>,
+    ) -> Result<u64, DbErr> {
+        let mut query = payment_settlement::Entity::find()
+            .filter(payment_settlement::Column::CouponId.eq(coupon_id))
+            .filter(payment_settlement::Column::Status.is_in([SETTLEMENT_STATUS_PENDING, SETTLEMENT_STATUS_SETTLED]));
+        if let Some(user_id) = user_id {
+            query = query.filter(payment_settlement::Column::UserId.eq(user_id));
+        }
+        query.count(db).await
+    }
+
+    fn truncate(error: &str) -> String {
+        error.chars().take(MAX_ERROR_LEN).collect()
+    }
+}
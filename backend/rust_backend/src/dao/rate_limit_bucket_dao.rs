@@ -0,0 +1,68 @@
+use sea_orm::*;
+use crate::entities::rate_limit_bucket;
+
+pub struct RateLimitBucketDao;
+
+impl RateLimitBucketDao {
+    /// 加锁读取令牌桶（SELECT ... FOR UPDATE），需在事务中调用
+    pub async fn find_for_update<C: ConnectionTrait>(
+        db: &C,
+        bucket_key: &str,
+    ) -> Result<Option<rate_limit_bucket::Model>, DbErr> {
+        rate_limit_bucket::Entity::find_by_id(bucket_key.to_string())
+            .lock_exclusive()
+            .one(db)
+            .await
+    }
+
+    /// 令牌桶不存在时插入（已存在时不变），之后可以用 [`Self::find_for_update`] 加锁读取
+    ///
+    /// 并发的首次请求不会因为重复插入而失败。
+    pub async fn create_if_missing<C: ConnectionTrait>(
+        db: &C,
+        bucket_key: String,
+        tokens: f64,
+        updated_at_ms: i64,
+    ) -> Result<(), DbErr> {
+        let bucket = rate_limit_bucket::ActiveModel {
+            bucket_key: Set(bucket_key),
+            tokens: Set(tokens),
+            updated_at_ms: Set(updated_at_ms),
+        };
+
+        rate_limit_bucket::Entity::insert(bucket)
+            .on_conflict_do_nothing()
+            .exec_without_returning(db)
+            .await?;
+        Ok(())
+    }
+
+    /// 更新令牌桶状态
+    pub async fn save<C: ConnectionTrait>(
+        db: &C,
+        bucket_key: String,
+        tokens: f64,
+        updated_at_ms: i64,
+    ) -> Result<(), DbErr> {
+        let bucket = rate_limit_bucket::ActiveModel {
+            bucket_key: Set(bucket_key),
+            tokens: Set(tokens),
+            updated_at_ms: Set(updated_at_ms),
+        };
+
+        bucket.update(db).await?;
+        Ok(())
+    }
+
+    /// 删除长时间未使用的令牌桶（已补满，与不存在等价）
+    pub async fn delete_idle(
+        db: &DatabaseConnection,
+        before_ms: i64,
+    ) -> Result<u64, DbErr> {
+        let result = rate_limit_bucket::Entity::delete_many()
+            .filter(rate_limit_bucket::Column::UpdatedAtMs.lt(before_ms))
+            .exec(db)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
@@ -2,6 +2,7 @@ use sea_orm::*;
 use sea_orm::sea_query::Expr;
 use crate::entities::{resume, Resume};
 use crate::models::Resume as ResumeModel;
+use crate::money::{self, Money};
 use anyhow::Result;
 
 pub struct ResumeDao;
@@ -44,7 +45,8 @@ impl ResumeDao {
             encryption_type: Set(encryption_type),
             encryption_mode: Set(encryption_mode),
             summary: Set(summary),
-            price: Set(0), // 默认 5 USDC = 5,000,000 (USDC has 6 decimals)
+            price: Set(0),
+            price_asset: Set(money::registry().payment_asset().symbol.clone()),
             view_count: Set(0),
             unlock_count: Set(0),
             status: Set("active".to_string()),
@@ -57,6 +59,15 @@ impl ResumeDao {
         Ok(result.id)
     }
     
+    /// 根据主键查询
+    pub async fn find_by_id(
+        db: &DatabaseConnection,
+        id: i64
+    ) -> Result<Option<resume::Model>> {
+        let resume = Resume::find_by_id(id).one(db).await?;
+        Ok(resume)
+    }
+    
     /// 根据 resume_id 查询
     pub async fn find_by_resume_id(
         db: &DatabaseConnection,
@@ -69,6 +80,43 @@ impl ResumeDao {
         Ok(resume)
     }
     
+    /// 根据 Seal 策略对象 ID（allowlist / 订阅服务）查询
+    pub async fn find_by_policy_object_id(
+        db: &DatabaseConnection,
+        policy_object_id: &str
+    ) -> Result<Option<resume::Model>> {
+        let resume = Resume::find()
+            .filter(resume::Column::PolicyObjectId.eq(policy_object_id))
+            .one(db)
+            .await?;
+        Ok(resume)
+    }
+
+    /// 根据 Walrus blob ID 查询
+    pub async fn find_by_blob_id(
+        db: &DatabaseConnection,
+        blob_id: &str
+    ) -> Result<Option<resume::Model>> {
+        let resume = Resume::find()
+            .filter(resume::Column::BlobId.eq(blob_id))
+            .one(db)
+            .await?;
+        Ok(resume)
+    }
+    
+    /// 根据 resume_id 查询并加行锁（需在事务中调用）
+    pub async fn find_by_resume_id_for_update<C: ConnectionTrait>(
+        db: &C,
+        resume_id: &str
+    ) -> Result<Option<resume::Model>> {
+        let resume = Resume::find()
+            .filter(resume::Column::ResumeId.eq(resume_id))
+            .lock_exclusive()
+            .one(db)
+            .await?;
+        Ok(resume)
+    }
+    
     /// 根据所有者查询所有简历
     pub async fn find_by_owner(
         db: &DatabaseConnection,
@@ -83,6 +131,20 @@ impl ResumeDao {
         Ok(resumes)
     }
     
+    /// 根据所有者用户 ID 查询简历
+    pub async fn find_by_owner_id(
+        db: &DatabaseConnection,
+        owner_id: i64
+    ) -> Result<Vec<resume::Model>> {
+        let resumes = Resume::find()
+            .filter(resume::Column::OwnerId.eq(owner_id))
+            .filter(resume::Column::Status.eq("active"))
+            .order_by_desc(resume::Column::CreatedAt)
+            .all(db)
+            .await?;
+        Ok(resumes)
+    }
+    
     /// 查询所有激活的简历（带分页）
     pub async fn find_all_active(
         db: &DatabaseConnection,
@@ -117,9 +179,8 @@ impl ResumeDao {
     }
     
     /// 增加解锁次数
-    #[allow(dead_code)]
-    pub async fn increment_unlock_count(
-        db: &DatabaseConnection,
+    pub async fn increment_unlock_count<C: ConnectionTrait>(
+        db: &C,
         resume_id: &str
     ) -> Result<()> {
         Resume::update_many()
@@ -166,9 +227,10 @@ impl ResumeDao {
     }
 
     /// 更新简历价格
-    pub async fn update_price(db: &DatabaseConnection, resume_id: &str, price: u64) -> Result<()> {
+    pub async fn update_price(db: &DatabaseConnection, resume_id: &str, price: &Money) -> Result<()> {
         Resume::update_many()
-            .col_expr(resume::Column::Price, Expr::value(price as i64))
+            .col_expr(resume::Column::Price, Expr::value(price.amount))
+            .col_expr(resume::Column::PriceAsset, Expr::value(price.asset.clone()))
             .col_expr(resume::Column::UpdatedAt, Expr::value(chrono::Utc::now().naive_utc()))
             .filter(resume::Column::ResumeId.eq(resume_id))
             .exec(db)
@@ -213,14 +275,15 @@ impl ResumeDao {
     pub async fn get_stats(
         db: &DatabaseConnection,
         owner_wallet: &str
-    ) -> Result<(i64, i64, i64)> {
+) -> Result<(i64, i64, Vec<Money>)> {
         let resumes = Self::find_by_owner(db, owner_wallet).await?;
         
         let total_views: i32 = resumes.iter().map(|r| r.view_count).sum();
         let total_unlocks: i32 = resumes.iter().map(|r| r.unlock_count).sum();
-        let total_earnings: i64 = resumes.iter()
-            .map(|r| r.price * r.unlock_count as i64)
-            .sum();
+        let earnings: Vec<Money> = resumes.iter()
+            .map(|r| money::registry().stored(r.price * r.unlock_count as i64, &r.price_asset))
+            .collect();
+        let total_earnings = Money::totals(&earnings).map_err(anyhow::Error::msg)?;
         
         Ok((total_views as i64, total_unlocks as i64, total_earnings))
     }
@@ -0,0 +1,57 @@
+use sea_orm::*;
+use crate::entities::resume_tier;
+
+pub struct ResumeTierDao;
+
+impl ResumeTierDao {
+    /// 简历的全部档位，按等级从低到高
+    pub async fn find_by_resume<C: ConnectionTrait>(
+        db: &C,
+        resume_id: i64,
+    ) -> Result<Vec<resume_tier::Model>, DbErr> {
+        resume_tier::Entity::find()
+            .filter(resume_tier::Column::ResumeId.eq(resume_id))
+            .order_by_asc(resume_tier::Column::Level)
+            .all(db)
+            .await
+    }
+
+    /// 根据 Seal 策略对象 ID 查询档位
+    pub async fn find_by_policy_object_id(
+        db: &DatabaseConnection,
+        policy_object_id: &str,
+    ) -> Result<Option<resume_tier::Model>, DbErr> {
+        resume_tier::Entity::find()
+            .filter(resume_tier::Column::PolicyObjectId.eq(policy_object_id))
+            .one(db)
+            .await
+    }
+
+    /// 根据 Walrus blob ID 查询档位
+    pub async fn find_by_blob_id(
+        db: &DatabaseConnection,
+        blob_id: &str,
+    ) -> Result<Option<resume_tier::Model>, DbErr> {
+        resume_tier::Entity::find()
+            .filter(resume_tier::Column::BlobId.eq(blob_id))
+            .one(db)
+            .await
+    }
+
+    /// 用新的档位集合替换简历的全部档位（需在事务中调用）
+    pub async fn replace<C: ConnectionTrait>(
+        db: &C,
+        resume_id: i64,
+        tiers: Vec<resume_tier::ActiveModel>,
+    ) -> Result<(), DbErr> {
+        resume_tier::Entity::delete_many()
+            .filter(resume_tier::Column::ResumeId.eq(resume_id))
+            .exec(db)
+            .await?;
+
+        if !tiers.is_empty() {
+            resume_tier::Entity::insert_many(tiers).exec(db).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use sea_orm::*;
+use sea_orm::sea_query::Expr;
+use crate::entities::seller_ledger_entry;
+
+/// 解锁收入账目
+pub const LEDGER_ENTRY_SALE: &str = "sale";
+/// 退款冲正账目
+pub const LEDGER_ENTRY_REFUND: &str = "refund";
+
+pub struct SellerLedgerDao;
+
+impl SellerLedgerDao {
+    /// 记录一笔解锁的佣金账目
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create<C: ConnectionTrait>(
+        db: &C,
+        unlock_record_id: i64,
+        seller_id: i64,
+        seller_wallet: String,
+        resume_id: i64,
+        asset: String,
+        gross_amount: i64,
+        fee_amount: i64,
+        net_amount: i64,
+    ) -> Result<seller_ledger_entry::Model, DbErr> {
+        let entry = seller_ledger_entry::ActiveModel {
+            unlock_record_id: Set(unlock_record_id),
+            entry_type: Set(LEDGER_ENTRY_SALE.to_string()),
+            seller_id: Set(seller_id),
+            seller_wallet: Set(seller_wallet),
+            resume_id: Set(resume_id),
+            asset: Set(asset),
+            gross_amount: Set(gross_amount),
+            fee_amount: Set(fee_amount),
+            net_amount: Set(net_amount),
+            payout_id: Set(None),
+            created_at: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        entry.insert(db).await
+    }
+
+    /// 为一笔 sale 账目记录金额取反的 refund 账目
+    pub async fn create_reversal<C: ConnectionTrait>(
+        db: &C,
+        sale: &seller_ledger_entry::Model,
+    ) -> Result<seller_ledger_entry::Model, DbErr> {
+        let entry = seller_ledger_entry::ActiveModel {
+            unlock_record_id: Set(sale.unlock_record_id),
+            entry_type: Set(LEDGER_ENTRY_REFUND.to_string()),
+            seller_id: Set(sale.seller_id),
+            seller_wallet: Set(sale.seller_wallet.clone()),
+            resume_id: Set(sale.resume_id),
+            asset: Set(sale.asset.clone()),
+            gross_amount: Set(-sale.gross_amount),
+            fee_amount: Set(-sale.fee_amount),
+            net_amount: Set(-sale.net_amount),
+            payout_id: Set(None),
+            created_at: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        entry.insert(db).await
+    }
+
+    /// 解锁记录对应的 sale 账目
+    pub async fn find_sale_by_unlock_record<C: ConnectionTrait>(
+        db: &C,
+        unlock_record_id: i64,
+    ) -> Result<Option<seller_ledger_entry::Model>, DbErr> {
+        seller_ledger_entry::Entity::find()
+            .filter(seller_ledger_entry::Column::UnlockRecordId.eq(unlock_record_id))
+            .filter(seller_ledger_entry::Column::EntryType.eq(LEDGER_ENTRY_SALE))
+            .one(db)
+            .await
+    }
+
+    /// 卖家的全部账目
+    pub async fn find_by_seller(
+        db: &DatabaseConnection,
+        seller_id: i64,
+    ) -> Result<Vec<seller_ledger_entry::Model>, DbErr> {
+        seller_ledger_entry::Entity::find()
+            .filter(seller_ledger_entry::Column::SellerId.eq(seller_id))
+            .order_by_desc(seller_ledger_entry::Column::CreatedAt)
+            .all(db)
+            .await
+    }
+
+    /// 所有未结算的账目（加行锁，需在事务中调用）
+    pub async fn find_unbatched_for_update<C: ConnectionTrait>(
+        db: &C,
+    ) -> Result<Vec<seller_ledger_entry::Model>, DbErr> {
+        seller_ledger_entry::Entity::find()
+            .filter(seller_ledger_entry::Column::PayoutId.is_null())
+            .order_by_asc(seller_ledger_entry::Column::Id)
+            .lock_exclusive()
+            .all(db)
+            .await
+    }
+
+    /// 把账目归入结算批次
+    pub async fn assign_payout<C: ConnectionTrait>(
+        db: &C,
+        entry_ids: Vec<i64>,
+        payout_id: i64,
+    ) -> Result<(), DbErr> {
+        seller_ledger_entry::Entity::update_many()
+            .col_expr(seller_ledger_entry::Column::PayoutId, Expr::value(payout_id))
+            .filter(seller_ledger_entry::Column::Id.is_in(entry_ids))
+            .filter(seller_ledger_entry::Column::PayoutId.is_null())
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+}
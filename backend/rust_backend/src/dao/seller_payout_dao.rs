@@ -0,0 +1,74 @@
+use sea_orm::*;
+use sea_orm::sea_query::Expr;
+use crate::entities::seller_payout;
+use crate::money::Money;
+
+/// 结算批次状态
+pub const PAYOUT_STATUS_PENDING: &str = "pending";
+pub const PAYOUT_STATUS_PAID: &str = "paid";
+
+pub struct SellerPayoutDao;
+
+impl SellerPayoutDao {
+    /// 创建结算批次
+    pub async fn create<C: ConnectionTrait>(
+        db: &C,
+        seller_id: i64,
+        seller_wallet: String,
+        amount: Money,
+        entry_count: i32,
+    ) -> Result<seller_payout::Model, DbErr> {
+        let payout = seller_payout::ActiveModel {
+            seller_id: Set(seller_id),
+            seller_wallet: Set(seller_wallet),
+            asset: Set(amount.asset),
+            amount: Set(amount.amount),
+            entry_count: Set(entry_count),
+            status: Set(PAYOUT_STATUS_PENDING.to_string()),
+            transaction_digest: Set(None),
+            paid_at: Set(None),
+            created_at: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        payout.insert(db).await
+    }
+
+    /// 根据 ID 查询
+    pub async fn find_by_id(
+        db: &DatabaseConnection,
+        id: i64,
+    ) -> Result<Option<seller_payout::Model>, DbErr> {
+        seller_payout::Entity::find_by_id(id).one(db).await
+    }
+
+    /// 卖家的全部结算批次
+    pub async fn find_by_seller(
+        db: &DatabaseConnection,
+        seller_id: i64,
+    ) -> Result<Vec<seller_payout::Model>, DbErr> {
+        seller_payout::Entity::find()
+            .filter(seller_payout::Column::SellerId.eq(seller_id))
+            .order_by_desc(seller_payout::Column::CreatedAt)
+            .all(db)
+            .await
+    }
+
+    /// 标记为已打款，返回是否更新（已打款的批次不会被覆盖）
+    pub async fn mark_paid(
+        db: &DatabaseConnection,
+        id: i64,
+        transaction_digest: String,
+    ) -> Result<bool, DbErr> {
+        let result = seller_payout::Entity::update_many()
+            .col_expr(seller_payout::Column::Status, Expr::value(PAYOUT_STATUS_PAID))
+            .col_expr(seller_payout::Column::TransactionDigest, Expr::value(transaction_digest))
+            .col_expr(seller_payout::Column::PaidAt, Expr::value(chrono::Utc::now().naive_utc()))
+            .filter(seller_payout::Column::Id.eq(id))
+            .filter(seller_payout::Column::Status.eq(PAYOUT_STATUS_PENDING))
+            .exec(db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
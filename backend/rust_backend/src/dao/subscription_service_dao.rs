@@ -0,0 +1,61 @@
+use sea_orm::*;
+use crate::entities::subscription_service;
+
+pub struct SubscriptionServiceDao;
+
+impl SubscriptionServiceDao {
+    /// 同步链上创建的订阅服务，已存在时更新订阅费、有效期和关联的简历
+    #[allow(clippy::too_many_arguments)]
+    pub async fn upsert(
+        db: &DatabaseConnection,
+        service_id: String,
+        name: String,
+        fee: i64,
+        ttl_ms: i64,
+        owner: String,
+        resume_id: Option<i64>,
+        tx_digest: String,
+    ) -> Result<subscription_service::Model, DbErr> {
+        let now = chrono::Utc::now().naive_utc();
+
+        match Self::find_by_service_id(db, &service_id).await? {
+            Some(existing) => {
+                let resume_id = resume_id.or(existing.resume_id);
+                let mut service: subscription_service::ActiveModel = existing.into();
+                service.name = Set(name);
+                service.fee = Set(fee);
+                service.ttl_ms = Set(ttl_ms);
+                service.owner = Set(owner);
+                service.resume_id = Set(resume_id);
+                service.updated_at = Set(now);
+                service.update(db).await
+            }
+            None => {
+                let service = subscription_service::ActiveModel {
+                    service_id: Set(service_id),
+                    name: Set(name),
+                    fee: Set(fee),
+                    ttl_ms: Set(ttl_ms),
+                    owner: Set(owner),
+                    resume_id: Set(resume_id),
+                    tx_digest: Set(tx_digest),
+                    created_at: Set(now),
+                    updated_at: Set(now),
+                    ..Default::default()
+                };
+                service.insert(db).await
+            }
+        }
+    }
+
+    /// 根据服务对象 ID 查询
+    pub async fn find_by_service_id(
+        db: &DatabaseConnection,
+        service_id: &str,
+    ) -> Result<Option<subscription_service::Model>, DbErr> {
+        subscription_service::Entity::find()
+            .filter(subscription_service::Column::ServiceId.eq(service_id))
+            .one(db)
+            .await
+    }
+}
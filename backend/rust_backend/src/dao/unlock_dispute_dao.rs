@@ -0,0 +1,88 @@
+use sea_orm::*;
+use sea_orm::sea_query::Expr;
+use crate::entities::unlock_dispute;
+
+/// 争议状态
+pub const DISPUTE_STATUS_OPEN: &str = "open";
+pub const DISPUTE_STATUS_REFUNDED: &str = "refunded";
+pub const DISPUTE_STATUS_REJECTED: &str = "rejected";
+
+pub struct UnlockDisputeDao;
+
+impl UnlockDisputeDao {
+    /// 创建争议
+    pub async fn create<C: ConnectionTrait>(
+        db: &C,
+        unlock_record_id: i64,
+        opened_by: i64,
+        reason: String,
+        evidence: String,
+    ) -> Result<unlock_dispute::Model, DbErr> {
+        let dispute = unlock_dispute::ActiveModel {
+            unlock_record_id: Set(unlock_record_id),
+            opened_by: Set(opened_by),
+            reason: Set(reason),
+            evidence: Set(evidence),
+            status: Set(DISPUTE_STATUS_OPEN.to_string()),
+            created_at: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        dispute.insert(db).await
+    }
+
+    /// 根据 ID 查询争议
+    pub async fn find_by_id<C: ConnectionTrait>(
+        db: &C,
+        id: i64,
+    ) -> Result<Option<unlock_dispute::Model>, DbErr> {
+        unlock_dispute::Entity::find_by_id(id).one(db).await
+    }
+
+    /// 解锁记录的争议
+    pub async fn find_by_unlock_record(
+        db: &DatabaseConnection,
+        unlock_record_id: i64,
+    ) -> Result<Option<unlock_dispute::Model>, DbErr> {
+        unlock_dispute::Entity::find()
+            .filter(unlock_dispute::Column::UnlockRecordId.eq(unlock_record_id))
+            .one(db)
+            .await
+    }
+
+    /// 按状态列出争议，最早发起的在前
+    pub async fn find_all(
+        db: &DatabaseConnection,
+        status: Option<&str>,
+    ) -> Result<Vec<unlock_dispute::Model>, DbErr> {
+        let mut query = unlock_dispute::Entity::find();
+        if let Some(status) = status {
+            query = query.filter(unlock_dispute::Column::Status.eq(status));
+        }
+
+        query.order_by_asc(unlock_dispute::Column::CreatedAt).all(db).await
+    }
+
+    /// 裁决处理中的争议，争议已被裁决时返回 false
+    pub async fn resolve<C: ConnectionTrait>(
+        db: &C,
+        id: i64,
+        status: &str,
+        resolved_by: i64,
+        resolution_note: Option<String>,
+        refund_transaction: Option<String>,
+    ) -> Result<bool, DbErr> {
+        let result = unlock_dispute::Entity::update_many()
+            .col_expr(unlock_dispute::Column::Status, Expr::value(status))
+            .col_expr(unlock_dispute::Column::ResolvedBy, Expr::value(resolved_by))
+            .col_expr(unlock_dispute::Column::ResolutionNote, Expr::value(resolution_note))
+            .col_expr(unlock_dispute::Column::RefundTransaction, Expr::value(refund_transaction))
+            .col_expr(unlock_dispute::Column::ResolvedAt, Expr::value(chrono::Utc::now().naive_utc()))
+            .filter(unlock_dispute::Column::Id.eq(id))
+            .filter(unlock_dispute::Column::Status.eq(DISPUTE_STATUS_OPEN))
+            .exec(db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -1,19 +1,25 @@
 use sea_orm::*;
+use sea_orm::sea_query::Expr;
 use crate::entities::unlock_record;
+use crate::models::UnlockStatus;
+use crate::money::Money;
 
 pub struct UnlockRecordDao;
 
 impl UnlockRecordDao {
     /// 创建解锁记录
-    #[allow(dead_code)]
-    pub async fn create(
-        db: &DatabaseConnection,
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create<C: ConnectionTrait>(
+        db: &C,
         resume_id: i64,
         buyer_id: i64,
         buyer_wallet: String,
+        organization_id: Option<i64>,
         seller_wallet: String,
-        amount: i64,
+        amount: Money,
+        tier: String,
         transaction_signature: String,
+        status: UnlockStatus,
         block_time: Option<i64>,
     ) -> Result<unlock_record::Model, DbErr> {
         let now = chrono::Utc::now();
@@ -22,10 +28,13 @@ impl UnlockRecordDao {
             resume_id: Set(resume_id),
             buyer_id: Set(buyer_id),
             buyer_wallet: Set(buyer_wallet),
+            organization_id: Set(organization_id),
             seller_wallet: Set(seller_wallet),
-            amount: Set(amount),
+            amount: Set(amount.amount),
+            asset: Set(amount.asset),
+            tier: Set(tier),
             transaction_signature: Set(transaction_signature),
-            status: Set("confirmed".to_string()),
+            status: Set(status.as_str().to_string()),
             block_time: Set(block_time),
             created_at: Set(now.naive_utc()),
             ..Default::default()
@@ -34,6 +43,71 @@ impl UnlockRecordDao {
         new_record.insert(db).await
     }
 
+    /// 根据 ID 查询解锁记录
+    pub async fn find_by_id(
+        db: &DatabaseConnection,
+        id: i64,
+    ) -> Result<Option<unlock_record::Model>, DbErr> {
+        unlock_record::Entity::find_by_id(id).one(db).await
+    }
+
+    /// 根据 ID 查询并锁定解锁记录（需在事务中调用）
+    pub async fn find_by_id_for_update<C: ConnectionTrait>(
+        db: &C,
+        id: i64,
+    ) -> Result<Option<unlock_record::Model>, DbErr> {
+        unlock_record::Entity::find_by_id(id).lock_exclusive().one(db).await
+    }
+
+    /// 条件更新状态：只有当前状态为 `from` 时才更新，返回是否更新成功
+    pub async fn update_status<C: ConnectionTrait>(
+        db: &C,
+        id: i64,
+        from: UnlockStatus,
+        to: UnlockStatus,
+    ) -> Result<bool, DbErr> {
+        let result = unlock_record::Entity::update_many()
+            .col_expr(unlock_record::Column::Status, Expr::value(to.as_str()))
+            .filter(unlock_record::Column::Id.eq(id))
+            .filter(unlock_record::Column::Status.eq(from.as_str()))
+            .exec(db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// 记录链上校验结果：校验时间、失败原因，以及尚未记录的区块时间
+    pub async fn record_verification<C: ConnectionTrait>(
+        db: &C,
+        id: i64,
+        failure_reason: Option<String>,
+        block_time: Option<i64>,
+    ) -> Result<(), DbErr> {
+        let mut update = unlock_record::Entity::update_many()
+            .col_expr(unlock_record::Column::FailureReason, Expr::value(failure_reason))
+            .col_expr(unlock_record::Column::VerifiedAt, Expr::value(chrono::Utc::now().naive_utc()));
+        if let Some(block_time) = block_time {
+            update = update.col_expr(
+                unlock_record::Column::BlockTime,
+                Expr::col(unlock_record::Column::BlockTime).if_null(block_time),
+            );
+        }
+
+        update.filter(unlock_record::Column::Id.eq(id)).exec(db).await?;
+        Ok(())
+    }
+
+    /// 根据交易签名查询解锁记录
+    pub async fn find_by_signature(
+        db: &DatabaseConnection,
+        transaction_signature: &str,
+    ) -> Result<Option<unlock_record::Model>, DbErr> {
+        unlock_record::Entity::find()
+            .filter(unlock_record::Column::TransactionSignature.eq(transaction_signature))
+            .one(db)
+            .await
+    }
+
     /// 检查是否已经存在相同的交易签名（防止重复记录）
     #[allow(dead_code)]
     pub async fn exists_by_signature(
@@ -49,20 +123,86 @@ impl UnlockRecordDao {
     }
 
     /// 根据简历 ID 和购买者 ID 查询解锁记录
-    #[allow(dead_code)]
-    pub async fn find_by_resume_and_buyer(
-        db: &DatabaseConnection,
+    pub async fn find_by_resume_and_buyer<C: ConnectionTrait>(
+        db: &C,
         resume_id: i64,
         buyer_id: i64,
     ) -> Result<Option<unlock_record::Model>, DbErr> {
         unlock_record::Entity::find()
             .filter(unlock_record::Column::ResumeId.eq(resume_id))
             .filter(unlock_record::Column::BuyerId.eq(buyer_id))
-            .filter(unlock_record::Column::Status.eq("confirmed"))
+            .filter(unlock_record::Column::Status.is_in(UnlockStatus::entitled()))
+            .one(db)
+            .await
+    }
+
+    /// 查询买家本人或其所在组织在某简历上的全部解锁记录（用于计算已拥有的档位）
+    pub async fn find_all_by_resume_for_buyer<C: ConnectionTrait>(
+        db: &C,
+        resume_id: i64,
+        buyer_id: i64,
+        organization_ids: &[i64],
+    ) -> Result<Vec<unlock_record::Model>, DbErr> {
+        let mut owner = Condition::any().add(unlock_record::Column::BuyerId.eq(buyer_id));
+        if !organization_ids.is_empty() {
+            owner = owner.add(unlock_record::Column::OrganizationId.is_in(organization_ids.to_vec()));
+        }
+
+        unlock_record::Entity::find()
+            .filter(unlock_record::Column::ResumeId.eq(resume_id))
+            .filter(unlock_record::Column::Status.is_in(UnlockStatus::entitled()))
+            .filter(owner)
+            .all(db)
+            .await
+    }
+
+    /// 简历上已售出的档位名称
+    pub async fn find_sold_tiers<C: ConnectionTrait>(
+        db: &C,
+        resume_id: i64,
+    ) -> Result<Vec<String>, DbErr> {
+        unlock_record::Entity::find()
+            .select_only()
+            .column(unlock_record::Column::Tier)
+            .distinct()
+            .filter(unlock_record::Column::ResumeId.eq(resume_id))
+            .filter(unlock_record::Column::Status.is_in(UnlockStatus::entitled()))
+            .into_tuple()
+            .all(db)
+            .await
+    }
+
+    /// 查询组织在某简历上的解锁记录
+    pub async fn find_by_resume_and_organizations(
+        db: &DatabaseConnection,
+        resume_id: i64,
+        organization_ids: &[i64],
+    ) -> Result<Option<unlock_record::Model>, DbErr> {
+        if organization_ids.is_empty() {
+            return Ok(None);
+        }
+
+        unlock_record::Entity::find()
+            .filter(unlock_record::Column::ResumeId.eq(resume_id))
+            .filter(unlock_record::Column::OrganizationId.is_in(organization_ids.to_vec()))
+            .filter(unlock_record::Column::Status.is_in(UnlockStatus::entitled()))
             .one(db)
             .await
     }
 
+    /// 查询组织的所有解锁记录
+    pub async fn find_by_organization(
+        db: &DatabaseConnection,
+        organization_id: i64,
+    ) -> Result<Vec<unlock_record::Model>, DbErr> {
+        unlock_record::Entity::find()
+            .filter(unlock_record::Column::OrganizationId.eq(organization_id))
+            .filter(unlock_record::Column::Status.is_in(UnlockStatus::entitled()))
+            .order_by_desc(unlock_record::Column::CreatedAt)
+            .all(db)
+            .await
+    }
+
     /// 根据购买者钱包地址查询所有已解锁的简历
     #[allow(dead_code)]
     pub async fn find_unlocked_resumes_by_buyer(
@@ -71,7 +211,20 @@ impl UnlockRecordDao {
     ) -> Result<Vec<unlock_record::Model>, DbErr> {
         unlock_record::Entity::find()
             .filter(unlock_record::Column::BuyerWallet.eq(buyer_wallet))
-            .filter(unlock_record::Column::Status.eq("confirmed"))
+            .filter(unlock_record::Column::Status.is_in(UnlockStatus::entitled()))
+            .order_by_desc(unlock_record::Column::CreatedAt)
+            .all(db)
+            .await
+    }
+
+    /// 查询用户已解锁的简历（按购买者用户 ID）
+    pub async fn find_unlocked_resumes_by_buyer_id(
+        db: &DatabaseConnection,
+        buyer_id: i64,
+    ) -> Result<Vec<unlock_record::Model>, DbErr> {
+        unlock_record::Entity::find()
+            .filter(unlock_record::Column::BuyerId.eq(buyer_id))
+            .filter(unlock_record::Column::Status.is_in(UnlockStatus::entitled()))
             .order_by_desc(unlock_record::Column::CreatedAt)
             .all(db)
             .await
@@ -85,7 +238,7 @@ impl UnlockRecordDao {
     ) -> Result<Vec<unlock_record::Model>, DbErr> {
         unlock_record::Entity::find()
             .filter(unlock_record::Column::ResumeId.eq(resume_id))
-            .filter(unlock_record::Column::Status.eq("confirmed"))
+            .filter(unlock_record::Column::Status.is_in(UnlockStatus::entitled()))
             .order_by_desc(unlock_record::Column::CreatedAt)
             .all(db)
             .await
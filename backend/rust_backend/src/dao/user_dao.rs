@@ -1,5 +1,8 @@
 use sea_orm::*;
+use crate::auth::permissions::USER_TYPE_JOB_SEEKER;
+use crate::dao::UserWalletDao;
 use crate::entities::{user, User};
+use crate::utils::validator::Chain;
 use anyhow::Result;
 
 pub struct UserDao;
@@ -18,34 +21,49 @@ impl UserDao {
         }
 
         // 不存在则创建
-        let nickname = Some(format!("User_{}", &wallet_address[..8]));
-        Self::create(db, wallet_address, nickname).await
+        let nickname = Some(Self::default_nickname(&wallet_address));
+        Self::create(db, wallet_address, nickname, USER_TYPE_JOB_SEEKER).await
     }
 
-    /// 创建用户
+    /// 默认昵称：`User_` + 地址前 8 个字符
+    pub fn default_nickname(wallet_address: &str) -> String {
+        format!("User_{}", wallet_address.chars().take(8).collect::<String>())
+    }
+
+    /// 创建用户，`wallet_address` 同时登记为主钱包（Sui）
     pub async fn create(
         db: &DatabaseConnection,
         wallet_address: String,
         nickname: Option<String>,
+        user_type: &str,
     ) -> Result<i64> {
+        let txn = db.begin().await?;
+
         let user = user::ActiveModel {
-            wallet_address: Set(wallet_address),
+            wallet_address: Set(wallet_address.clone()),
             nickname: Set(nickname),
-            user_type: Set("job_seeker".to_string()),
+            user_type: Set(user_type.to_string()),
             created_at: Set(chrono::Utc::now().naive_utc()),
             updated_at: Set(chrono::Utc::now().naive_utc()),
             ..Default::default()
         };
         
-        let result = user.insert(db).await?;
+        let result = user.insert(&txn).await?;
+        UserWalletDao::create(&txn, result.id, Chain::Sui.as_str(), wallet_address, true).await?;
+
+        txn.commit().await?;
         Ok(result.id)
     }
     
-    /// 根据钱包地址查询用户
+    /// 根据钱包地址查询用户（主钱包或任意已关联的钱包）
     pub async fn find_by_wallet(
         db: &DatabaseConnection,
         wallet_address: &str
     ) -> Result<Option<user::Model>> {
+        if let Some(linked) = UserWalletDao::find_by_address(db, wallet_address).await? {
+            return Self::find_by_id(db, linked.user_id).await;
+        }
+
         let user = User::find()
             .filter(user::Column::WalletAddress.eq(wallet_address))
             .one(db)
@@ -78,7 +96,7 @@ impl UserDao {
         }
         
         // 不存在则创建
-        let user_id = Self::create(db, wallet_address.clone(), nickname).await?;
+        let user_id = Self::create(db, wallet_address.clone(), nickname, USER_TYPE_JOB_SEEKER).await?;
         
         // 返回创建的用户
         Self::find_by_id(db, user_id)
@@ -104,6 +122,23 @@ impl UserDao {
         Ok(())
     }
     
+    /// 更新用户类型（角色）
+    pub async fn update_user_type(
+        db: &DatabaseConnection,
+        user_id: i64,
+        user_type: &str,
+    ) -> Result<user::Model> {
+        let user = Self::find_by_id(db, user_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("User not found"))?;
+
+        let mut user: user::ActiveModel = user.into();
+        user.user_type = Set(user_type.to_string());
+        user.updated_at = Set(chrono::Utc::now().naive_utc());
+
+        Ok(user.update(db).await?)
+    }
+
     /// 获取用户统计
     pub async fn get_user_stats(
         _db: &DatabaseConnection,
@@ -0,0 +1,70 @@
+use sea_orm::*;
+use crate::entities::user_wallet;
+
+pub struct UserWalletDao;
+
+impl UserWalletDao {
+    /// 关联钱包
+    pub async fn create<C: ConnectionTrait>(
+        db: &C,
+        user_id: i64,
+        chain: &str,
+        address: String,
+        is_primary: bool,
+    ) -> Result<user_wallet::Model, DbErr> {
+        let now = chrono::Utc::now().naive_utc();
+
+        let wallet = user_wallet::ActiveModel {
+            user_id: Set(user_id),
+            chain: Set(chain.to_string()),
+            address: Set(address),
+            is_primary: Set(is_primary),
+            verified_at: Set(now),
+            created_at: Set(now),
+            ..Default::default()
+        };
+
+        wallet.insert(db).await
+    }
+
+    /// 根据 ID 查询
+    pub async fn find_by_id(
+        db: &DatabaseConnection,
+        wallet_id: i64,
+    ) -> Result<Option<user_wallet::Model>, DbErr> {
+        user_wallet::Entity::find_by_id(wallet_id).one(db).await
+    }
+
+    /// 根据地址查询（任意链）
+    pub async fn find_by_address(
+        db: &DatabaseConnection,
+        address: &str,
+    ) -> Result<Option<user_wallet::Model>, DbErr> {
+        user_wallet::Entity::find()
+            .filter(user_wallet::Column::Address.eq(address))
+            .one(db)
+            .await
+    }
+
+    /// 查询用户关联的所有钱包（主钱包在前）
+    pub async fn find_by_user(
+        db: &DatabaseConnection,
+        user_id: i64,
+    ) -> Result<Vec<user_wallet::Model>, DbErr> {
+        user_wallet::Entity::find()
+            .filter(user_wallet::Column::UserId.eq(user_id))
+            .order_by_desc(user_wallet::Column::IsPrimary)
+            .order_by_asc(user_wallet::Column::CreatedAt)
+            .all(db)
+            .await
+    }
+
+    /// 取消关联
+    pub async fn delete<C: ConnectionTrait>(
+        db: &C,
+        wallet_id: i64,
+    ) -> Result<(), DbErr> {
+        user_wallet::Entity::delete_by_id(wallet_id).exec(db).await?;
+        Ok(())
+    }
+}
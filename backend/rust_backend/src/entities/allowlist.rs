@@ -0,0 +1,53 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 链上创建的 allowlist（由事件索引同步）
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "allowlists")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// Allowlist 对象 ID
+    #[sea_orm(unique, column_type = "String(StringLen::N(100))")]
+    pub allowlist_id: String,
+
+    /// 链上名称
+    #[sea_orm(column_type = "String(StringLen::N(255))")]
+    pub name: String,
+
+    /// 创建者地址
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub creator: String,
+
+    /// 关联的简历 ID
+    pub resume_id: Option<i64>,
+
+    /// 创建交易摘要
+    #[sea_orm(column_type = "String(StringLen::N(150))")]
+    pub tx_digest: String,
+
+    /// 创建时间
+    pub created_at: DateTime,
+
+    /// 更新时间
+    pub updated_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::resume::Entity",
+        from = "Column::ResumeId",
+        to = "super::resume::Column::Id"
+    )]
+    Resume,
+}
+
+impl Related<super::resume::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Resume.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
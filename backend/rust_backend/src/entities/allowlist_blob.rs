@@ -0,0 +1,50 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 发布到 allowlist 的 blob（由事件索引同步）
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "allowlist_blobs")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// Allowlist 对象 ID
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub allowlist_id: String,
+
+    /// Walrus blob ID
+    #[sea_orm(column_type = "String(StringLen::N(150))")]
+    pub blob_id: String,
+
+    /// 发布者地址
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub publisher: String,
+
+    /// 按 blob ID 匹配到的简历 ID
+    pub resume_id: Option<i64>,
+
+    /// 发布交易摘要
+    #[sea_orm(column_type = "String(StringLen::N(150))")]
+    pub tx_digest: String,
+
+    /// 创建时间
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::resume::Entity",
+        from = "Column::ResumeId",
+        to = "super::resume::Column::Id"
+    )]
+    Resume,
+}
+
+impl Related<super::resume::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Resume.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -16,18 +16,18 @@ pub struct Model {
     #[sea_orm(column_type = "String(StringLen::N(100))")]
     pub member_address: String,
     
-    /// 关联的简历 ID
-    pub resume_id: i64,
+    /// 关联的简历 ID（allowlist 尚未关联简历时为 None）
+    pub resume_id: Option<i64>,
     
     /// 添加者地址
     #[sea_orm(column_type = "String(StringLen::N(100))")]
     pub added_by: String,
     
-    /// 添加交易哈希
+    /// 最近一次添加 / 移除交易哈希
     #[sea_orm(column_type = "String(StringLen::N(150))")]
     pub tx_digest: String,
     
-    /// 状态: active(激活), removed(已移除)
+    /// 状态: pending(已提交添加交易，待链上确认), active(激活), removed(已移除)
     #[sea_orm(column_type = "String(StringLen::N(20))")]
     pub status: String,
     
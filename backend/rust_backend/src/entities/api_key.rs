@@ -0,0 +1,82 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// API Key 表
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "api_keys")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// Key 名称
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub name: String,
+
+    /// Key 前缀（用于识别，不可用于认证）
+    #[sea_orm(column_type = "String(StringLen::N(16))")]
+    pub key_prefix: String,
+
+    /// Key 的 SHA-256 哈希（明文 key 不落库）
+    #[sea_orm(unique, column_type = "String(StringLen::N(64))")]
+    pub key_hash: String,
+
+    /// 创建者用户 ID
+    pub user_id: i64,
+
+    /// 所属组织 ID（组织 Key）
+    pub organization_id: Option<i64>,
+
+    /// 授权范围，逗号分隔，如 `resumes:read,unlocks:write`
+    #[sea_orm(column_type = "String(StringLen::N(255))")]
+    pub scopes: String,
+
+    /// 最后使用时间
+    pub last_used_at: Option<DateTime>,
+
+    /// 吊销时间
+    pub revoked_at: Option<DateTime>,
+
+    /// 创建时间
+    pub created_at: DateTime,
+
+    /// 更新时间
+    pub updated_at: DateTime,
+}
+
+impl Model {
+    /// 是否拥有某个授权范围
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.split(',').any(|s| s.trim() == scope)
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::UserId",
+        to = "super::user::Column::Id"
+    )]
+    User,
+
+    #[sea_orm(
+        belongs_to = "super::organization::Entity",
+        from = "Column::OrganizationId",
+        to = "super::organization::Column::Id"
+    )]
+    Organization,
+}
+
+impl Related<super::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl Related<super::organization::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Organization.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
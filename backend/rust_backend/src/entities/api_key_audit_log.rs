@@ -0,0 +1,49 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// API Key 审计日志表
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "api_key_audit_logs")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// API Key ID
+    pub api_key_id: i64,
+
+    /// HTTP 方法
+    #[sea_orm(column_type = "String(StringLen::N(10))")]
+    pub method: String,
+
+    /// 请求路径
+    #[sea_orm(column_type = "String(StringLen::N(255))")]
+    pub path: String,
+
+    /// 响应状态码
+    pub status_code: i32,
+
+    /// IP 地址
+    #[sea_orm(column_type = "String(StringLen::N(45))", nullable)]
+    pub ip_address: Option<String>,
+
+    /// 创建时间
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::api_key::Entity",
+        from = "Column::ApiKeyId",
+        to = "super::api_key::Column::Id"
+    )]
+    ApiKey,
+}
+
+impl Related<super::api_key::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ApiKey.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,47 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 签名挑战 nonce 表（登录、关联钱包）
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "auth_nonces")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// 签名钱包地址
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub wallet_address: String,
+
+    /// 随机 nonce
+    #[sea_orm(unique, column_type = "String(StringLen::N(64))")]
+    pub nonce: String,
+
+    /// 待签名的完整消息
+    #[sea_orm(column_type = "Text")]
+    pub message: String,
+
+    /// 用途: login / link_wallet
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub purpose: String,
+
+    /// 签名钱包所在链
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub chain: String,
+
+    /// 关联钱包时发起请求的用户 ID
+    pub user_id: Option<i64>,
+
+    /// 是否已使用（每个 nonce 只能登录一次）
+    pub used: bool,
+
+    /// 过期时间
+    pub expires_at: DateTime,
+
+    /// 创建时间
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,63 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 登录会话表
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "auth_sessions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// 会话 token 的 SHA-256 哈希（明文 token 不落库）
+    #[sea_orm(unique, column_type = "String(StringLen::N(64))")]
+    pub token_hash: String,
+
+    /// 用户 ID
+    pub user_id: i64,
+
+    /// 已验证的钱包地址
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub wallet_address: String,
+
+    /// 设备标签（客户端登录时提供）
+    #[sea_orm(column_type = "String(StringLen::N(100))", nullable)]
+    pub device_label: Option<String>,
+
+    /// 登录 IP
+    #[sea_orm(column_type = "String(StringLen::N(45))", nullable)]
+    pub ip_address: Option<String>,
+
+    /// 登录 User-Agent
+    #[sea_orm(column_type = "String(StringLen::N(255))", nullable)]
+    pub user_agent: Option<String>,
+
+    /// 最后活跃时间
+    pub last_seen_at: Option<DateTime>,
+
+    /// 过期时间（滑动续期）
+    pub expires_at: DateTime,
+
+    /// 注销时间
+    pub revoked_at: Option<DateTime>,
+
+    /// 创建时间
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::UserId",
+        to = "super::user::Column::Id"
+    )]
+    User,
+}
+
+impl Related<super::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,60 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 优惠码表
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "coupons")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// 优惠码（大写）
+    #[sea_orm(unique, column_type = "String(StringLen::N(50))")]
+    pub code: String,
+
+    /// 减免方式: percent(百分比), fixed(固定金额)
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub discount_type: String,
+
+    /// 百分比 (1-100) 或固定减免金额（资产最小单位）
+    pub discount_value: i64,
+
+    /// 固定减免的资产，percent 为 None
+    #[sea_orm(column_type = "String(StringLen::N(20))", nullable)]
+    pub asset: Option<String>,
+
+    /// 总使用次数上限
+    pub max_redemptions: Option<i32>,
+
+    /// 每人使用次数上限
+    pub max_per_user: Option<i32>,
+
+    /// 已使用次数
+    pub redeemed_count: i32,
+
+    /// 过期时间
+    pub expires_at: Option<DateTime>,
+
+    /// 是否可用
+    pub active: bool,
+
+    /// 创建的管理员用户 ID
+    pub created_by: i64,
+
+    /// 创建时间
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::coupon_redemption::Entity")]
+    Redemptions,
+}
+
+impl Related<super::coupon_redemption::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Redemptions.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
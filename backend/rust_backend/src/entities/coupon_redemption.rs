@@ -0,0 +1,47 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 优惠码使用记录表
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "coupon_redemptions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// 优惠码
+    pub coupon_id: i64,
+
+    /// 使用者用户 ID
+    pub user_id: i64,
+
+    /// 使用优惠码的解锁记录
+    pub unlock_record_id: i64,
+
+    /// 减免金额（资产最小单位）
+    pub discount: i64,
+
+    /// 资产符号
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub asset: String,
+
+    /// 创建时间
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::coupon::Entity",
+        from = "Column::CouponId",
+        to = "super::coupon::Column::Id"
+    )]
+    Coupon,
+}
+
+impl Related<super::coupon::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Coupon.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
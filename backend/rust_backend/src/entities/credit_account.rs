@@ -0,0 +1,44 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 额度账户表，每个用户或组织每种资产一个
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "credit_accounts")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// 所有者类型: user(个人), organization(组织)
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub owner_type: String,
+
+    /// 用户 ID 或组织 ID
+    pub owner_id: i64,
+
+    /// 资产符号
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub asset: String,
+
+    /// 余额（资产最小单位）
+    pub balance: i64,
+
+    /// 创建时间
+    pub created_at: DateTime,
+
+    /// 更新时间
+    pub updated_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::credit_transaction::Entity")]
+    Transactions,
+}
+
+impl Related<super::credit_transaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Transactions.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,35 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 额度套餐表
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "credit_bundles")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// 套餐名称
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub name: String,
+
+    /// 售价（资产最小单位）
+    pub price: i64,
+
+    /// 到账额度（不少于售价，差额为赠送）
+    pub credits: i64,
+
+    /// 资产符号
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub asset: String,
+
+    /// 是否在售
+    pub active: bool,
+
+    /// 创建时间
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
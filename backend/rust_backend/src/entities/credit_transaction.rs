@@ -0,0 +1,57 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 额度流水表，每笔充值、扣减和退回一条
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "credit_transactions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// 额度账户
+    pub account_id: i64,
+
+    /// 类型: topup(充值), unlock(解锁扣减), refund(退款退回)
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub kind: String,
+
+    /// 变动金额，扣减为负
+    pub amount: i64,
+
+    /// 变动后余额
+    pub balance_after: i64,
+
+    /// 充值的套餐
+    pub bundle_id: Option<i64>,
+
+    /// 关联的解锁记录
+    pub unlock_record_id: Option<i64>,
+
+    /// 充值的结算交易摘要
+    #[sea_orm(column_type = "String(StringLen::N(150))", nullable)]
+    pub reference: Option<String>,
+
+    /// 操作用户 ID
+    pub created_by: i64,
+
+    /// 创建时间
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::credit_account::Entity",
+        from = "Column::AccountId",
+        to = "super::credit_account::Column::Id"
+    )]
+    Account,
+}
+
+impl Related<super::credit_account::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Account.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
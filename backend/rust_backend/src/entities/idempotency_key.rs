@@ -0,0 +1,55 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 幂等键表，保存支付相关请求的第一次响应供重试时重放
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "idempotency_keys")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// 发起请求的用户 ID
+    pub user_id: i64,
+
+    /// 客户端提供的幂等键
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub idempotency_key: String,
+
+    /// 请求方法
+    #[sea_orm(column_type = "String(StringLen::N(10))")]
+    pub method: String,
+
+    /// 请求路径（含查询参数）
+    #[sea_orm(column_type = "String(StringLen::N(255))")]
+    pub path: String,
+
+    /// 方法、路径和请求体的 SHA-256 哈希
+    #[sea_orm(column_type = "String(StringLen::N(64))")]
+    pub request_hash: String,
+
+    /// 状态: processing(处理中), completed(已保存响应)
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub status: String,
+
+    /// 保存的响应状态码
+    pub response_status: Option<i32>,
+
+    /// 需要重放的响应头（JSON 对象）
+    #[sea_orm(column_type = "Text", nullable)]
+    pub response_headers: Option<String>,
+
+    /// 保存的响应体
+    #[sea_orm(column_type = "Text", nullable)]
+    pub response_body: Option<String>,
+
+    /// 创建时间
+    pub created_at: DateTime,
+
+    /// 保存响应的时间
+    pub completed_at: Option<DateTime>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,44 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 链上事件索引游标（每个合约包的每个事件流一行）
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "indexer_cursors")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// 合约包 ID
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub package_id: String,
+
+    /// 事件类型（模块名）
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub event_type: String,
+
+    /// 最后处理的事件所在交易
+    #[sea_orm(column_type = "String(StringLen::N(150))", nullable)]
+    pub tx_digest: Option<String>,
+
+    /// 最后处理的事件在交易中的序号
+    #[sea_orm(column_type = "String(StringLen::N(20))", nullable)]
+    pub event_seq: Option<String>,
+
+    /// 最后处理的事件的链上时间（毫秒）
+    pub last_event_timestamp_ms: Option<i64>,
+
+    /// 最近一次追上链上最新事件的时间
+    pub caught_up_at: Option<DateTime>,
+
+    /// 最近一次轮询的错误
+    #[sea_orm(column_type = "String(StringLen::N(500))", nullable)]
+    pub last_error: Option<String>,
+
+    /// 更新时间
+    pub updated_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
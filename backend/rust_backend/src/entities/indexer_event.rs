@@ -0,0 +1,34 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 索引器已处理的事件（按交易摘要和事件序号去重）
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "indexer_events")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// 合约包 ID
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub package_id: String,
+
+    /// 事件类型（模块名）
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub event_type: String,
+
+    /// 交易摘要
+    #[sea_orm(column_type = "String(StringLen::N(150))")]
+    pub tx_digest: String,
+
+    /// 事件在交易中的序号
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub event_seq: String,
+
+    /// 处理时间
+    pub processed_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -3,6 +3,31 @@ pub mod resume;
 pub mod unlock_record;
 pub mod allowlist_member;
 pub mod access_log;
+pub mod auth_nonce;
+pub mod auth_session;
+pub mod organization;
+pub mod organization_member;
+pub mod api_key;
+pub mod api_key_audit_log;
+pub mod rate_limit_bucket;
+pub mod user_wallet;
+pub mod payment_receipt;
+pub mod seller_ledger_entry;
+pub mod seller_payout;
+pub mod resume_tier;
+pub mod unlock_dispute;
+pub mod credit_account;
+pub mod credit_transaction;
+pub mod credit_bundle;
+pub mod coupon;
+pub mod coupon_redemption;
+pub mod idempotency_key;
+pub mod allowlist;
+pub mod allowlist_blob;
+pub mod subscription_service;
+pub mod indexer_cursor;
+pub mod indexer_event;
+pub mod payment_settlement;
 
 pub use user::Entity as User;
 pub use resume::Entity as Resume;
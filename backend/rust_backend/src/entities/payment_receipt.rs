@@ -0,0 +1,84 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 付费解锁收据表
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "payment_receipts")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// 收据 ID (UUID)
+    #[sea_orm(unique, column_type = "String(StringLen::N(36))")]
+    pub receipt_id: String,
+
+    /// 对应的解锁记录
+    #[sea_orm(unique)]
+    pub unlock_record_id: i64,
+
+    /// 简历 ID
+    #[sea_orm(column_type = "String(StringLen::N(64))")]
+    pub resume_id: String,
+
+    /// 购买者用户 ID
+    pub buyer_id: i64,
+
+    /// 卖家（简历所有者）用户 ID
+    pub seller_id: i64,
+
+    /// 购买者钱包地址
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub buyer_wallet: String,
+
+    /// 卖家钱包地址
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub seller_wallet: String,
+
+    /// 支付金额（代币最小单位）
+    pub amount: i64,
+
+    /// 支付代币
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub asset: String,
+
+    /// 支付网络
+    #[sea_orm(column_type = "String(StringLen::N(50))")]
+    pub network: String,
+
+    /// 结算交易摘要
+    #[sea_orm(column_type = "String(StringLen::N(150))")]
+    pub transaction_digest: String,
+
+    /// 被签名的收据 JSON
+    #[sea_orm(column_type = "Text")]
+    pub payload: String,
+
+    /// Ed25519 签名 (base64)
+    #[sea_orm(column_type = "String(StringLen::N(128))")]
+    pub signature: String,
+
+    /// 签名公钥 ID
+    #[sea_orm(column_type = "String(StringLen::N(16))")]
+    pub key_id: String,
+
+    /// 创建时间
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::unlock_record::Entity",
+        from = "Column::UnlockRecordId",
+        to = "super::unlock_record::Column::Id"
+    )]
+    UnlockRecord,
+}
+
+impl Related<super::unlock_record::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UnlockRecord.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,101 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// x402 结算意向：结算前写入，结算后记录交易摘要，写入解锁记录或额度流水后完成
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "payment_settlements")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// 用途: unlock(付费解锁) / credit_topup(购买额度套餐)
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub purpose: String,
+
+    /// 付款用户 ID
+    pub user_id: i64,
+
+    /// 付款用户钱包
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub wallet: String,
+
+    /// 代表组织购买时的组织 ID
+    pub organization_id: Option<i64>,
+
+    /// 解锁的简历
+    pub resume_id: Option<i64>,
+
+    /// 解锁的档位
+    #[sea_orm(column_type = "String(StringLen::N(50))", nullable)]
+    pub tier: Option<String>,
+
+    /// 是否为从低档升级（不增加解锁次数）
+    pub upgrade: bool,
+
+    /// 使用的优惠码
+    pub coupon_id: Option<i64>,
+
+    /// 优惠码减免金额
+    pub discount: i64,
+
+    /// 购买的额度套餐
+    pub bundle_id: Option<i64>,
+
+    /// 购买时套餐的到账额度
+    pub credits: Option<i64>,
+
+    /// 充值的额度账户所有者类型: user / organization
+    #[sea_orm(column_type = "String(StringLen::N(20))", nullable)]
+    pub credit_owner_type: Option<String>,
+
+    /// 充值的额度账户所有者 ID
+    pub credit_owner_id: Option<i64>,
+
+    /// 应付金额（资产最小单位）
+    pub amount: i64,
+
+    /// 资产符号
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub asset: String,
+
+    /// x402 支付代币地址
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub pay_asset: String,
+
+    /// x402 网络
+    #[sea_orm(column_type = "String(StringLen::N(50))")]
+    pub network: String,
+
+    /// 状态: pending / settled / completed / failed
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub status: String,
+
+    /// 结算交易摘要
+    #[sea_orm(column_type = "String(StringLen::N(150))", nullable)]
+    pub transaction: Option<String>,
+
+    /// facilitator 识别出的付款地址
+    #[sea_orm(column_type = "String(StringLen::N(100))", nullable)]
+    pub payer: Option<String>,
+
+    /// 完成后写入的解锁记录
+    pub unlock_record_id: Option<i64>,
+
+    /// 完成后写入的额度流水
+    pub credit_transaction_id: Option<i64>,
+
+    /// 结算或写入失败的原因
+    #[sea_orm(column_type = "String(StringLen::N(500))", nullable)]
+    pub error: Option<String>,
+
+    /// 创建时间
+    pub created_at: DateTime,
+
+    /// 更新时间
+    pub updated_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
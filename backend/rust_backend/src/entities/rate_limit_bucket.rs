@@ -0,0 +1,23 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 限流令牌桶表
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "rate_limit_buckets")]
+pub struct Model {
+    /// 桶标识: 路由组 + IP / 钱包 / API Key
+    #[sea_orm(primary_key, auto_increment = false, column_type = "String(StringLen::N(191))")]
+    pub bucket_key: String,
+
+    /// 剩余令牌数
+    #[sea_orm(column_type = "Double")]
+    pub tokens: f64,
+
+    /// 上次更新时间（毫秒时间戳）
+    pub updated_at_ms: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -51,8 +51,12 @@ pub struct Model {
     #[sea_orm(column_type = "Json")]
     pub summary: serde_json::Value,
     
-    /// 解锁价格 (单位: SOL 的 lamports)
+    /// 解锁价格（`price_asset` 的最小单位）
     pub price: i64,
+
+    /// 价格资产符号，如 USDC
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub price_asset: String,
     
     /// 浏览次数
     pub view_count: i32,
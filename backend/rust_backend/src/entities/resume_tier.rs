@@ -0,0 +1,71 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 简历价格档位表（完整简历 `full` 不在此表中，由 resumes 表本身表示）
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "resume_tiers")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// 简历主键
+    pub resume_id: i64,
+
+    /// 档位名称，如 profile / contact
+    #[sea_orm(column_type = "String(StringLen::N(32))")]
+    pub tier: String,
+
+    /// 档位等级，越高包含的内容越多
+    pub level: i32,
+
+    /// 档位价格（`price_asset` 的最小单位）
+    pub price: i64,
+
+    /// 价格资产符号
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub price_asset: String,
+
+    /// 该档位内容的 Walrus Blob ID
+    #[sea_orm(column_type = "String(StringLen::N(150))")]
+    pub blob_id: String,
+
+    /// 加密类型: simple / seal
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub encryption_type: String,
+
+    /// Seal 加密 ID
+    #[sea_orm(column_type = "String(StringLen::N(150))", nullable)]
+    pub encryption_id: Option<String>,
+
+    /// Seal Policy Object ID
+    #[sea_orm(column_type = "String(StringLen::N(100))", nullable)]
+    pub policy_object_id: Option<String>,
+
+    /// 简单加密模式的密钥
+    #[sea_orm(column_type = "Text", nullable)]
+    pub encryption_key: Option<String>,
+
+    /// 创建时间
+    pub created_at: DateTime,
+
+    /// 更新时间
+    pub updated_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::resume::Entity",
+        from = "Column::ResumeId",
+        to = "super::resume::Column::Id"
+    )]
+    Resume,
+}
+
+impl Related<super::resume::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Resume.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,64 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 卖家佣金账本表，每笔付费解锁一条 sale 账目，退款时再记一条金额为负的 refund 账目
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "seller_ledger_entries")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// 对应的解锁记录
+    pub unlock_record_id: i64,
+
+    /// 账目类型: sale(解锁收入), refund(退款冲正)
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub entry_type: String,
+
+    /// 卖家用户 ID
+    pub seller_id: i64,
+
+    /// 卖家钱包地址
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub seller_wallet: String,
+
+    /// 简历 ID
+    pub resume_id: i64,
+
+    /// 资产符号
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub asset: String,
+
+    /// 买家支付金额
+    pub gross_amount: i64,
+
+    /// 平台佣金
+    pub fee_amount: i64,
+
+    /// 卖家应得
+    pub net_amount: i64,
+
+    /// 所属结算批次，None 表示未结算
+    pub payout_id: Option<i64>,
+
+    /// 创建时间
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::seller_payout::Entity",
+        from = "Column::PayoutId",
+        to = "super::seller_payout::Column::Id"
+    )]
+    Payout,
+}
+
+impl Related<super::seller_payout::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Payout.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,55 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 卖家结算批次表
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "seller_payouts")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// 卖家用户 ID
+    pub seller_id: i64,
+
+    /// 打款钱包地址
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub seller_wallet: String,
+
+    /// 资产符号
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub asset: String,
+
+    /// 应付金额（net 合计）
+    pub amount: i64,
+
+    /// 包含的账目数
+    pub entry_count: i32,
+
+    /// 状态: pending / paid
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub status: String,
+
+    /// 打款交易摘要
+    #[sea_orm(column_type = "String(StringLen::N(150))", nullable)]
+    pub transaction_digest: Option<String>,
+
+    /// 打款时间
+    pub paid_at: Option<DateTime>,
+
+    /// 创建时间
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::seller_ledger_entry::Entity")]
+    LedgerEntries,
+}
+
+impl Related<super::seller_ledger_entry::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LedgerEntries.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,64 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 解锁争议表，每条解锁记录最多一次争议
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "unlock_disputes")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// 争议的解锁记录
+    #[sea_orm(unique)]
+    pub unlock_record_id: i64,
+
+    /// 发起争议的用户 ID
+    pub opened_by: i64,
+
+    /// 争议原因
+    #[sea_orm(column_type = "Text")]
+    pub reason: String,
+
+    /// 证据（JSON 字符串数组）
+    #[sea_orm(column_type = "Text")]
+    pub evidence: String,
+
+    /// 状态: open(处理中), refunded(已退款), rejected(已驳回)
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub status: String,
+
+    /// 裁决说明
+    #[sea_orm(column_type = "Text", nullable)]
+    pub resolution_note: Option<String>,
+
+    /// 裁决的管理员用户 ID
+    pub resolved_by: Option<i64>,
+
+    /// 退款交易摘要
+    #[sea_orm(column_type = "String(StringLen::N(150))", nullable)]
+    pub refund_transaction: Option<String>,
+
+    /// 创建时间
+    pub created_at: DateTime,
+
+    /// 裁决时间
+    pub resolved_at: Option<DateTime>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::unlock_record::Entity",
+        from = "Column::UnlockRecordId",
+        to = "super::unlock_record::Column::Id"
+    )]
+    UnlockRecord,
+}
+
+impl Related<super::unlock_record::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UnlockRecord.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -18,20 +18,38 @@ pub struct Model {
     #[sea_orm(column_type = "String(StringLen::N(100))")]
     pub buyer_wallet: String,
     
+    /// 购买时所属组织 ID（组织成员共享解锁权益）
+    pub organization_id: Option<i64>,
+    
     /// 卖家钱包地址 - 支持 Sui/Solana
     #[sea_orm(column_type = "String(StringLen::N(100))")]
     pub seller_wallet: String,
     
-    /// 支付金额 (lamports 或其他单位)
+    /// 支付金额（`asset` 的最小单位）
     pub amount: i64,
+
+    /// 支付资产符号，如 USDC
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub asset: String,
+
+    /// 购买的档位，`full` 为完整简历
+    #[sea_orm(column_type = "String(StringLen::N(32))")]
+    pub tier: String,
     
     /// 交易签名 - 支持 Sui/Solana
     #[sea_orm(unique, column_type = "String(StringLen::N(150))")]
     pub transaction_signature: String,
     
-    /// 支付状态: pending(待确认), confirmed(已确认), failed(失败)
+    /// 支付状态: pending(待确认), confirmed(已确认), failed(失败), disputed / refunded / rejected(争议)
     #[sea_orm(column_type = "String(StringLen::N(20))")]
     pub status: String,
+
+    /// 链上校验失败的原因
+    #[sea_orm(column_type = "String(StringLen::N(500))", nullable)]
+    pub failure_reason: Option<String>,
+
+    /// 链上校验完成时间
+    pub verified_at: Option<DateTime>,
     
     /// 区块时间戳
     pub block_time: Option<i64>,
@@ -16,7 +16,7 @@ pub struct Model {
     #[sea_orm(column_type = "String(StringLen::N(100))")]
     pub nickname: Option<String>,
     
-    /// 用户类型: job_seeker(求职者) 或 recruiter(招聘者)
+    /// 用户类型: job_seeker(求职者)、recruiter(招聘者) 或 both(两者兼有)
     #[sea_orm(column_type = "String(StringLen::N(20))")]
     pub user_type: String,
     
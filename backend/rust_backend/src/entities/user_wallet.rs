@@ -0,0 +1,48 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 用户钱包表（一个用户可关联多条链上的多个钱包）
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "user_wallets")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    /// 用户 ID
+    pub user_id: i64,
+
+    /// 链: sui / solana
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub chain: String,
+
+    /// 规范化后的钱包地址
+    #[sea_orm(column_type = "String(StringLen::N(100))")]
+    pub address: String,
+
+    /// 是否为主钱包（users.wallet_address）
+    pub is_primary: bool,
+
+    /// 签名验证时间
+    pub verified_at: DateTime,
+
+    /// 创建时间
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::UserId",
+        to = "super::user::Column::Id"
+    )]
+    User,
+}
+
+impl Related<super::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,310 @@
+use std::collections::BTreeMap;
+
+use actix_web::{
+    body::{self, BoxBody, MessageBody},
+    dev::{Payload, ServiceRequest, ServiceResponse},
+    http::{header, StatusCode},
+    middleware::Next,
+    web, Error, HttpMessage, HttpResponse, ResponseError,
+};
+use sea_orm::DatabaseConnection;
+use sha2::{Digest, Sha256};
+
+use crate::auth::AuthenticatedWallet;
+use crate::dao::idempotency_key_dao::IDEMPOTENCY_STATUS_PROCESSING;
+use crate::dao::IdempotencyKeyDao;
+use crate::entities::idempotency_key;
+use crate::idempotency::{IDEMPOTENCY_KEY_HEADER, IDEMPOTENT_REPLAYED_HEADER};
+use crate::models::ApiResponse;
+use crate::x402::middleware::SettlementRecorded;
+use crate::x402::types::PAYMENT_RESPONSE_HEADER;
+
+/// 幂等键最大长度
+const MAX_KEY_LEN: usize = 100;
+/// 幂等键保留时间，过期后同一个键可以重新使用
+const KEY_RETENTION_SECS: i64 = 24 * 60 * 60;
+/// 随响应一起保存并重放的响应头
+const REPLAYED_HEADERS: &[&str] = &["content-type", PAYMENT_RESPONSE_HEADER];
+
+/// 幂等键错误
+#[derive(Debug, thiserror::Error)]
+pub enum IdempotencyError {
+    /// 幂等键格式错误 (400)
+    #[error("{0}")]
+    InvalidKey(String),
+
+    /// 同一个键用于不同的请求，或者第一次请求仍在处理中 (409)
+    #[error("{0}")]
+    Conflict(String),
+
+    /// 读写幂等键失败 (500)
+    #[error("{0}")]
+    Internal(String),
+}
+
+impl ResponseError for IdempotencyError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            IdempotencyError::InvalidKey(_) => StatusCode::BAD_REQUEST,
+            IdempotencyError::Conflict(_) => StatusCode::CONFLICT,
+            IdempotencyError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(ApiResponse::<()>::error(self.to_string()))
+    }
+}
+
+/// 按 `Idempotency-Key` 请求头保存并重放响应
+///
+/// 每个用户的幂等键第一次使用时执行请求并保存响应（状态码、响应体和 [`REPLAYED_HEADERS`]），
+/// 之后方法、路径和请求体都相同的重试直接重放该响应；同一个键用于不同请求时返回 409，
+/// 第一次请求尚未完成时也返回 409。402、429 和 5xx 响应不保存，客户端可以用同一个键重试
+/// （如带上 `X-PAYMENT` 重新提交）；但已写入结算意向的响应（带 [`SettlementRecorded`] 扩展）
+/// 即使是 5xx 也保存，避免重试再次报价导致重复付款。没有请求头或未认证的请求直接放行。
+pub async fn replay(
+    mut req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, Error> {
+    let Some(key) = req.headers().get(IDEMPOTENCY_KEY_HEADER) else {
+        return next.call(req).await.map(ServiceResponse::map_into_boxed_body);
+    };
+    let key = parse_key(key.to_str().ok())?;
+
+    let db = req.app_data::<web::Data<DatabaseConnection>>().cloned();
+    let user_id = req.extensions().get::<AuthenticatedWallet>().map(|w| w.user_id());
+    let (Some(db), Some(user_id)) = (db, user_id) else {
+        return next.call(req).await.map(ServiceResponse::map_into_boxed_body);
+    };
+
+    // 读出请求体计算哈希，再放回去交给 handler
+    let body = req.extract::<web::Bytes>().await?;
+    req.set_payload(Payload::from(body.clone()));
+
+    let method = req.method().to_string();
+    let path = req
+        .uri()
+        .path_and_query()
+        .map(|pq| pq.as_str().to_string())
+        .unwrap_or_else(|| req.path().to_string());
+    let hash = request_hash(&method, &path, &body);
+
+    let record = match claim(&db, user_id, &key, &method, &path, &hash).await? {
+        Claim::New(record) => record,
+        Claim::Existing(existing) => {
+            if existing.request_hash != hash {
+                return Err(IdempotencyError::Conflict(
+                    "Idempotency-Key has already been used with a different request".to_string(),
+                )
+                .into());
+            }
+            if existing.status == IDEMPOTENCY_STATUS_PROCESSING {
+                return Err(IdempotencyError::Conflict(
+                    "A request with this Idempotency-Key is still being processed".to_string(),
+                )
+                .into());
+            }
+            log::info!("Replaying {} {} for user {} (Idempotency-Key {})", method, path, user_id, key);
+            return Ok(req.into_response(stored_response(&existing)));
+        }
+    };
+
+    let http_req = req.request().clone();
+    let res = match next.call(req).await {
+        Ok(res) => res.map_into_boxed_body(),
+        Err(e) => ServiceResponse::from_err(e, http_req),
+    };
+
+    let settlement = res.response().extensions().get::<SettlementRecorded>().copied();
+    match settlement {
+        None if !is_replayable(res.status()) => {
+            release(&db, &record).await;
+            return Ok(res);
+        }
+        Some(SettlementRecorded(settlement_id)) if !is_replayable(res.status()) => {
+            log::warn!(
+                "Storing {} response for Idempotency-Key {} of user {}: settlement {} was recorded",
+                res.status(), key, user_id, settlement_id
+            );
+        }
+        _ => {}
+    }
+
+    let (http_req, res) = res.into_parts();
+    let (res, body) = res.into_parts();
+    let body = body::to_bytes(body).await.map_err(|e| {
+        IdempotencyError::Internal(format!("Failed to read response body: {}", e))
+    });
+    let body = match body {
+        Ok(body) => body,
+        Err(e) => {
+            if settlement.is_none() {
+                release(&db, &record).await;
+            }
+            return Err(e.into());
+        }
+    };
+
+    match String::from_utf8(body.to_vec()) {
+        Ok(text) => {
+            let headers: BTreeMap<&str, &str> = REPLAYED_HEADERS
+                .iter()
+                .filter_map(|name| res.headers().get(*name).and_then(|v| v.to_str().ok()).map(|v| (*name, v)))
+                .collect();
+            let headers = serde_json::to_string(&headers).unwrap_or_else(|_| "{}".to_string());
+            if let Err(e) = IdempotencyKeyDao::complete(&db, record.id, res.status().as_u16() as i32, headers, text).await {
+                log::error!("Failed to store response for Idempotency-Key {} of user {}: {}", key, user_id, e);
+                // 已写入结算意向时保持占用，重试返回 409 而不是重新付款
+                if settlement.is_none() {
+                    release(&db, &record).await;
+                }
+            }
+        }
+        Err(_) if settlement.is_none() => release(&db, &record).await,
+        Err(_) => {}
+    }
+
+    Ok(ServiceResponse::new(http_req, res.set_body(body).map_into_boxed_body()))
+}
+
+enum Claim {
+    /// 本次请求占用了幂等键
+    New(idempotency_key::Model),
+    /// 幂等键已被之前的请求占用
+    Existing(idempotency_key::Model),
+}
+
+/// 占用幂等键；已存在但已过期的键会被删除后重新占用
+async fn claim(
+    db: &DatabaseConnection,
+    user_id: i64,
+    key: &str,
+    method: &str,
+    path: &str,
+    hash: &str,
+) -> Result<Claim, IdempotencyError> {
+    let expired_before = (chrono::Utc::now() - chrono::Duration::seconds(KEY_RETENTION_SECS)).naive_utc();
+
+    for _ in 0..2 {
+        let created = IdempotencyKeyDao::create(db, user_id, key.to_string(), method.to_string(), path.to_string(), hash.to_string())
+            .await
+            .map_err(|e| IdempotencyError::Internal(format!("Failed to store Idempotency-Key: {}", e)))?;
+        if let Some(record) = created {
+            // 顺便清理过期的幂等键
+            if let Err(e) = IdempotencyKeyDao::delete_expired(db, expired_before).await {
+                log::warn!("Failed to clean up expired idempotency keys: {}", e);
+            }
+            return Ok(Claim::New(record));
+        }
+
+        let existing = IdempotencyKeyDao::find(db, user_id, key)
+            .await
+            .map_err(|e| IdempotencyError::Internal(format!("Failed to fetch Idempotency-Key: {}", e)))?;
+        match existing {
+            Some(existing) if existing.created_at < expired_before => {
+                IdempotencyKeyDao::delete(db, existing.id)
+                    .await
+                    .map_err(|e| IdempotencyError::Internal(format!("Failed to release Idempotency-Key: {}", e)))?;
+            }
+            Some(existing) => return Ok(Claim::Existing(existing)),
+            // 并发释放，重试占用
+            None => {}
+        }
+    }
+
+    Err(IdempotencyError::Conflict(
+        "A request with this Idempotency-Key is still being processed".to_string(),
+    ))
+}
+
+/// 释放幂等键，失败时只记录日志（键过期后也会自动失效）
+async fn release(db: &DatabaseConnection, record: &idempotency_key::Model) {
+    if let Err(e) = IdempotencyKeyDao::delete(db, record.id).await {
+        log::warn!("Failed to release Idempotency-Key {} of user {}: {}", record.idempotency_key, record.user_id, e);
+    }
+}
+
+/// 由保存的记录重建响应
+fn stored_response(record: &idempotency_key::Model) -> HttpResponse {
+    let status = record
+        .response_status
+        .and_then(|status| u16::try_from(status).ok())
+        .and_then(|status| StatusCode::from_u16(status).ok())
+        .unwrap_or(StatusCode::OK);
+    let headers: BTreeMap<String, String> = record
+        .response_headers
+        .as_deref()
+        .and_then(|headers| serde_json::from_str(headers).ok())
+        .unwrap_or_default();
+
+    let mut response = HttpResponse::build(status);
+    response.insert_header((header::CONTENT_TYPE, "application/json"));
+    for (name, value) in headers {
+        response.insert_header((name, value));
+    }
+    response
+        .insert_header((IDEMPOTENT_REPLAYED_HEADER, "true"))
+        .body(record.response_body.clone().unwrap_or_default())
+}
+
+/// 校验幂等键：1-100 个可见 ASCII 字符
+fn parse_key(value: Option<&str>) -> Result<String, IdempotencyError> {
+    let key = value.map(str::trim).unwrap_or_default();
+    if key.is_empty() || key.len() > MAX_KEY_LEN || !key.chars().all(|c| c.is_ascii_graphic()) {
+        return Err(IdempotencyError::InvalidKey(format!(
+            "Idempotency-Key must be 1-{} visible ASCII characters",
+            MAX_KEY_LEN
+        )));
+    }
+    Ok(key.to_string())
+}
+
+/// 请求指纹：方法、路径（含查询参数）和请求体的 SHA-256
+fn request_hash(method: &str, path: &str, body: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(method.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(path.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(body);
+    hex::encode(hasher.finalize())
+}
+
+/// 是否保存响应：需要支付、被限流和服务端错误都是暂时的，允许用同一个键重试
+fn is_replayable(status: StatusCode) -> bool {
+    status != StatusCode::PAYMENT_REQUIRED && status != StatusCode::TOO_MANY_REQUESTS && !status.is_server_error()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::x402::middleware::PaymentError;
+
+    #[test]
+    fn test_idempotency_key_rules() {
+        assert_eq!(parse_key(Some(" 3f2a-retry ")).unwrap(), "3f2a-retry");
+        assert!(parse_key(Some("")).is_err());
+        assert!(parse_key(Some("has space")).is_err());
+        assert!(parse_key(Some(&"k".repeat(MAX_KEY_LEN + 1))).is_err());
+
+        let hash = request_hash("POST", "/api/unlock-records", br#"{"resume_id":1}"#);
+        assert_eq!(hash, request_hash("POST", "/api/unlock-records", br#"{"resume_id":1}"#));
+        assert_ne!(hash, request_hash("POST", "/api/unlock-records", br#"{"resume_id":2}"#));
+        assert_ne!(hash, request_hash("POST", "/api/unlock-records?tier=full", br#"{"resume_id":1}"#));
+
+        assert!(is_replayable(StatusCode::OK));
+        assert!(is_replayable(StatusCode::BAD_REQUEST));
+        assert!(!is_replayable(StatusCode::PAYMENT_REQUIRED));
+        assert!(!is_replayable(StatusCode::TOO_MANY_REQUESTS));
+        assert!(!is_replayable(StatusCode::BAD_GATEWAY));
+
+        // 写入结算意向之后的 5xx 带标记，中间件保存响应
+        let error = PaymentError::Internal("Payment was settled but could not be recorded".to_string()).after_settlement(7);
+        let response = error.error_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(response.extensions().get::<SettlementRecorded>().map(|s| s.0), Some(7));
+        let response = PaymentError::Internal("Database not configured".to_string()).error_response();
+        assert!(response.extensions().get::<SettlementRecorded>().is_none());
+    }
+}
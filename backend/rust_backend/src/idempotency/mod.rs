@@ -0,0 +1,6 @@
+pub mod middleware;
+
+/// 客户端提供幂等键的请求头
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+/// 重放的响应带上该响应头，值为 `true`
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "Idempotent-Replayed";
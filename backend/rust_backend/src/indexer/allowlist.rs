@@ -0,0 +1,139 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+use serde::Deserialize;
+
+use crate::indexer::EventHandler;
+use crate::services::AllowlistService;
+use crate::sui::ChainEvent;
+
+/// `allowlist::AllowlistCreated`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AllowlistCreated {
+    pub allowlist_id: String,
+    pub name: String,
+    pub creator: String,
+}
+
+/// `allowlist::AccountAdded` / `allowlist::AccountRemoved`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AccountChanged {
+    pub allowlist_id: String,
+    pub account: String,
+    pub operator: String,
+}
+
+/// `allowlist::BlobPublished`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct BlobPublished {
+    pub allowlist_id: String,
+    pub blob_id: String,
+    pub publisher: String,
+}
+
+/// allowlist 模块发出的事件
+#[derive(Debug, Clone, PartialEq)]
+pub enum AllowlistEvent {
+    Created(AllowlistCreated),
+    AccountAdded(AccountChanged),
+    AccountRemoved(AccountChanged),
+    BlobPublished(BlobPublished),
+}
+
+impl AllowlistEvent {
+    /// 按事件类型名解析，未知类型返回 None
+    pub fn parse(event: &ChainEvent) -> Result<Option<Self>, String> {
+        let name = event.event_type.rsplit("::").next().unwrap_or_default();
+        let json = event.parsed_json.clone();
+        let parsed = match name {
+            "AllowlistCreated" => serde_json::from_value(json).map(Self::Created),
+            "AccountAdded" => serde_json::from_value(json).map(Self::AccountAdded),
+            "AccountRemoved" => serde_json::from_value(json).map(Self::AccountRemoved),
+            "BlobPublished" => serde_json::from_value(json).map(Self::BlobPublished),
+            _ => return Ok(None),
+        };
+
+        parsed
+            .map(Some)
+            .map_err(|e| format!("Invalid {} event: {}", name, e))
+    }
+}
+
+/// 将 allowlist 事件同步到 `allowlists` / `allowlist_members` / `allowlist_blobs`
+pub struct AllowlistEventHandler;
+
+#[async_trait]
+impl EventHandler for AllowlistEventHandler {
+    fn module(&self) -> &'static str {
+        "allowlist"
+    }
+
+    async fn handle(&self, db: &DatabaseConnection, event: &ChainEvent) -> Result<(), String> {
+        let digest = event.id.tx_digest.as_str();
+        let parsed = match AllowlistEvent::parse(event) {
+            Ok(Some(parsed)) => parsed,
+            Ok(None) => return Ok(()),
+            Err(e) => {
+                // 无法解析的事件重试也不会成功，跳过以免阻塞后续事件
+                println!("⚠️  Indexer: skipping event {}#{}: {}", digest, event.id.event_seq, e);
+                return Ok(());
+            }
+        };
+
+        match parsed {
+            AllowlistEvent::Created(e) => {
+                AllowlistService::record_created(db, &e.allowlist_id, &e.name, &e.creator, digest).await
+            }
+            AllowlistEvent::AccountAdded(e) => {
+                AllowlistService::add_member(db, &e.allowlist_id, &e.account, &e.operator, digest).await
+            }
+            AllowlistEvent::AccountRemoved(e) => {
+                AllowlistService::remove_member(db, &e.allowlist_id, &e.account, digest).await
+            }
+            AllowlistEvent::BlobPublished(e) => {
+                AllowlistService::publish_blob(db, &e.allowlist_id, &e.blob_id, &e.publisher, digest).await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sui::EventId;
+
+    fn event(event_type: &str, parsed_json: serde_json::Value) -> ChainEvent {
+        ChainEvent {
+            id: EventId { tx_digest: "digest".to_string(), event_seq: "0".to_string() },
+            event_type: event_type.to_string(),
+            sender: "0xa".to_string(),
+            parsed_json,
+            timestamp_ms: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_allowlist_events() {
+        let added = event(
+            "0xabc::allowlist::AccountAdded",
+            serde_json::json!({ "allowlist_id": "0x1", "account": "0x2", "operator": "0x3" }),
+        );
+        assert_eq!(
+            AllowlistEvent::parse(&added),
+            Ok(Some(AllowlistEvent::AccountAdded(AccountChanged {
+                allowlist_id: "0x1".to_string(),
+                account: "0x2".to_string(),
+                operator: "0x3".to_string(),
+            })))
+        );
+
+        let published = event(
+            "0xabc::allowlist::BlobPublished",
+            serde_json::json!({ "allowlist_id": "0x1", "blob_id": "blob", "publisher": "0x3" }),
+        );
+        assert!(matches!(AllowlistEvent::parse(&published), Ok(Some(AllowlistEvent::BlobPublished(_)))));
+
+        // 未知事件忽略，字段缺失报错
+        assert_eq!(AllowlistEvent::parse(&event("0xabc::allowlist::Other", serde_json::json!({}))), Ok(None));
+        assert!(AllowlistEvent::parse(&event("0xabc::allowlist::AccountRemoved", serde_json::json!({}))).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+/// `rust_backend backfill [--from-checkpoint <N>] [--module <name>]` 的参数
+///
+/// 不带 `--from-checkpoint` 时从已保存的游标追到最新事件；带上时清空游标，
+/// 从该检查点的时间开始重放（已处理过的事件会跳过）。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BackfillArgs {
+    pub from_checkpoint: Option<u64>,
+    /// 只回填该模块，如 `allowlist`
+    pub module: Option<String>,
+}
+
+impl BackfillArgs {
+    /// 解析命令行参数（不含程序名），不是 `backfill` 命令时返回 None
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Option<Self>, String> {
+        let mut args = args.into_iter();
+        if args.next().as_deref() != Some("backfill") {
+            return Ok(None);
+        }
+
+        let mut parsed = Self::default();
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            let mut value = || {
+                inline
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("{} requires a value", flag))
+            };
+
+            match flag.as_str() {
+                "--from-checkpoint" => {
+                    let value = value()?;
+                    let sequence = value
+                        .parse()
+                        .map_err(|_| format!("Invalid checkpoint sequence number: {}", value))?;
+                    parsed.from_checkpoint = Some(sequence);
+                }
+                "--module" => parsed.module = Some(value()?),
+                _ => return Err(format!("Unknown backfill option: {}", flag)),
+            }
+        }
+
+        Ok(Some(parsed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Option<BackfillArgs>, String> {
+        BackfillArgs::parse(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn test_parse_backfill_args() {
+        assert_eq!(parse(&[]), Ok(None));
+        assert_eq!(parse(&["serve"]), Ok(None));
+        assert_eq!(parse(&["backfill"]), Ok(Some(BackfillArgs::default())));
+        assert_eq!(
+            parse(&["backfill", "--from-checkpoint", "120", "--module=allowlist"]),
+            Ok(Some(BackfillArgs { from_checkpoint: Some(120), module: Some("allowlist".to_string()) }))
+        );
+
+        assert!(parse(&["backfill", "--from-checkpoint", "-1"]).is_err());
+        assert!(parse(&["backfill", "--module"]).is_err());
+        assert!(parse(&["backfill", "--force"]).is_err());
+    }
+}
@@ -0,0 +1,129 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+
+use crate::dao::IndexerCursorDao;
+use crate::sui::{ChainEvent, EventId};
+
+/// 错误信息最大长度（与 indexer_cursors.last_error 一致）
+const MAX_ERROR_LEN: usize = 500;
+
+/// 索引器的持久化状态：每个事件流的游标，以及按 (交易摘要, 事件序号) 记录的已处理事件
+#[async_trait]
+pub trait CheckpointStore: Send + Sync {
+    /// 事件流最后处理的事件，从未处理过时为 None
+    async fn load_cursor(&self, package_id: &str, stream: &str) -> Result<Option<EventId>, String>;
+
+    /// 游标前移到该事件之后
+    async fn save_cursor(&self, package_id: &str, stream: &str, event: &ChainEvent) -> Result<(), String>;
+
+    /// 清空游标，下次从第一个事件开始
+    async fn reset_cursor(&self, package_id: &str, stream: &str) -> Result<(), String>;
+
+    /// 事件是否已经处理过
+    async fn is_processed(&self, id: &EventId) -> Result<bool, String>;
+
+    /// 记录事件已处理
+    async fn mark_processed(&self, package_id: &str, stream: &str, id: &EventId) -> Result<(), String>;
+
+    /// 记录一轮轮询的结果：成功时更新追上最新事件的时间，失败时保存错误
+    async fn record_poll(&self, package_id: &str, stream: &str, error: Option<&str>) -> Result<(), String>;
+}
+
+/// 保存在 `indexer_cursors` / `indexer_events` 表中的检查点
+pub struct DbCheckpointStore {
+    db: DatabaseConnection,
+}
+
+impl DbCheckpointStore {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl CheckpointStore for DbCheckpointStore {
+    async fn load_cursor(&self, package_id: &str, stream: &str) -> Result<Option<EventId>, String> {
+        let cursor = IndexerCursorDao::find(&self.db, package_id, stream)
+            .await
+            .map_err(|e| format!("Failed to load indexer cursor: {}", e))?;
+
+        Ok(cursor.and_then(|cursor| match (cursor.tx_digest, cursor.event_seq) {
+            (Some(tx_digest), Some(event_seq)) => Some(EventId { tx_digest, event_seq }),
+            _ => None,
+        }))
+    }
+
+    async fn save_cursor(&self, package_id: &str, stream: &str, event: &ChainEvent) -> Result<(), String> {
+        let position = (event.id.tx_digest.clone(), event.id.event_seq.clone(), event.timestamp_ms);
+        IndexerCursorDao::save(&self.db, package_id, stream, Some(position), false, None)
+            .await
+            .map_err(|e| format!("Failed to save indexer cursor: {}", e))
+    }
+
+    async fn reset_cursor(&self, package_id: &str, stream: &str) -> Result<(), String> {
+        IndexerCursorDao::reset(&self.db, package_id, stream)
+            .await
+            .map_err(|e| format!("Failed to reset indexer cursor: {}", e))
+    }
+
+    async fn is_processed(&self, id: &EventId) -> Result<bool, String> {
+        IndexerCursorDao::is_processed(&self.db, &id.tx_digest, &id.event_seq)
+            .await
+            .map_err(|e| format!("Failed to check processed event: {}", e))
+    }
+
+    async fn mark_processed(&self, package_id: &str, stream: &str, id: &EventId) -> Result<(), String> {
+        IndexerCursorDao::mark_processed(&self.db, package_id, stream, &id.tx_digest, &id.event_seq)
+            .await
+            .map_err(|e| format!("Failed to mark event processed: {}", e))
+    }
+
+    async fn record_poll(&self, package_id: &str, stream: &str, error: Option<&str>) -> Result<(), String> {
+        let last_error = error.map(|e| e.chars().take(MAX_ERROR_LEN).collect());
+        IndexerCursorDao::save(&self.db, package_id, stream, None, error.is_none(), last_error)
+            .await
+            .map_err(|e| format!("Failed to record indexer poll: {}", e))
+    }
+}
+
+/// 进程内的检查点，供测试使用
+#[cfg(test)]
+#[derive(Default)]
+pub struct MemoryCheckpointStore {
+    cursors: std::sync::Mutex<std::collections::HashMap<String, EventId>>,
+    processed: std::sync::Mutex<std::collections::HashSet<EventId>>,
+}
+
+#[cfg(test)]
+#[async_trait]
+impl CheckpointStore for MemoryCheckpointStore {
+    async fn load_cursor(&self, package_id: &str, stream: &str) -> Result<Option<EventId>, String> {
+        Ok(self.cursors.lock().unwrap().get(&format!("{}::{}", package_id, stream)).cloned())
+    }
+
+    async fn save_cursor(&self, package_id: &str, stream: &str, event: &ChainEvent) -> Result<(), String> {
+        self.cursors
+            .lock()
+            .unwrap()
+            .insert(format!("{}::{}", package_id, stream), event.id.clone());
+        Ok(())
+    }
+
+    async fn reset_cursor(&self, package_id: &str, stream: &str) -> Result<(), String> {
+        self.cursors.lock().unwrap().remove(&format!("{}::{}", package_id, stream));
+        Ok(())
+    }
+
+    async fn is_processed(&self, id: &EventId) -> Result<bool, String> {
+        Ok(self.processed.lock().unwrap().contains(id))
+    }
+
+    async fn mark_processed(&self, _package_id: &str, _stream: &str, id: &EventId) -> Result<(), String> {
+        self.processed.lock().unwrap().insert(id.clone());
+        Ok(())
+    }
+
+    async fn record_poll(&self, _package_id: &str, _stream: &str, _error: Option<&str>) -> Result<(), String> {
+        Ok(())
+    }
+}
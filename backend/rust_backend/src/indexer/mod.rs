@@ -0,0 +1,276 @@
+pub mod allowlist;
+pub mod backfill;
+pub mod checkpoint;
+pub mod subscription;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+
+use crate::models::IndexerConfig;
+use crate::sui::{ChainEvent, EventId, SuiRpcClient};
+
+pub use allowlist::AllowlistEventHandler;
+pub use backfill::BackfillArgs;
+pub use checkpoint::{CheckpointStore, DbCheckpointStore};
+pub use subscription::SubscriptionEventHandler;
+
+/// 处理某个 Move 模块发出的事件
+#[async_trait]
+pub trait EventHandler: Send + Sync {
+    /// 订阅的模块名，如 `allowlist`，同时作为检查点的事件类型
+    fn module(&self) -> &'static str;
+
+    /// 处理单个事件；返回错误时索引器停在该事件之前，下一轮轮询重试
+    ///
+    /// 处理成功后事件按 (交易摘要, 事件序号) 记为已处理，重放时不会再次调用；
+    /// 处理成功但记录失败时会再次调用，因此处理逻辑本身也应可以重复执行。
+    async fn handle(&self, db: &DatabaseConnection, event: &ChainEvent) -> Result<(), String>;
+}
+
+/// 后台轮询合约包的链上事件，按模块分发给对应的处理器
+pub struct ChainIndexer {
+    db: DatabaseConnection,
+    sui: Arc<dyn SuiRpcClient>,
+    store: Arc<dyn CheckpointStore>,
+    package_id: String,
+    config: IndexerConfig,
+    handlers: Vec<Box<dyn EventHandler>>,
+    /// 已从检查点加载的游标
+    cursors: HashMap<&'static str, Option<EventId>>,
+}
+
+impl ChainIndexer {
+    pub fn new(
+        db: DatabaseConnection,
+        sui: Arc<dyn SuiRpcClient>,
+        store: Arc<dyn CheckpointStore>,
+        package_id: String,
+        config: IndexerConfig,
+        handlers: Vec<Box<dyn EventHandler>>,
+    ) -> Self {
+        Self {
+            db,
+            sui,
+            store,
+            package_id,
+            config,
+            handlers,
+            cursors: HashMap::new(),
+        }
+    }
+
+    /// 在后台任务中持续轮询
+    pub fn spawn(self) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(self.run())
+    }
+
+    async fn run(mut self) {
+        let mut interval = tokio::time::interval(Duration::from_secs(self.config.poll_interval_secs.max(1)));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            for index in 0..self.handlers.len() {
+                let result = self.poll_module(index, None).await;
+                self.record_poll(index, &result).await;
+            }
+        }
+    }
+
+    /// 回填：从指定检查点（的时间）开始重放事件，未指定时从已保存的游标追到最新事件
+    ///
+    /// 已处理过的事件直接跳过，因此可以安全地重复回填。返回新处理的事件数。
+    pub async fn backfill(&mut self, args: &BackfillArgs) -> Result<usize, String> {
+        let since = match args.from_checkpoint {
+            Some(sequence) => Some(
+                self.sui
+                    .get_checkpoint_timestamp(sequence)
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .ok_or_else(|| format!("Checkpoint {} not found", sequence))?,
+            ),
+            None => None,
+        };
+
+        let mut processed = 0;
+        let mut matched = false;
+        for index in 0..self.handlers.len() {
+            let module = self.handlers[index].module();
+            if args.module.as_deref().is_some_and(|m| m != module) {
+                continue;
+            }
+            matched = true;
+
+            if since.is_some() {
+                self.store.reset_cursor(&self.package_id, module).await?;
+                self.cursors.remove(module);
+            }
+            let result = self.poll_module(index, since).await;
+            self.record_poll(index, &result).await;
+            let count = result?;
+            println!("🔎 Backfilled {} new {}::{} events", count, self.package_id, module);
+            processed += count;
+        }
+
+        if !matched {
+            return Err(format!("Unknown module '{}'", args.module.as_deref().unwrap_or_default()));
+        }
+        Ok(processed)
+    }
+
+    /// 拉取并处理某个模块的所有新事件，返回新处理的事件数
+    ///
+    /// `since_ms` 之前的事件只推进游标、不处理（回填时使用）。
+    async fn poll_module(&mut self, index: usize, since_ms: Option<i64>) -> Result<usize, String> {
+        let handler = &self.handlers[index];
+        let module = handler.module();
+        let mut cursor = match self.cursors.get(module) {
+            Some(cursor) => cursor.clone(),
+            None => {
+                let cursor = self.store.load_cursor(&self.package_id, module).await?;
+                self.cursors.insert(module, cursor.clone());
+                cursor
+            }
+        };
+        let mut processed = 0;
+
+        loop {
+            let page = self
+                .sui
+                .query_module_events(&self.package_id, module, cursor.as_ref(), self.config.page_size)
+                .await
+                .map_err(|e| e.to_string())?;
+
+            for event in &page.events {
+                let before_start = since_ms.is_some_and(|since| event.timestamp_ms.is_some_and(|ts| ts < since));
+                if !before_start && !self.store.is_processed(&event.id).await? {
+                    handler.handle(&self.db, event).await?;
+                    self.store.mark_processed(&self.package_id, module, &event.id).await?;
+                    processed += 1;
+                }
+
+                self.store.save_cursor(&self.package_id, module, event).await?;
+                cursor = Some(event.id.clone());
+                self.cursors.insert(module, cursor.clone());
+            }
+
+            if !page.has_next_page || page.events.is_empty() {
+                return Ok(processed);
+            }
+        }
+    }
+
+    /// 保存轮询结果（用于计算索引延迟），失败时打印日志
+    async fn record_poll(&self, index: usize, result: &Result<usize, String>) {
+        let module = self.handlers[index].module();
+        if let Err(e) = result {
+            println!("⚠️  Indexer: failed to process {}::{} events: {}", self.package_id, module, e);
+        }
+
+        let error = result.as_ref().err().map(String::as_str);
+        if let Err(e) = self.store.record_poll(&self.package_id, module, error).await {
+            println!("⚠️  Indexer: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::checkpoint::MemoryCheckpointStore;
+    use crate::sui::StubSuiClient;
+    use std::sync::Mutex;
+
+    /// 记录收到的事件，摘要为 `fail` 的事件处理失败
+    #[derive(Clone, Default)]
+    struct RecordingHandler {
+        seen: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl EventHandler for RecordingHandler {
+        fn module(&self) -> &'static str {
+            "allowlist"
+        }
+
+        async fn handle(&self, _db: &DatabaseConnection, event: &ChainEvent) -> Result<(), String> {
+            if event.id.tx_digest == "fail" {
+                return Err("boom".to_string());
+            }
+            self.seen.lock().unwrap().push(event.id.tx_digest.clone());
+            Ok(())
+        }
+    }
+
+    fn event(digest: &str) -> ChainEvent {
+        ChainEvent {
+            id: EventId { tx_digest: digest.to_string(), event_seq: "0".to_string() },
+            event_type: "0x1::allowlist::AccountAdded".to_string(),
+            sender: "0xa".to_string(),
+            parsed_json: serde_json::Value::Null,
+            timestamp_ms: None,
+        }
+    }
+
+    fn indexer(stub: &Arc<StubSuiClient>, store: &Arc<MemoryCheckpointStore>, handler: &RecordingHandler) -> ChainIndexer {
+        ChainIndexer::new(
+            DatabaseConnection::Disconnected,
+            stub.clone(),
+            store.clone(),
+            "0x1".to_string(),
+            IndexerConfig { page_size: 2, ..IndexerConfig::default() },
+            vec![Box::new(handler.clone())],
+        )
+    }
+
+    #[actix_web::test]
+    async fn poll_module_pages_through_events_and_stops_on_failure() {
+        let stub = Arc::new(StubSuiClient::new());
+        for digest in ["a", "b", "c"] {
+            stub.push_event("0x1", "allowlist", event(digest));
+        }
+        let store = Arc::new(MemoryCheckpointStore::default());
+        let handler = RecordingHandler::default();
+        let mut indexer = indexer(&stub, &store, &handler);
+
+        assert_eq!(indexer.poll_module(0, None).await, Ok(3));
+        // 没有新事件时不会重复处理
+        assert_eq!(indexer.poll_module(0, None).await, Ok(0));
+
+        stub.push_event("0x1", "allowlist", event("fail"));
+        stub.push_event("0x1", "allowlist", event("d"));
+        assert!(indexer.poll_module(0, None).await.is_err());
+        assert!(indexer.poll_module(0, None).await.is_err());
+        assert_eq!(*handler.seen.lock().unwrap(), ["a", "b", "c"]);
+    }
+
+    #[actix_web::test]
+    async fn restart_resumes_from_checkpoint_and_backfill_skips_processed_events() {
+        let stub = Arc::new(StubSuiClient::new());
+        for (digest, timestamp_ms) in [("a", 1_000), ("b", 2_000)] {
+            stub.push_event("0x1", "allowlist", ChainEvent { timestamp_ms: Some(timestamp_ms), ..event(digest) });
+        }
+        stub.insert_checkpoint(7, 2_000);
+        let store = Arc::new(MemoryCheckpointStore::default());
+        let handler = RecordingHandler::default();
+
+        assert_eq!(indexer(&stub, &store, &handler).poll_module(0, None).await, Ok(2));
+
+        // 重启后从保存的游标继续
+        stub.push_event("0x1", "allowlist", ChainEvent { timestamp_ms: Some(3_000), ..event("c") });
+        let mut restarted = indexer(&stub, &store, &handler);
+        assert_eq!(restarted.poll_module(0, None).await, Ok(1));
+
+        // 从检查点回填：重放 b、c，但都已处理过
+        let args = BackfillArgs { from_checkpoint: Some(7), module: None };
+        assert_eq!(restarted.backfill(&args).await, Ok(0));
+        assert_eq!(*handler.seen.lock().unwrap(), ["a", "b", "c"]);
+
+        let unknown = BackfillArgs { from_checkpoint: None, module: Some("nope".to_string()) };
+        assert!(restarted.backfill(&unknown).await.is_err());
+    }
+}
@@ -0,0 +1,168 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Deserializer};
+
+use crate::indexer::EventHandler;
+use crate::services::SubscriptionService;
+use crate::sui::ChainEvent;
+
+/// `subscription::ServiceCreated`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ServiceCreated {
+    pub service_id: String,
+    pub name: String,
+    #[serde(deserialize_with = "u64_as_i64")]
+    pub fee: i64,
+    /// 订阅有效期（毫秒）
+    #[serde(deserialize_with = "u64_as_i64")]
+    pub ttl: i64,
+    pub owner: String,
+}
+
+/// `subscription::SubscriptionCreated`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SubscriptionCreated {
+    pub subscription_id: String,
+    pub service_id: String,
+    pub subscriber: String,
+    #[serde(deserialize_with = "u64_as_i64")]
+    pub fee_paid: i64,
+    /// 订阅时间（毫秒）
+    #[serde(deserialize_with = "u64_as_i64")]
+    pub created_at: i64,
+}
+
+/// subscription 模块发出的事件（`ServiceBlobPublished` 不需要同步）
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubscriptionEvent {
+    ServiceCreated(ServiceCreated),
+    SubscriptionCreated(SubscriptionCreated),
+}
+
+impl SubscriptionEvent {
+    /// 按事件类型名解析，未知类型返回 None
+    pub fn parse(event: &ChainEvent) -> Result<Option<Self>, String> {
+        let name = event.event_type.rsplit("::").next().unwrap_or_default();
+        let json = event.parsed_json.clone();
+        let parsed = match name {
+            "ServiceCreated" => serde_json::from_value(json).map(Self::ServiceCreated),
+            "SubscriptionCreated" => serde_json::from_value(json).map(Self::SubscriptionCreated),
+            _ => return Ok(None),
+        };
+
+        parsed
+            .map(Some)
+            .map_err(|e| format!("Invalid {} event: {}", name, e))
+    }
+}
+
+/// Move 的 u64 在事件 JSON 中以字符串表示；超出 i64 范围视为无效
+fn u64_as_i64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Text(String),
+        Number(u64),
+    }
+
+    let value = match Raw::deserialize(deserializer)? {
+        Raw::Text(text) => text.parse::<u64>().map_err(serde::de::Error::custom)?,
+        Raw::Number(number) => number,
+    };
+    i64::try_from(value).map_err(serde::de::Error::custom)
+}
+
+/// 同步订阅服务，并为订阅写入解锁记录
+pub struct SubscriptionEventHandler;
+
+#[async_trait]
+impl EventHandler for SubscriptionEventHandler {
+    fn module(&self) -> &'static str {
+        "subscription"
+    }
+
+    async fn handle(&self, db: &DatabaseConnection, event: &ChainEvent) -> Result<(), String> {
+        let digest = event.id.tx_digest.as_str();
+        let parsed = match SubscriptionEvent::parse(event) {
+            Ok(Some(parsed)) => parsed,
+            Ok(None) => return Ok(()),
+            Err(e) => {
+                // 无法解析的事件重试也不会成功，跳过以免阻塞后续事件
+                println!("⚠️  Indexer: skipping event {}#{}: {}", digest, event.id.event_seq, e);
+                return Ok(());
+            }
+        };
+
+        match parsed {
+            SubscriptionEvent::ServiceCreated(e) => {
+                SubscriptionService::record_service(db, &e.service_id, &e.name, e.fee, e.ttl, &e.owner, digest).await
+            }
+            SubscriptionEvent::SubscriptionCreated(e) => {
+                let created_at = Some(e.created_at).filter(|ms| *ms > 0).or(event.timestamp_ms);
+                SubscriptionService::record_subscription(db, &e.service_id, &e.subscriber, e.fee_paid, created_at, digest)
+                    .await
+                    .map(|_| ())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sui::EventId;
+
+    fn event(event_type: &str, parsed_json: serde_json::Value) -> ChainEvent {
+        ChainEvent {
+            id: EventId { tx_digest: "digest".to_string(), event_seq: "0".to_string() },
+            event_type: event_type.to_string(),
+            sender: "0xa".to_string(),
+            parsed_json,
+            timestamp_ms: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_subscription_events() {
+        let created = event(
+            "0xabc::subscription::SubscriptionCreated",
+            serde_json::json!({
+                "subscription_id": "0x1",
+                "service_id": "0x2",
+                "subscriber": "0x3",
+                "fee_paid": "1000000000",
+                "created_at": "1700000000000",
+            }),
+        );
+        assert_eq!(
+            SubscriptionEvent::parse(&created),
+            Ok(Some(SubscriptionEvent::SubscriptionCreated(SubscriptionCreated {
+                subscription_id: "0x1".to_string(),
+                service_id: "0x2".to_string(),
+                subscriber: "0x3".to_string(),
+                fee_paid: 1_000_000_000,
+                created_at: 1_700_000_000_000,
+            })))
+        );
+
+        let service = event(
+            "0xabc::subscription::ServiceCreated",
+            serde_json::json!({ "service_id": "0x2", "name": "resume", "fee": 5, "ttl": "60000", "owner": "0x4" }),
+        );
+        assert!(matches!(
+            SubscriptionEvent::parse(&service),
+            Ok(Some(SubscriptionEvent::ServiceCreated(ServiceCreated { fee: 5, ttl: 60_000, .. })))
+        ));
+
+        // 超出 i64 的金额报错，其余事件忽略
+        let overflow = event(
+            "0xabc::subscription::ServiceCreated",
+            serde_json::json!({ "service_id": "0x2", "name": "x", "fee": "18446744073709551615", "ttl": "0", "owner": "0x4" }),
+        );
+        assert!(SubscriptionEvent::parse(&overflow).is_err());
+        assert_eq!(
+            SubscriptionEvent::parse(&event("0xabc::subscription::ServiceBlobPublished", serde_json::json!({}))),
+            Ok(None)
+        );
+    }
+}
@@ -1,15 +1,32 @@
+mod auth;
 mod controllers;
 mod dao;
 mod entities;
+mod idempotency;
+mod indexer;
 mod models;
+mod money;
+mod rate_limit;
 mod routes;
 mod services;
+mod sui;
 mod utils;
+mod x402;
 
 use actix_web::{web, App, HttpServer};
 use actix_cors::Cors;
 use std::env;
 use utils::database::{DatabaseConfig, init_db};
+use models::{AuthConfig, CommissionConfig, FacilitatorMode, IndexerConfig, RateLimitConfig, SuiConfig, SuiRpcMode, X402Config};
+use rate_limit::RateLimiter;
+use std::sync::Arc;
+use std::time::Duration;
+use sui::{HttpSuiClient, StubSuiClient, SuiRpcClient};
+use utils::receipt_signer::ReceiptSigner;
+use idempotency::IDEMPOTENT_REPLAYED_HEADER;
+use indexer::{AllowlistEventHandler, BackfillArgs, ChainIndexer, DbCheckpointStore, EventHandler, SubscriptionEventHandler};
+use x402::types::PAYMENT_RESPONSE_HEADER;
+use x402::{Facilitator, HttpFacilitator, MockFacilitator};
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
@@ -37,6 +54,130 @@ async fn main() -> std::io::Result<()> {
     println!("✅ Database connection established");
     println!();
 
+    // 登录认证配置
+    let auth_config = web::Data::new(AuthConfig::from_env());
+
+    // 限流器（所有 worker 共享同一个实例）
+    let rate_limit_config = RateLimitConfig::from_env();
+    println!(
+        "🚦 Rate limiting: {} ({:?} backend)",
+        if rate_limit_config.enabled { "enabled" } else { "disabled" },
+        rate_limit_config.backend
+    );
+    let rate_limiter = web::Data::new(RateLimiter::new(rate_limit_config, db_data.get_ref().clone()));
+
+    // x402 支付配置
+    let x402_config = X402Config::from_env();
+    println!(
+        "💳 x402 payments: {} {} on {} via {}",
+        x402_config.asset_name, x402_config.asset, x402_config.network, x402_config.facilitator_url
+    );
+    if x402_config.pay_to.is_empty() {
+        println!("⚠️  ADDRESS is not set, paid routes will fail until a receiver address is configured");
+    }
+    let facilitator: Arc<dyn Facilitator> = match x402_config.facilitator {
+        FacilitatorMode::Http => Arc::new(
+            HttpFacilitator::new(
+                &x402_config.facilitator_url,
+                Duration::from_secs(x402_config.facilitator_timeout_secs),
+                x402_config.facilitator_max_retries,
+            )
+            .expect("Failed to initialize facilitator client"),
+        ),
+        FacilitatorMode::Mock => {
+            println!("⚠️  Using the in-process mock facilitator, payments are NOT settled on chain");
+            Arc::new(MockFacilitator::new())
+        }
+    };
+    let facilitator = web::Data::from(facilitator);
+    let x402_config = web::Data::new(x402_config);
+
+    // Sui RPC（校验客户端提交的解锁交易）
+    let sui_config = SuiConfig::from_env();
+    let sui: Arc<dyn SuiRpcClient> = match sui_config.rpc {
+        SuiRpcMode::Http => {
+            println!("⛓️  Sui RPC: {}", sui_config.rpc_url);
+            Arc::new(
+                HttpSuiClient::new(&sui_config.rpc_url, Duration::from_secs(sui_config.rpc_timeout_secs))
+                    .expect("Failed to initialize Sui RPC client"),
+            )
+        }
+        SuiRpcMode::Stub => {
+            println!("⚠️  Using the in-process Sui RPC stub, unlock records will stay pending");
+            Arc::new(StubSuiClient::new())
+        }
+    };
+
+    // 链上事件索引（同步 allowlist 成员和订阅），游标保存在 indexer_cursors
+    let indexer_config = IndexerConfig::from_env();
+    let indexer = |package_id: &String, config: IndexerConfig| {
+        let handlers: Vec<Box<dyn EventHandler>> = vec![Box::new(AllowlistEventHandler), Box::new(SubscriptionEventHandler)];
+        ChainIndexer::new(
+            db_data.get_ref().clone(),
+            sui.clone(),
+            Arc::new(DbCheckpointStore::new(db_data.get_ref().clone())),
+            package_id.clone(),
+            config,
+            handlers,
+        )
+    };
+
+    // `rust_backend backfill ...`：回填后退出，不启动服务器
+    let backfill_args = BackfillArgs::parse(env::args().skip(1)).map_err(std::io::Error::other)?;
+    if let Some(args) = backfill_args {
+        let package_id = sui_config
+            .package_id
+            .as_ref()
+            .ok_or_else(|| std::io::Error::other("SUI_PACKAGE_ID is required for backfill"))?;
+        println!("🔎 Backfilling {} events {:?}", package_id, args);
+        let processed = indexer(package_id, indexer_config)
+            .backfill(&args)
+            .await
+            .map_err(std::io::Error::other)?;
+        println!("✅ Backfill finished, {} new events processed", processed);
+        return Ok(());
+    }
+
+    match (&sui_config.package_id, indexer_config.enabled) {
+        (Some(package_id), true) => {
+            println!(
+                "🔎 Chain indexer: polling {} every {}s",
+                package_id, indexer_config.poll_interval_secs
+            );
+            indexer(package_id, indexer_config).spawn();
+        }
+        (None, true) => println!("⚠️  INDEXER_ENABLED is set but SUI_PACKAGE_ID is not, chain indexer disabled"),
+        (_, false) => println!("🔎 Chain indexer: disabled"),
+    }
+
+    let sui = web::Data::from(sui);
+    let sui_config = web::Data::new(sui_config);
+
+    // 付费收据签名密钥
+    // 只有 mock 模式允许临时密钥，否则重启后旧收据无法离线验证
+    let receipt_signer = ReceiptSigner::from_env(x402_config.facilitator == FacilitatorMode::Mock)
+        .expect("Invalid receipt signing key configuration");
+    println!("🧾 Receipt signing key: {} ({})", receipt_signer.key_id(), receipt_signer.public_key_hex());
+    let receipt_signer = web::Data::new(receipt_signer);
+
+    // 平台佣金
+    let commission_config = CommissionConfig::from_env();
+    println!(
+        "💰 Commission: {} bps, minimum {}",
+        commission_config.rate_bps, commission_config.minimum
+    );
+    let commission_config = web::Data::new(commission_config);
+
+    // 对账：已结算但没有写入解锁记录或额度的 x402 支付
+    services::PaymentSettlementService::spawn_reconciler(
+        db_data.get_ref().clone(),
+        receipt_signer.clone().into_inner(),
+        commission_config.clone().into_inner(),
+    );
+
+    // 校验路由访问策略表
+    auth::policy::validate().expect("Invalid route access policy table");
+
     // 读取服务器配置
     let host = env::var("HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
     let port = env::var("PORT").unwrap_or_else(|_| "4021".to_string());
@@ -46,31 +187,101 @@ async fn main() -> std::io::Result<()> {
     println!();
     println!("Available endpoints:");
     println!();
+    println!("🔐 Auth Endpoints:");
+    println!("  POST /api/auth/challenge             - Request login nonce");
+    println!("  POST /api/auth/login                 - Verify wallet signature, get token");
+    println!("  POST /api/auth/logout                - Revoke current session");
+    println!("  GET  /api/auth/sessions              - List active sessions/devices");
+    println!("  DEL  /api/auth/sessions/{{id}}         - Revoke one session");
+    println!("  POST /api/auth/sessions/revoke-others - Revoke all other sessions");
+    println!();
     println!("👤 User Endpoints:");
-    println!("  POST /api/users/register             - Create or get user");
+    println!("  POST /api/users/register             - Register and pick role (auth)");
+    println!("  PUT  /api/users/role                 - Switch role (auth)");
     println!("  GET  /api/users/wallet/{{wallet}}      - Get user by wallet");
     println!("  GET  /api/users/id/{{id}}               - Get user by ID");
     println!("  POST /api/users/{{id}}/reputation      - Update reputation");
+    println!("  POST /api/users/wallets/challenge    - Request link-wallet nonce (auth)");
+    println!("  POST /api/users/wallets              - Link Sui/Solana wallet by signature (auth)");
+    println!("  GET  /api/users/wallets              - List linked wallets (auth)");
+    println!("  DEL  /api/users/wallets/{{id}}         - Unlink wallet (auth)");
     println!();
     println!("📄 Resume Endpoints:");
     println!("  POST /api/resumes                    - Create resume");
     println!("  GET  /api/resumes/summaries          - Get all resume summaries");
     println!("  GET  /api/resumes/my/{{owner}}         - Get my resumes");
     println!("  PUT  /api/resumes/{{resume_id}}        - Update resume");
-    println!("  DEL  /api/resumes/{{resume_id}}/{{owner}} - Delete resume");
+    println!("  GET  /api/resumes/detail/{{resume_id}} - Get resume detail (owner, auth)");
+    println!("  PUT  /api/resumes/price              - Set resume price (owner, auth)");
+    println!("  PUT  /api/resumes/name               - Update resume name (owner, auth)");
+    println!("  DEL  /api/resumes/{{resume_id}}        - Delete resume (owner, auth)");
+    println!("  GET  /api/resumes/{{resume_id}}/tiers  - List price tiers");
+    println!("  PUT  /api/resumes/{{resume_id}}/tiers  - Set price tiers (owner, auth)");
+    println!("  GET  /api/resumes/{{resume_id}}/allowlist-members  - List allowlist members (owner, auth)");
+    println!("  POST /api/resumes/{{resume_id}}/allowlist-members  - Record pending allowlist addition (owner, auth)");
+    println!("  DELETE /api/resumes/{{resume_id}}/allowlist-members/{{address}}  - Mark allowlist member removed (owner, auth)");
+    println!("  POST /api/resumes/{{resume_id}}/unlock - Pay via x402 and unlock a tier (recruiter, auth)");
+    println!("  POST /api/resumes/{{resume_id}}/unlock/credits - Unlock a tier with prepaid credits (recruiter, auth)");
     println!();
     println!("🔓 Unlock Record Endpoints:");
-    println!("  POST /api/unlock-records                     - Create unlock record");
+    println!("  POST /api/unlock-records                     - Create unlock record (verified on chain)");
+    println!("  POST /api/unlock-records/{{id}}/verify         - Retry on-chain verification (buyer/seller)");
     println!("  GET  /api/unlock-records/check/{{id}}/{{buyer}}  - Check unlock status");
-    println!("  GET  /api/unlock-records/buyer/{{wallet}}      - Get buyer's unlocked resumes");
-    println!("  GET  /api/unlock-records/resume/{{id}}         - Get resume's unlock records");
+    println!("  GET  /api/unlock-records/buyer/{{wallet}}      - Get my unlocked resumes (auth)");
+    println!("  GET  /api/unlock-records/resume/{{id}}         - Get resume's unlock records (owner)");
+    println!("  POST /api/unlock-records/{{id}}/dispute        - Open a dispute (buyer)");
+    println!("  GET  /api/unlock-records/{{id}}/dispute        - Get dispute (buyer/seller)");
+    println!("  GET  /api/unlock-records/disputes            - List disputes (admin)");
+    println!("  POST /api/unlock-records/disputes/{{id}}/resolve - Refund or reject a dispute (admin)");
+    println!();
+    println!("🏢 Organization Endpoints:");
+    println!("  POST /api/organizations                      - Create organization (recruiter)");
+    println!("  GET  /api/organizations/my                   - List my organizations");
+    println!("  GET  /api/organizations/{{id}}/members         - List members");
+    println!("  POST /api/organizations/{{id}}/members         - Add member (admin)");
+    println!("  DEL  /api/organizations/{{id}}/members/{{uid}}   - Remove member (admin or self)");
+    println!("  GET  /api/organizations/{{id}}/unlocks         - Organization's unlocked resumes");
+    println!("  GET  /api/organizations/{{id}}/spend           - Organization spend summary");
+    println!();
+    println!("🔑 API Key Endpoints (wallet session only):");
+    println!("  POST /api/api-keys                           - Create scoped API key");
+    println!("  GET  /api/api-keys                           - List my API keys");
+    println!("  POST /api/api-keys/{{id}}/rotate               - Rotate API key");
+    println!("  DEL  /api/api-keys/{{id}}                      - Revoke API key");
+    println!("  GET  /api/api-keys/{{id}}/audit                - API key audit trail");
     println!();
     println!("📊 Access Log Endpoints:");
     println!("  POST /api/access-logs                        - Create access log");
-    println!("  GET  /api/access-logs/resume/{{id}}            - Get resume's access logs");
-    println!("  GET  /api/access-logs/accessor/{{address}}     - Get accessor's logs");
+    println!("  GET  /api/access-logs/resume/{{id}}            - Get resume's access logs (owner)");
+    println!("  GET  /api/access-logs/accessor/{{address}}     - Get my access logs (auth)");
     println!("  GET  /api/access-logs/count/{{id}}             - Count resume access");
     println!();
+    println!("🔎 Indexer Endpoints:");
+    println!("  GET  /api/indexer/status                     - Event cursors and indexing lag");
+    println!();
+    println!("🧾 Receipt Endpoints:");
+    println!("  GET  /api/receipts/public-key                - Receipt signing public key");
+    println!("  GET  /api/receipts/{{receipt_id}}              - Get signed receipt (buyer/seller)");
+    println!();
+    println!("💰 Payout Endpoints:");
+    println!("  GET  /api/payouts/balance                    - Seller pending/paid totals");
+    println!("  GET  /api/payouts/ledger                     - Seller gross/fee/net per unlock");
+    println!("  GET  /api/payouts                            - Seller payout batches");
+    println!("  POST /api/payouts/batches                    - Batch pending entries (admin)");
+    println!("  POST /api/payouts/{{payout_id}}/paid           - Mark payout paid (admin)");
+    println!("  GET  /api/credits/balance                    - Credit balance (?organization_id=)");
+    println!("  GET  /api/credits/ledger                     - Credit top-ups and debits (?organization_id=)");
+    println!("  GET  /api/credits/bundles                    - Credit bundles on sale");
+    println!("  POST /api/credits/bundles                    - Create credit bundle (admin)");
+    println!("  DELETE /api/credits/bundles/{{bundle_id}}      - Deactivate credit bundle (admin)");
+    println!("  POST /api/credits/bundles/{{bundle_id}}/purchase - Buy credits via x402 (auth)");
+    println!("  POST /api/coupons                            - Create coupon (admin)");
+    println!("  GET  /api/coupons                            - List coupons (admin)");
+    println!("  DELETE /api/coupons/{{code}}                   - Deactivate coupon (admin)");
+    println!();
+    println!("💳 x402 Paid Endpoints:");
+    println!("  GET  /api/premium/content                    - Premium content (402 until paid)");
+    println!();
 
     // 启动服务器
     HttpServer::new(move || {
@@ -78,15 +289,34 @@ async fn main() -> std::io::Result<()> {
             .allow_any_origin()
             .allow_any_method()
             .allow_any_header()
+            .expose_headers([PAYMENT_RESPONSE_HEADER, IDEMPOTENT_REPLAYED_HEADER])
             .max_age(3600);
 
         App::new()
             .wrap(cors)
             .app_data(db_data.clone())  // SeaORM 数据库连接
+            .app_data(auth_config.clone())
+            .app_data(rate_limiter.clone())
+            .app_data(x402_config.clone())
+            .app_data(facilitator.clone())
+            .app_data(sui.clone())
+            .app_data(sui_config.clone())
+            .app_data(receipt_signer.clone())
+            .app_data(commission_config.clone())
+            .configure(routes::config_auth_routes)
             .configure(routes::config_user_routes)
             .configure(routes::config_resume_routes)
             .configure(routes::config_unlock_record_routes)
+            .configure(routes::config_organization_routes)
+            .configure(routes::config_api_key_routes)
             .configure(routes::config_access_log_routes)
+            .configure(routes::config_receipt_routes)
+            .configure(routes::config_payout_routes)
+            .configure(routes::config_credit_routes)
+            .configure(routes::config_coupon_routes)
+            .configure(routes::config_indexer_routes)
+            // `/api` 前缀的 scope 必须最后注册，否则会先匹配其他 `/api/...` 路由
+            .configure(routes::config_example_routes)
     })
     .bind(&bind_addr)?
     .run()
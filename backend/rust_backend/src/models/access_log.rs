@@ -1,14 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-/// 创建访问记录请求
+/// 创建访问记录请求（访问者为当前登录钱包）
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CreateAccessLogRequest {
     /// 简历 ID (UUID 字符串)
     pub resume_id: String,
     
-    /// 访问者地址
-    pub accessor_address: String,
-    
     /// 访问类型: view(查看), download(下载), decrypt(解密)
     pub access_type: String,
     
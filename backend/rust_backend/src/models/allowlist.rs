@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+/// 记录待确认的 allowlist 成员添加请求
+#[derive(Debug, Deserialize)]
+pub struct AddAllowlistMemberRequest {
+    /// 成员 Sui 地址
+    pub member_address: String,
+    /// 已提交的 `allowlist::add` 交易摘要
+    pub tx_digest: String,
+    /// 目标 allowlist，默认为简历本身的策略对象；也可以是某个档位的策略对象
+    pub policy_object_id: Option<String>,
+}
+
+/// 移除 allowlist 成员的查询参数
+#[derive(Debug, Deserialize)]
+pub struct RemoveAllowlistMemberQuery {
+    /// 已提交的 `allowlist::remove` 交易摘要
+    pub tx_digest: Option<String>,
+}
+
+/// Allowlist 成员响应
+#[derive(Debug, Serialize)]
+pub struct AllowlistMemberResponse {
+    pub id: i64,
+    pub policy_object_id: String,
+    pub member_address: String,
+    /// pending / active / removed
+    pub status: String,
+    /// 加入方式: purchase(成员已付费解锁该简历) / manual(所有者手动添加)
+    pub source: String,
+    /// 付费加入时对应的解锁记录
+    pub unlock_record_id: Option<i64>,
+    pub added_by: String,
+    pub tx_digest: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
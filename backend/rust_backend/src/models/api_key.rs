@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+/// 创建 API Key 请求
+#[derive(Debug, Deserialize)]
+pub struct CreateApiKeyRequest {
+    /// Key 名称（如 "ATS 生产环境"）
+    pub name: String,
+    /// 授权范围，如 `["resumes:read", "unlocks:write"]`
+    pub scopes: Vec<String>,
+    /// 组织 Key：指定后需为该组织管理员
+    pub organization_id: Option<i64>,
+}
+
+/// API Key 响应（不含明文 key）
+#[derive(Debug, Serialize)]
+pub struct ApiKeyResponse {
+    pub id: i64,
+    pub name: String,
+    pub key_prefix: String,
+    pub organization_id: Option<i64>,
+    pub scopes: Vec<String>,
+    pub last_used_at: Option<String>,
+    pub revoked_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<crate::entities::api_key::Model> for ApiKeyResponse {
+    fn from(model: crate::entities::api_key::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            key_prefix: model.key_prefix,
+            organization_id: model.organization_id,
+            scopes: model.scopes.split(',').map(|s| s.to_string()).collect(),
+            last_used_at: model.last_used_at.map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string()),
+            revoked_at: model.revoked_at.map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string()),
+            created_at: model.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    }
+}
+
+/// 创建/轮换 API Key 响应，明文 key 只返回这一次
+#[derive(Debug, Serialize)]
+pub struct ApiKeySecretResponse {
+    /// 明文 key，通过 `X-API-Key` 头使用
+    pub key: String,
+    pub api_key: ApiKeyResponse,
+}
+
+/// API Key 审计日志响应
+#[derive(Debug, Serialize)]
+pub struct ApiKeyAuditLogResponse {
+    pub method: String,
+    pub path: String,
+    pub status_code: i32,
+    pub ip_address: Option<String>,
+    pub created_at: String,
+}
+
+impl From<crate::entities::api_key_audit_log::Model> for ApiKeyAuditLogResponse {
+    fn from(model: crate::entities::api_key_audit_log::Model) -> Self {
+        Self {
+            method: model.method,
+            path: model.path,
+            status_code: model.status_code,
+            ip_address: model.ip_address,
+            created_at: model.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+
+/// 申请登录挑战请求
+#[derive(Debug, Deserialize)]
+pub struct ChallengeRequest {
+    /// 要登录的钱包地址
+    pub wallet_address: String,
+}
+
+/// 登录挑战响应
+#[derive(Debug, Serialize)]
+pub struct ChallengeResponse {
+    pub nonce: String,
+    /// 客户端需要用钱包 signPersonalMessage 签名的完整消息
+    pub message: String,
+    pub expires_at: String,
+}
+
+/// 钱包签名登录请求
+#[derive(Debug, Deserialize)]
+pub struct LoginRequest {
+    /// 挑战中返回的 nonce
+    pub nonce: String,
+    /// 钱包返回的 base64 序列化签名
+    pub signature: String,
+    /// 设备标签，如 "MacBook Chrome"，用于会话管理列表
+    pub device_label: Option<String>,
+}
+
+/// 登录客户端信息，随会话保存
+#[derive(Debug, Clone, Default)]
+pub struct SessionClientInfo {
+    pub device_label: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+/// 会话（设备）响应
+#[derive(Debug, Serialize)]
+pub struct SessionResponse {
+    pub id: i64,
+    pub wallet_address: String,
+    pub device_label: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub created_at: String,
+    pub last_seen_at: Option<String>,
+    pub expires_at: String,
+    /// 是否为发起请求的会话
+    pub current: bool,
+}
+
+impl SessionResponse {
+    pub fn new(model: crate::entities::auth_session::Model, current_session_id: Option<i64>) -> Self {
+        Self {
+            current: current_session_id == Some(model.id),
+            id: model.id,
+            wallet_address: model.wallet_address,
+            device_label: model.device_label,
+            ip_address: model.ip_address,
+            user_agent: model.user_agent,
+            created_at: model.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+            last_seen_at: model.last_seen_at.map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string()),
+            expires_at: model.expires_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    }
+}
+
+/// 登录成功响应
+#[derive(Debug, Serialize)]
+pub struct LoginResponse {
+    /// 会话 token，后续请求放在 `Authorization: Bearer <token>` 中
+    pub token: String,
+    pub user_id: i64,
+    pub wallet_address: String,
+    pub expires_at: String,
+}
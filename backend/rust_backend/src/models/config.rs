@@ -1,4 +1,8 @@
 use serde::Deserialize;
+use std::env;
+
+use crate::rate_limit::RateLimit;
+use crate::utils::validator::Validator;
 
 /// 环境配置
 #[derive(Debug, Clone, Deserialize)]
@@ -16,3 +20,385 @@ impl Default for EnvConfig {
         }
     }
 }
+
+/// 登录认证配置
+#[derive(Debug, Clone)]
+pub struct AuthConfig {
+    /// 登录挑战 nonce 有效期（秒）
+    pub nonce_ttl_secs: i64,
+    /// 会话空闲有效期（秒），每次请求顺延
+    pub session_ttl_secs: i64,
+    /// 会话最长有效期（秒），从登录起算，滑动续期不会超过它
+    pub session_max_lifetime_secs: i64,
+    /// 管理员钱包（已规范化），可使用管理员 override 读取他人的记录
+    pub admin_wallets: Vec<String>,
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            nonce_ttl_secs: 300,
+            session_ttl_secs: 7200,
+            session_max_lifetime_secs: 30 * 24 * 3600,
+            admin_wallets: Vec::new(),
+        }
+    }
+}
+
+impl AuthConfig {
+    /// 从环境变量读取，未设置时使用默认值
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            nonce_ttl_secs: env::var("AUTH_NONCE_TTL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.nonce_ttl_secs),
+            session_ttl_secs: env::var("SESSION_TTL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.session_ttl_secs),
+            session_max_lifetime_secs: env::var("SESSION_MAX_LIFETIME_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.session_max_lifetime_secs),
+            admin_wallets: env::var("ADMIN_WALLETS")
+                .map(|v| Self::parse_admin_wallets(&v))
+                .unwrap_or(default.admin_wallets),
+        }
+    }
+
+    /// 解析逗号分隔的管理员钱包列表，非法地址忽略并告警
+    fn parse_admin_wallets(value: &str) -> Vec<String> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .filter_map(|address| match Validator::normalize_sui_address(address) {
+                Ok(address) => Some(address),
+                Err(e) => {
+                    log::warn!("ADMIN_WALLETS: ignoring '{}': {}", address, e.message);
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// 限流状态存储后端
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitBackend {
+    /// 进程内存，单实例部署
+    Memory,
+    /// 数据库，多实例共享
+    Database,
+}
+
+/// 限流配置，每个路由组一组令牌桶参数
+#[derive(Debug, Clone)]
+pub struct RateLimitConfig {
+    pub enabled: bool,
+    pub backend: RateLimitBackend,
+    /// 是否信任 `X-Forwarded-For` / `Forwarded`（部署在反向代理后时开启）
+    pub trust_proxy: bool,
+    pub default: RateLimit,
+    pub login: RateLimit,
+    pub register: RateLimit,
+    pub access_log: RateLimit,
+    pub public_read: RateLimit,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            backend: RateLimitBackend::Memory,
+            trust_proxy: false,
+            default: RateLimit::new(120, 60),
+            login: RateLimit::new(10, 60),
+            register: RateLimit::new(5, 3600),
+            access_log: RateLimit::new(30, 60),
+            public_read: RateLimit::new(60, 60),
+        }
+    }
+}
+
+impl RateLimitConfig {
+    /// 从环境变量读取，未设置或格式错误时使用默认值
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        let limit = |name: &str, fallback: RateLimit| match env::var(name) {
+            Ok(value) => RateLimit::parse(&value).unwrap_or_else(|e| {
+                log::warn!("{}: {}, using default", name, e);
+                fallback
+            }),
+            Err(_) => fallback,
+        };
+        let flag = |name: &str, fallback: bool| {
+            env::var(name)
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(fallback)
+        };
+
+        Self {
+            enabled: flag("RATE_LIMIT_ENABLED", default.enabled),
+            backend: match env::var("RATE_LIMIT_BACKEND").as_deref() {
+                Ok("database") => RateLimitBackend::Database,
+                _ => RateLimitBackend::Memory,
+            },
+            trust_proxy: flag("RATE_LIMIT_TRUST_PROXY", default.trust_proxy),
+            default: limit("RATE_LIMIT_DEFAULT", default.default),
+            login: limit("RATE_LIMIT_LOGIN", default.login),
+            register: limit("RATE_LIMIT_REGISTER", default.register),
+            access_log: limit("RATE_LIMIT_ACCESS_LOG", default.access_log),
+            public_read: limit("RATE_LIMIT_PUBLIC_READ", default.public_read),
+        }
+    }
+}
+
+/// x402 facilitator 实现
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FacilitatorMode {
+    /// 通过 HTTP 调用 `FACILITATOR_URL`
+    Http,
+    /// 进程内模拟，测试和本地开发使用
+    Mock,
+}
+
+/// x402 支付配置
+#[derive(Debug, Clone)]
+pub struct X402Config {
+    pub facilitator: FacilitatorMode,
+    /// Facilitator 服务地址
+    pub facilitator_url: String,
+    /// 单次请求 facilitator 的超时（秒）
+    pub facilitator_timeout_secs: u64,
+    /// 超时/不可用时的最大重试次数
+    pub facilitator_max_retries: u32,
+    /// 收款地址
+    pub pay_to: String,
+    /// 支付网络，如 `solana-devnet`
+    pub network: String,
+    /// 支付代币（mint 地址）
+    pub asset: String,
+    /// 代币精度
+    pub asset_decimals: u8,
+    /// 代币名称
+    pub asset_name: String,
+    /// 支付签名的有效期（秒）
+    pub max_timeout_secs: u64,
+}
+
+impl Default for X402Config {
+    fn default() -> Self {
+        Self {
+            facilitator: FacilitatorMode::Http,
+            facilitator_url: "https://facilitator.x402.org".to_string(),
+            facilitator_timeout_secs: 10,
+            facilitator_max_retries: 2,
+            pay_to: String::new(),
+            network: "solana-devnet".to_string(),
+            asset: "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
+            asset_decimals: 6,
+            asset_name: "USDC".to_string(),
+            max_timeout_secs: 60,
+        }
+    }
+}
+
+impl X402Config {
+    /// 从环境变量读取，未设置时使用默认值
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            facilitator: match env::var("X402_FACILITATOR").as_deref() {
+                Ok("mock") => FacilitatorMode::Mock,
+                _ => default.facilitator,
+            },
+            facilitator_url: env::var("FACILITATOR_URL").unwrap_or(default.facilitator_url),
+            facilitator_timeout_secs: env::var("FACILITATOR_TIMEOUT_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.facilitator_timeout_secs),
+            facilitator_max_retries: env::var("FACILITATOR_MAX_RETRIES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.facilitator_max_retries),
+            pay_to: env::var("ADDRESS").unwrap_or(default.pay_to),
+            network: env::var("NETWORK").unwrap_or(default.network),
+            asset: env::var("TOKEN_MINT_ADDRESS").unwrap_or(default.asset),
+            asset_decimals: env::var("TOKEN_DECIMALS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.asset_decimals),
+            asset_name: env::var("TOKEN_NAME").unwrap_or(default.asset_name),
+            max_timeout_secs: env::var("X402_MAX_TIMEOUT_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.max_timeout_secs),
+        }
+    }
+}
+
+/// Sui RPC 实现
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuiRpcMode {
+    /// 通过 JSON-RPC 调用 `SUI_RPC_URL`
+    Http,
+    /// 进程内替身，测试和本地开发使用
+    Stub,
+}
+
+/// Sui 链上校验配置
+#[derive(Debug, Clone)]
+pub struct SuiConfig {
+    pub rpc: SuiRpcMode,
+    /// 全节点 JSON-RPC 地址
+    pub rpc_url: String,
+    /// 单次请求的超时（秒）
+    pub rpc_timeout_secs: u64,
+    /// 合约包 ID，设置后只认可该包发出的 Subscription
+    pub package_id: Option<String>,
+}
+
+impl Default for SuiConfig {
+    fn default() -> Self {
+        Self {
+            rpc: SuiRpcMode::Http,
+            rpc_url: "https://fullnode.testnet.sui.io:443".to_string(),
+            rpc_timeout_secs: 10,
+            package_id: None,
+        }
+    }
+}
+
+impl SuiConfig {
+    /// 从环境变量读取，未设置时使用默认值
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            rpc: match env::var("SUI_RPC").as_deref() {
+                Ok("stub") => SuiRpcMode::Stub,
+                _ => default.rpc,
+            },
+            rpc_url: env::var("SUI_RPC_URL").unwrap_or(default.rpc_url),
+            rpc_timeout_secs: env::var("SUI_RPC_TIMEOUT_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.rpc_timeout_secs),
+            package_id: env::var("SUI_PACKAGE_ID")
+                .ok()
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+                .or(default.package_id),
+        }
+    }
+}
+
+/// 链上事件索引配置（需要同时设置 `SUI_PACKAGE_ID`）
+#[derive(Debug, Clone)]
+pub struct IndexerConfig {
+    pub enabled: bool,
+    /// 轮询间隔（秒）
+    pub poll_interval_secs: u64,
+    /// 每次查询的事件数
+    pub page_size: usize,
+}
+
+impl Default for IndexerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            poll_interval_secs: 5,
+            page_size: 50,
+        }
+    }
+}
+
+impl IndexerConfig {
+    /// 从环境变量读取，未设置时使用默认值
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            enabled: env::var("INDEXER_ENABLED")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.enabled),
+            poll_interval_secs: env::var("INDEXER_POLL_INTERVAL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|secs| *secs > 0)
+                .unwrap_or(default.poll_interval_secs),
+            page_size: env::var("INDEXER_PAGE_SIZE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|size| (1..=1000).contains(size))
+                .unwrap_or(default.page_size),
+        }
+    }
+}
+
+/// 平台佣金配置：按比例抽成，且不低于最低佣金
+#[derive(Debug, Clone)]
+pub struct CommissionConfig {
+    /// 佣金比例（基点，1 bps = 0.01%）
+    pub rate_bps: u32,
+    /// 最低佣金（代币最小单位）
+    pub minimum: i64,
+}
+
+impl Default for CommissionConfig {
+    fn default() -> Self {
+        Self {
+            rate_bps: 500,
+            minimum: 0,
+        }
+    }
+}
+
+impl CommissionConfig {
+    /// 从环境变量读取，未设置时使用默认值
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            rate_bps: env::var("COMMISSION_RATE_BPS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|bps| *bps <= 10_000)
+                .unwrap_or(default.rate_bps),
+            minimum: env::var("COMMISSION_MINIMUM")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|min| *min >= 0)
+                .unwrap_or(default.minimum),
+        }
+    }
+
+    /// 拆分买家支付金额，返回 (佣金, 卖家所得)；佣金不超过支付金额
+    pub fn split(&self, gross: i64) -> (i64, i64) {
+        if gross <= 0 {
+            return (0, 0);
+        }
+        let rate_fee = (gross as i128 * self.rate_bps as i128 / 10_000) as i64;
+        let fee = rate_fee.max(self.minimum).min(gross);
+        (fee, gross - fee)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commission_split() {
+        let config = CommissionConfig { rate_bps: 500, minimum: 100 };
+
+        assert_eq!(config.split(1_000_000), (50_000, 950_000));
+        // 低于最低佣金时按最低佣金收取
+        assert_eq!(config.split(1_000), (100, 900));
+        // 佣金不超过支付金额
+        assert_eq!(config.split(50), (50, 0));
+        assert_eq!(config.split(0), (0, 0));
+    }
+}
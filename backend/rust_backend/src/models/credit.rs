@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+
+use crate::money::{self, Money};
+
+/// 额度账户查询参数
+#[derive(Debug, Deserialize)]
+pub struct CreditAccountQuery {
+    /// 查询（或充值到）组织账户，不填为个人账户
+    pub organization_id: Option<i64>,
+}
+
+/// 额度余额响应
+#[derive(Debug, Serialize)]
+pub struct CreditBalanceResponse {
+    /// user / organization
+    pub owner_type: String,
+    pub owner_id: i64,
+    /// 每种资产一项
+    pub balances: Vec<Money>,
+}
+
+/// 额度流水响应
+#[derive(Debug, Serialize)]
+pub struct CreditTransactionResponse {
+    pub id: i64,
+    /// topup / unlock / refund
+    pub kind: String,
+    /// 变动金额，扣减为负
+    pub amount: Money,
+    pub balance_after: Money,
+    pub bundle_id: Option<i64>,
+    pub unlock_record_id: Option<i64>,
+    pub reference: Option<String>,
+    pub created_by: i64,
+    pub created_at: String,
+}
+
+impl CreditTransactionResponse {
+    pub fn new(model: crate::entities::credit_transaction::Model, asset: &str) -> Self {
+        let registry = money::registry();
+        Self {
+            id: model.id,
+            kind: model.kind,
+            amount: registry.stored(model.amount, asset),
+            balance_after: registry.stored(model.balance_after, asset),
+            bundle_id: model.bundle_id,
+            unlock_record_id: model.unlock_record_id,
+            reference: model.reference,
+            created_by: model.created_by,
+            created_at: model.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    }
+}
+
+/// 创建额度套餐请求（管理员）
+#[derive(Debug, Deserialize)]
+pub struct CreateCreditBundleRequest {
+    pub name: String,
+    /// 十进制售价
+    pub price: String,
+    /// 十进制到账额度，不少于售价
+    pub credits: String,
+    /// 资产符号，默认为 x402 收款资产
+    pub asset: Option<String>,
+}
+
+/// 额度套餐响应
+#[derive(Debug, Serialize)]
+pub struct CreditBundleResponse {
+    pub id: i64,
+    pub name: String,
+    pub price: Money,
+    pub credits: Money,
+    pub active: bool,
+}
+
+impl From<crate::entities::credit_bundle::Model> for CreditBundleResponse {
+    fn from(model: crate::entities::credit_bundle::Model) -> Self {
+        let registry = money::registry();
+        Self {
+            id: model.id,
+            name: model.name,
+            price: registry.stored(model.price, &model.asset),
+            credits: registry.stored(model.credits, &model.asset),
+            active: model.active,
+        }
+    }
+}
+
+/// 购买额度套餐响应
+#[derive(Debug, Serialize)]
+pub struct CreditTopupResponse {
+    pub bundle_id: i64,
+    /// 到账额度
+    pub credited: Money,
+    /// 充值后余额
+    pub balance: Money,
+    /// 结算交易摘要和网络
+    pub transaction: String,
+    pub network: String,
+}
+
+/// 创建优惠码请求（管理员）
+#[derive(Debug, Deserialize)]
+pub struct CreateCouponRequest {
+    /// 优惠码（字母、数字、`_`、`-`，不区分大小写）
+    pub code: String,
+    /// percent 或 fixed
+    pub discount_type: String,
+    /// percent 为 1-100 的整数，fixed 为十进制减免金额
+    pub discount_value: String,
+    /// fixed 减免的资产，默认为 x402 收款资产
+    pub asset: Option<String>,
+    /// 总使用次数上限
+    pub max_redemptions: Option<i32>,
+    /// 每人使用次数上限
+    pub max_per_user: Option<i32>,
+    /// 过期时间（UTC，`YYYY-MM-DD HH:MM:SS`）
+    pub expires_at: Option<String>,
+}
+
+/// 优惠码响应
+#[derive(Debug, Serialize)]
+pub struct CouponResponse {
+    pub code: String,
+    pub discount_type: String,
+    /// percent 时为百分比
+    pub percent_off: Option<i64>,
+    /// fixed 时为减免金额
+    pub amount_off: Option<Money>,
+    pub max_redemptions: Option<i32>,
+    pub max_per_user: Option<i32>,
+    pub redeemed_count: i32,
+    pub expires_at: Option<String>,
+    pub active: bool,
+    pub created_at: String,
+}
+
+impl From<crate::entities::coupon::Model> for CouponResponse {
+    fn from(model: crate::entities::coupon::Model) -> Self {
+        let (percent_off, amount_off) = match &model.asset {
+            Some(asset) => (None, Some(money::registry().stored(model.discount_value, asset))),
+            None => (Some(model.discount_value), None),
+        };
+        Self {
+            code: model.code,
+            discount_type: model.discount_type,
+            percent_off,
+            amount_off,
+            max_redemptions: model.max_redemptions,
+            max_per_user: model.max_per_user,
+            redeemed_count: model.redeemed_count,
+            expires_at: model.expires_at.map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string()),
+            active: model.active,
+            created_at: model.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use serde::Serialize;
+
+/// 链上事件索引某个事件流的进度
+#[derive(Debug, Serialize)]
+pub struct IndexerStreamStatus {
+    pub package_id: String,
+    /// 事件类型（模块名），如 `allowlist`
+    pub event_type: String,
+    /// 最后处理的事件
+    pub tx_digest: Option<String>,
+    pub event_seq: Option<String>,
+    /// 最后处理的事件的链上时间（毫秒）
+    pub last_event_timestamp_ms: Option<i64>,
+    /// 最近一次追上链上最新事件的时间
+    pub caught_up_at: Option<String>,
+    /// 索引延迟：距最近一次追上最新事件的秒数，从未追上时为 None
+    pub lag_secs: Option<i64>,
+    /// 最近一次轮询的错误
+    pub last_error: Option<String>,
+    pub updated_at: String,
+}
@@ -3,8 +3,29 @@ pub mod config;
 pub mod response;
 pub mod unlock_record;
 pub mod access_log;
+pub mod auth;
+pub mod organization;
+pub mod api_key;
+pub mod user_wallet;
+pub mod receipt;
+pub mod payout;
+pub mod credit;
+pub mod allowlist;
+pub mod indexer;
 
 pub use resume::*;
 pub use response::*;
 pub use unlock_record::*;
 pub use access_log::*;
+pub use auth::*;
+pub use organization::*;
+pub use api_key::*;
+pub use user_wallet::*;
+pub use receipt::*;
+pub use payout::*;
+pub use credit::*;
+pub use allowlist::*;
+pub use indexer::*;
+pub use config::{
+    AuthConfig, CommissionConfig, FacilitatorMode, IndexerConfig, RateLimitBackend, RateLimitConfig, SuiConfig, SuiRpcMode, X402Config,
+};
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+use crate::money::Money;
+
+/// 创建组织请求
+#[derive(Debug, Deserialize)]
+pub struct CreateOrganizationRequest {
+    pub name: String,
+}
+
+/// 添加组织成员请求
+#[derive(Debug, Deserialize)]
+pub struct AddOrganizationMemberRequest {
+    /// 成员钱包地址（需已登录过）
+    pub wallet_address: String,
+    /// 成员角色: admin / member，默认 member
+    pub role: Option<String>,
+}
+
+/// 组织响应
+#[derive(Debug, Serialize)]
+pub struct OrganizationResponse {
+    pub id: i64,
+    pub name: String,
+    pub created_by: i64,
+    /// 当前用户在组织中的角色
+    pub role: String,
+    pub created_at: String,
+}
+
+/// 组织成员响应
+#[derive(Debug, Serialize)]
+pub struct OrganizationMemberResponse {
+    pub user_id: i64,
+    pub wallet_address: String,
+    pub role: String,
+    pub created_at: String,
+}
+
+impl From<crate::entities::organization_member::Model> for OrganizationMemberResponse {
+    fn from(model: crate::entities::organization_member::Model) -> Self {
+        Self {
+            user_id: model.user_id,
+            wallet_address: model.wallet_address,
+            role: model.role,
+            created_at: model.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    }
+}
+
+/// 组织消费统计
+#[derive(Debug, Serialize)]
+pub struct OrganizationSpendResponse {
+    pub organization_id: i64,
+    /// 已解锁简历数
+    pub unlock_count: u64,
+    /// 按资产汇总的消费金额
+    pub totals: Vec<Money>,
+}
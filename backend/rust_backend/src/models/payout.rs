@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+
+use crate::money::{self, Money};
+
+/// 标记结算批次已打款请求
+#[derive(Debug, Deserialize)]
+pub struct MarkPayoutPaidRequest {
+    /// 打款交易摘要
+    pub transaction_digest: String,
+}
+
+/// 卖家余额汇总，每种资产一项
+#[derive(Debug, Serialize)]
+pub struct SellerBalanceResponse {
+    pub seller_id: i64,
+    pub balances: Vec<AssetBalance>,
+}
+
+/// 单一资产的卖家余额（按 net 计算）
+#[derive(Debug, Serialize)]
+pub struct AssetBalance {
+    pub asset: String,
+    /// 未进入结算批次的金额
+    pub pending: Money,
+    /// 已进入结算批次、尚未打款的金额
+    pub processing: Money,
+    /// 已打款金额
+    pub paid: Money,
+    /// 买家支付总额
+    pub total_gross: Money,
+    /// 平台佣金总额
+    pub total_fee: Money,
+    /// 账目数
+    pub entry_count: u64,
+}
+
+/// 卖家账目响应
+#[derive(Debug, Serialize)]
+pub struct SellerLedgerEntryResponse {
+    pub id: i64,
+    pub unlock_record_id: i64,
+    /// sale(解锁收入) / refund(退款冲正，金额为负)
+    pub entry_type: String,
+    pub resume_id: i64,
+    pub gross_amount: Money,
+    pub fee_amount: Money,
+    pub net_amount: Money,
+    pub payout_id: Option<i64>,
+    pub created_at: String,
+}
+
+impl From<crate::entities::seller_ledger_entry::Model> for SellerLedgerEntryResponse {
+    fn from(model: crate::entities::seller_ledger_entry::Model) -> Self {
+        let registry = money::registry();
+        Self {
+            id: model.id,
+            unlock_record_id: model.unlock_record_id,
+            entry_type: model.entry_type,
+            resume_id: model.resume_id,
+            gross_amount: registry.stored(model.gross_amount, &model.asset),
+            fee_amount: registry.stored(model.fee_amount, &model.asset),
+            net_amount: registry.stored(model.net_amount, &model.asset),
+            payout_id: model.payout_id,
+            created_at: model.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    }
+}
+
+/// 结算批次响应
+#[derive(Debug, Serialize)]
+pub struct SellerPayoutResponse {
+    pub id: i64,
+    pub seller_id: i64,
+    pub seller_wallet: String,
+    pub amount: Money,
+    pub entry_count: i32,
+    pub status: String,
+    pub transaction_digest: Option<String>,
+    pub paid_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<crate::entities::seller_payout::Model> for SellerPayoutResponse {
+    fn from(model: crate::entities::seller_payout::Model) -> Self {
+        Self {
+            id: model.id,
+            seller_id: model.seller_id,
+            seller_wallet: model.seller_wallet,
+            amount: money::registry().stored(model.amount, &model.asset),
+            entry_count: model.entry_count,
+            status: model.status,
+            transaction_digest: model.transaction_digest,
+            paid_at: model.paid_at.map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string()),
+            created_at: model.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+/// 被签名的收据内容
+///
+/// 签名针对序列化后的 JSON 原文（即响应中的 `payload` 字段），验证时不要重新序列化。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceiptPayload {
+    pub receipt_id: String,
+    pub resume_id: String,
+    /// 购买的档位（早期收据没有该字段，均为完整简历）
+    #[serde(default = "full_tier")]
+    pub tier: String,
+    pub buyer: String,
+    pub seller: String,
+    /// 代币最小单位的金额
+    pub amount: i64,
+    pub asset: String,
+    pub network: String,
+    /// 结算交易摘要
+    pub transaction: String,
+    /// 签发时间（Unix 秒）
+    pub issued_at: i64,
+    /// 签名公钥 ID
+    pub key_id: String,
+}
+
+fn full_tier() -> String {
+    crate::services::resume_tier_service::FULL_TIER.to_string()
+}
+
+/// 收据响应
+#[derive(Debug, Clone, Serialize)]
+pub struct PaymentReceiptResponse {
+    pub receipt_id: String,
+    pub algorithm: String,
+    pub key_id: String,
+    /// 被签名的 JSON 原文
+    pub payload: String,
+    /// base64 编码的签名
+    pub signature: String,
+    /// 解析后的收据内容，便于展示
+    pub receipt: ReceiptPayload,
+}
+
+/// 查询收据签名公钥
+#[derive(Debug, Deserialize)]
+pub struct ReceiptPublicKeyQuery {
+    /// 收据中的 key_id，不传为当前签名公钥；轮换前的公钥也可以查询
+    pub key_id: Option<String>,
+}
+
+/// 收据签名公钥
+#[derive(Debug, Clone, Serialize)]
+pub struct ReceiptPublicKeyResponse {
+    pub algorithm: String,
+    pub key_id: String,
+    /// 十六进制编码的 Ed25519 公钥
+    pub public_key: String,
+    /// 是否为当前用于签名的公钥（false 为已轮换的旧公钥）
+    pub current: bool,
+}
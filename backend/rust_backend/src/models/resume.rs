@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+use crate::models::PaymentReceiptResponse;
+use crate::money::Money;
+
 /// 简历基本信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PersonalInfo {
@@ -98,25 +101,26 @@ pub struct Resume {
 }
 
 /// 包含价格的简历（用于"我的简历"列表）
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ResumeWithPrice {
     #[serde(flatten)]
     pub resume: Resume,
-    pub price: i64, // 价格（USDC 最小单位，6 decimals）
+    pub price: Money,
     pub view_count: i32,
     pub unlock_count: i32,
     pub status: String,
 }
 
 /// 我的简历摘要（只包含必要字段，不包含详细内容）
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MyResumeSummary {
     pub id: String,
     pub name: Option<String>, // 简历名称
     pub owner: String,
     pub created_at: i64,
     pub updated_at: i64,
-    pub price: i64,
+    pub price: Money,
     pub view_count: i32,
     pub unlock_count: i32,
     pub status: String,
@@ -135,7 +139,8 @@ pub struct MyResumeSummary {
 }
 
 /// 简历摘要（公开信息）
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ResumeSummary {
     pub id: String,
     pub name: String, // 脱敏后的姓名
@@ -144,17 +149,17 @@ pub struct ResumeSummary {
     pub experience: String,
     pub education: String,
     pub city: String,
-    pub price: f64, // SOL or USDC
+    pub price: Money,
     pub owner: String,
 }
 
 /// 简历列表摘要（用于公开列表展示，简化版本）
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ResumeListItem {
     pub id: String,
     pub name: Option<String>, // 简历名称
     pub owner: String,
-    pub price: i64, // 价格（USDC 最小单位，6 decimals）
+    pub price: Money,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub policy_object_id: Option<String>, // Seal Policy Object ID (Allowlist ID 或 Service ID)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -167,26 +172,108 @@ pub struct ResumeListItem {
     pub blob_id: Option<String>, // Walrus Blob ID
 }
 
+/// 付费解锁简历的响应：解密所需的凭据
+#[derive(Debug, Clone, Serialize)]
+pub struct ResumeUnlockResponse {
+    pub resume_id: String,
+    /// 解锁的档位，凭据只对应该档位的内容
+    pub tier: String,
+    /// 本次购买（或升级）创建的解锁记录（之前已拥有该档位时为 None）
+    pub unlock_record_id: Option<i64>,
+    pub already_unlocked: bool,
+    /// 本次支付金额（之前已解锁或免费时为 0）
+    pub amount: Money,
+    /// 支付方式: x402 / credits / free，之前已解锁时为 None
+    pub payment_method: Option<String>,
+    /// 优惠码减免的金额
+    pub discount: Option<Money>,
+    /// 结算交易摘要和网络（之前已解锁或免费时为 None）
+    pub transaction: Option<String>,
+    pub network: Option<String>,
+    pub blob_id: String,
+    pub encryption_type: String,
+    pub encryption_mode: Option<String>,
+    pub encryption_id: Option<String>,
+    pub policy_object_id: Option<String>,
+    /// 简单加密模式的密钥（Seal 模式为 None）
+    pub encryption_key: Option<String>,
+    /// 服务端签名的收据（只为本次已结算的购买签发）
+    pub receipt: Option<PaymentReceiptResponse>,
+}
+
+/// 付费解锁的查询参数
+#[derive(Debug, Clone, Deserialize)]
+pub struct UnlockResumeQuery {
+    /// 要购买的档位，默认为完整简历 `full`
+    pub tier: Option<String>,
+    /// 优惠码
+    pub coupon: Option<String>,
+}
+
+/// 额度解锁的查询参数
+#[derive(Debug, Clone, Deserialize)]
+pub struct UnlockWithCreditsQuery {
+    /// 要购买的档位，默认为完整简历 `full`
+    pub tier: Option<String>,
+    /// 优惠码
+    pub coupon: Option<String>,
+    /// 从该组织的额度账户扣减（需为成员），不传为个人账户，与额度余额、购买套餐的默认账户一致
+    pub organization_id: Option<i64>,
+}
+
+/// 单个价格档位
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResumeTierRequest {
+    /// 档位名称（小写字母、数字、`_`、`-`），`full` 保留给完整简历
+    pub tier: String,
+    /// 档位等级，越高包含的内容越多，必须为正数
+    pub level: i32,
+    /// 十进制价格，资产与简历价格相同
+    pub price: String,
+    pub blob_id: String,
+    /// "simple" 或 "seal"，默认与简历相同
+    pub encryption_type: Option<String>,
+    pub encryption_id: Option<String>,
+    pub policy_object_id: Option<String>,
+    /// 简单加密模式的密钥
+    pub encryption_key: Option<String>,
+}
+
+/// 设置简历价格档位请求（整体替换，完整简历 `full` 不需要也不能列出）
+#[derive(Debug, Clone, Deserialize)]
+pub struct SetResumeTiersRequest {
+    pub tiers: Vec<ResumeTierRequest>,
+}
+
+/// 价格档位（公开信息，不包含解密凭据）
+#[derive(Debug, Clone, Serialize)]
+pub struct ResumeTierResponse {
+    pub tier: String,
+    pub level: i32,
+    pub price: Money,
+    pub encryption_type: String,
+}
+
 /// 设置简历价格请求
 #[derive(Debug, Clone, Deserialize)]
 pub struct SetPriceRequest {
     pub resume_id: String,
-    pub owner: String,
-    pub price: u64, // 价格，单位：USDC 最小单位 (1 USDC = 1_000_000 micro-units, 6 decimals)
+    /// 十进制价格，如 `"5.25"`（按资产精度精确换算，不接受更多小数位）
+    pub price: String,
+    /// 价格资产符号，默认为 x402 收款资产
+    pub asset: Option<String>,
 }
 
 /// 更新简历名称请求
 #[derive(Debug, Clone, Deserialize)]
 pub struct UpdateResumeNameRequest {
     pub resume_id: String,
-    pub owner: String,
     pub name: String,
 }
 
 /// 简历创建请求
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateResumeRequest {
-    pub owner: String,
     pub ipfs_cid: Option<String>,  // 已废弃，使用 blob_id
     pub blob_id: Option<String>,   // Walrus Blob ID
     pub encryption_key: Option<String>,  // 简单加密的密钥（Seal 加密时为 None）
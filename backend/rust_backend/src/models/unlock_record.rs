@@ -1,22 +1,94 @@
 use serde::{Deserialize, Serialize};
 
-/// 创建解锁记录请求
+use crate::money::{self, Money};
+
+/// 解锁记录状态
+///
+/// ```text
+/// pending → confirmed → disputed → refunded
+///    ↓                      ↓
+///  failed                rejected
+/// ```
+///
+/// confirmed / disputed / rejected 的记录授予解锁权益，refunded 后权益收回。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnlockStatus {
+    /// 待确认
+    Pending,
+    /// 已确认
+    Confirmed,
+    /// 支付失败
+    Failed,
+    /// 争议处理中
+    Disputed,
+    /// 已退款
+    Refunded,
+    /// 争议被驳回
+    Rejected,
+}
+
+impl UnlockStatus {
+    /// 授予解锁权益的状态
+    pub const ENTITLED: [UnlockStatus; 3] = [UnlockStatus::Confirmed, UnlockStatus::Disputed, UnlockStatus::Rejected];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnlockStatus::Pending => "pending",
+            UnlockStatus::Confirmed => "confirmed",
+            UnlockStatus::Failed => "failed",
+            UnlockStatus::Disputed => "disputed",
+            UnlockStatus::Refunded => "refunded",
+            UnlockStatus::Rejected => "rejected",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(UnlockStatus::Pending),
+            "confirmed" => Some(UnlockStatus::Confirmed),
+            "failed" => Some(UnlockStatus::Failed),
+            "disputed" => Some(UnlockStatus::Disputed),
+            "refunded" => Some(UnlockStatus::Refunded),
+            "rejected" => Some(UnlockStatus::Rejected),
+            _ => None,
+        }
+    }
+
+    /// 授予解锁权益的状态字符串，供查询过滤使用
+    pub fn entitled() -> Vec<&'static str> {
+        Self::ENTITLED.iter().map(UnlockStatus::as_str).collect()
+    }
+
+    /// 是否允许从当前状态转换到 `next`
+    pub fn can_transition_to(&self, next: UnlockStatus) -> bool {
+        matches!(
+            (self, next),
+            (UnlockStatus::Pending, UnlockStatus::Confirmed)
+                | (UnlockStatus::Pending, UnlockStatus::Failed)
+                | (UnlockStatus::Confirmed, UnlockStatus::Disputed)
+                | (UnlockStatus::Disputed, UnlockStatus::Refunded)
+                | (UnlockStatus::Disputed, UnlockStatus::Rejected)
+        )
+    }
+}
+
+/// 创建解锁记录请求（购买者为当前登录钱包）
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CreateUnlockRecordRequest {
     /// 简历 ID
     pub resume_id: i64,
     
-    /// 购买者用户 ID
-    pub buyer_id: i64,
-    
-    /// 购买者钱包地址
-    pub buyer_wallet: String,
+    /// 代表购买的组织 ID（可选，只属于一个组织时默认使用该组织）
+    pub organization_id: Option<i64>,
     
     /// 卖家钱包地址
     pub seller_wallet: String,
     
-    /// 支付金额
+    /// 支付金额（资产最小单位）
     pub amount: i64,
+
+    /// 支付资产符号（可选，默认为 x402 收款资产）
+    pub asset: Option<String>,
     
     /// 交易签名（Sui transaction digest）
     pub transaction_signature: String,
@@ -32,10 +104,15 @@ pub struct UnlockRecordResponse {
     pub resume_id: i64,
     pub buyer_id: i64,
     pub buyer_wallet: String,
+    pub organization_id: Option<i64>,
     pub seller_wallet: String,
-    pub amount: i64,
+    pub amount: Money,
+    pub tier: String,
     pub transaction_signature: String,
     pub status: String,
+    /// 链上校验失败的原因
+    pub failure_reason: Option<String>,
+    pub verified_at: Option<String>,
     pub block_time: Option<i64>,
     pub created_at: String,
 }
@@ -47,10 +124,14 @@ impl From<crate::entities::unlock_record::Model> for UnlockRecordResponse {
             resume_id: model.resume_id,
             buyer_id: model.buyer_id,
             buyer_wallet: model.buyer_wallet,
+            organization_id: model.organization_id,
             seller_wallet: model.seller_wallet,
-            amount: model.amount,
+            amount: money::registry().stored(model.amount, &model.asset),
+            tier: model.tier,
             transaction_signature: model.transaction_signature,
             status: model.status,
+            failure_reason: model.failure_reason,
+            verified_at: model.verified_at.map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string()),
             block_time: model.block_time,
             created_at: model.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
         }
@@ -58,8 +139,103 @@ impl From<crate::entities::unlock_record::Model> for UnlockRecordResponse {
 }
 
 /// 检查解锁状态请求
+#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 pub struct CheckUnlockRequest {
     pub resume_id: i64,
     pub buyer_id: i64,
 }
+
+/// 发起争议请求
+#[derive(Debug, Deserialize)]
+pub struct OpenDisputeRequest {
+    /// 争议原因
+    pub reason: String,
+
+    /// 证据（链接、截图地址或文字说明）
+    #[serde(default)]
+    pub evidence: Vec<String>,
+}
+
+/// 争议裁决请求（管理员）
+#[derive(Debug, Deserialize)]
+pub struct ResolveDisputeRequest {
+    /// 裁决结果: refund(退款) 或 reject(驳回)
+    pub resolution: String,
+
+    /// 裁决说明
+    pub note: Option<String>,
+
+    /// 退款交易摘要（付费解锁退款时必填）
+    pub refund_transaction: Option<String>,
+}
+
+/// 争议列表查询参数
+#[derive(Debug, Deserialize)]
+pub struct DisputeListQuery {
+    /// open / refunded / rejected，不填返回全部
+    pub status: Option<String>,
+}
+
+/// 争议响应
+#[derive(Debug, Serialize)]
+pub struct UnlockDisputeResponse {
+    pub id: i64,
+    pub unlock_record_id: i64,
+    pub opened_by: i64,
+    pub reason: String,
+    pub evidence: Vec<String>,
+    pub status: String,
+    pub resolution_note: Option<String>,
+    pub resolved_by: Option<i64>,
+    pub refund_transaction: Option<String>,
+    pub created_at: String,
+    pub resolved_at: Option<String>,
+}
+
+impl From<crate::entities::unlock_dispute::Model> for UnlockDisputeResponse {
+    fn from(model: crate::entities::unlock_dispute::Model) -> Self {
+        Self {
+            id: model.id,
+            unlock_record_id: model.unlock_record_id,
+            opened_by: model.opened_by,
+            reason: model.reason,
+            evidence: serde_json::from_str(&model.evidence).unwrap_or_default(),
+            status: model.status,
+            resolution_note: model.resolution_note,
+            resolved_by: model.resolved_by,
+            refund_transaction: model.refund_transaction,
+            created_at: model.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+            resolved_at: model.resolved_at.map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlock_status_transitions() {
+        use UnlockStatus::*;
+
+        assert!(Pending.can_transition_to(Confirmed));
+        assert!(Pending.can_transition_to(Failed));
+        assert!(Confirmed.can_transition_to(Disputed));
+        assert!(Disputed.can_transition_to(Refunded));
+        assert!(Disputed.can_transition_to(Rejected));
+
+        // 不能跳过争议直接退款，终态不能再转换
+        assert!(!Confirmed.can_transition_to(Refunded));
+        assert!(!Pending.can_transition_to(Disputed));
+        assert!(!Refunded.can_transition_to(Confirmed));
+        assert!(!Rejected.can_transition_to(Disputed));
+        assert!(!Failed.can_transition_to(Confirmed));
+
+        for status in [Pending, Confirmed, Failed, Disputed, Refunded, Rejected] {
+            assert_eq!(UnlockStatus::parse(status.as_str()), Some(status));
+        }
+        assert!(UnlockStatus::entitled().contains(&"disputed"));
+        assert!(!UnlockStatus::entitled().contains(&"refunded"));
+    }
+}
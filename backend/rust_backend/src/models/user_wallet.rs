@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+/// 申请关联钱包挑战请求
+#[derive(Debug, Deserialize)]
+pub struct LinkWalletChallengeRequest {
+    /// 链: sui / solana
+    pub chain: String,
+    /// 要关联的钱包地址
+    pub address: String,
+}
+
+/// 提交关联钱包签名请求
+#[derive(Debug, Deserialize)]
+pub struct LinkWalletRequest {
+    /// 挑战中返回的 nonce
+    pub nonce: String,
+    /// 待关联钱包对挑战消息的签名
+    /// - Sui: signPersonalMessage 返回的 base64 序列化签名
+    /// - Solana: signMessage 返回的 base58 签名
+    pub signature: String,
+}
+
+/// 用户钱包响应
+#[derive(Debug, Serialize)]
+pub struct UserWalletResponse {
+    pub id: i64,
+    pub chain: String,
+    pub address: String,
+    pub is_primary: bool,
+    pub verified_at: String,
+}
+
+impl From<crate::entities::user_wallet::Model> for UserWalletResponse {
+    fn from(model: crate::entities::user_wallet::Model) -> Self {
+        Self {
+            id: model.id,
+            chain: model.chain,
+            address: model.address,
+            is_primary: model.is_primary,
+            verified_at: model.verified_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use std::sync::OnceLock;
+
+use crate::models::X402Config;
+use crate::money::Money;
+
+/// 可计价的资产
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Asset {
+    /// 符号，如 `USDC`，即数据库中记录的资产标识
+    pub symbol: String,
+    /// 精度（最小单位的小数位数）
+    pub decimals: u8,
+    /// 所在链: sui / solana
+    pub chain: String,
+    /// 链上标识：Solana 为 mint 地址，Sui 为 coin type
+    pub address: String,
+}
+
+impl Asset {
+    fn new(symbol: &str, decimals: u8, chain: &str, address: &str) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            decimals,
+            chain: chain.to_string(),
+            address: address.to_string(),
+        }
+    }
+
+    /// 以最小单位构造金额
+    pub fn money(&self, amount: i64) -> Money {
+        Money {
+            amount,
+            asset: self.symbol.clone(),
+            decimals: self.decimals,
+        }
+    }
+
+    /// 精确解析十进制字符串（如 `"1.25"`），小数位超过精度或为负数时报错
+    pub fn parse(&self, value: &str) -> Result<Money, String> {
+        let value = value.trim();
+        let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+        let valid_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+        if (whole.is_empty() && fraction.is_empty()) || !valid_digits(whole) || !valid_digits(fraction) {
+            return Err(format!("Invalid {} amount: '{}'", self.symbol, value));
+        }
+        if fraction.len() > self.decimals as usize {
+            return Err(format!(
+                "{} supports at most {} decimal places",
+                self.symbol, self.decimals
+            ));
+        }
+
+        let digits = format!("{}{:0<width$}", whole, fraction, width = self.decimals as usize);
+        let amount = digits
+            .parse::<i64>()
+            .map_err(|_| format!("{} amount is too large: '{}'", self.symbol, value))?;
+
+        Ok(self.money(amount))
+    }
+}
+
+/// 资产注册表
+#[derive(Debug, Clone)]
+pub struct AssetRegistry {
+    assets: Vec<Asset>,
+    /// x402 收款使用的资产符号
+    payment_symbol: String,
+}
+
+impl AssetRegistry {
+    /// 内置资产加上 x402 配置的收款资产（同符号时以配置为准）
+    pub fn new(config: &X402Config) -> Self {
+        let chain = config.network.split('-').next().unwrap_or(&config.network);
+        let payment = Asset::new(&config.asset_name, config.asset_decimals, chain, &config.asset);
+
+        let mut assets = vec![
+            Asset::new("SUI", 9, "sui", "0x2::sui::SUI"),
+            Asset::new("SOL", 9, "solana", "So11111111111111111111111111111111111111112"),
+            Asset::new("USDC", 6, "solana", "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"),
+        ];
+        assets.retain(|a| !a.symbol.eq_ignore_ascii_case(&payment.symbol));
+        let payment_symbol = payment.symbol.clone();
+        assets.push(payment);
+
+        Self { assets, payment_symbol }
+    }
+
+    /// 按符号查找（不区分大小写）
+    pub fn get(&self, symbol: &str) -> Option<&Asset> {
+        self.assets.iter().find(|a| a.symbol.eq_ignore_ascii_case(symbol.trim()))
+    }
+
+    /// 按符号查找，未登记时报错
+    pub fn require(&self, symbol: &str) -> Result<&Asset, String> {
+        self.get(symbol).ok_or_else(|| format!("Unknown asset '{}'", symbol))
+    }
+
+    /// 按符号查找，未指定时使用 x402 收款资产
+    pub fn resolve(&self, symbol: Option<&str>) -> Result<&Asset, String> {
+        match symbol {
+            Some(symbol) => self.require(symbol),
+            None => Ok(self.payment_asset()),
+        }
+    }
+
+    /// x402 收款资产，新简历默认以它定价
+    pub fn payment_asset(&self) -> &Asset {
+        self.get(&self.payment_symbol).expect("payment asset is always registered")
+    }
+
+    /// 还原数据库中的金额；资产未登记时按 0 位精度处理并告警
+    pub fn stored(&self, amount: i64, symbol: &str) -> Money {
+        match self.get(symbol) {
+            Some(asset) => asset.money(amount),
+            None => {
+                log::warn!("Unknown asset '{}' in stored amount {}", symbol, amount);
+                Money { amount, asset: symbol.to_string(), decimals: 0 }
+            }
+        }
+    }
+}
+
+/// 全局资产注册表，首次使用时从 x402 环境配置构建
+pub fn registry() -> &'static AssetRegistry {
+    static REGISTRY: OnceLock<AssetRegistry> = OnceLock::new();
+    REGISTRY.get_or_init(|| AssetRegistry::new(&X402Config::from_env()))
+}
@@ -0,0 +1,112 @@
+pub mod asset;
+
+use std::fmt;
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+
+pub use asset::registry;
+
+/// 金额：资产最小单位的整数，附带资产符号和精度
+///
+/// 所有换算都基于整数和字符串，不经过浮点数。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Money {
+    /// 最小单位金额
+    pub amount: i64,
+    /// 资产符号
+    pub asset: String,
+    /// 资产精度
+    pub decimals: u8,
+}
+
+impl Money {
+    /// 同资产相加，资产不同或溢出时报错
+    pub fn checked_add(&self, other: &Money) -> Result<Money, String> {
+        if self.asset != other.asset {
+            return Err(format!("Cannot add {} to {}", other.asset, self.asset));
+        }
+        let amount = self
+            .amount
+            .checked_add(other.amount)
+            .ok_or_else(|| format!("{} amount overflow", self.asset))?;
+        Ok(Money { amount, ..self.clone() })
+    }
+
+    /// 按资产分组求和，保持首次出现的顺序
+    pub fn totals<'a>(items: impl IntoIterator<Item = &'a Money>) -> Result<Vec<Money>, String> {
+        let mut totals: Vec<Money> = Vec::new();
+        for item in items {
+            match totals.iter_mut().find(|t| t.asset == item.asset) {
+                Some(total) => *total = total.checked_add(item)?,
+                None => totals.push(item.clone()),
+            }
+        }
+        Ok(totals)
+    }
+
+    /// 十进制表示（保留全部精度），如 `1.250000`
+    pub fn to_decimal_string(&self) -> String {
+        let sign = if self.amount < 0 { "-" } else { "" };
+        let digits = self.amount.unsigned_abs().to_string();
+        let decimals = self.decimals as usize;
+        if decimals == 0 {
+            return format!("{}{}", sign, digits);
+        }
+
+        let padded = format!("{:0>width$}", digits, width = decimals + 1);
+        let (whole, fraction) = padded.split_at(padded.len() - decimals);
+        format!("{}{}.{}", sign, whole, fraction)
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.to_decimal_string(), self.asset)
+    }
+}
+
+/// 序列化为 `{ amount, asset, decimals, display }`，`amount` 为字符串以免 JS 丢失精度
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Money", 4)?;
+        state.serialize_field("amount", &self.amount.to_string())?;
+        state.serialize_field("asset", &self.asset)?;
+        state.serialize_field("decimals", &self.decimals)?;
+        state.serialize_field("display", &self.to_decimal_string())?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::asset::AssetRegistry;
+    use crate::models::X402Config;
+
+    #[test]
+    fn test_money_conversions_are_exact() {
+        let registry = AssetRegistry::new(&X402Config::default());
+        let usdc = registry.require("usdc").unwrap();
+        let sui = registry.require("SUI").unwrap();
+
+        assert_eq!(usdc.parse("5").unwrap().amount, 5_000_000);
+        assert_eq!(usdc.parse("0.000001").unwrap().amount, 1);
+        assert_eq!(usdc.parse(".5").unwrap().amount, 500_000);
+        assert_eq!(sui.parse("0.05").unwrap().amount, 50_000_000);
+        assert_eq!(sui.parse("0").unwrap().amount, 0);
+        assert!(usdc.parse("0.0000001").is_err());
+        assert!(usdc.parse("-1").is_err());
+        assert!(usdc.parse("1e6").is_err());
+        assert!(usdc.parse("99999999999999999").is_err());
+
+        assert_eq!(usdc.money(5_000_000).to_decimal_string(), "5.000000");
+        assert_eq!(sui.money(1).to_string(), "0.000000001 SUI");
+        assert_eq!(usdc.parse("123.45").unwrap().to_decimal_string(), "123.450000");
+
+        let totals = Money::totals(&[usdc.money(1), sui.money(2), usdc.money(3)]).unwrap();
+        assert_eq!(totals, vec![usdc.money(4), sui.money(2)]);
+        assert!(usdc.money(1).checked_add(&sui.money(1)).is_err());
+        assert_eq!(registry.payment_asset().symbol, "USDC");
+    }
+}
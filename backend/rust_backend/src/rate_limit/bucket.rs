@@ -0,0 +1,132 @@
+/// 一个路由组的限流参数：最多 `capacity` 次突发，`window_secs` 秒内补满
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub capacity: u32,
+    pub window_secs: u64,
+}
+
+impl RateLimit {
+    pub const fn new(capacity: u32, window_secs: u64) -> Self {
+        Self { capacity, window_secs }
+    }
+
+    /// 解析 `次数/秒数` 格式，如 `10/60`
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let (capacity, window) = value
+            .trim()
+            .split_once('/')
+            .ok_or_else(|| format!("Invalid rate limit '{}', expected <count>/<seconds>", value))?;
+
+        let capacity: u32 = capacity
+            .trim()
+            .parse()
+            .map_err(|_| format!("Invalid rate limit count in '{}'", value))?;
+        let window_secs: u64 = window
+            .trim()
+            .parse()
+            .map_err(|_| format!("Invalid rate limit window in '{}'", value))?;
+
+        if capacity == 0 || window_secs == 0 {
+            return Err(format!("Rate limit '{}' must be positive", value));
+        }
+
+        Ok(Self { capacity, window_secs })
+    }
+
+    /// 每毫秒补充的令牌数
+    fn refill_per_ms(&self) -> f64 {
+        self.capacity as f64 / (self.window_secs as f64 * 1000.0)
+    }
+}
+
+/// 令牌桶状态
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TokenBucket {
+    pub tokens: f64,
+    pub updated_at_ms: i64,
+}
+
+impl TokenBucket {
+    /// 新桶是满的
+    pub fn full(limit: &RateLimit, now_ms: i64) -> Self {
+        Self {
+            tokens: limit.capacity as f64,
+            updated_at_ms: now_ms,
+        }
+    }
+
+    /// 按经过的时间补充令牌
+    fn refill(&mut self, limit: &RateLimit, now_ms: i64) {
+        let elapsed = (now_ms - self.updated_at_ms).max(0) as f64;
+        self.tokens = (self.tokens + elapsed * limit.refill_per_ms()).min(limit.capacity as f64);
+        self.updated_at_ms = now_ms;
+    }
+
+    /// 所有桶都有令牌时各取一个；任一不足时都不扣减，返回最长需等待的毫秒数
+    pub fn take_all(buckets: &mut [TokenBucket], limit: &RateLimit, now_ms: i64) -> Result<(), u64> {
+        for bucket in buckets.iter_mut() {
+            bucket.refill(limit, now_ms);
+        }
+
+        let wait_ms = buckets
+            .iter()
+            .filter(|bucket| bucket.tokens < 1.0)
+            .map(|bucket| ((1.0 - bucket.tokens) / limit.refill_per_ms()).ceil() as u64)
+            .max();
+        if let Some(wait_ms) = wait_ms {
+            return Err(wait_ms);
+        }
+
+        for bucket in buckets.iter_mut() {
+            bucket.tokens -= 1.0;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rate_limit() {
+        assert_eq!(RateLimit::parse("10/60"), Ok(RateLimit::new(10, 60)));
+        assert_eq!(RateLimit::parse(" 5 / 3600 "), Ok(RateLimit::new(5, 3600)));
+        assert!(RateLimit::parse("10").is_err());
+        assert!(RateLimit::parse("0/60").is_err());
+        assert!(RateLimit::parse("ten/60").is_err());
+    }
+
+    #[test]
+    fn test_token_bucket_refill() {
+        // 2 次突发，每 10 秒补满（5 秒一个令牌）
+        let limit = RateLimit::new(2, 10);
+        let mut bucket = [TokenBucket::full(&limit, 0)];
+
+        assert!(TokenBucket::take_all(&mut bucket, &limit, 0).is_ok());
+        assert!(TokenBucket::take_all(&mut bucket, &limit, 0).is_ok());
+        assert_eq!(TokenBucket::take_all(&mut bucket, &limit, 0), Err(5000));
+
+        // 2 秒后还差 3 秒
+        assert_eq!(TokenBucket::take_all(&mut bucket, &limit, 2000), Err(3000));
+        assert!(TokenBucket::take_all(&mut bucket, &limit, 5000).is_ok());
+
+        // 长时间空闲不会超过容量
+        let mut bucket = [TokenBucket::full(&limit, 0)];
+        TokenBucket::take_all(&mut bucket, &limit, 1_000_000).unwrap();
+        assert_eq!(bucket[0].tokens, 1.0);
+    }
+
+    #[test]
+    fn test_take_all_is_all_or_nothing() {
+        let limit = RateLimit::new(1, 10);
+        let mut buckets = [TokenBucket::full(&limit, 0), TokenBucket { tokens: 0.0, updated_at_ms: 0 }];
+
+        // 第二个桶为空，第一个桶也不扣减
+        assert_eq!(TokenBucket::take_all(&mut buckets, &limit, 0), Err(10_000));
+        assert_eq!(buckets[0].tokens, 1.0);
+
+        assert!(TokenBucket::take_all(&mut buckets, &limit, 10_000).is_ok());
+        assert_eq!(buckets.map(|b| b.tokens), [0.0, 0.0]);
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use sea_orm::{DatabaseConnection, TransactionTrait};
+
+use crate::dao::RateLimitBucketDao;
+use crate::models::{RateLimitBackend, RateLimitConfig};
+use crate::rate_limit::bucket::{RateLimit, TokenBucket};
+
+/// 每处理多少次请求清理一次空闲的令牌桶
+const PRUNE_EVERY: u64 = 1000;
+
+/// 限流路由组，在路由策略表中为每条路由声明
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitGroup {
+    /// 未单独声明的路由
+    Default,
+    /// 申请挑战、登录
+    Login,
+    /// 用户注册
+    Register,
+    /// 写访问记录
+    AccessLog,
+    /// 公开的列表读取
+    PublicRead,
+}
+
+impl RateLimitGroup {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RateLimitGroup::Default => "default",
+            RateLimitGroup::Login => "login",
+            RateLimitGroup::Register => "register",
+            RateLimitGroup::AccessLog => "access_log",
+            RateLimitGroup::PublicRead => "public_read",
+        }
+    }
+}
+
+/// 令牌桶状态存储
+enum Store {
+    /// 单实例内存存储
+    Memory(Mutex<HashMap<String, TokenBucket>>),
+    /// 数据库存储，多实例共享
+    Database(DatabaseConnection),
+}
+
+/// 令牌桶限流器
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    store: Store,
+    calls: AtomicU64,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig, db: DatabaseConnection) -> Self {
+        let store = match config.backend {
+            RateLimitBackend::Memory => Store::Memory(Mutex::new(HashMap::new())),
+            RateLimitBackend::Database => Store::Database(db),
+        };
+
+        Self {
+            config,
+            store,
+            calls: AtomicU64::new(0),
+        }
+    }
+
+    pub fn config(&self) -> &RateLimitConfig {
+        &self.config
+    }
+
+    /// 路由组对应的限流参数
+    pub fn limit_for(&self, group: RateLimitGroup) -> RateLimit {
+        match group {
+            RateLimitGroup::Default => self.config.default,
+            RateLimitGroup::Login => self.config.login,
+            RateLimitGroup::Register => self.config.register,
+            RateLimitGroup::AccessLog => self.config.access_log,
+            RateLimitGroup::PublicRead => self.config.public_read,
+        }
+    }
+
+    /// 检查路由组下的每个桶；全部有令牌时各取一个，任一不足时都不扣减并返回最长需等待的毫秒数
+    pub async fn check(&self, group: RateLimitGroup, keys: &[String]) -> Result<(), u64> {
+        let limit = self.limit_for(group);
+        let now_ms = chrono::Utc::now().timestamp_millis();
+
+        // 固定加锁顺序，避免数据库存储的并发请求互相等待
+        let mut bucket_keys: Vec<String> = keys.iter().map(|key| format!("{}:{}", group.as_str(), key)).collect();
+        bucket_keys.sort();
+        bucket_keys.dedup();
+
+        let result = match &self.store {
+            Store::Memory(buckets) => {
+                let mut buckets = buckets.lock().unwrap_or_else(|e| e.into_inner());
+                let mut taken: Vec<TokenBucket> = bucket_keys
+                    .iter()
+                    .map(|key| buckets.get(key).copied().unwrap_or_else(|| TokenBucket::full(&limit, now_ms)))
+                    .collect();
+                let result = TokenBucket::take_all(&mut taken, &limit, now_ms);
+                for (key, bucket) in bucket_keys.iter().zip(taken) {
+                    buckets.insert(key.clone(), bucket);
+                }
+                result
+            }
+            Store::Database(db) => match Self::take_from_db(db, &bucket_keys, &limit, now_ms).await {
+                Ok(result) => result,
+                Err(e) => {
+                    // 限流存储故障时放行，不影响正常业务
+                    log::warn!("Rate limit store error for {:?}: {}", bucket_keys, e);
+                    Ok(())
+                }
+            },
+        };
+
+        if self.calls.fetch_add(1, Ordering::Relaxed) % PRUNE_EVERY == PRUNE_EVERY - 1 {
+            self.prune(now_ms).await;
+        }
+
+        result
+    }
+
+    async fn take_from_db(
+        db: &DatabaseConnection,
+        bucket_keys: &[String],
+        limit: &RateLimit,
+        now_ms: i64,
+    ) -> Result<Result<(), u64>, String> {
+        let txn = db
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        let mut buckets = Vec::with_capacity(bucket_keys.len());
+        for bucket_key in bucket_keys {
+            let full = TokenBucket::full(limit, now_ms);
+            RateLimitBucketDao::create_if_missing(&txn, bucket_key.clone(), full.tokens, full.updated_at_ms)
+                .await
+                .map_err(|e| format!("Failed to create bucket: {}", e))?;
+            let row = RateLimitBucketDao::find_for_update(&txn, bucket_key)
+                .await
+                .map_err(|e| format!("Failed to fetch bucket: {}", e))?
+                .ok_or_else(|| format!("Bucket {} disappeared", bucket_key))?;
+            buckets.push(TokenBucket { tokens: row.tokens, updated_at_ms: row.updated_at_ms });
+        }
+
+        let result = TokenBucket::take_all(&mut buckets, limit, now_ms);
+
+        for (bucket_key, bucket) in bucket_keys.iter().zip(&buckets) {
+            RateLimitBucketDao::save(&txn, bucket_key.clone(), bucket.tokens, bucket.updated_at_ms)
+                .await
+                .map_err(|e| format!("Failed to save bucket: {}", e))?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| format!("Failed to commit bucket: {}", e))?;
+
+        Ok(result)
+    }
+
+    /// 清理空闲超过最长补满窗口的桶，这些桶已经补满，与不存在等价
+    async fn prune(&self, now_ms: i64) {
+        let max_window_secs = [
+            self.config.default,
+            self.config.login,
+            self.config.register,
+            self.config.access_log,
+            self.config.public_read,
+        ]
+        .iter()
+        .map(|limit| limit.window_secs)
+        .max()
+        .unwrap_or(0);
+        let before_ms = now_ms - (max_window_secs * 1000) as i64;
+
+        match &self.store {
+            Store::Memory(buckets) => {
+                let mut buckets = buckets.lock().unwrap_or_else(|e| e.into_inner());
+                buckets.retain(|_, bucket| bucket.updated_at_ms >= before_ms);
+            }
+            Store::Database(db) => {
+                if let Err(e) = RateLimitBucketDao::delete_idle(db, before_ms).await {
+                    log::warn!("Failed to prune rate limit buckets: {}", e);
+                }
+            }
+        }
+    }
+}
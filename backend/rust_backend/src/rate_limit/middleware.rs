@@ -0,0 +1,90 @@
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::{header, StatusCode},
+    middleware::Next,
+    web, Error, HttpMessage, HttpResponse, ResponseError,
+};
+
+use crate::auth::policy::find_policy;
+use crate::auth::AuthenticatedWallet;
+use crate::models::ApiResponse;
+use crate::rate_limit::{RateLimitGroup, RateLimiter};
+
+/// 请求被限流 (429)
+#[derive(Debug, thiserror::Error)]
+#[error("Too many requests, retry after {retry_after_secs} seconds")]
+pub struct RateLimitError {
+    pub retry_after_secs: u64,
+}
+
+impl ResponseError for RateLimitError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::TOO_MANY_REQUESTS
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code())
+            .insert_header((header::RETRY_AFTER, self.retry_after_secs.to_string()))
+            .json(ApiResponse::<()>::error(self.to_string()))
+    }
+}
+
+/// 按客户端 IP 限流，在认证之前执行，未认证的请求同样计数
+///
+/// 未配置 [`RateLimiter`] 时直接放行。
+pub async fn enforce_ip(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    if let Some(limiter) = enabled_limiter(&req) {
+        let client_ip = if limiter.config().trust_proxy {
+            req.connection_info().realip_remote_addr().map(|s| s.to_string())
+        } else {
+            req.peer_addr().map(|addr| addr.ip().to_string())
+        };
+        check(&req, &limiter, format!("ip:{}", client_ip.as_deref().unwrap_or("unknown"))).await?;
+    }
+
+    next.call(req).await
+}
+
+/// 按钱包或 API Key 限流，在认证之后执行（认证中间件把会话写入请求扩展）；未认证的请求直接放行
+pub async fn enforce_identity(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let key = req.extensions().get::<AuthenticatedWallet>().map(|wallet| match wallet.api_key() {
+        Some(key) => format!("api_key:{}", key.id),
+        None => format!("wallet:{}", wallet.wallet()),
+    });
+    if let (Some(limiter), Some(key)) = (enabled_limiter(&req), key) {
+        check(&req, &limiter, key).await?;
+    }
+
+    next.call(req).await
+}
+
+fn enabled_limiter(req: &ServiceRequest) -> Option<web::Data<RateLimiter>> {
+    req.app_data::<web::Data<RateLimiter>>()
+        .cloned()
+        .filter(|limiter| limiter.config().enabled)
+}
+
+/// 从路由策略表中该路由所属限流组的桶里取一个令牌
+async fn check(req: &ServiceRequest, limiter: &RateLimiter, key: String) -> Result<(), Error> {
+    let group = req
+        .match_pattern()
+        .and_then(|pattern| find_policy(req.method(), &pattern))
+        .map(|p| p.rate_limit)
+        .unwrap_or(RateLimitGroup::Default);
+
+    if let Err(wait_ms) = limiter.check(group, std::slice::from_ref(&key)).await {
+        log::warn!("Rate limited {} {} ({:?}, key {})", req.method(), req.path(), group, key);
+        return Err(RateLimitError {
+            retry_after_secs: wait_ms.div_ceil(1000).max(1),
+        }
+        .into());
+    }
+    Ok(())
+}
@@ -0,0 +1,6 @@
+pub mod bucket;
+pub mod limiter;
+pub mod middleware;
+
+pub use bucket::RateLimit;
+pub use limiter::{RateLimitGroup, RateLimiter};
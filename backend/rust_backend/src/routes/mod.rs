@@ -1,73 +1,380 @@
-use actix_web::web;
-use crate::controllers::{weather_handler, premium_content_handler, ResumeController, UnlockRecordController, AccessLogController};
+use actix_web::{http::Method, web};
+use crate::auth::policy::route;
+use crate::controllers::{weather_handler, premium_content_handler, ResumeController, UnlockRecordController, AccessLogController, AuthController, OrganizationController, ApiKeyController, UserWalletController, ReceiptController, PayoutController, CreditController, CouponController, AllowlistController, IndexerController};
 use crate::controllers::user_controller;
 
 /// 配置示例路由
-#[allow(dead_code)]
 pub fn config_example_routes(cfg: &mut web::ServiceConfig) {
+    const SCOPE: &str = "/api";
     cfg.service(
-        web::scope("/api")
-            .route("/weather", web::get().to(weather_handler))
-            .route("/premium/content", web::get().to(premium_content_handler)),
+        web::scope(SCOPE)
+            .service(route(SCOPE, Method::GET, "/weather", weather_handler))
+            // x402 付费内容
+            .service(route(SCOPE, Method::GET, "/premium/content", premium_content_handler)),
+    );
+}
+
+/// 配置登录认证路由
+pub fn config_auth_routes(cfg: &mut web::ServiceConfig) {
+    const SCOPE: &str = "/api/auth";
+    cfg.service(
+        web::scope(SCOPE)
+            // 申请登录挑战 nonce
+            .service(route(SCOPE, Method::POST, "/challenge", AuthController::challenge))
+            // 提交钱包签名，换取会话 token
+            .service(route(SCOPE, Method::POST, "/login", AuthController::login))
+            // 注销当前会话
+            .service(route(SCOPE, Method::POST, "/logout", AuthController::logout))
+            // 会话（设备）管理
+            .service(route(SCOPE, Method::GET, "/sessions", AuthController::list_sessions))
+            .service(route(SCOPE, Method::DELETE, "/sessions/{session_id}", AuthController::revoke_session))
+            .service(route(SCOPE, Method::POST, "/sessions/revoke-others", AuthController::revoke_other_sessions)),
     );
 }
 
 /// 配置用户路由
 pub fn config_user_routes(cfg: &mut web::ServiceConfig) {
+    // 关联钱包的 scope 需在 /api/users 之前注册，否则会被其先匹配
+    const WALLETS_SCOPE: &str = "/api/users/wallets";
+    cfg.service(
+        web::scope(WALLETS_SCOPE)
+            // 申请关联挑战、提交签名完成关联
+            .service(route(WALLETS_SCOPE, Method::POST, "/challenge", UserWalletController::challenge))
+            .service(route(WALLETS_SCOPE, Method::POST, "", UserWalletController::link))
+            // 列出和取消关联
+            .service(route(WALLETS_SCOPE, Method::GET, "", UserWalletController::list))
+            .service(route(WALLETS_SCOPE, Method::DELETE, "/{wallet_id}", UserWalletController::unlink)),
+    );
+
     user_controller::config(cfg);
 }
 
 /// 配置简历路由(包含所有简历相关路由)
 pub fn config_resume_routes(cfg: &mut web::ServiceConfig) {
+    const SCOPE: &str = "/api/resumes";
     cfg.service(
-        web::scope("/api/resumes")
+        web::scope(SCOPE)
             // 公开路由(无需支付)
-            .route("", web::post().to(ResumeController::create))
-            .route("/summaries", web::get().to(ResumeController::get_summaries))
-            .route("/my/{owner}", web::get().to(ResumeController::get_my_resumes))
-            .route("/detail/{resume_id}/{owner}", web::get().to(ResumeController::get_resume_detail))
-            
+            .service(route(SCOPE, Method::POST, "", ResumeController::create))
+            .service(route(SCOPE, Method::GET, "/summaries", ResumeController::get_summaries))
+            .service(route(SCOPE, Method::GET, "/my/{owner}", ResumeController::get_my_resumes))
+            .service(route(SCOPE, Method::GET, "/detail/{resume_id}", ResumeController::get_resume_detail))
+
+            // 价格档位
+            .service(route(SCOPE, Method::GET, "/{resume_id}/tiers", ResumeController::get_tiers))
+            .service(route(SCOPE, Method::PUT, "/{resume_id}/tiers", ResumeController::set_tiers))
+
+            // allowlist 成员（仅限所有者）
+            .service(route(SCOPE, Method::GET, "/{resume_id}/allowlist-members", AllowlistController::list_members))
+            .service(route(SCOPE, Method::POST, "/{resume_id}/allowlist-members", AllowlistController::add_member))
+            .service(route(SCOPE, Method::DELETE, "/{resume_id}/allowlist-members/{member_address}", AllowlistController::remove_member))
+
+            // x402 付费解锁（按档位）
+            .service(route(SCOPE, Method::POST, "/{resume_id}/unlock", ResumeController::unlock))
+            // 预付额度解锁
+            .service(route(SCOPE, Method::POST, "/{resume_id}/unlock/credits", ResumeController::unlock_with_credits))
+
             // 管理路由
-            .route("/price", web::put().to(ResumeController::set_price))
-            .route("/name", web::put().to(ResumeController::update_name))
-            .route("/{resume_id}", web::put().to(ResumeController::update))
-            .route("/{resume_id}/{owner}", web::delete().to(ResumeController::delete)),
+            .service(route(SCOPE, Method::PUT, "/price", ResumeController::set_price))
+            .service(route(SCOPE, Method::PUT, "/name", ResumeController::update_name))
+            .service(route(SCOPE, Method::PUT, "/{resume_id}", ResumeController::update))
+            .service(route(SCOPE, Method::DELETE, "/{resume_id}", ResumeController::delete)),
     );
 }
 
-/// 配置解锁简历路由 - 已废弃
-#[allow(dead_code)]
-pub fn config_unlock_routes(_cfg: &mut web::ServiceConfig) {
-    // 此函数已废弃，unlock 路由已从项目中移除
-    // 保留此函数是为了不破坏 main.rs 的调用，但它现在是空的
-}
-
 /// 配置解锁记录路由
 pub fn config_unlock_record_routes(cfg: &mut web::ServiceConfig) {
+    const SCOPE: &str = "/api/unlock-records";
     cfg.service(
-        web::scope("/api/unlock-records")
+        web::scope(SCOPE)
             // 创建解锁记录（支付成功后调用）
-            .route("", web::post().to(UnlockRecordController::create))
+            .service(route(SCOPE, Method::POST, "", UnlockRecordController::create))
             // 检查解锁状态
-            .route("/check/{resume_id}/{buyer_id}", web::get().to(UnlockRecordController::check_unlock))
+            .service(route(SCOPE, Method::GET, "/check/{resume_id}/{buyer_id}", UnlockRecordController::check_unlock))
             // 获取用户已解锁的简历列表
-            .route("/buyer/{buyer_wallet}", web::get().to(UnlockRecordController::get_unlocked_resumes))
+            .service(route(SCOPE, Method::GET, "/buyer/{buyer_wallet}", UnlockRecordController::get_unlocked_resumes))
             // 获取简历的解锁记录（所有者查看）
-            .route("/resume/{resume_id}", web::get().to(UnlockRecordController::get_resume_unlock_records)),
+            .service(route(SCOPE, Method::GET, "/resume/{resume_id}", UnlockRecordController::get_resume_unlock_records))
+            // 重新校验 pending 的解锁记录
+            .service(route(SCOPE, Method::POST, "/{record_id}/verify", UnlockRecordController::verify))
+            // 管理员：争议列表和裁决
+            .service(route(SCOPE, Method::GET, "/disputes", UnlockRecordController::list_disputes))
+            .service(route(SCOPE, Method::POST, "/disputes/{dispute_id}/resolve", UnlockRecordController::resolve_dispute))
+            // 买家发起争议，买家和卖家查看争议
+            .service(route(SCOPE, Method::POST, "/{record_id}/dispute", UnlockRecordController::open_dispute))
+            .service(route(SCOPE, Method::GET, "/{record_id}/dispute", UnlockRecordController::get_dispute)),
+    );
+}
+
+/// 配置招聘组织路由
+pub fn config_organization_routes(cfg: &mut web::ServiceConfig) {
+    const SCOPE: &str = "/api/organizations";
+    cfg.service(
+        web::scope(SCOPE)
+            // 创建组织
+            .service(route(SCOPE, Method::POST, "", OrganizationController::create))
+            // 我加入的组织
+            .service(route(SCOPE, Method::GET, "/my", OrganizationController::get_my_organizations))
+            // 成员管理
+            .service(route(SCOPE, Method::GET, "/{org_id}/members", OrganizationController::get_members))
+            .service(route(SCOPE, Method::POST, "/{org_id}/members", OrganizationController::add_member))
+            .service(route(SCOPE, Method::DELETE, "/{org_id}/members/{user_id}", OrganizationController::remove_member))
+            // 组织级解锁记录和消费统计
+            .service(route(SCOPE, Method::GET, "/{org_id}/unlocks", OrganizationController::get_unlocks))
+            .service(route(SCOPE, Method::GET, "/{org_id}/spend", OrganizationController::get_spend)),
+    );
+}
+
+/// 配置 API Key 管理路由
+pub fn config_api_key_routes(cfg: &mut web::ServiceConfig) {
+    const SCOPE: &str = "/api/api-keys";
+    cfg.service(
+        web::scope(SCOPE)
+            // 创建和列出 Key
+            .service(route(SCOPE, Method::POST, "", ApiKeyController::create))
+            .service(route(SCOPE, Method::GET, "", ApiKeyController::list))
+            // 轮换和吊销
+            .service(route(SCOPE, Method::POST, "/{key_id}/rotate", ApiKeyController::rotate))
+            .service(route(SCOPE, Method::DELETE, "/{key_id}", ApiKeyController::revoke))
+            // 审计日志
+            .service(route(SCOPE, Method::GET, "/{key_id}/audit", ApiKeyController::get_audit_logs)),
+    );
+}
+
+/// 配置付费收据路由
+pub fn config_receipt_routes(cfg: &mut web::ServiceConfig) {
+    const SCOPE: &str = "/api/receipts";
+    cfg.service(
+        web::scope(SCOPE)
+            // 签名公钥（需在 /{receipt_id} 之前注册）
+            .service(route(SCOPE, Method::GET, "/public-key", ReceiptController::get_public_key))
+            // 获取收据（买家或卖家）
+            .service(route(SCOPE, Method::GET, "/{receipt_id}", ReceiptController::get_receipt)),
+    );
+}
+
+/// 配置卖家结算路由
+pub fn config_payout_routes(cfg: &mut web::ServiceConfig) {
+    const SCOPE: &str = "/api/payouts";
+    cfg.service(
+        web::scope(SCOPE)
+            // 卖家余额、账目和结算批次
+            .service(route(SCOPE, Method::GET, "/balance", PayoutController::get_balance))
+            .service(route(SCOPE, Method::GET, "/ledger", PayoutController::get_ledger))
+            .service(route(SCOPE, Method::GET, "", PayoutController::get_payouts))
+            // 管理员：打包结算批次、标记已打款
+            .service(route(SCOPE, Method::POST, "/batches", PayoutController::create_batches))
+            .service(route(SCOPE, Method::POST, "/{payout_id}/paid", PayoutController::mark_paid)),
+    );
+}
+
+/// 配置预付额度路由
+pub fn config_credit_routes(cfg: &mut web::ServiceConfig) {
+    const SCOPE: &str = "/api/credits";
+    cfg.service(
+        web::scope(SCOPE)
+            // 额度余额和流水
+            .service(route(SCOPE, Method::GET, "/balance", CreditController::get_balance))
+            .service(route(SCOPE, Method::GET, "/ledger", CreditController::get_ledger))
+            // 额度套餐（管理员维护）
+            .service(route(SCOPE, Method::GET, "/bundles", CreditController::get_bundles))
+            .service(route(SCOPE, Method::POST, "/bundles", CreditController::create_bundle))
+            .service(route(SCOPE, Method::DELETE, "/bundles/{bundle_id}", CreditController::deactivate_bundle))
+            // x402 购买套餐充值
+            .service(route(SCOPE, Method::POST, "/bundles/{bundle_id}/purchase", CreditController::purchase_bundle)),
+    );
+}
+
+/// 配置优惠码路由（管理员）
+pub fn config_coupon_routes(cfg: &mut web::ServiceConfig) {
+    const SCOPE: &str = "/api/coupons";
+    cfg.service(
+        web::scope(SCOPE)
+            .service(route(SCOPE, Method::POST, "", CouponController::create))
+            .service(route(SCOPE, Method::GET, "", CouponController::list))
+            .service(route(SCOPE, Method::DELETE, "/{code}", CouponController::deactivate)),
+    );
+}
+
+/// 配置链上事件索引路由
+pub fn config_indexer_routes(cfg: &mut web::ServiceConfig) {
+    const SCOPE: &str = "/api/indexer";
+    cfg.service(
+        web::scope(SCOPE)
+            // 各事件流的游标和延迟
+            .service(route(SCOPE, Method::GET, "/status", IndexerController::status)),
     );
 }
 
 /// 配置访问记录路由
 pub fn config_access_log_routes(cfg: &mut web::ServiceConfig) {
+    const SCOPE: &str = "/api/access-logs";
     cfg.service(
-        web::scope("/api/access-logs")
+        web::scope(SCOPE)
             // 创建访问记录
-            .route("", web::post().to(AccessLogController::create))
+            .service(route(SCOPE, Method::POST, "", AccessLogController::create))
             // 获取简历的访问记录
-            .route("/resume/{resume_id}", web::get().to(AccessLogController::get_resume_logs))
+            .service(route(SCOPE, Method::GET, "/resume/{resume_id}", AccessLogController::get_resume_logs))
             // 获取访问者的访问记录
-            .route("/accessor/{accessor}", web::get().to(AccessLogController::get_accessor_logs))
+            .service(route(SCOPE, Method::GET, "/accessor/{accessor}", AccessLogController::get_accessor_logs))
             // 统计简历访问次数
-            .route("/count/{resume_id}", web::get().to(AccessLogController::count_resume_access)),
+            .service(route(SCOPE, Method::GET, "/count/{resume_id}", AccessLogController::count_resume_access)),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{test, App};
+
+    /// 所有路由都必须能在策略表中找到条目（缺失时构建 App 会 panic）
+    #[actix_web::test]
+    async fn test_all_routes_have_policies() {
+        let app = test::init_service(
+            App::new()
+                .configure(config_auth_routes)
+                .configure(config_user_routes)
+                .configure(config_resume_routes)
+                .configure(config_unlock_record_routes)
+                .configure(config_organization_routes)
+                .configure(config_api_key_routes)
+                .configure(config_access_log_routes)
+                .configure(config_receipt_routes)
+                .configure(config_payout_routes)
+                .configure(config_credit_routes)
+                .configure(config_coupon_routes)
+                .configure(config_indexer_routes)
+                .configure(config_example_routes),
+        )
+        .await;
+
+        // 写路由在没有会话时直接被拒绝，不会进入 handler
+        let req = test::TestRequest::delete().uri("/api/resumes/resume-1").to_request();
+        let err = test::try_call_service(&app, req).await.unwrap_err();
+        assert_eq!(err.as_response_error().status_code(), actix_web::http::StatusCode::UNAUTHORIZED);
+
+        // 解锁状态会暴露谁买了谁的简历，同样需要登录
+        let req = test::TestRequest::get().uri("/api/unlock-records/check/1/2").to_request();
+        let err = test::try_call_service(&app, req).await.unwrap_err();
+        assert_eq!(err.as_response_error().status_code(), actix_web::http::StatusCode::UNAUTHORIZED);
+    }
+
+    /// 超出限流组的令牌后返回 429 和 Retry-After
+    #[actix_web::test]
+    async fn test_login_routes_are_rate_limited() {
+        use crate::models::RateLimitConfig;
+        use crate::rate_limit::{RateLimit, RateLimiter};
+
+        let config = RateLimitConfig {
+            login: RateLimit::new(1, 60),
+            ..RateLimitConfig::default()
+        };
+        let limiter = RateLimiter::new(config, sea_orm::DatabaseConnection::Disconnected);
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(limiter))
+                .configure(config_auth_routes),
+        )
+        .await;
+
+        let challenge = || {
+            test::TestRequest::post()
+                .uri("/api/auth/challenge")
+                .set_json(serde_json::json!({ "wallet_address": "0x1" }))
+                .to_request()
+        };
+
+        // 第一次请求消耗唯一的令牌（没有数据库，handler 本身会失败）
+        let _ = test::try_call_service(&app, challenge()).await;
+
+        let err = test::try_call_service(&app, challenge()).await.unwrap_err();
+        let response = err.error_response();
+        assert_eq!(response.status(), actix_web::http::StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers().get("retry-after").unwrap(), "60");
+    }
+
+    /// 未认证的请求在认证之前按 IP 限流，注册接口被刷时不会每次都查询会话
+    #[actix_web::test]
+    async fn test_unauthenticated_requests_are_rate_limited_before_auth() {
+        use crate::models::RateLimitConfig;
+        use crate::rate_limit::{RateLimit, RateLimiter};
+
+        let config = RateLimitConfig {
+            register: RateLimit::new(1, 60),
+            ..RateLimitConfig::default()
+        };
+        let limiter = RateLimiter::new(config, sea_orm::DatabaseConnection::Disconnected);
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(limiter))
+                .configure(config_user_routes),
+        )
+        .await;
+
+        let register = || test::TestRequest::post().uri("/api/users/register").to_request();
+
+        let err = test::try_call_service(&app, register()).await.unwrap_err();
+        assert_eq!(err.as_response_error().status_code(), actix_web::http::StatusCode::UNAUTHORIZED);
+
+        let err = test::try_call_service(&app, register()).await.unwrap_err();
+        assert_eq!(err.as_response_error().status_code(), actix_web::http::StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    /// 付费路由未携带 X-PAYMENT 时返回 402 和 x402 支付要求
+    #[actix_web::test]
+    async fn test_paid_route_requires_payment() {
+        use crate::models::X402Config;
+        use crate::x402::types::{PaymentPayload, SCHEME_EXACT, X402_VERSION};
+        use crate::x402::{Facilitator, MockFacilitator};
+
+        let config = X402Config {
+            pay_to: "payee".to_string(),
+            ..X402Config::default()
+        };
+        let facilitator: std::sync::Arc<dyn Facilitator> = std::sync::Arc::new(MockFacilitator::new());
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(config))
+                .app_data(web::Data::from(facilitator))
+                .configure(config_example_routes),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/api/premium/content").to_request();
+        let err = test::try_call_service(&app, req).await.unwrap_err();
+        let response = err.error_response();
+        assert_eq!(response.status(), actix_web::http::StatusCode::PAYMENT_REQUIRED);
+
+        let body = actix_web::body::to_bytes(response.into_body()).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["x402Version"], 1);
+        assert_eq!(body["accepts"][0]["payTo"], "payee");
+        assert_eq!(body["accepts"][0]["maxAmountRequired"], "10000");
+
+        // 通过 mock facilitator 验证并结算
+        let payment = PaymentPayload {
+            x402_version: X402_VERSION,
+            scheme: SCHEME_EXACT.to_string(),
+            network: body["accepts"][0]["network"].as_str().unwrap().to_string(),
+            payload: serde_json::json!({ "transaction": "AQID" }),
+        };
+        let paid = || {
+            test::TestRequest::get()
+                .uri("/api/premium/content")
+                .insert_header(("X-PAYMENT", payment.to_header()))
+                .to_request()
+        };
+        let res = test::call_service(&app, paid()).await;
+        assert!(res.status().is_success());
+        assert!(res.headers().contains_key("x-payment-response"));
+
+        // 同一笔交易不能重复使用
+        let res = test::call_service(&app, paid()).await;
+        assert_eq!(res.status(), actix_web::http::StatusCode::PAYMENT_REQUIRED);
+
+        // 免费路由不受影响
+        let req = test::TestRequest::get().uri("/api/weather").to_request();
+        let res = test::call_service(&app, req).await;
+        assert!(res.status().is_success());
+    }
+}
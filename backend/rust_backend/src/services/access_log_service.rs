@@ -6,6 +6,7 @@ pub struct AccessLogService;
 
 impl AccessLogService {
     /// 创建访问记录
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_access_log(
         db: &DatabaseConnection,
         resume_id: String,
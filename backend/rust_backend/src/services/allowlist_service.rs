@@ -0,0 +1,341 @@
+use std::collections::HashMap;
+use sea_orm::DatabaseConnection;
+use crate::auth::{AuthError, AuthenticatedWallet};
+use crate::dao::{AllowlistDao, AllowlistMemberDao, ResumeDao, ResumeTierDao, UnlockRecordDao, UserWalletDao};
+use crate::entities::{allowlist_member, resume};
+use crate::models::{AddAllowlistMemberRequest, AllowlistMemberResponse};
+use crate::utils::validator::Validator;
+
+/// 交易摘要最大长度（与 allowlist_members.tx_digest 一致）
+const MAX_TX_DIGEST_LEN: usize = 150;
+
+/// 根据链上 allowlist 事件同步 `allowlists` / `allowlist_members` / `allowlist_blobs`
+pub struct AllowlistService;
+
+impl AllowlistService {
+    /// 记录 allowlist 的创建者；简历已用该 allowlist 作为策略时一并关联
+    pub async fn record_created(
+        db: &DatabaseConnection,
+        allowlist_id: &str,
+        name: &str,
+        creator: &str,
+        tx_digest: &str,
+    ) -> Result<(), String> {
+        let allowlist_id = Self::normalize(allowlist_id)?;
+        let creator = Self::normalize(creator)?;
+        let resume_id = Self::resolve_resume_id(db, &allowlist_id).await?;
+
+        AllowlistDao::create_if_missing(
+            db,
+            allowlist_id,
+            name.to_string(),
+            creator,
+            resume_id,
+            tx_digest.to_string(),
+        )
+        .await
+        .map_err(|e| format!("Failed to record allowlist: {}", e))?;
+
+        Ok(())
+    }
+
+    /// 成员加入 allowlist
+    pub async fn add_member(
+        db: &DatabaseConnection,
+        allowlist_id: &str,
+        account: &str,
+        operator: &str,
+        tx_digest: &str,
+    ) -> Result<(), String> {
+        let allowlist_id = Self::normalize(allowlist_id)?;
+        let account = Self::normalize(account)?;
+        let operator = Self::normalize(operator)?;
+        let resume_id = Self::resolve_resume_id(db, &allowlist_id).await?;
+
+        AllowlistMemberDao::upsert_active(db, allowlist_id, account, resume_id, operator, tx_digest.to_string())
+            .await
+            .map_err(|e| format!("Failed to upsert allowlist member: {}", e))?;
+
+        Ok(())
+    }
+
+    /// 成员被移出 allowlist（未记录过的成员忽略）
+    pub async fn remove_member(
+        db: &DatabaseConnection,
+        allowlist_id: &str,
+        account: &str,
+        tx_digest: &str,
+    ) -> Result<(), String> {
+        let allowlist_id = Self::normalize(allowlist_id)?;
+        let account = Self::normalize(account)?;
+
+        AllowlistMemberDao::mark_removed(db, &allowlist_id, &account, Some(tx_digest))
+            .await
+            .map_err(|e| format!("Failed to remove allowlist member: {}", e))?;
+
+        Ok(())
+    }
+
+    /// 记录发布到 allowlist 的 blob，并按 blob ID 将 allowlist 及其成员关联到简历
+    pub async fn publish_blob(
+        db: &DatabaseConnection,
+        allowlist_id: &str,
+        blob_id: &str,
+        publisher: &str,
+        tx_digest: &str,
+    ) -> Result<(), String> {
+        let allowlist_id = Self::normalize(allowlist_id)?;
+        let publisher = Self::normalize(publisher)?;
+        let resume_id = Self::find_resume_by_blob(db, blob_id).await?;
+
+        AllowlistDao::create_blob_if_missing(
+            db,
+            allowlist_id.clone(),
+            blob_id.to_string(),
+            publisher,
+            resume_id,
+            tx_digest.to_string(),
+        )
+        .await
+        .map_err(|e| format!("Failed to record allowlist blob: {}", e))?;
+
+        if let Some(resume_id) = resume_id {
+            AllowlistDao::link_resume(db, &allowlist_id, resume_id)
+                .await
+                .map_err(|e| format!("Failed to link allowlist to resume: {}", e))?;
+            AllowlistMemberDao::link_resume(db, &allowlist_id, resume_id)
+                .await
+                .map_err(|e| format!("Failed to link allowlist members to resume: {}", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// 查找 allowlist 对应的简历：已关联的简历 > 以其为策略的简历 > 以其为策略的档位所属简历
+    async fn resolve_resume_id(db: &DatabaseConnection, allowlist_id: &str) -> Result<Option<i64>, String> {
+        if let Some(allowlist) = AllowlistDao::find_by_allowlist_id(db, allowlist_id)
+            .await
+            .map_err(|e| format!("Failed to fetch allowlist: {}", e))?
+            && allowlist.resume_id.is_some()
+        {
+            return Ok(allowlist.resume_id);
+        }
+
+        if let Some(resume) = ResumeDao::find_by_policy_object_id(db, allowlist_id)
+            .await
+            .map_err(|e| format!("Failed to fetch resume: {}", e))?
+        {
+            return Ok(Some(resume.id));
+        }
+
+        let tier = ResumeTierDao::find_by_policy_object_id(db, allowlist_id)
+            .await
+            .map_err(|e| format!("Failed to fetch resume tier: {}", e))?;
+        Ok(tier.map(|t| t.resume_id))
+    }
+
+    /// 查找 blob 所属的简历（简历本身或其某个档位）
+    async fn find_resume_by_blob(db: &DatabaseConnection, blob_id: &str) -> Result<Option<i64>, String> {
+        if let Some(resume) = ResumeDao::find_by_blob_id(db, blob_id)
+            .await
+            .map_err(|e| format!("Failed to fetch resume: {}", e))?
+        {
+            return Ok(Some(resume.id));
+        }
+
+        let tier = ResumeTierDao::find_by_blob_id(db, blob_id)
+            .await
+            .map_err(|e| format!("Failed to fetch resume tier: {}", e))?;
+        Ok(tier.map(|t| t.resume_id))
+    }
+
+    /// 获取简历，只有所有者（或管理员 override）可以管理其 allowlist 成员
+    pub async fn get_resume_for_owner(
+        db: &DatabaseConnection,
+        resume_id: &str,
+        wallet: &AuthenticatedWallet,
+    ) -> Result<Option<resume::Model>, AuthError> {
+        let Some(resume) = ResumeDao::find_by_resume_id(db, resume_id)
+            .await
+            .map_err(|e| AuthError::Internal(format!("Failed to fetch resume: {}", e)))?
+            .filter(|r| r.status != "deleted")
+        else {
+            return Ok(None);
+        };
+
+        wallet.require_owner(Some(resume.owner_id), "this resume")?;
+        Ok(Some(resume))
+    }
+
+    /// 列出简历（及其各档位）allowlist 的成员，并标注成员是付费加入还是手动添加
+    pub async fn list_members(
+        db: &DatabaseConnection,
+        resume: &resume::Model,
+    ) -> Result<Vec<AllowlistMemberResponse>, String> {
+        let policies = Self::policies_of(db, resume).await?;
+        if policies.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let members = AllowlistMemberDao::find_by_policies(db, &policies)
+            .await
+            .map_err(|e| format!("Failed to fetch allowlist members: {}", e))?;
+        let purchases = Self::purchases_by_wallet(db, resume.id).await?;
+
+        Ok(members
+            .into_iter()
+            .map(|member| {
+                let unlock_record_id = purchases.get(&member.member_address).copied();
+                Self::to_response(member, unlock_record_id)
+            })
+            .collect())
+    }
+
+    /// 记录所有者已提交的添加交易，成员在链上事件确认前为 pending
+    pub async fn record_pending_member(
+        db: &DatabaseConnection,
+        resume: &resume::Model,
+        added_by: &str,
+        request: AddAllowlistMemberRequest,
+    ) -> Result<AllowlistMemberResponse, String> {
+        let member_address = Self::normalize(&request.member_address)?;
+        let tx_digest = Self::validate_tx_digest(&request.tx_digest)?;
+        let policy_object_id = Self::resolve_policy(db, resume, request.policy_object_id.as_deref()).await?;
+
+        let member = AllowlistMemberDao::record_pending(
+            db,
+            policy_object_id,
+            member_address,
+            resume.id,
+            Self::normalize(added_by)?,
+            tx_digest,
+        )
+        .await
+        .map_err(|e| format!("Failed to record allowlist member: {}", e))?
+        .ok_or_else(|| "Address is already an active member of this allowlist".to_string())?;
+
+        let purchases = Self::purchases_by_wallet(db, resume.id).await?;
+        let unlock_record_id = purchases.get(&member.member_address).copied();
+        Ok(Self::to_response(member, unlock_record_id))
+    }
+
+    /// 标记成员已从简历的 allowlist 中移除，返回被移除的成员数
+    pub async fn record_removal(
+        db: &DatabaseConnection,
+        resume: &resume::Model,
+        member_address: &str,
+        tx_digest: Option<&str>,
+    ) -> Result<usize, String> {
+        let member_address = Self::normalize(member_address)?;
+        let tx_digest = tx_digest.map(Self::validate_tx_digest).transpose()?;
+
+        let mut removed = 0;
+        for policy_object_id in Self::policies_of(db, resume).await? {
+            if AllowlistMemberDao::mark_removed(db, &policy_object_id, &member_address, tx_digest.as_deref())
+                .await
+                .map_err(|e| format!("Failed to remove allowlist member: {}", e))?
+            {
+                removed += 1;
+            }
+        }
+
+        if removed == 0 {
+            return Err("Address is not a member of this resume's allowlist".to_string());
+        }
+        Ok(removed)
+    }
+
+    /// 简历本身及各档位的策略对象 ID（规范化后）
+    async fn policies_of(db: &DatabaseConnection, resume: &resume::Model) -> Result<Vec<String>, String> {
+        let tiers = ResumeTierDao::find_by_resume(db, resume.id)
+            .await
+            .map_err(|e| format!("Failed to fetch resume tiers: {}", e))?;
+
+        let mut policies: Vec<String> = Vec::new();
+        let candidates = std::iter::once(resume.policy_object_id.clone()).chain(tiers.into_iter().map(|t| t.policy_object_id));
+        for policy in candidates.flatten() {
+            if let Ok(policy) = Validator::normalize_sui_address(&policy)
+                && !policies.contains(&policy)
+            {
+                policies.push(policy);
+            }
+        }
+        Ok(policies)
+    }
+
+    /// 校验请求中的策略对象属于该简历，未指定时使用简历本身的策略对象
+    async fn resolve_policy(
+        db: &DatabaseConnection,
+        resume: &resume::Model,
+        requested: Option<&str>,
+    ) -> Result<String, String> {
+        let policies = Self::policies_of(db, resume).await?;
+        match requested {
+            Some(requested) => {
+                let requested = Self::normalize(requested)?;
+                policies
+                    .into_iter()
+                    .find(|policy| *policy == requested)
+                    .ok_or_else(|| "policy_object_id is not an allowlist of this resume".to_string())
+            }
+            None => resume
+                .policy_object_id
+                .as_deref()
+                .and_then(|policy| Validator::normalize_sui_address(policy).ok())
+                .ok_or_else(|| "Resume has no allowlist policy object".to_string()),
+        }
+    }
+
+    /// 已付费解锁该简历的钱包（买家钱包及其关联的 Sui 钱包）→ 解锁记录 ID
+    async fn purchases_by_wallet(db: &DatabaseConnection, resume_id: i64) -> Result<HashMap<String, i64>, String> {
+        let records = UnlockRecordDao::find_by_resume_id(db, resume_id)
+            .await
+            .map_err(|e| format!("Failed to fetch unlock records: {}", e))?;
+
+        let mut purchases = HashMap::new();
+        for record in records {
+            if let Ok(wallet) = Validator::normalize_sui_address(&record.buyer_wallet) {
+                purchases.entry(wallet).or_insert(record.id);
+            }
+            let linked = UserWalletDao::find_by_user(db, record.buyer_id)
+                .await
+                .map_err(|e| format!("Failed to fetch buyer wallets: {}", e))?;
+            for wallet in linked {
+                if let Ok(address) = Validator::normalize_sui_address(&wallet.address) {
+                    purchases.entry(address).or_insert(record.id);
+                }
+            }
+        }
+        Ok(purchases)
+    }
+
+    fn to_response(member: allowlist_member::Model, unlock_record_id: Option<i64>) -> AllowlistMemberResponse {
+        AllowlistMemberResponse {
+            id: member.id,
+            policy_object_id: member.policy_object_id,
+            member_address: member.member_address,
+            status: member.status,
+            source: if unlock_record_id.is_some() { "purchase" } else { "manual" }.to_string(),
+            unlock_record_id,
+            added_by: member.added_by,
+            tx_digest: member.tx_digest,
+            created_at: member.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+            updated_at: member.updated_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    }
+
+    fn validate_tx_digest(tx_digest: &str) -> Result<String, String> {
+        let tx_digest = tx_digest.trim();
+        if tx_digest.is_empty() {
+            return Err("tx_digest is required".to_string());
+        }
+        if tx_digest.len() > MAX_TX_DIGEST_LEN {
+            return Err(format!("tx_digest must be at most {} characters", MAX_TX_DIGEST_LEN));
+        }
+        Ok(tx_digest.to_string())
+    }
+
+    fn normalize(address: &str) -> Result<String, String> {
+        Validator::normalize_sui_address(address).map_err(|e| e.to_string())
+    }
+}
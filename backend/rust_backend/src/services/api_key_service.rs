@@ -0,0 +1,169 @@
+use actix_web::http::StatusCode;
+use sea_orm::DatabaseConnection;
+use crate::auth::policy::API_KEY_SCOPES;
+use crate::auth::AuthError;
+use crate::dao::{ApiKeyAuditLogDao, ApiKeyDao};
+use crate::entities::{api_key, api_key_audit_log};
+use crate::services::AuthService;
+
+/// 明文 key 的前缀，便于在日志和代码仓库扫描中识别
+const KEY_PREFIX: &str = "rvk_";
+
+/// 单次返回的审计日志条数
+const AUDIT_LOG_LIMIT: u64 = 200;
+
+/// API Key 服务
+pub struct ApiKeyService;
+
+impl ApiKeyService {
+    /// 创建 API Key，返回 (记录, 明文 key)
+    pub async fn create_key(
+        db: &DatabaseConnection,
+        user_id: i64,
+        name: &str,
+        scopes: &[String],
+        organization_id: Option<i64>,
+    ) -> Result<(api_key::Model, String), String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("API key name cannot be empty".to_string());
+        }
+
+        let scopes = Self::normalize_scopes(scopes)?;
+        let (raw_key, key_prefix) = Self::generate_key();
+
+        let key = ApiKeyDao::create(
+            db,
+            name.to_string(),
+            key_prefix,
+            AuthService::hash_token(&raw_key),
+            user_id,
+            organization_id,
+            scopes,
+        )
+        .await
+        .map_err(|e| format!("Failed to create API key: {}", e))?;
+
+        log::info!("API key {} ({}) created by user {}", key.id, key.key_prefix, user_id);
+
+        Ok((key, raw_key))
+    }
+
+    /// 获取用户创建的所有 Key
+    pub async fn list_keys(
+        db: &DatabaseConnection,
+        user_id: i64,
+    ) -> Result<Vec<api_key::Model>, String> {
+        ApiKeyDao::find_by_user(db, user_id)
+            .await
+            .map_err(|e| format!("Failed to fetch API keys: {}", e))
+    }
+
+    /// 获取用户自己创建的 Key，不存在或不属于该用户时返回 403
+    pub async fn get_owned_key(
+        db: &DatabaseConnection,
+        api_key_id: i64,
+        user_id: i64,
+    ) -> Result<api_key::Model, AuthError> {
+        ApiKeyDao::find_by_id(db, api_key_id)
+            .await
+            .map_err(|e| AuthError::Internal(format!("Failed to fetch API key: {}", e)))?
+            .filter(|key| key.user_id == user_id)
+            .ok_or_else(|| AuthError::Forbidden("Forbidden: API key not found".to_string()))
+    }
+
+    /// 轮换 Key：生成新的明文 key，旧 key 立即失效
+    pub async fn rotate_key(
+        db: &DatabaseConnection,
+        key: api_key::Model,
+    ) -> Result<(api_key::Model, String), String> {
+        if key.revoked_at.is_some() {
+            return Err("Cannot rotate a revoked API key".to_string());
+        }
+
+        let (raw_key, key_prefix) = Self::generate_key();
+        ApiKeyDao::rotate(db, key.id, key_prefix.clone(), AuthService::hash_token(&raw_key))
+            .await
+            .map_err(|e| format!("Failed to rotate API key: {}", e))?;
+
+        log::info!("API key {} rotated, new prefix {}", key.id, key_prefix);
+
+        Ok((api_key::Model { key_prefix, ..key }, raw_key))
+    }
+
+    /// 吊销 Key
+    pub async fn revoke_key(db: &DatabaseConnection, api_key_id: i64) -> Result<(), String> {
+        ApiKeyDao::revoke(db, api_key_id)
+            .await
+            .map_err(|e| format!("Failed to revoke API key: {}", e))
+    }
+
+    /// 获取 Key 的审计日志（最近的在前）
+    pub async fn get_audit_logs(
+        db: &DatabaseConnection,
+        api_key_id: i64,
+    ) -> Result<Vec<api_key_audit_log::Model>, String> {
+        ApiKeyAuditLogDao::find_by_api_key(db, api_key_id, AUDIT_LOG_LIMIT)
+            .await
+            .map_err(|e| format!("Failed to fetch API key audit logs: {}", e))
+    }
+
+    /// 校验明文 key，返回未吊销的 Key
+    pub async fn authenticate(
+        db: &DatabaseConnection,
+        raw_key: &str,
+    ) -> Result<api_key::Model, String> {
+        ApiKeyDao::find_active_by_hash(db, &AuthService::hash_token(raw_key))
+            .await
+            .map_err(|e| format!("Failed to fetch API key: {}", e))?
+            .ok_or_else(|| "Invalid or revoked API key".to_string())
+    }
+
+    /// 记录一次调用并更新最后使用时间，失败只记日志不影响请求
+    pub async fn record_usage(
+        db: &DatabaseConnection,
+        api_key_id: i64,
+        method: String,
+        path: String,
+        status: StatusCode,
+        ip_address: Option<String>,
+    ) {
+        if let Err(e) = ApiKeyAuditLogDao::create(db, api_key_id, method, path, status.as_u16() as i32, ip_address).await {
+            log::warn!("Failed to write audit log for API key {}: {}", api_key_id, e);
+        }
+        if let Err(e) = ApiKeyDao::touch_last_used(db, api_key_id).await {
+            log::warn!("Failed to update last_used_at for API key {}: {}", api_key_id, e);
+        }
+    }
+
+    /// 校验并去重 scope，返回逗号分隔的存储格式
+    fn normalize_scopes(scopes: &[String]) -> Result<String, String> {
+        let mut normalized: Vec<&str> = Vec::new();
+        for scope in scopes {
+            let scope = scope.trim();
+            if !API_KEY_SCOPES.contains(&scope) {
+                return Err(format!(
+                    "Unknown API key scope '{}', expected one of: {}",
+                    scope,
+                    API_KEY_SCOPES.join(", ")
+                ));
+            }
+            if !normalized.contains(&scope) {
+                normalized.push(scope);
+            }
+        }
+
+        if normalized.is_empty() {
+            return Err("API key must have at least one scope".to_string());
+        }
+
+        Ok(normalized.join(","))
+    }
+
+    /// 生成 (明文 key, 展示用前缀)
+    fn generate_key() -> (String, String) {
+        let raw_key = format!("{}{}", KEY_PREFIX, AuthService::random_hex(32));
+        let key_prefix = raw_key[..KEY_PREFIX.len() + 8].to_string();
+        (raw_key, key_prefix)
+    }
+}
@@ -0,0 +1,265 @@
+use rand::RngCore;
+use sea_orm::DatabaseConnection;
+use sha2::{Digest, Sha256};
+use crate::dao::{AuthNonceDao, AuthSessionDao, UserDao};
+use crate::entities::auth_session;
+use crate::models::{AuthConfig, ChallengeResponse, LoginResponse, SessionClientInfo};
+use crate::utils::sui_signature::SuiSignature;
+use crate::utils::validator::Chain;
+
+/// 签名挑战用途
+pub const NONCE_PURPOSE_LOGIN: &str = "login";
+pub const NONCE_PURPOSE_LINK_WALLET: &str = "link_wallet";
+
+/// 两次刷新 last_seen_at 的最小间隔（秒），避免每个请求都写库
+const SESSION_TOUCH_INTERVAL_SECS: i64 = 60;
+
+/// 过期/注销的会话保留多久后清理（秒）
+const SESSION_RETENTION_SECS: i64 = 7 * 24 * 3600;
+
+/// 钱包签名登录服务
+pub struct AuthService;
+
+impl AuthService {
+    /// 签发登录挑战：生成 nonce 和待签名消息
+    ///
+    /// `wallet_address` 需已经过 `Validator::normalize_sui_address` 规范化，
+    /// 这样才能与签名恢复出的地址逐字节比较。
+    pub async fn issue_challenge(
+        db: &DatabaseConnection,
+        config: &AuthConfig,
+        wallet_address: String,
+    ) -> Result<ChallengeResponse, String> {
+        let nonce = Self::random_hex(16);
+        let now = chrono::Utc::now();
+        let expires_at = now + chrono::Duration::seconds(config.nonce_ttl_secs);
+
+        let message = format!(
+            "ResumeVault wants you to sign in with your Sui account:\n{}\n\nNonce: {}\nIssued At: {}\nExpiration Time: {}",
+            wallet_address,
+            nonce,
+            now.to_rfc3339(),
+            expires_at.to_rfc3339(),
+        );
+
+        AuthNonceDao::create(
+            db,
+            wallet_address,
+            nonce.clone(),
+            message.clone(),
+            NONCE_PURPOSE_LOGIN,
+            Chain::Sui.as_str(),
+            None,
+            expires_at.naive_utc(),
+        )
+        .await
+        .map_err(|e| format!("Failed to create login challenge: {}", e))?;
+
+        Ok(ChallengeResponse {
+            nonce,
+            message,
+            expires_at: expires_at.to_rfc3339(),
+        })
+    }
+
+    /// 验证钱包签名并签发会话 token
+    pub async fn login(
+        db: &DatabaseConnection,
+        config: &AuthConfig,
+        nonce: &str,
+        signature: &str,
+        client: SessionClientInfo,
+    ) -> Result<LoginResponse, String> {
+        // 1. 查找挑战
+        let challenge = AuthNonceDao::find_by_nonce(db, nonce)
+            .await
+            .map_err(|e| format!("Failed to fetch login challenge: {}", e))?
+            .ok_or_else(|| "Unknown login challenge".to_string())?;
+        if challenge.purpose != NONCE_PURPOSE_LOGIN {
+            return Err("Unknown login challenge".to_string());
+        }
+
+        // 2. 验证签名，签名者地址必须与申请挑战的钱包一致
+        let signer = SuiSignature::verify_personal_message(challenge.message.as_bytes(), signature)?;
+        if signer != challenge.wallet_address {
+            return Err("Signature does not match wallet address".to_string());
+        }
+
+        // 3. 消费 nonce（防止重放）
+        let consumed = AuthNonceDao::consume(db, nonce)
+            .await
+            .map_err(|e| format!("Failed to consume login challenge: {}", e))?;
+        if !consumed {
+            return Err("Login challenge expired or already used".to_string());
+        }
+
+        // 4. 获取或创建用户
+        let user = UserDao::get_or_create(db, signer.clone(), Some(UserDao::default_nickname(&signer)))
+            .await
+            .map_err(|e| format!("Failed to create/get user: {}", e))?;
+
+        // 5. 签发会话 token（只存储哈希）
+        let token = Self::random_hex(32);
+        let expires_at = chrono::Utc::now() + chrono::Duration::seconds(config.session_ttl_secs);
+
+        AuthSessionDao::create(db, Self::hash_token(&token), user.id, signer.clone(), client, expires_at.naive_utc())
+            .await
+            .map_err(|e| format!("Failed to create session: {}", e))?;
+
+        // 顺便清理早已过期/注销的会话
+        let before = (chrono::Utc::now() - chrono::Duration::seconds(SESSION_RETENTION_SECS)).naive_utc();
+        if let Err(e) = AuthSessionDao::delete_expired(db, before).await {
+            log::warn!("Failed to clean up expired sessions: {}", e);
+        }
+
+        log::info!("Wallet {} logged in as user {}", signer, user.id);
+
+        Ok(LoginResponse {
+            token,
+            user_id: user.id,
+            wallet_address: signer,
+            expires_at: expires_at.to_rfc3339(),
+        })
+    }
+
+    /// 校验会话 token，返回有效会话；已注销或过期的会话立即被拒绝
+    ///
+    /// 有效会话按 `session_ttl_secs` 滑动续期，但不超过登录后 `session_max_lifetime_secs`。
+    pub async fn authenticate(
+        db: &DatabaseConnection,
+        config: &AuthConfig,
+        token: &str,
+    ) -> Result<auth_session::Model, String> {
+        let mut session = AuthSessionDao::find_active_by_token_hash(db, &Self::hash_token(token))
+            .await
+            .map_err(|e| format!("Failed to fetch session: {}", e))?
+            .ok_or_else(|| "Invalid or expired session".to_string())?;
+
+        let now = chrono::Utc::now().naive_utc();
+        if let Some(expires_at) = Self::refreshed_expiry(&session, config, now) {
+            match AuthSessionDao::touch(db, session.id, now, expires_at).await {
+                Ok(_) => {
+                    session.last_seen_at = Some(now);
+                    session.expires_at = expires_at;
+                }
+                Err(e) => log::warn!("Failed to refresh session {}: {}", session.id, e),
+            }
+        }
+
+        Ok(session)
+    }
+
+    /// 计算滑动续期后的过期时间；距上次刷新不足间隔时返回 None
+    fn refreshed_expiry(
+        session: &auth_session::Model,
+        config: &AuthConfig,
+        now: chrono::NaiveDateTime,
+    ) -> Option<chrono::NaiveDateTime> {
+        let recently_seen = session
+            .last_seen_at
+            .is_some_and(|t| now - t < chrono::Duration::seconds(SESSION_TOUCH_INTERVAL_SECS));
+        if recently_seen {
+            return None;
+        }
+
+        let sliding = now + chrono::Duration::seconds(config.session_ttl_secs);
+        let hard_limit = session.created_at + chrono::Duration::seconds(config.session_max_lifetime_secs);
+        Some(sliding.min(hard_limit))
+    }
+
+    /// 获取用户的所有有效会话
+    pub async fn list_sessions(
+        db: &DatabaseConnection,
+        user_id: i64,
+    ) -> Result<Vec<auth_session::Model>, String> {
+        AuthSessionDao::find_active_by_user(db, user_id)
+            .await
+            .map_err(|e| format!("Failed to fetch sessions: {}", e))
+    }
+
+    /// 注销用户的某个会话
+    pub async fn revoke_session(
+        db: &DatabaseConnection,
+        user_id: i64,
+        session_id: i64,
+    ) -> Result<(), String> {
+        let revoked = AuthSessionDao::revoke_for_user(db, user_id, session_id)
+            .await
+            .map_err(|e| format!("Failed to revoke session: {}", e))?;
+
+        if !revoked {
+            return Err("Session not found or already revoked".to_string());
+        }
+        Ok(())
+    }
+
+    /// 注销用户除当前会话外的所有会话，返回注销数量
+    pub async fn revoke_other_sessions(
+        db: &DatabaseConnection,
+        user_id: i64,
+        current_session_id: i64,
+    ) -> Result<u64, String> {
+        AuthSessionDao::revoke_others(db, user_id, current_session_id)
+            .await
+            .map_err(|e| format!("Failed to revoke sessions: {}", e))
+    }
+
+    /// 注销会话
+    pub async fn logout(db: &DatabaseConnection, session_id: i64) -> Result<(), String> {
+        AuthSessionDao::revoke(db, session_id)
+            .await
+            .map_err(|e| format!("Failed to revoke session: {}", e))
+    }
+
+    /// 计算 token 的 SHA-256 哈希（十六进制）
+    pub fn hash_token(token: &str) -> String {
+        hex::encode(Sha256::digest(token.as_bytes()))
+    }
+
+    /// 生成 `len` 字节的随机十六进制串
+    pub fn random_hex(len: usize) -> String {
+        let mut bytes = vec![0u8; len];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        hex::encode(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(hour: u32, min: u32) -> chrono::NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 1, 1).unwrap().and_hms_opt(hour, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_sliding_session_expiry() {
+        let config = AuthConfig {
+            nonce_ttl_secs: 300,
+            session_ttl_secs: 3600,
+            session_max_lifetime_secs: 3 * 3600,
+            admin_wallets: Vec::new(),
+        };
+        let session = auth_session::Model {
+            id: 1,
+            token_hash: String::new(),
+            user_id: 1,
+            wallet_address: String::new(),
+            device_label: None,
+            ip_address: None,
+            user_agent: None,
+            last_seen_at: Some(at(0, 0)),
+            expires_at: at(1, 0),
+            revoked_at: None,
+            created_at: at(0, 0),
+        };
+
+        // 刚刷新过，不再写库
+        assert_eq!(AuthService::refreshed_expiry(&session, &config, at(0, 0)), None);
+        // 顺延一个空闲周期
+        assert_eq!(AuthService::refreshed_expiry(&session, &config, at(0, 30)), Some(at(1, 30)));
+        // 不超过最长有效期
+        assert_eq!(AuthService::refreshed_expiry(&session, &config, at(2, 30)), Some(at(3, 0)));
+    }
+}
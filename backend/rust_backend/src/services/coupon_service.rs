@@ -0,0 +1,276 @@
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, Set};
+use crate::dao::coupon_dao::{COUPON_FIXED, COUPON_PERCENT};
+use crate::dao::{CouponDao, CouponRedemptionDao, PaymentSettlementDao};
+use crate::entities::coupon;
+use crate::models::CreateCouponRequest;
+use crate::money::{self, Money};
+use crate::x402::middleware::PaymentError;
+
+/// 优惠码服务
+pub struct CouponService;
+
+impl CouponService {
+    /// 规范化优惠码：去空白、转大写，只允许字母、数字、`_`、`-`
+    pub fn normalize_code(code: &str) -> Result<String, String> {
+        let code = code.trim().to_ascii_uppercase();
+        if !(3..=50).contains(&code.len()) {
+            return Err("Coupon code must be 3-50 characters".to_string());
+        }
+        if !code.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+            return Err("Coupon code may only contain letters, digits, '_' and '-'".to_string());
+        }
+        Ok(code)
+    }
+
+    /// 创建优惠码（管理员）
+    pub async fn create(
+        db: &DatabaseConnection,
+        created_by: i64,
+        request: CreateCouponRequest,
+    ) -> Result<coupon::Model, String> {
+        let code = Self::normalize_code(&request.code)?;
+
+        let (discount_value, asset) = match request.discount_type.as_str() {
+            COUPON_PERCENT => {
+                let percent: i64 = request
+                    .discount_value
+                    .trim()
+                    .parse()
+                    .map_err(|_| "Percent discount must be an integer".to_string())?;
+                if !(1..=100).contains(&percent) {
+                    return Err("Percent discount must be between 1 and 100".to_string());
+                }
+                (percent, None)
+            }
+            COUPON_FIXED => {
+                let amount = money::registry()
+                    .resolve(request.asset.as_deref())?
+                    .parse(&request.discount_value)?;
+                if amount.amount <= 0 {
+                    return Err("Fixed discount must be positive".to_string());
+                }
+                (amount.amount, Some(amount.asset))
+            }
+            other => return Err(format!("Unknown discount_type '{}', expected 'percent' or 'fixed'", other)),
+        };
+
+        if request.max_redemptions.is_some_and(|n| n <= 0) || request.max_per_user.is_some_and(|n| n <= 0) {
+            return Err("Usage limits must be positive".to_string());
+        }
+        let expires_at = request
+            .expires_at
+            .as_deref()
+            .map(|t| {
+                chrono::NaiveDateTime::parse_from_str(t.trim(), "%Y-%m-%d %H:%M:%S")
+                    .map_err(|_| "expires_at must be formatted as 'YYYY-MM-DD HH:MM:SS'".to_string())
+            })
+            .transpose()?;
+
+        if CouponDao::find_by_code(db, &code)
+            .await
+            .map_err(|e| format!("Failed to check coupon: {}", e))?
+            .is_some()
+        {
+            return Err(format!("Coupon {} already exists", code));
+        }
+
+        CouponDao::create(
+            db,
+            coupon::ActiveModel {
+                code: Set(code),
+                discount_type: Set(request.discount_type),
+                discount_value: Set(discount_value),
+                asset: Set(asset),
+                max_redemptions: Set(request.max_redemptions),
+                max_per_user: Set(request.max_per_user),
+                redeemed_count: Set(0),
+                expires_at: Set(expires_at),
+                active: Set(true),
+                created_by: Set(created_by),
+                created_at: Set(chrono::Utc::now().naive_utc()),
+                ..Default::default()
+            },
+        )
+        .await
+        .map_err(|e| format!("Failed to create coupon: {}", e))
+    }
+
+    /// 全部优惠码（管理员）
+    pub async fn list(db: &DatabaseConnection) -> Result<Vec<coupon::Model>, String> {
+        CouponDao::find_all(db)
+            .await
+            .map_err(|e| format!("Failed to fetch coupons: {}", e))
+    }
+
+    /// 停用优惠码（管理员），优惠码不存在或已停用时返回 false
+    pub async fn deactivate(db: &DatabaseConnection, code: &str) -> Result<bool, String> {
+        let code = Self::normalize_code(code)?;
+        CouponDao::deactivate(db, &code)
+            .await
+            .map_err(|e| format!("Failed to deactivate coupon: {}", e))
+    }
+
+    /// 优惠码对应付金额 `due` 的减免金额（不超过 `due`），优惠码不可用时返回原因
+    pub fn discount(coupon: &coupon::Model, due: &Money, now: chrono::NaiveDateTime) -> Result<Money, String> {
+        if !coupon.active {
+            return Err(format!("Coupon {} is no longer active", coupon.code));
+        }
+        if coupon.expires_at.is_some_and(|expires_at| expires_at <= now) {
+            return Err(format!("Coupon {} has expired", coupon.code));
+        }
+        if coupon.max_redemptions.is_some_and(|max| coupon.redeemed_count >= max) {
+            return Err(format!("Coupon {} has been fully redeemed", coupon.code));
+        }
+
+        let amount = match coupon.asset.as_deref() {
+            None => (due.amount as i128 * coupon.discount_value as i128 / 100) as i64,
+            Some(asset) if asset == due.asset => coupon.discount_value.min(due.amount),
+            Some(asset) => return Err(format!("Coupon {} only applies to {} prices", coupon.code, asset)),
+        };
+
+        Ok(Money { amount: amount.max(0), ..due.clone() })
+    }
+
+    /// 报价时使用：应付金额减去优惠码减免后的金额（不加锁，结算时会重新检查）
+    pub async fn quote(
+        db: &DatabaseConnection,
+        code: &str,
+        buyer_id: Option<i64>,
+        due: Money,
+    ) -> Result<Money, PaymentError> {
+        if due.amount <= 0 {
+            return Ok(due);
+        }
+        let code = Self::normalize_code(code).map_err(PaymentError::Rejected)?;
+
+        let coupon = CouponDao::find_by_code(db, &code)
+            .await
+            .map_err(|e| PaymentError::Internal(format!("Failed to fetch coupon: {}", e)))?
+            .ok_or_else(|| PaymentError::Rejected(format!("Coupon {} not found", code)))?;
+        Self::check_limits(db, &coupon, buyer_id).await?;
+
+        let discount = Self::discount(&coupon, &due, chrono::Utc::now().naive_utc()).map_err(PaymentError::Rejected)?;
+        Ok(Money { amount: due.amount - discount.amount, ..due })
+    }
+
+    /// 结算时使用：加锁读取优惠码并计算减免，需在事务中调用；应付为 0 时不使用优惠码
+    ///
+    /// x402 支付的优惠码在结算完成后才记录使用，未完成的结算意向也计入使用次数；
+    /// 意向与优惠码行锁在同一事务中写入，并发购买不会超出上限。
+    pub async fn reserve<C: ConnectionTrait>(
+        db: &C,
+        code: &str,
+        buyer_id: i64,
+        due: &Money,
+    ) -> Result<Option<(coupon::Model, Money)>, PaymentError> {
+        if due.amount <= 0 {
+            return Ok(None);
+        }
+        let code = Self::normalize_code(code).map_err(PaymentError::Rejected)?;
+
+        let coupon = CouponDao::find_by_code_for_update(db, &code)
+            .await
+            .map_err(|e| PaymentError::Internal(format!("Failed to fetch coupon: {}", e)))?
+            .ok_or_else(|| PaymentError::Rejected(format!("Coupon {} not found", code)))?;
+        Self::check_limits(db, &coupon, Some(buyer_id)).await?;
+
+        let discount = Self::discount(&coupon, due, chrono::Utc::now().naive_utc()).map_err(PaymentError::Rejected)?;
+        Ok(Some((coupon, discount)))
+    }
+
+    /// 记录优惠码使用并增加使用次数（与解锁记录在同一事务中写入）
+    pub async fn redeem<C: ConnectionTrait>(
+        db: &C,
+        coupon_id: i64,
+        user_id: i64,
+        unlock_record_id: i64,
+        discount: Money,
+    ) -> Result<(), String> {
+        CouponRedemptionDao::create(db, coupon_id, user_id, unlock_record_id, discount)
+            .await
+            .map_err(|e| format!("Failed to record coupon redemption: {}", e))?;
+        CouponDao::increment_redeemed(db, coupon_id)
+            .await
+            .map_err(|e| format!("Failed to update coupon usage: {}", e))
+    }
+
+    /// 检查总使用次数和每人使用次数，已使用次数包括未完成的 x402 结算意向
+    async fn check_limits<C: ConnectionTrait>(
+        db: &C,
+        coupon: &coupon::Model,
+        user_id: Option<i64>,
+    ) -> Result<(), PaymentError> {
+        let usage_error = |e: DbErr| PaymentError::Internal(format!("Failed to check coupon usage: {}", e));
+
+        if let Some(max_redemptions) = coupon.max_redemptions {
+            let open = PaymentSettlementDao::count_open_with_coupon(db, coupon.id, None)
+                .await
+                .map_err(usage_error)?;
+            if coupon.redeemed_count as u64 + open >= max_redemptions as u64 {
+                return Err(PaymentError::Rejected(format!("Coupon {} has been fully redeemed", coupon.code)));
+            }
+        }
+
+        let (Some(max_per_user), Some(user_id)) = (coupon.max_per_user, user_id) else {
+            return Ok(());
+        };
+        let used = CouponRedemptionDao::count_by_user(db, coupon.id, user_id)
+            .await
+            .map_err(usage_error)?;
+        let open = PaymentSettlementDao::count_open_with_coupon(db, coupon.id, Some(user_id))
+            .await
+            .map_err(usage_error)?;
+        if used + open >= max_per_user as u64 {
+            return Err(PaymentError::Rejected(format!("You have already used coupon {}", coupon.code)));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coupon(discount_type: &str, discount_value: i64, asset: Option<&str>) -> coupon::Model {
+        let now = chrono::Utc::now().naive_utc();
+        coupon::Model {
+            id: 1,
+            code: "WELCOME".to_string(),
+            discount_type: discount_type.to_string(),
+            discount_value,
+            asset: asset.map(str::to_string),
+            max_redemptions: Some(2),
+            max_per_user: None,
+            redeemed_count: 0,
+            expires_at: Some(now + chrono::Duration::days(1)),
+            active: true,
+            created_by: 1,
+            created_at: now,
+        }
+    }
+
+    #[test]
+    fn test_coupon_discount() {
+        let now = chrono::Utc::now().naive_utc();
+        let due = Money { amount: 1_000_000, asset: "USDC".to_string(), decimals: 6 };
+
+        let percent = coupon(COUPON_PERCENT, 25, None);
+        assert_eq!(CouponService::discount(&percent, &due, now).unwrap().amount, 250_000);
+
+        // 固定减免不超过应付金额，且只适用于同一资产
+        let fixed = coupon(COUPON_FIXED, 5_000_000, Some("USDC"));
+        assert_eq!(CouponService::discount(&fixed, &due, now).unwrap().amount, 1_000_000);
+        let other_asset = coupon(COUPON_FIXED, 100, Some("SUI"));
+        assert!(CouponService::discount(&other_asset, &due, now).is_err());
+
+        let expired = coupon::Model { expires_at: Some(now - chrono::Duration::seconds(1)), ..percent.clone() };
+        assert!(CouponService::discount(&expired, &due, now).is_err());
+        let used_up = coupon::Model { redeemed_count: 2, ..percent.clone() };
+        assert!(CouponService::discount(&used_up, &due, now).is_err());
+        let inactive = coupon::Model { active: false, ..percent };
+        assert!(CouponService::discount(&inactive, &due, now).is_err());
+
+        assert_eq!(CouponService::normalize_code(" spring-25 ").unwrap(), "SPRING-25");
+        assert!(CouponService::normalize_code("no spaces").is_err());
+    }
+}
@@ -0,0 +1,393 @@
+use sea_orm::{ConnectionTrait, DatabaseConnection, Set, TransactionTrait};
+use crate::auth::AuthError;
+use crate::dao::credit_account_dao::{CREDIT_OWNER_ORGANIZATION, CREDIT_OWNER_USER};
+use crate::dao::credit_transaction_dao::{CREDIT_KIND_REFUND, CREDIT_KIND_TOPUP, CREDIT_KIND_UNLOCK};
+use crate::dao::payment_settlement_dao::{SETTLEMENT_PURPOSE_CREDIT_TOPUP, SETTLEMENT_STATUS_SETTLED};
+use crate::dao::{CreditAccountDao, CreditBundleDao, CreditTransactionDao, PaymentSettlementDao};
+use crate::entities::{credit_account, credit_bundle, credit_transaction, payment_settlement};
+use crate::models::{CreateCreditBundleRequest, CreditBalanceResponse, CreditTransactionResponse};
+use crate::money;
+use crate::services::OrganizationService;
+use crate::x402::facilitator::SettleResponse;
+use crate::x402::middleware::PaymentError;
+use crate::x402::{Facilitator, VerifiedPayment};
+
+/// 额度账户所有者
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreditOwner {
+    User(i64),
+    Organization(i64),
+}
+
+impl CreditOwner {
+    pub fn owner_type(&self) -> &'static str {
+        match self {
+            CreditOwner::User(_) => CREDIT_OWNER_USER,
+            CreditOwner::Organization(_) => CREDIT_OWNER_ORGANIZATION,
+        }
+    }
+
+    pub fn owner_id(&self) -> i64 {
+        match *self {
+            CreditOwner::User(id) | CreditOwner::Organization(id) => id,
+        }
+    }
+}
+
+/// 一次额度套餐购买的结果
+pub struct CreditTopup {
+    pub bundle: credit_bundle::Model,
+    pub transaction: credit_transaction::Model,
+    pub settlement: SettleResponse,
+}
+
+/// 预付额度服务
+pub struct CreditService;
+
+impl CreditService {
+    /// 确定额度账户：指定组织时必须是其成员，否则为个人账户
+    pub async fn resolve_owner(
+        db: &DatabaseConnection,
+        user_id: i64,
+        organization_id: Option<i64>,
+    ) -> Result<CreditOwner, AuthError> {
+        match organization_id {
+            Some(organization_id) => {
+                OrganizationService::require_member(db, organization_id, user_id, false).await?;
+                Ok(CreditOwner::Organization(organization_id))
+            }
+            None => Ok(CreditOwner::User(user_id)),
+        }
+    }
+
+    /// 额度余额（每种资产一项）
+    pub async fn get_balance(
+        db: &DatabaseConnection,
+        owner: CreditOwner,
+    ) -> Result<CreditBalanceResponse, String> {
+        let accounts = CreditAccountDao::find_by_owner(db, owner.owner_type(), owner.owner_id())
+            .await
+            .map_err(|e| format!("Failed to fetch credit accounts: {}", e))?;
+
+        Ok(CreditBalanceResponse {
+            owner_type: owner.owner_type().to_string(),
+            owner_id: owner.owner_id(),
+            balances: accounts
+                .iter()
+                .map(|a| money::registry().stored(a.balance, &a.asset))
+                .collect(),
+        })
+    }
+
+    /// 额度流水，最新的在前
+    pub async fn get_ledger(
+        db: &DatabaseConnection,
+        owner: CreditOwner,
+    ) -> Result<Vec<CreditTransactionResponse>, String> {
+        let accounts = CreditAccountDao::find_by_owner(db, owner.owner_type(), owner.owner_id())
+            .await
+            .map_err(|e| format!("Failed to fetch credit accounts: {}", e))?;
+        if accounts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let transactions = CreditTransactionDao::find_by_accounts(db, accounts.iter().map(|a| a.id).collect())
+            .await
+            .map_err(|e| format!("Failed to fetch credit transactions: {}", e))?;
+
+        Ok(transactions
+            .into_iter()
+            .map(|t| {
+                let asset = accounts
+                    .iter()
+                    .find(|a| a.id == t.account_id)
+                    .map(|a| a.asset.clone())
+                    .unwrap_or_default();
+                CreditTransactionResponse::new(t, &asset)
+            })
+            .collect())
+    }
+
+    /// 创建额度套餐（管理员）
+    pub async fn create_bundle(
+        db: &DatabaseConnection,
+        request: CreateCreditBundleRequest,
+    ) -> Result<credit_bundle::Model, String> {
+        let name = request.name.trim().to_string();
+        if name.is_empty() || name.chars().count() > 100 {
+            return Err("name must be 1-100 characters".to_string());
+        }
+
+        let asset = money::registry().resolve(request.asset.as_deref())?;
+        let price = asset.parse(&request.price)?;
+        let credits = asset.parse(&request.credits)?;
+        if price.amount <= 0 {
+            return Err("price must be positive".to_string());
+        }
+        if credits.amount < price.amount {
+            return Err("credits must not be less than price".to_string());
+        }
+
+        CreditBundleDao::create(db, name, price, credits.amount)
+            .await
+            .map_err(|e| format!("Failed to create bundle: {}", e))
+    }
+
+    /// 在售的额度套餐
+    pub async fn list_bundles(db: &DatabaseConnection) -> Result<Vec<credit_bundle::Model>, String> {
+        CreditBundleDao::find_active(db)
+            .await
+            .map_err(|e| format!("Failed to fetch bundles: {}", e))
+    }
+
+    /// 下架额度套餐（管理员），套餐不存在或已下架时返回 false
+    pub async fn deactivate_bundle(db: &DatabaseConnection, bundle_id: i64) -> Result<bool, String> {
+        CreditBundleDao::deactivate(db, bundle_id)
+            .await
+            .map_err(|e| format!("Failed to deactivate bundle: {}", e))
+    }
+
+    /// 在售的套餐，供 x402 定价使用
+    pub async fn find_active_bundle(
+        db: &DatabaseConnection,
+        bundle_id: i64,
+    ) -> Result<Option<credit_bundle::Model>, String> {
+        CreditBundleDao::find_by_id(db, bundle_id)
+            .await
+            .map(|bundle| bundle.filter(|b| b.active))
+            .map_err(|e| format!("Failed to fetch bundle: {}", e))
+    }
+
+    /// x402 购买额度套餐
+    ///
+    /// 先创建额度账户并写入 pending 的结算意向，再结算支付，最后由 [`Self::complete_topup`]
+    /// 在同一事务中给账户加额度并记录流水；加额度失败的意向由后台对账任务重试。
+    #[allow(clippy::too_many_arguments)]
+    pub async fn purchase_bundle(
+        db: &DatabaseConnection,
+        facilitator: &dyn Facilitator,
+        payment: Option<&VerifiedPayment>,
+        bundle_id: i64,
+        owner: CreditOwner,
+        user_id: i64,
+        wallet: &str,
+    ) -> Result<CreditTopup, PaymentError> {
+        let bundle = Self::find_active_bundle(db, bundle_id)
+            .await
+            .map_err(PaymentError::Internal)?
+            .ok_or_else(|| PaymentError::NotFound("Credit bundle not found".to_string()))?;
+
+        // 中间件报价之后套餐价格发生变化时不结算
+        let payment = payment
+            .filter(|p| p.requirements.max_amount_required == bundle.price.to_string())
+            .ok_or_else(|| PaymentError::Rejected("Bundle price changed, please retry".to_string()))?;
+
+        CreditAccountDao::create_if_missing(db, owner.owner_type(), owner.owner_id(), &bundle.asset)
+            .await
+            .map_err(|e| PaymentError::Internal(format!("Failed to create credit account: {}", e)))?;
+        let settlement = PaymentSettlementDao::create(
+            db,
+            payment_settlement::ActiveModel {
+                purpose: Set(SETTLEMENT_PURPOSE_CREDIT_TOPUP.to_string()),
+                user_id: Set(user_id),
+                wallet: Set(wallet.to_string()),
+                bundle_id: Set(Some(bundle.id)),
+                credits: Set(Some(bundle.credits)),
+                credit_owner_type: Set(Some(owner.owner_type().to_string())),
+                credit_owner_id: Set(Some(owner.owner_id())),
+                amount: Set(bundle.price),
+                asset: Set(bundle.asset.clone()),
+                pay_asset: Set(payment.requirements.asset.clone()),
+                network: Set(payment.requirements.network.clone()),
+                ..Default::default()
+            },
+        )
+        .await
+        .map_err(|e| PaymentError::Internal(format!("Failed to record settlement: {}", e)))?;
+
+        let settled = match facilitator.settle(&payment.payment, &payment.requirements).await {
+            Ok(settled) => settled,
+            Err(e) => {
+                // 明确被拒绝时没有扣款；超时等结果未知的错误只记录原因
+                let recorded = if e.is_payment_error() {
+                    PaymentSettlementDao::mark_failed(db, settlement.id, &e.to_string()).await
+                } else {
+                    PaymentSettlementDao::record_error(db, settlement.id, &e.to_string()).await
+                };
+                if let Err(db_err) = recorded {
+                    log::warn!("Failed to record settlement {} error: {}", settlement.id, db_err);
+                }
+                // 明确被拒绝时没有扣款，可以用同一个幂等键重新支付
+                let error = PaymentError::from_facilitator(e, &payment.requirements);
+                return Err(if matches!(error, PaymentError::Required { .. }) {
+                    error
+                } else {
+                    error.after_settlement(settlement.id)
+                });
+            }
+        };
+
+        match PaymentSettlementDao::mark_settled(db, settlement.id, &settled.transaction, settled.payer.clone()).await {
+            Ok(true) => {}
+            result => {
+                log::error!(
+                    "Payment {} for settlement {} was settled but could not be recorded: {:?}",
+                    settled.transaction, settlement.id, result
+                );
+                return Err(PaymentError::Internal("Payment was settled but could not be recorded".to_string())
+                    .after_settlement(settlement.id));
+            }
+        }
+
+        let transaction = match Self::complete_topup(db, settlement.id).await {
+            Ok(transaction) => transaction,
+            Err(e) => {
+                log::error!(
+                    "Payment {} for credit bundle {} by user {} was settled but not credited, settlement {} will be retried: {}",
+                    settled.transaction, bundle.id, user_id, settlement.id, e
+                );
+                if let Err(db_err) = PaymentSettlementDao::record_error(db, settlement.id, &e).await {
+                    log::warn!("Failed to record settlement {} error: {}", settlement.id, db_err);
+                }
+                return Err(PaymentError::Internal(format!(
+                    "Payment was settled but the credits could not be added yet, it will be retried: {}",
+                    e
+                ))
+                .after_settlement(settlement.id));
+            }
+        };
+
+        log::info!(
+            "Credit bundle {} bought for {} {} by user {} (tx {})",
+            bundle.id, owner.owner_type(), owner.owner_id(), user_id, settled.transaction
+        );
+        Ok(CreditTopup { bundle, transaction, settlement: settled })
+    }
+
+    /// 为已结算的套餐购买给额度账户加额度并记录流水，同一事务中把意向转为 completed
+    ///
+    /// 意向不是 settled 状态时返回错误。购买请求结算后直接调用，失败时由对账任务重试。
+    pub async fn complete_topup(
+        db: &DatabaseConnection,
+        settlement_id: i64,
+    ) -> Result<credit_transaction::Model, String> {
+        let txn = db.begin().await.map_err(|e| format!("Failed to start transaction: {}", e))?;
+        let settlement = PaymentSettlementDao::find_by_id_for_update(&txn, settlement_id)
+            .await
+            .map_err(|e| format!("Failed to fetch settlement: {}", e))?
+            .ok_or_else(|| format!("Settlement {} not found", settlement_id))?;
+        if settlement.status != SETTLEMENT_STATUS_SETTLED {
+            return Err(format!("Settlement {} is {}", settlement.id, settlement.status));
+        }
+        let owner = match (settlement.credit_owner_type.as_deref(), settlement.credit_owner_id) {
+            (Some(CREDIT_OWNER_USER), Some(id)) => CreditOwner::User(id),
+            (Some(CREDIT_OWNER_ORGANIZATION), Some(id)) => CreditOwner::Organization(id),
+            _ => return Err(format!("Settlement {} has no credit account owner", settlement.id)),
+        };
+        let credits = settlement
+            .credits
+            .ok_or_else(|| format!("Settlement {} has no credits", settlement.id))?;
+
+        let account = Self::lock_account(&txn, owner, &settlement.asset).await?;
+        let transaction = Self::apply(
+            &txn,
+            &account,
+            credits,
+            CREDIT_KIND_TOPUP,
+            settlement.bundle_id,
+            None,
+            settlement.transaction.clone(),
+            settlement.user_id,
+        )
+        .await?;
+
+        PaymentSettlementDao::mark_completed(&txn, settlement.id, None, Some(transaction.id))
+            .await
+            .map_err(|e| format!("Failed to complete settlement: {}", e))?;
+        txn.commit().await.map_err(|e| format!("Failed to commit top-up: {}", e))?;
+
+        Ok(transaction)
+    }
+
+    /// 加锁读取额度账户，不存在时创建余额为 0 的账户；需在事务中调用
+    pub async fn lock_account<C: ConnectionTrait>(
+        db: &C,
+        owner: CreditOwner,
+        asset: &str,
+    ) -> Result<credit_account::Model, String> {
+        if let Some(account) = CreditAccountDao::find_for_update(db, owner.owner_type(), owner.owner_id(), asset)
+            .await
+            .map_err(|e| format!("Failed to fetch credit account: {}", e))?
+        {
+            return Ok(account);
+        }
+
+        CreditAccountDao::create_if_missing(db, owner.owner_type(), owner.owner_id(), asset)
+            .await
+            .map_err(|e| format!("Failed to create credit account: {}", e))?;
+        CreditAccountDao::find_for_update(db, owner.owner_type(), owner.owner_id(), asset)
+            .await
+            .map_err(|e| format!("Failed to fetch credit account: {}", e))?
+            .ok_or_else(|| "Credit account not found".to_string())
+    }
+
+    /// 变动已加锁账户的余额并记录流水，余额不能为负
+    #[allow(clippy::too_many_arguments)]
+    pub async fn apply<C: ConnectionTrait>(
+        db: &C,
+        account: &credit_account::Model,
+        amount: i64,
+        kind: &str,
+        bundle_id: Option<i64>,
+        unlock_record_id: Option<i64>,
+        reference: Option<String>,
+        created_by: i64,
+    ) -> Result<credit_transaction::Model, String> {
+        let balance = account
+            .balance
+            .checked_add(amount)
+            .filter(|balance| *balance >= 0)
+            .ok_or_else(|| "Insufficient credits".to_string())?;
+
+        CreditAccountDao::update_balance(db, account.id, balance)
+            .await
+            .map_err(|e| format!("Failed to update credit balance: {}", e))?;
+        CreditTransactionDao::create(db, account.id, kind, amount, balance, bundle_id, unlock_record_id, reference, created_by)
+            .await
+            .map_err(|e| format!("Failed to record credit transaction: {}", e))
+    }
+
+    /// 用额度支付一次解锁（账户已加锁且余额充足）
+    pub async fn debit_unlock<C: ConnectionTrait>(
+        db: &C,
+        account: &credit_account::Model,
+        amount: i64,
+        unlock_record_id: i64,
+        buyer_id: i64,
+    ) -> Result<credit_transaction::Model, String> {
+        Self::apply(db, account, -amount, CREDIT_KIND_UNLOCK, None, Some(unlock_record_id), None, buyer_id).await
+    }
+
+    /// 退款时把解锁扣减的额度退回原账户；该解锁不是用额度支付时返回 None
+    pub async fn refund_unlock<C: ConnectionTrait>(
+        db: &C,
+        unlock_record_id: i64,
+        refunded_by: i64,
+    ) -> Result<Option<credit_transaction::Model>, String> {
+        let Some(debit) = CreditTransactionDao::find_by_unlock_record(db, unlock_record_id, CREDIT_KIND_UNLOCK)
+            .await
+            .map_err(|e| format!("Failed to fetch credit transaction: {}", e))?
+        else {
+            return Ok(None);
+        };
+
+        let account = CreditAccountDao::find_by_id_for_update(db, debit.account_id)
+            .await
+            .map_err(|e| format!("Failed to fetch credit account: {}", e))?
+            .ok_or_else(|| "Credit account not found".to_string())?;
+
+        Self::apply(db, &account, -debit.amount, CREDIT_KIND_REFUND, None, Some(unlock_record_id), None, refunded_by)
+            .await
+            .map(Some)
+    }
+}
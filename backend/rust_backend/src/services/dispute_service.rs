@@ -0,0 +1,198 @@
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use crate::auth::{AuthError, AuthenticatedWallet};
+use crate::dao::unlock_dispute_dao::{DISPUTE_STATUS_OPEN, DISPUTE_STATUS_REFUNDED, DISPUTE_STATUS_REJECTED};
+use crate::dao::{ResumeDao, SellerLedgerDao, UnlockDisputeDao, UnlockRecordDao};
+use crate::entities::{unlock_dispute, unlock_record};
+use crate::models::{OpenDisputeRequest, ResolveDisputeRequest, UnlockStatus};
+use crate::services::{CreditService, UnlockRecordService};
+
+/// 争议原因最大长度
+const MAX_REASON_LEN: usize = 2000;
+/// 证据最多条数及每条最大长度
+const MAX_EVIDENCE_ITEMS: usize = 10;
+const MAX_EVIDENCE_LEN: usize = 500;
+
+/// 解锁争议服务
+pub struct DisputeService;
+
+impl DisputeService {
+    /// 获取解锁记录，只有买家、卖家（简历所有者）或管理员 override 可以访问
+    pub async fn get_record_for_party(
+        db: &DatabaseConnection,
+        record_id: i64,
+        wallet: &AuthenticatedWallet,
+    ) -> Result<Option<unlock_record::Model>, AuthError> {
+        let Some(record) = UnlockRecordDao::find_by_id(db, record_id)
+            .await
+            .map_err(|e| AuthError::Internal(format!("Failed to fetch unlock record: {}", e)))?
+        else {
+            return Ok(None);
+        };
+
+        if record.buyer_id != wallet.user_id() {
+            let seller_id = ResumeDao::find_by_id(db, record.resume_id)
+                .await
+                .map_err(|e| AuthError::Internal(format!("Failed to fetch resume: {}", e)))?
+                .map(|resume| resume.owner_id);
+            wallet.require_owner(seller_id, "this unlock record")?;
+        }
+
+        Ok(Some(record))
+    }
+
+    /// 买家对已确认的解锁发起争议，解锁记录转为 disputed（争议期间保留解锁权益）
+    pub async fn open_dispute(
+        db: &DatabaseConnection,
+        record_id: i64,
+        opened_by: i64,
+        request: OpenDisputeRequest,
+    ) -> Result<unlock_dispute::Model, String> {
+        let reason = request.reason.trim();
+        if reason.is_empty() {
+            return Err("reason is required".to_string());
+        }
+        if reason.chars().count() > MAX_REASON_LEN {
+            return Err(format!("reason must be at most {} characters", MAX_REASON_LEN));
+        }
+        let evidence: Vec<String> = request
+            .evidence
+            .iter()
+            .map(|item| item.trim().to_string())
+            .filter(|item| !item.is_empty())
+            .collect();
+        if evidence.len() > MAX_EVIDENCE_ITEMS {
+            return Err(format!("At most {} evidence items are allowed", MAX_EVIDENCE_ITEMS));
+        }
+        if evidence.iter().any(|item| item.chars().count() > MAX_EVIDENCE_LEN) {
+            return Err(format!("Each evidence item must be at most {} characters", MAX_EVIDENCE_LEN));
+        }
+        let evidence = serde_json::to_string(&evidence)
+            .map_err(|e| format!("Failed to serialize evidence: {}", e))?;
+
+        let txn = db.begin().await.map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        let record = UnlockRecordDao::find_by_id_for_update(&txn, record_id)
+            .await
+            .map_err(|e| format!("Failed to fetch unlock record: {}", e))?
+            .ok_or_else(|| "Unlock record not found".to_string())?;
+        if record.buyer_id != opened_by {
+            return Err("Only the buyer can dispute this unlock".to_string());
+        }
+
+        UnlockRecordService::transition(&txn, &record, UnlockStatus::Disputed).await?;
+        let dispute = UnlockDisputeDao::create(&txn, record.id, opened_by, reason.to_string(), evidence)
+            .await
+            .map_err(|e| format!("Failed to create dispute: {}", e))?;
+
+        txn.commit().await.map_err(|e| format!("Failed to commit dispute: {}", e))?;
+
+        log::info!("Dispute {} opened on unlock record {} by user {}", dispute.id, record.id, opened_by);
+        Ok(dispute)
+    }
+
+    /// 解锁记录的争议
+    pub async fn get_by_record(
+        db: &DatabaseConnection,
+        record_id: i64,
+    ) -> Result<Option<unlock_dispute::Model>, String> {
+        UnlockDisputeDao::find_by_unlock_record(db, record_id)
+            .await
+            .map_err(|e| format!("Failed to fetch dispute: {}", e))
+    }
+
+    /// 列出争议（管理员）
+    pub async fn list(
+        db: &DatabaseConnection,
+        status: Option<&str>,
+    ) -> Result<Vec<unlock_dispute::Model>, String> {
+        if let Some(status) = status
+            && ![DISPUTE_STATUS_OPEN, DISPUTE_STATUS_REFUNDED, DISPUTE_STATUS_REJECTED].contains(&status)
+        {
+            return Err(format!("Unknown dispute status '{}'", status));
+        }
+
+        UnlockDisputeDao::find_all(db, status)
+            .await
+            .map_err(|e| format!("Failed to fetch disputes: {}", e))
+    }
+
+    /// 裁决争议（管理员），争议不存在时返回 None
+    ///
+    /// `refund`: 解锁记录转为 refunded 并收回解锁权益，卖家账本记入冲正账目；
+    /// 用额度支付的解锁自动退回额度，链上支付的退款由管理员完成并提供退款交易摘要。
+    /// `reject`: 解锁记录转为 rejected，保留解锁权益。
+    pub async fn resolve(
+        db: &DatabaseConnection,
+        dispute_id: i64,
+        resolved_by: i64,
+        request: ResolveDisputeRequest,
+    ) -> Result<Option<unlock_dispute::Model>, String> {
+        let (record_status, dispute_status) = match request.resolution.as_str() {
+            "refund" => (UnlockStatus::Refunded, DISPUTE_STATUS_REFUNDED),
+            "reject" => (UnlockStatus::Rejected, DISPUTE_STATUS_REJECTED),
+            other => return Err(format!("Unknown resolution '{}', expected 'refund' or 'reject'", other)),
+        };
+        let note = request.note.map(|note| note.trim().to_string()).filter(|note| !note.is_empty());
+        let refund_transaction = request
+            .refund_transaction
+            .map(|tx| tx.trim().to_string())
+            .filter(|tx| !tx.is_empty());
+        if record_status == UnlockStatus::Rejected && refund_transaction.is_some() {
+            return Err("refund_transaction is only allowed when refunding".to_string());
+        }
+
+        let txn = db.begin().await.map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        let Some(dispute) = UnlockDisputeDao::find_by_id(&txn, dispute_id)
+            .await
+            .map_err(|e| format!("Failed to fetch dispute: {}", e))?
+        else {
+            return Ok(None);
+        };
+        if dispute.status != DISPUTE_STATUS_OPEN {
+            return Err(format!("Dispute {} is already {}", dispute_id, dispute.status));
+        }
+
+        let record = UnlockRecordDao::find_by_id_for_update(&txn, dispute.unlock_record_id)
+            .await
+            .map_err(|e| format!("Failed to fetch unlock record: {}", e))?
+            .ok_or_else(|| "Unlock record not found".to_string())?;
+
+        if record_status == UnlockStatus::Refunded {
+            let credited = CreditService::refund_unlock(&txn, record.id, resolved_by).await?;
+            if credited.is_none() && record.amount > 0 && refund_transaction.is_none() {
+                return Err("refund_transaction is required to refund a paid unlock".to_string());
+            }
+
+            // 未结算的收入随冲正账目在下一批次抵消，已打款的从卖家后续收入中扣回
+            if let Some(sale) = SellerLedgerDao::find_sale_by_unlock_record(&txn, record.id)
+                .await
+                .map_err(|e| format!("Failed to fetch ledger entry: {}", e))?
+            {
+                SellerLedgerDao::create_reversal(&txn, &sale)
+                    .await
+                    .map_err(|e| format!("Failed to record refund ledger entry: {}", e))?;
+            }
+        }
+
+        UnlockRecordService::transition(&txn, &record, record_status).await?;
+        let resolved = UnlockDisputeDao::resolve(&txn, dispute.id, dispute_status, resolved_by, note, refund_transaction)
+            .await
+            .map_err(|e| format!("Failed to resolve dispute: {}", e))?;
+        if !resolved {
+            return Err(format!("Dispute {} is already resolved", dispute_id));
+        }
+
+        let dispute = UnlockDisputeDao::find_by_id(&txn, dispute.id)
+            .await
+            .map_err(|e| format!("Failed to fetch dispute: {}", e))?;
+
+        txn.commit().await.map_err(|e| format!("Failed to commit resolution: {}", e))?;
+
+        log::info!(
+            "Dispute {} on unlock record {} resolved as {} by admin {}",
+            dispute_id, record.id, dispute_status, resolved_by
+        );
+        Ok(dispute)
+    }
+}
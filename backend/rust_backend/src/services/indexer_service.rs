@@ -0,0 +1,63 @@
+use chrono::NaiveDateTime;
+use sea_orm::DatabaseConnection;
+use crate::dao::IndexerCursorDao;
+use crate::entities::indexer_cursor;
+use crate::models::IndexerStreamStatus;
+
+/// 链上事件索引的进度和延迟
+pub struct IndexerService;
+
+impl IndexerService {
+    /// 合约包各事件流的索引进度
+    pub async fn status(db: &DatabaseConnection, package_id: &str) -> Result<Vec<IndexerStreamStatus>, String> {
+        let cursors = IndexerCursorDao::find_by_package(db, package_id)
+            .await
+            .map_err(|e| format!("Failed to fetch indexer cursors: {}", e))?;
+
+        let now = chrono::Utc::now().naive_utc();
+        Ok(cursors.into_iter().map(|cursor| Self::to_status(cursor, now)).collect())
+    }
+
+    fn to_status(cursor: indexer_cursor::Model, now: NaiveDateTime) -> IndexerStreamStatus {
+        IndexerStreamStatus {
+            lag_secs: cursor.caught_up_at.map(|at| (now - at).num_seconds().max(0)),
+            caught_up_at: cursor.caught_up_at.map(|at| at.format("%Y-%m-%d %H:%M:%S").to_string()),
+            package_id: cursor.package_id,
+            event_type: cursor.event_type,
+            tx_digest: cursor.tx_digest,
+            event_seq: cursor.event_seq,
+            last_event_timestamp_ms: cursor.last_event_timestamp_ms,
+            last_error: cursor.last_error,
+            updated_at: cursor.updated_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lag_is_time_since_last_caught_up() {
+        let now = chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap().and_hms_opt(12, 0, 30).unwrap();
+        let cursor = indexer_cursor::Model {
+            id: 1,
+            package_id: "0x1".to_string(),
+            event_type: "allowlist".to_string(),
+            tx_digest: Some("digest".to_string()),
+            event_seq: Some("0".to_string()),
+            last_event_timestamp_ms: Some(1_767_268_800_000),
+            caught_up_at: Some(now - chrono::Duration::seconds(30)),
+            last_error: Some("Sui RPC timed out".to_string()),
+            updated_at: now,
+        };
+
+        let status = IndexerService::to_status(cursor.clone(), now);
+        assert_eq!(status.lag_secs, Some(30));
+        assert_eq!(status.caught_up_at.as_deref(), Some("2026-01-01 12:00:00"));
+
+        // 从未追上最新事件时没有延迟数据
+        let status = IndexerService::to_status(indexer_cursor::Model { caught_up_at: None, ..cursor }, now);
+        assert_eq!(status.lag_secs, None);
+    }
+}
@@ -2,8 +2,38 @@ pub mod resume_service;
 pub mod user_service;
 pub mod unlock_record_service;
 pub mod access_log_service;
+pub mod auth_service;
+pub mod organization_service;
+pub mod api_key_service;
+pub mod user_wallet_service;
+pub mod receipt_service;
+pub mod payout_service;
+pub mod resume_tier_service;
+pub mod dispute_service;
+pub mod credit_service;
+pub mod coupon_service;
+pub mod unlock_verification_service;
+pub mod allowlist_service;
+pub mod subscription_service;
+pub mod indexer_service;
+pub mod payment_settlement_service;
 
 pub use resume_service::ResumeService;
 pub use user_service::UserService;
 pub use unlock_record_service::UnlockRecordService;
 pub use access_log_service::AccessLogService;
+pub use auth_service::AuthService;
+pub use organization_service::OrganizationService;
+pub use api_key_service::ApiKeyService;
+pub use user_wallet_service::UserWalletService;
+pub use receipt_service::ReceiptService;
+pub use payout_service::PayoutService;
+pub use resume_tier_service::ResumeTierService;
+pub use dispute_service::DisputeService;
+pub use credit_service::CreditService;
+pub use coupon_service::CouponService;
+pub use unlock_verification_service::UnlockVerificationService;
+pub use allowlist_service::AllowlistService;
+pub use subscription_service::SubscriptionService;
+pub use indexer_service::IndexerService;
+pub use payment_settlement_service::PaymentSettlementService;
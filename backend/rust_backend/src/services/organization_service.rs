@@ -0,0 +1,224 @@
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use crate::auth::AuthError;
+use crate::dao::{OrganizationDao, OrganizationMemberDao, UnlockRecordDao, UserDao};
+use crate::entities::{organization, organization_member, unlock_record};
+use crate::models::OrganizationSpendResponse;
+use crate::money::{self, Money};
+
+/// 组织成员角色
+pub const ORG_ROLE_ADMIN: &str = "admin";
+pub const ORG_ROLE_MEMBER: &str = "member";
+
+/// 招聘组织服务
+pub struct OrganizationService;
+
+impl OrganizationService {
+    /// 创建组织，创建者自动成为管理员
+    pub async fn create_organization(
+        db: &DatabaseConnection,
+        user_id: i64,
+        wallet_address: &str,
+        name: &str,
+    ) -> Result<organization::Model, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Organization name cannot be empty".to_string());
+        }
+
+        let txn = db
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        let org = OrganizationDao::create(&txn, name.to_string(), user_id)
+            .await
+            .map_err(|e| format!("Failed to create organization: {}", e))?;
+
+        OrganizationMemberDao::add(&txn, org.id, user_id, wallet_address.to_string(), ORG_ROLE_ADMIN)
+            .await
+            .map_err(|e| format!("Failed to add organization admin: {}", e))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| format!("Failed to commit organization: {}", e))?;
+
+        Ok(org)
+    }
+
+    /// 获取用户加入的组织及其角色
+    pub async fn get_my_organizations(
+        db: &DatabaseConnection,
+        user_id: i64,
+    ) -> Result<Vec<(organization::Model, String)>, String> {
+        let memberships = OrganizationMemberDao::find_by_user(db, user_id)
+            .await
+            .map_err(|e| format!("Failed to fetch memberships: {}", e))?;
+
+        let orgs = OrganizationDao::find_by_ids(db, memberships.iter().map(|m| m.organization_id).collect())
+            .await
+            .map_err(|e| format!("Failed to fetch organizations: {}", e))?;
+
+        Ok(orgs
+            .into_iter()
+            .filter_map(|org| {
+                let role = memberships
+                    .iter()
+                    .find(|m| m.organization_id == org.id)?
+                    .role
+                    .clone();
+                Some((org, role))
+            })
+            .collect())
+    }
+
+    /// 要求用户是组织成员（`admin_only` 时必须是管理员），否则返回 403
+    pub async fn require_member(
+        db: &DatabaseConnection,
+        organization_id: i64,
+        user_id: i64,
+        admin_only: bool,
+    ) -> Result<organization_member::Model, AuthError> {
+        let member = OrganizationMemberDao::find(db, organization_id, user_id)
+            .await
+            .map_err(|e| AuthError::Internal(format!("Failed to fetch membership: {}", e)))?
+            .ok_or_else(|| AuthError::Forbidden("Forbidden: You are not a member of this organization".to_string()))?;
+
+        if admin_only && member.role != ORG_ROLE_ADMIN {
+            return Err(AuthError::Forbidden(
+                "Forbidden: Organization admin role required".to_string(),
+            ));
+        }
+
+        Ok(member)
+    }
+
+    /// 获取组织成员列表
+    pub async fn get_members(
+        db: &DatabaseConnection,
+        organization_id: i64,
+    ) -> Result<Vec<organization_member::Model>, String> {
+        OrganizationMemberDao::find_by_organization(db, organization_id)
+            .await
+            .map_err(|e| format!("Failed to fetch members: {}", e))
+    }
+
+    /// 添加组织成员（成员钱包需已登录过）
+    pub async fn add_member(
+        db: &DatabaseConnection,
+        organization_id: i64,
+        wallet_address: &str,
+        role: Option<&str>,
+    ) -> Result<organization_member::Model, String> {
+        let role = role.unwrap_or(ORG_ROLE_MEMBER);
+        if role != ORG_ROLE_ADMIN && role != ORG_ROLE_MEMBER {
+            return Err(format!("Invalid organization role '{}', expected admin or member", role));
+        }
+
+        let user = UserDao::find_by_wallet(db, wallet_address)
+            .await
+            .map_err(|e| format!("Failed to fetch user: {}", e))?
+            .ok_or_else(|| "User not found, the wallet must sign in first".to_string())?;
+
+        let existing = OrganizationMemberDao::find(db, organization_id, user.id)
+            .await
+            .map_err(|e| format!("Failed to fetch membership: {}", e))?;
+        if existing.is_some() {
+            return Err("User is already a member of this organization".to_string());
+        }
+
+        OrganizationMemberDao::add(db, organization_id, user.id, user.wallet_address, role)
+            .await
+            .map_err(|e| format!("Failed to add member: {}", e))
+    }
+
+    /// 移除组织成员，组织至少保留一名管理员
+    pub async fn remove_member(
+        db: &DatabaseConnection,
+        organization_id: i64,
+        user_id: i64,
+    ) -> Result<(), String> {
+        let member = OrganizationMemberDao::find(db, organization_id, user_id)
+            .await
+            .map_err(|e| format!("Failed to fetch membership: {}", e))?
+            .ok_or_else(|| "Member not found".to_string())?;
+
+        if member.role == ORG_ROLE_ADMIN {
+            let admins = OrganizationMemberDao::count_admins(db, organization_id)
+                .await
+                .map_err(|e| format!("Failed to count admins: {}", e))?;
+            if admins <= 1 {
+                return Err("Cannot remove the last admin of an organization".to_string());
+            }
+        }
+
+        OrganizationMemberDao::remove(db, organization_id, user_id)
+            .await
+            .map_err(|e| format!("Failed to remove member: {}", e))?;
+
+        Ok(())
+    }
+
+    /// 获取组织已解锁的简历记录
+    pub async fn get_unlocks(
+        db: &DatabaseConnection,
+        organization_id: i64,
+    ) -> Result<Vec<unlock_record::Model>, String> {
+        UnlockRecordDao::find_by_organization(db, organization_id)
+            .await
+            .map_err(|e| format!("Failed to fetch organization unlocks: {}", e))
+    }
+
+    /// 统计组织消费
+    pub async fn get_spend(
+        db: &DatabaseConnection,
+        organization_id: i64,
+    ) -> Result<OrganizationSpendResponse, String> {
+        let records = Self::get_unlocks(db, organization_id).await?;
+
+        let amounts: Vec<Money> = records
+            .iter()
+            .map(|r| money::registry().stored(r.amount, &r.asset))
+            .collect();
+
+        Ok(OrganizationSpendResponse {
+            organization_id,
+            unlock_count: records.len() as u64,
+            totals: Money::totals(&amounts)?,
+        })
+    }
+
+    /// 用户所属的全部组织 ID
+    pub async fn organization_ids_of(
+        db: &DatabaseConnection,
+        user_id: i64,
+    ) -> Result<Vec<i64>, String> {
+        let memberships = OrganizationMemberDao::find_by_user(db, user_id)
+            .await
+            .map_err(|e| format!("Failed to fetch memberships: {}", e))?;
+
+        Ok(memberships.into_iter().map(|m| m.organization_id).collect())
+    }
+
+    /// 确定一次购买归属的组织
+    ///
+    /// 指定了组织时必须是其成员；未指定且只属于一个组织时归属该组织
+    pub async fn resolve_purchase_organization(
+        db: &DatabaseConnection,
+        user_id: i64,
+        requested: Option<i64>,
+    ) -> Result<Option<i64>, AuthError> {
+        if let Some(organization_id) = requested {
+            Self::require_member(db, organization_id, user_id, false).await?;
+            return Ok(Some(organization_id));
+        }
+
+        let ids = Self::organization_ids_of(db, user_id)
+            .await
+            .map_err(AuthError::Internal)?;
+
+        Ok(match ids.as_slice() {
+            [only] => Some(*only),
+            _ => None,
+        })
+    }
+}
@@ -0,0 +1,83 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use sea_orm::DatabaseConnection;
+use crate::dao::payment_settlement_dao::{SETTLEMENT_PURPOSE_CREDIT_TOPUP, SETTLEMENT_PURPOSE_UNLOCK};
+use crate::dao::PaymentSettlementDao;
+use crate::models::CommissionConfig;
+use crate::services::{CreditService, UnlockRecordService};
+use crate::utils::receipt_signer::ReceiptSigner;
+
+/// 对账间隔
+const RECONCILE_INTERVAL: Duration = Duration::from_secs(60);
+/// 结算后超过该时间仍未完成的意向才由对账任务处理，不与正在进行的购买请求竞争
+const RECONCILE_AFTER_SECS: i64 = 60;
+/// 每轮最多处理的意向数
+const RECONCILE_BATCH: u64 = 50;
+
+/// x402 结算意向对账：为已结算但没有写入解锁记录或额度流水的支付补写记录
+pub struct PaymentSettlementService;
+
+impl PaymentSettlementService {
+    /// 处理一批停留在 settled 的意向，返回完成的数量；单个意向失败时记录原因，下一轮重试
+    pub async fn reconcile(
+        db: &DatabaseConnection,
+        signer: &ReceiptSigner,
+        commission: &CommissionConfig,
+    ) -> Result<usize, String> {
+        let before = chrono::Utc::now().naive_utc() - chrono::Duration::seconds(RECONCILE_AFTER_SECS);
+        let settlements = PaymentSettlementDao::find_settled_before(db, before, RECONCILE_BATCH)
+            .await
+            .map_err(|e| format!("Failed to fetch settlements: {}", e))?;
+
+        let mut completed = 0;
+        for settlement in settlements {
+            let result = match settlement.purpose.as_str() {
+                SETTLEMENT_PURPOSE_UNLOCK => UnlockRecordService::complete_settlement(db, signer, commission, settlement.id)
+                    .await
+                    .map(|(record, _)| format!("unlock record {}", record.id)),
+                SETTLEMENT_PURPOSE_CREDIT_TOPUP => CreditService::complete_topup(db, settlement.id)
+                    .await
+                    .map(|transaction| format!("credit transaction {}", transaction.id)),
+                other => Err(format!("Unknown settlement purpose '{}'", other)),
+            };
+
+            match result {
+                Ok(written) => {
+                    log::info!(
+                        "Reconciled settlement {} (tx {:?}) as {}",
+                        settlement.id, settlement.transaction, written
+                    );
+                    completed += 1;
+                }
+                Err(e) => {
+                    log::error!("Failed to reconcile settlement {} (tx {:?}): {}", settlement.id, settlement.transaction, e);
+                    if let Err(db_err) = PaymentSettlementDao::record_error(db, settlement.id, &e).await {
+                        log::warn!("Failed to record settlement {} error: {}", settlement.id, db_err);
+                    }
+                }
+            }
+        }
+
+        Ok(completed)
+    }
+
+    /// 在后台任务中定期对账
+    pub fn spawn_reconciler(
+        db: DatabaseConnection,
+        signer: Arc<ReceiptSigner>,
+        commission: Arc<CommissionConfig>,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(RECONCILE_INTERVAL);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                interval.tick().await;
+                if let Err(e) = Self::reconcile(&db, &signer, &commission).await {
+                    println!("⚠️  Settlement reconciler: {}", e);
+                }
+            }
+        })
+    }
+}
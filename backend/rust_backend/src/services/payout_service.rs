@@ -0,0 +1,154 @@
+use std::collections::{BTreeMap, HashSet};
+
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use crate::dao::seller_payout_dao::PAYOUT_STATUS_PAID;
+use crate::dao::{SellerLedgerDao, SellerPayoutDao};
+use crate::entities::{seller_ledger_entry, seller_payout};
+use crate::models::{AssetBalance, SellerBalanceResponse};
+use crate::money;
+
+pub struct PayoutService;
+
+impl PayoutService {
+    /// 卖家余额：按资产分别统计未结算 / 结算中 / 已打款
+    pub async fn get_balance(
+        db: &DatabaseConnection,
+        seller_id: i64,
+    ) -> Result<SellerBalanceResponse, String> {
+        let entries = SellerLedgerDao::find_by_seller(db, seller_id)
+            .await
+            .map_err(|e| format!("Failed to fetch ledger entries: {}", e))?;
+        let paid_payouts: HashSet<i64> = SellerPayoutDao::find_by_seller(db, seller_id)
+            .await
+            .map_err(|e| format!("Failed to fetch payouts: {}", e))?
+            .into_iter()
+            .filter(|p| p.status == PAYOUT_STATUS_PAID)
+            .map(|p| p.id)
+            .collect();
+
+        let mut balances: Vec<AssetBalance> = Vec::new();
+        for entry in &entries {
+            let index = match balances.iter().position(|b| b.asset == entry.asset) {
+                Some(index) => index,
+                None => {
+                    let zero = money::registry().stored(0, &entry.asset);
+                    balances.push(AssetBalance {
+                        asset: entry.asset.clone(),
+                        pending: zero.clone(),
+                        processing: zero.clone(),
+                        paid: zero.clone(),
+                        total_gross: zero.clone(),
+                        total_fee: zero,
+                        entry_count: 0,
+                    });
+                    balances.len() - 1
+                }
+            };
+            let balance = &mut balances[index];
+
+            balance.entry_count += 1;
+            balance.total_gross.amount += entry.gross_amount;
+            balance.total_fee.amount += entry.fee_amount;
+            match entry.payout_id {
+                None => balance.pending.amount += entry.net_amount,
+                Some(id) if paid_payouts.contains(&id) => balance.paid.amount += entry.net_amount,
+                Some(_) => balance.processing.amount += entry.net_amount,
+            }
+        }
+
+        Ok(SellerBalanceResponse { seller_id, balances })
+    }
+
+    /// 卖家的账目明细
+    pub async fn get_ledger(
+        db: &DatabaseConnection,
+        seller_id: i64,
+    ) -> Result<Vec<seller_ledger_entry::Model>, String> {
+        SellerLedgerDao::find_by_seller(db, seller_id)
+            .await
+            .map_err(|e| format!("Failed to fetch ledger entries: {}", e))
+    }
+
+    /// 卖家的结算批次
+    pub async fn get_payouts(
+        db: &DatabaseConnection,
+        seller_id: i64,
+    ) -> Result<Vec<seller_payout::Model>, String> {
+        SellerPayoutDao::find_by_seller(db, seller_id)
+            .await
+            .map_err(|e| format!("Failed to fetch payouts: {}", e))
+    }
+
+    /// 把所有未结算的账目按卖家和资产打包成结算批次（管理员）
+    pub async fn create_batches(db: &DatabaseConnection) -> Result<Vec<seller_payout::Model>, String> {
+        let txn = db.begin().await.map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        let entries = SellerLedgerDao::find_unbatched_for_update(&txn)
+            .await
+            .map_err(|e| format!("Failed to fetch unbatched entries: {}", e))?;
+
+        let mut groups: BTreeMap<(i64, String), Vec<seller_ledger_entry::Model>> = BTreeMap::new();
+        for entry in entries {
+            groups.entry((entry.seller_id, entry.asset.clone())).or_default().push(entry);
+        }
+
+        let mut payouts = Vec::with_capacity(groups.len());
+        for ((seller_id, asset), entries) in groups {
+            // 退款冲正后合计不为正的留在下一批次，用后续收入抵扣
+            let net: i64 = entries.iter().map(|e| e.net_amount).sum();
+            if net <= 0 {
+                continue;
+            }
+            let amount = money::registry().stored(net, &asset);
+            // 打款到最近一笔账目记录的卖家钱包
+            let seller_wallet = entries
+                .iter()
+                .max_by_key(|e| e.id)
+                .map(|e| e.seller_wallet.clone())
+                .unwrap_or_default();
+
+            let payout = SellerPayoutDao::create(&txn, seller_id, seller_wallet, amount, entries.len() as i32)
+                .await
+                .map_err(|e| format!("Failed to create payout: {}", e))?;
+            SellerLedgerDao::assign_payout(&txn, entries.iter().map(|e| e.id).collect(), payout.id)
+                .await
+                .map_err(|e| format!("Failed to assign ledger entries: {}", e))?;
+
+            payouts.push(payout);
+        }
+
+        txn.commit().await.map_err(|e| format!("Failed to commit payouts: {}", e))?;
+
+        Ok(payouts)
+    }
+
+    /// 标记结算批次已打款（管理员），批次不存在时返回 None
+    pub async fn mark_paid(
+        db: &DatabaseConnection,
+        payout_id: i64,
+        transaction_digest: &str,
+    ) -> Result<Option<seller_payout::Model>, String> {
+        let transaction_digest = transaction_digest.trim();
+        if transaction_digest.is_empty() {
+            return Err("transaction_digest is required".to_string());
+        }
+
+        let Some(payout) = SellerPayoutDao::find_by_id(db, payout_id)
+            .await
+            .map_err(|e| format!("Failed to fetch payout: {}", e))?
+        else {
+            return Ok(None);
+        };
+
+        let updated = SellerPayoutDao::mark_paid(db, payout.id, transaction_digest.to_string())
+            .await
+            .map_err(|e| format!("Failed to mark payout paid: {}", e))?;
+        if !updated {
+            return Err(format!("Payout {} is already paid", payout_id));
+        }
+
+        SellerPayoutDao::find_by_id(db, payout_id)
+            .await
+            .map_err(|e| format!("Failed to fetch payout: {}", e))
+    }
+}
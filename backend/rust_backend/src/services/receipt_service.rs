@@ -0,0 +1,96 @@
+use sea_orm::{ConnectionTrait, DatabaseConnection, Set};
+use crate::auth::{AuthError, AuthenticatedWallet};
+use crate::dao::PaymentReceiptDao;
+use crate::entities::{payment_receipt, resume, unlock_record};
+use crate::models::{PaymentReceiptResponse, ReceiptPayload};
+use crate::utils::receipt_signer::{ReceiptSigner, RECEIPT_ALGORITHM};
+use crate::x402::facilitator::SettleResponse;
+
+/// 付费收据服务
+pub struct ReceiptService;
+
+impl ReceiptService {
+    /// 为一次已结算的解锁签发收据（与解锁记录在同一事务中写入）
+    pub async fn issue<C: ConnectionTrait>(
+        db: &C,
+        signer: &ReceiptSigner,
+        record: &unlock_record::Model,
+        resume: &resume::Model,
+        asset: &str,
+        settlement: &SettleResponse,
+    ) -> Result<payment_receipt::Model, String> {
+        let now = chrono::Utc::now();
+        let payload = ReceiptPayload {
+            receipt_id: uuid::Uuid::new_v4().to_string(),
+            resume_id: resume.resume_id.clone(),
+            tier: record.tier.clone(),
+            buyer: record.buyer_wallet.clone(),
+            seller: record.seller_wallet.clone(),
+            amount: record.amount,
+            asset: asset.to_string(),
+            network: settlement.network.clone(),
+            transaction: settlement.transaction.clone(),
+            issued_at: now.timestamp(),
+            key_id: signer.key_id().to_string(),
+        };
+        let text = serde_json::to_string(&payload)
+            .map_err(|e| format!("Failed to serialize receipt: {}", e))?;
+
+        let receipt = payment_receipt::ActiveModel {
+            receipt_id: Set(payload.receipt_id),
+            unlock_record_id: Set(record.id),
+            resume_id: Set(payload.resume_id),
+            buyer_id: Set(record.buyer_id),
+            seller_id: Set(resume.owner_id),
+            buyer_wallet: Set(payload.buyer),
+            seller_wallet: Set(payload.seller),
+            amount: Set(payload.amount),
+            asset: Set(payload.asset),
+            network: Set(payload.network),
+            transaction_digest: Set(payload.transaction),
+            signature: Set(signer.sign(text.as_bytes())),
+            payload: Set(text),
+            key_id: Set(payload.key_id),
+            created_at: Set(now.naive_utc()),
+            ..Default::default()
+        };
+
+        PaymentReceiptDao::create(db, receipt)
+            .await
+            .map_err(|e| format!("Failed to save receipt: {}", e))
+    }
+
+    /// 获取收据，只有买家和卖家（或管理员 override）可以查看
+    pub async fn get_receipt(
+        db: &DatabaseConnection,
+        receipt_id: &str,
+        wallet: &AuthenticatedWallet,
+    ) -> Result<Option<payment_receipt::Model>, AuthError> {
+        let receipt = PaymentReceiptDao::find_by_receipt_id(db, receipt_id)
+            .await
+            .map_err(|e| AuthError::Internal(format!("Failed to fetch receipt: {}", e)))?;
+
+        if let Some(receipt) = &receipt
+            && receipt.seller_id != wallet.user_id()
+        {
+            wallet.require_owner(Some(receipt.buyer_id), "this receipt")?;
+        }
+
+        Ok(receipt)
+    }
+
+    /// 转换为响应
+    pub fn to_response(receipt: payment_receipt::Model) -> Result<PaymentReceiptResponse, String> {
+        let parsed: ReceiptPayload = serde_json::from_str(&receipt.payload)
+            .map_err(|e| format!("Failed to parse receipt: {}", e))?;
+
+        Ok(PaymentReceiptResponse {
+            receipt_id: receipt.receipt_id,
+            algorithm: RECEIPT_ALGORITHM.to_string(),
+            key_id: receipt.key_id,
+            payload: receipt.payload,
+            signature: receipt.signature,
+            receipt: parsed,
+        })
+    }
+}
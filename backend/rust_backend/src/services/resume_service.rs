@@ -1,6 +1,9 @@
 use crate::models::{Resume, CreateResumeRequest, MyResumeSummary, ResumeListItem};
-use crate::dao::ResumeDao;
-use crate::services::UserService;
+use crate::auth::AuthError;
+use crate::dao::{ResumeDao, UserDao};
+use crate::entities::resume;
+use crate::money::{self, Money};
+use crate::services::ResumeTierService;
 use sea_orm::DatabaseConnection;
 
 /// 简历服务层
@@ -8,23 +11,22 @@ pub struct ResumeService;
 
 impl ResumeService {
     /// 创建简历（前端已加密并上传，后端只存储 CID）
+    ///
+    /// `user_id` / `owner` 为已认证的调用者
     pub async fn create_resume(
         db: &DatabaseConnection,
+        user_id: i64,
+        owner: &str,
         request: CreateResumeRequest,
         blob_id: String,  // Walrus Blob ID
     ) -> Result<String, String> {
-        // 1. 先确保用户存在(如果不存在则自动创建)
-        let user_id = UserService::create_or_get_user(db, request.owner.clone())
-            .await
-            .map_err(|e| format!("Failed to create/get user: {}", e))?;
-
-        // 2. 生成简历 ID
+        // 1. 生成简历 ID
         let resume_id = format!("resume-{}", uuid::Uuid::new_v4());
         let now = chrono::Utc::now().timestamp();
 
         let resume = Resume {
             id: resume_id.clone(),
-            owner: request.owner.clone(),
+            owner: owner.to_string(),
             personal: request.personal,
             skills: request.skills,
             desired_position: request.desired_position,
@@ -45,7 +47,7 @@ impl ResumeService {
         log::info!("Creating resume with Blob ID: {}, encryption_type: {:?}, encryption_mode: {:?}", 
                    blob_id, request.encryption_type, request.encryption_mode);
 
-        // 3. 创建简历记录
+        // 2. 创建简历记录
         // 注意: 简单加密时 encryption_key 由前端管理不存储; Seal 加密时为 None
         let encryption_key = request.encryption_key.unwrap_or_default();
         
@@ -77,7 +79,7 @@ impl ResumeService {
                 id: r.resume_id.clone(),
                 name: r.name.clone(), // 映射 name 字段
                 owner: r.owner_wallet.clone(),
-                price: r.price,
+                price: money::registry().stored(r.price, &r.price_asset),
                 policy_object_id: r.policy_object_id.clone(),
                 encryption_id: r.encryption_id.clone(),
                 encryption_type: if r.encryption_type.is_empty() {
@@ -100,13 +102,21 @@ impl ResumeService {
     }
 
     /// 获取我的简历（只返回摘要信息）
+    ///
+    /// `owner` 可以是用户的任一关联钱包，返回该用户名下的全部简历
     pub async fn get_my_resumes(
         db: &DatabaseConnection,
         owner: &str
     ) -> Result<Vec<MyResumeSummary>, String> {
-        let resumes = ResumeDao::find_by_owner(db, owner)
+        let user = UserDao::find_by_wallet(db, owner)
             .await
-            .map_err(|e| format!("Failed to fetch resumes: {}", e))?;
+            .map_err(|e| format!("Failed to fetch user: {}", e))?;
+
+        let resumes = match user {
+            Some(user) => ResumeDao::find_by_owner_id(db, user.id).await,
+            None => ResumeDao::find_by_owner(db, owner).await,
+        }
+        .map_err(|e| format!("Failed to fetch resumes: {}", e))?;
         
         // 只返回摘要信息，不包含详细内容
         let summaries: Vec<MyResumeSummary> = resumes.iter()
@@ -118,7 +128,7 @@ impl ResumeService {
                     owner: resume.owner,
                     created_at: resume.created_at,
                     updated_at: resume.updated_at,
-                    price: r.price,
+                    price: money::registry().stored(r.price, &r.price_asset),
                     view_count: r.view_count,
                     unlock_count: r.unlock_count,
                     status: r.status.clone(),
@@ -135,11 +145,11 @@ impl ResumeService {
         Ok(summaries)
     }
 
-    /// 根据 owner 和简历 ID 获取简历详情
+    /// 获取简历详情（`user_id` 为已认证的用户）
     pub async fn get_resume_detail(
         db: &DatabaseConnection,
         resume_id: &str,
-        owner: &str
+        user_id: i64
     ) -> Result<Resume, String> {
         // 1. 获取简历
         let resume = ResumeDao::find_by_resume_id(db, resume_id)
@@ -148,9 +158,7 @@ impl ResumeService {
             .ok_or_else(|| "Resume not found".to_string())?;
 
         // 2. 验证所有权
-        if resume.owner_wallet != owner {
-            return Err("Unauthorized: You don't own this resume".to_string());
-        }
+        Self::ensure_owner(&resume, user_id)?;
 
         // 3. 从 summary 解析完整简历数据
         let mut resume_data: Resume = serde_json::from_value(resume.summary.clone())
@@ -170,10 +178,11 @@ impl ResumeService {
         Ok(resume_data)
     }
 
-    /// 更新简历
+    /// 更新简历（`user_id` 为已认证的用户）
     pub async fn update_resume(
         db: &DatabaseConnection,
         resume_id: &str,
+        user_id: i64,
         request: CreateResumeRequest
     ) -> Result<(), String> {
         let existing = ResumeDao::find_by_resume_id(db, resume_id)
@@ -181,6 +190,9 @@ impl ResumeService {
             .map_err(|e| format!("Failed to fetch resume: {}", e))?
             .ok_or_else(|| "Resume not found".to_string())?;
 
+        // 验证所有权
+        Self::ensure_owner(&existing, user_id)?;
+
         // 使用新的 blob_id（如果提供），否则保留旧的
         let new_blob_id = request.blob_id
             .clone()
@@ -224,11 +236,11 @@ impl ResumeService {
         Ok(())
     }
 
-    /// 删除简历
+    /// 删除简历（`user_id` 为已认证的用户）
     pub async fn delete_resume(
         db: &DatabaseConnection,
         resume_id: &str,
-        owner: &str
+        user_id: i64
     ) -> Result<(), String> {
         let resume = ResumeDao::find_by_resume_id(db, resume_id)
             .await
@@ -236,9 +248,7 @@ impl ResumeService {
             .ok_or_else(|| "Resume not found".to_string())?;
 
         // 验证所有权
-        if resume.owner_wallet != owner {
-            return Err("Unauthorized: You don't own this resume".to_string());
-        }
+        Self::ensure_owner(&resume, user_id)?;
 
         ResumeDao::soft_delete(db, resume_id)
             .await
@@ -247,12 +257,12 @@ impl ResumeService {
         Ok(())
     }
 
-    /// 设置简历价格
+    /// 设置简历价格（`user_id` 为已认证的用户）
     pub async fn set_resume_price(
         db: &DatabaseConnection,
         resume_id: &str,
-        owner: &str,
-        price: u64,
+        user_id: i64,
+        price: &Money,
     ) -> Result<(), String> {
         let resume = ResumeDao::find_by_resume_id(db, resume_id)
             .await
@@ -260,9 +270,11 @@ impl ResumeService {
             .ok_or_else(|| "Resume not found".to_string())?;
 
         // 验证所有权
-        if resume.owner_wallet != owner {
-            return Err("Unauthorized: You don't own this resume".to_string());
-        }
+        Self::ensure_owner(&resume, user_id)?;
+
+        // 新价格不能让已有档位比完整简历更贵或换成另一种资产
+        let tiers = ResumeTierService::load(db, &resume).await?;
+        ResumeTierService::check_full_price(&tiers, price)?;
 
         // 更新价格
         ResumeDao::update_price(db, resume_id, price)
@@ -272,11 +284,11 @@ impl ResumeService {
         Ok(())
     }
 
-    /// 更新简历名称
+    /// 更新简历名称（`user_id` 为已认证的用户）
     pub async fn update_resume_name(
         db: &DatabaseConnection,
         resume_id: &str,
-        owner: &str,
+        user_id: i64,
         name: String,
     ) -> Result<(), String> {
         let resume = ResumeDao::find_by_resume_id(db, resume_id)
@@ -285,9 +297,7 @@ impl ResumeService {
             .ok_or_else(|| "Resume not found".to_string())?;
 
         // 验证所有权
-        if resume.owner_wallet != owner {
-            return Err("Unauthorized: You don't own this resume".to_string());
-        }
+        Self::ensure_owner(&resume, user_id)?;
 
         // 更新名称
         ResumeDao::update_name(db, resume_id, name)
@@ -296,4 +306,34 @@ impl ResumeService {
 
         Ok(())
     }
+
+    /// 查询简历所有者的用户 ID，简历不存在时返回 None
+    pub async fn owner_id_of(
+        db: &DatabaseConnection,
+        resume_id: &str,
+    ) -> Result<Option<i64>, AuthError> {
+        ResumeDao::find_by_resume_id(db, resume_id)
+            .await
+            .map(|resume| resume.map(|r| r.owner_id))
+            .map_err(|e| AuthError::Internal(format!("Failed to fetch resume: {}", e)))
+    }
+
+    /// 按主键查询简历所有者的用户 ID，简历不存在时返回 None
+    pub async fn owner_id_by_id(
+        db: &DatabaseConnection,
+        id: i64,
+    ) -> Result<Option<i64>, AuthError> {
+        ResumeDao::find_by_id(db, id)
+            .await
+            .map(|resume| resume.map(|r| r.owner_id))
+            .map_err(|e| AuthError::Internal(format!("Failed to fetch resume: {}", e)))
+    }
+
+    /// 校验已认证的用户是否为简历所有者（用户的任一关联钱包创建的简历都算）
+    fn ensure_owner(resume: &resume::Model, user_id: i64) -> Result<(), String> {
+        if resume.owner_id != user_id {
+            return Err("Unauthorized: You don't own this resume".to_string());
+        }
+        Ok(())
+    }
 }
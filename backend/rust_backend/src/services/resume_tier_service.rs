@@ -0,0 +1,358 @@
+use std::collections::HashSet;
+
+use sea_orm::{ConnectionTrait, DatabaseConnection, Set, TransactionTrait};
+use crate::dao::{ResumeDao, ResumeTierDao, UnlockRecordDao};
+use crate::entities::{resume, resume_tier, unlock_record};
+use crate::models::{ResumeTierRequest, ResumeTierResponse};
+use crate::money::{self, Money};
+use crate::services::OrganizationService;
+
+/// 完整简历档位，由 resumes 表本身表示，始终为最高档位
+pub const FULL_TIER: &str = "full";
+
+/// 档位名称最大长度
+const MAX_TIER_NAME_LEN: usize = 32;
+
+/// 档位内容的解密凭据
+#[derive(Debug, Clone)]
+pub struct TierCredentials {
+    pub blob_id: String,
+    pub encryption_type: String,
+    pub encryption_mode: Option<String>,
+    pub encryption_id: Option<String>,
+    pub policy_object_id: Option<String>,
+    pub encryption_key: Option<String>,
+}
+
+/// 可购买的档位
+#[derive(Debug, Clone)]
+pub struct Tier {
+    pub name: String,
+    pub level: i32,
+    pub price: Money,
+    pub credentials: TierCredentials,
+}
+
+impl From<&Tier> for ResumeTierResponse {
+    fn from(tier: &Tier) -> Self {
+        Self {
+            tier: tier.name.clone(),
+            level: tier.level,
+            price: tier.price.clone(),
+            encryption_type: tier.credentials.encryption_type.clone(),
+        }
+    }
+}
+
+/// 简历价格档位服务
+pub struct ResumeTierService;
+
+impl ResumeTierService {
+    /// 简历的全部档位（按等级从低到高，最后为 `full`）
+    pub fn build(resume: &resume::Model, stored: Vec<resume_tier::Model>) -> Vec<Tier> {
+        let registry = money::registry();
+        let mut tiers: Vec<Tier> = stored
+            .into_iter()
+            .map(|t| Tier {
+                name: t.tier,
+                level: t.level,
+                price: registry.stored(t.price, &t.price_asset),
+                credentials: TierCredentials {
+                    blob_id: t.blob_id,
+                    encryption_type: t.encryption_type,
+                    encryption_mode: resume.encryption_mode.clone(),
+                    encryption_id: t.encryption_id,
+                    policy_object_id: t.policy_object_id,
+                    encryption_key: t.encryption_key,
+                },
+            })
+            .collect();
+
+        tiers.push(Tier {
+            name: FULL_TIER.to_string(),
+            level: i32::MAX,
+            price: registry.stored(resume.price, &resume.price_asset),
+            credentials: TierCredentials {
+                blob_id: resume.blob_id.clone(),
+                encryption_type: resume.encryption_type.clone(),
+                encryption_mode: resume.encryption_mode.clone(),
+                encryption_id: resume.encryption_id.clone(),
+                policy_object_id: resume.policy_object_id.clone(),
+                encryption_key: resume.encryption_key.clone(),
+            },
+        });
+        tiers
+    }
+
+    /// 读取简历的全部档位
+    pub async fn load<C: ConnectionTrait>(db: &C, resume: &resume::Model) -> Result<Vec<Tier>, String> {
+        let stored = ResumeTierDao::find_by_resume(db, resume.id)
+            .await
+            .map_err(|e| format!("Failed to fetch resume tiers: {}", e))?;
+        Ok(Self::build(resume, stored))
+    }
+
+    /// 解锁记录中已拥有的最高档位
+    pub fn owned<'a>(tiers: &'a [Tier], records: &[unlock_record::Model]) -> Option<&'a Tier> {
+        records
+            .iter()
+            .filter_map(|r| tiers.iter().find(|t| t.name == r.tier))
+            .max_by_key(|t| t.level)
+    }
+
+    /// 从已拥有的档位升级到目标档位需要支付的金额：差价，已拥有更高档位时为 0
+    ///
+    /// 两个档位的计价资产不同时不抵扣，按目标档位全价计算。
+    pub fn upgrade_price(target: &Tier, owned: Option<&Tier>) -> Money {
+        match owned {
+            Some(owned) if owned.level >= target.level => Money { amount: 0, ..target.price.clone() },
+            Some(owned) if owned.price.asset == target.price.asset => Money {
+                amount: (target.price.amount - owned.price.amount).max(0),
+                ..target.price.clone()
+            },
+            _ => target.price.clone(),
+        }
+    }
+
+    /// 本次购买应付的金额；档位不存在时返回 None
+    ///
+    /// `buyer_id` 为 None（未登录）时按全价计算。
+    pub async fn quote(
+        db: &DatabaseConnection,
+        resume: &resume::Model,
+        tier: &str,
+        buyer_id: Option<i64>,
+    ) -> Result<Option<Money>, String> {
+        let tiers = Self::load(db, resume).await?;
+        let Some(target) = tiers.iter().find(|t| t.name == tier) else {
+            return Ok(None);
+        };
+
+        let records = match buyer_id {
+            Some(buyer_id) => {
+                let organization_ids = OrganizationService::organization_ids_of(db, buyer_id).await?;
+                UnlockRecordDao::find_all_by_resume_for_buyer(db, resume.id, buyer_id, &organization_ids)
+                    .await
+                    .map_err(|e| format!("Failed to fetch unlock records: {}", e))?
+            }
+            None => Vec::new(),
+        };
+
+        Ok(Some(Self::upgrade_price(target, Self::owned(&tiers, &records))))
+    }
+
+    /// 公开的档位列表；简历不存在或已下架时返回 None
+    pub async fn list(
+        db: &DatabaseConnection,
+        resume_id: &str,
+    ) -> Result<Option<Vec<ResumeTierResponse>>, String> {
+        let Some(resume) = ResumeDao::find_by_resume_id(db, resume_id)
+            .await
+            .map_err(|e| format!("Failed to fetch resume: {}", e))?
+            .filter(|r| r.status == "active")
+        else {
+            return Ok(None);
+        };
+
+        let tiers = Self::load(db, &resume).await?;
+        Ok(Some(tiers.iter().map(Into::into).collect()))
+    }
+
+    /// 设置简历的价格档位（`user_id` 为已认证的用户，必须是简历所有者）
+    ///
+    /// 已售出的档位不能删除，以便买家之后按差价升级。
+    pub async fn set_tiers(
+        db: &DatabaseConnection,
+        resume_id: &str,
+        user_id: i64,
+        requests: Vec<ResumeTierRequest>,
+    ) -> Result<Vec<ResumeTierResponse>, String> {
+        let resume = ResumeDao::find_by_resume_id(db, resume_id)
+            .await
+            .map_err(|e| format!("Failed to fetch resume: {}", e))?
+            .filter(|r| r.status != "deleted")
+            .ok_or_else(|| "Resume not found".to_string())?;
+        if resume.owner_id != user_id {
+            return Err("Unauthorized: You don't own this resume".to_string());
+        }
+
+        let full_price = money::registry().stored(resume.price, &resume.price_asset);
+        let sold = UnlockRecordDao::find_sold_tiers(db, resume.id)
+            .await
+            .map_err(|e| format!("Failed to fetch sold tiers: {}", e))?;
+        let validated = Self::validate(&requests, &full_price, &sold)?;
+
+        let now = chrono::Utc::now().naive_utc();
+        let models = requests
+            .into_iter()
+            .zip(validated)
+            .map(|(request, price)| resume_tier::ActiveModel {
+                resume_id: Set(resume.id),
+                tier: Set(request.tier.trim().to_string()),
+                level: Set(request.level),
+                price: Set(price.amount),
+                price_asset: Set(price.asset),
+                blob_id: Set(request.blob_id.trim().to_string()),
+                encryption_type: Set(request
+                    .encryption_type
+                    .unwrap_or_else(|| resume.encryption_type.clone())),
+                encryption_id: Set(request.encryption_id),
+                policy_object_id: Set(request.policy_object_id),
+                encryption_key: Set(request.encryption_key),
+                created_at: Set(now),
+                updated_at: Set(now),
+                ..Default::default()
+            })
+            .collect();
+
+        let txn = db.begin().await.map_err(|e| format!("Failed to start transaction: {}", e))?;
+        ResumeTierDao::replace(&txn, resume.id, models)
+            .await
+            .map_err(|e| format!("Failed to save resume tiers: {}", e))?;
+        txn.commit().await.map_err(|e| format!("Failed to commit resume tiers: {}", e))?;
+
+        let tiers = Self::load(db, &resume).await?;
+        Ok(tiers.iter().map(Into::into).collect())
+    }
+
+    /// 校验完整简历的新价格：与已有档位同一资产，且不低于任何档位（否则档位升级到完整简历将免费）
+    pub fn check_full_price(tiers: &[Tier], full_price: &Money) -> Result<(), String> {
+        for tier in tiers.iter().filter(|t| t.name != FULL_TIER) {
+            if tier.price.asset != full_price.asset {
+                return Err(format!(
+                    "Tier '{}' is priced in {}, the full resume must use the same asset",
+                    tier.name, tier.price.asset
+                ));
+            }
+            if tier.price.amount > full_price.amount {
+                return Err(format!(
+                    "Tier '{}' costs more than the new full resume price ({})",
+                    tier.name, tier.price
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// 校验档位设置，返回每个档位解析后的价格
+    ///
+    /// 名称和等级唯一，价格按等级不递减且不高于完整简历，已售出的档位必须保留。
+    fn validate(requests: &[ResumeTierRequest], full_price: &Money, sold: &[String]) -> Result<Vec<Money>, String> {
+        let asset = money::registry().require(&full_price.asset)?;
+        let mut names = HashSet::new();
+        let mut levels = HashSet::new();
+        let mut prices = Vec::with_capacity(requests.len());
+
+        for request in requests {
+            let name = request.tier.trim();
+            if name.is_empty()
+                || name.len() > MAX_TIER_NAME_LEN
+                || !name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+            {
+                return Err(format!("Invalid tier name '{}'", request.tier));
+            }
+            if name == FULL_TIER {
+                return Err(format!("Tier '{}' is reserved for the full resume", FULL_TIER));
+            }
+            if !names.insert(name) {
+                return Err(format!("Duplicate tier '{}'", name));
+            }
+            if request.level <= 0 || request.level == i32::MAX {
+                return Err(format!("Tier '{}' has an invalid level", name));
+            }
+            if !levels.insert(request.level) {
+                return Err(format!("Duplicate tier level {}", request.level));
+            }
+            if request.blob_id.trim().is_empty() {
+                return Err(format!("Tier '{}' requires a blob_id", name));
+            }
+            if let Some(encryption_type) = &request.encryption_type
+                && encryption_type != "simple"
+                && encryption_type != "seal"
+            {
+                return Err(format!("Tier '{}' has an invalid encryption_type", name));
+            }
+
+            let price = asset.parse(&request.price)?;
+            if price.amount > full_price.amount {
+                return Err(format!(
+                    "Tier '{}' costs more than the full resume ({})",
+                    name, full_price
+                ));
+            }
+            prices.push((request.level, price));
+        }
+
+        let mut by_level: Vec<&(i32, Money)> = prices.iter().collect();
+        by_level.sort_by_key(|(level, _)| *level);
+        if by_level.windows(2).any(|w| w[0].1.amount > w[1].1.amount) {
+            return Err("Tier prices must not decrease as the level increases".to_string());
+        }
+
+        if let Some(missing) = sold.iter().find(|t| t.as_str() != FULL_TIER && !names.contains(t.as_str())) {
+            return Err(format!("Tier '{}' has been sold and cannot be removed", missing));
+        }
+
+        Ok(prices.into_iter().map(|(_, price)| price).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tier(name: &str, level: i32, amount: i64) -> Tier {
+        Tier {
+            name: name.to_string(),
+            level,
+            price: Money { amount, asset: "USDC".to_string(), decimals: 6 },
+            credentials: TierCredentials {
+                blob_id: format!("blob-{}", name),
+                encryption_type: "seal".to_string(),
+                encryption_mode: None,
+                encryption_id: None,
+                policy_object_id: None,
+                encryption_key: None,
+            },
+        }
+    }
+
+    fn request(name: &str, level: i32, price: &str) -> ResumeTierRequest {
+        ResumeTierRequest {
+            tier: name.to_string(),
+            level,
+            price: price.to_string(),
+            blob_id: format!("blob-{}", name),
+            encryption_type: None,
+            encryption_id: None,
+            policy_object_id: None,
+            encryption_key: None,
+        }
+    }
+
+    #[test]
+    fn test_upgrade_pays_only_the_difference() {
+        let profile = tier("profile", 1, 1_000_000);
+        let contact = tier("contact", 2, 3_000_000);
+        let full = tier(FULL_TIER, i32::MAX, 5_000_000);
+
+        assert_eq!(ResumeTierService::upgrade_price(&full, None).amount, 5_000_000);
+        assert_eq!(ResumeTierService::upgrade_price(&contact, Some(&profile)).amount, 2_000_000);
+        assert_eq!(ResumeTierService::upgrade_price(&full, Some(&contact)).amount, 2_000_000);
+        // 已拥有更高档位时无需再付费
+        assert_eq!(ResumeTierService::upgrade_price(&profile, Some(&full)).amount, 0);
+
+        let full_price = full.price.clone();
+        assert!(ResumeTierService::validate(&[request("profile", 1, "1"), request("contact", 2, "3")], &full_price, &[]).is_ok());
+        assert!(ResumeTierService::validate(&[request("profile", 1, "3"), request("contact", 2, "1")], &full_price, &[]).is_err());
+        assert!(ResumeTierService::validate(&[request("contact", 2, "6")], &full_price, &[]).is_err());
+        assert!(ResumeTierService::validate(&[request(FULL_TIER, 1, "1")], &full_price, &[]).is_err());
+        assert!(ResumeTierService::validate(&[request("profile", 1, "1")], &full_price, &["contact".to_string()]).is_err());
+
+        // 完整简历改价后不能低于已有档位，也不能换资产
+        let tiers = [profile.clone(), contact.clone(), full.clone()];
+        assert!(ResumeTierService::check_full_price(&tiers, &Money { amount: 3_000_000, ..full_price.clone() }).is_ok());
+        assert!(ResumeTierService::check_full_price(&tiers, &Money { amount: 2_999_999, ..full_price.clone() }).is_err());
+        assert!(ResumeTierService::check_full_price(&tiers, &Money { asset: "SUI".to_string(), ..full_price }).is_err());
+    }
+}
@@ -0,0 +1,194 @@
+use sea_orm::{DatabaseConnection, SqlErr, TransactionTrait};
+use crate::dao::{ResumeDao, ResumeTierDao, SubscriptionServiceDao, UnlockRecordDao, UserDao};
+use crate::entities::{resume, unlock_record};
+use crate::models::UnlockStatus;
+use crate::money;
+use crate::services::resume_tier_service::FULL_TIER;
+use crate::services::UnlockRecordService;
+use crate::utils::validator::Validator;
+
+/// 订阅服务收取的资产（`subscription::subscribe` 只接受 `Coin<SUI>`）
+const SUBSCRIPTION_ASSET: &str = "SUI";
+
+/// 根据链上 subscription 事件同步订阅服务，并为订阅写入解锁记录
+pub struct SubscriptionService;
+
+impl SubscriptionService {
+    /// 同步订阅服务的订阅费和有效期
+    #[allow(clippy::too_many_arguments)]
+    pub async fn record_service(
+        db: &DatabaseConnection,
+        service_id: &str,
+        name: &str,
+        fee: i64,
+        ttl_ms: i64,
+        owner: &str,
+        tx_digest: &str,
+    ) -> Result<(), String> {
+        let service_id = Self::normalize(service_id)?;
+        let owner = Self::normalize(owner)?;
+        let resume_id = Self::find_resume(db, &service_id).await?.map(|(resume, _)| resume.id);
+
+        SubscriptionServiceDao::upsert(
+            db,
+            service_id,
+            name.to_string(),
+            fee,
+            ttl_ms,
+            owner,
+            resume_id,
+            tx_digest.to_string(),
+        )
+        .await
+        .map_err(|e| format!("Failed to record subscription service: {}", e))?;
+
+        Ok(())
+    }
+
+    /// 为链上订阅写入 confirmed 解锁记录（以交易摘要去重）
+    ///
+    /// 订阅的服务不是任何简历的策略、订阅者是简历所有者，或该交易已有记录时不新建记录；
+    /// 已有的 pending 记录与订阅相符时直接确认。返回新建或被确认的记录。
+    pub async fn record_subscription(
+        db: &DatabaseConnection,
+        service_id: &str,
+        subscriber: &str,
+        fee_paid: i64,
+        created_at_ms: Option<i64>,
+        tx_digest: &str,
+    ) -> Result<Option<unlock_record::Model>, String> {
+        let service_id = Self::normalize(service_id)?;
+        let subscriber = Self::normalize(subscriber)?;
+        let block_time = created_at_ms.map(|ms| ms / 1000);
+
+        let Some((resume, tier)) = Self::find_resume(db, &service_id).await? else {
+            log::info!("Subscription {} is for service {} which no resume uses, skipping", tx_digest, service_id);
+            return Ok(None);
+        };
+
+        if let Some(existing) = UnlockRecordDao::find_by_signature(db, tx_digest)
+            .await
+            .map_err(|e| format!("Failed to fetch unlock record: {}", e))?
+        {
+            return Self::confirm_existing(db, existing, &resume, &subscriber, block_time).await;
+        }
+
+        let buyer_id = UserDao::create_or_get(db, subscriber.clone())
+            .await
+            .map_err(|e| format!("Failed to fetch subscriber: {}", e))?;
+        if buyer_id == resume.owner_id {
+            return Ok(None);
+        }
+
+        let seller_wallet = SubscriptionServiceDao::find_by_service_id(db, &service_id)
+            .await
+            .map_err(|e| format!("Failed to fetch subscription service: {}", e))?
+            .map(|service| service.owner)
+            .unwrap_or_else(|| resume.owner_wallet.clone());
+
+        let txn = db.begin().await.map_err(|e| format!("Failed to start transaction: {}", e))?;
+        let record = match UnlockRecordDao::create(
+            &txn,
+            resume.id,
+            buyer_id,
+            subscriber.clone(),
+            None,
+            seller_wallet,
+            money::registry().stored(fee_paid, SUBSCRIPTION_ASSET),
+            tier,
+            tx_digest.to_string(),
+            UnlockStatus::Confirmed,
+            block_time,
+        )
+        .await
+        {
+            Ok(record) => record,
+            // 客户端同时提交了该交易的解锁记录
+            Err(e) if matches!(e.sql_err(), Some(SqlErr::UniqueConstraintViolation(_))) => {
+                drop(txn);
+                let existing = UnlockRecordDao::find_by_signature(db, tx_digest)
+                    .await
+                    .map_err(|e| format!("Failed to fetch unlock record: {}", e))?
+                    .ok_or_else(|| "Unlock record not found".to_string())?;
+                return Self::confirm_existing(db, existing, &resume, &subscriber, block_time).await;
+            }
+            Err(e) => return Err(format!("Failed to create unlock record: {}", e)),
+        };
+        UnlockRecordDao::record_verification(&txn, record.id, None, block_time)
+            .await
+            .map_err(|e| format!("Failed to record verification: {}", e))?;
+        txn.commit().await.map_err(|e| format!("Failed to commit unlock record: {}", e))?;
+
+        log::info!(
+            "Recorded subscription {} to service {} as unlock record {}",
+            tx_digest, service_id, record.id
+        );
+        Ok(Some(record))
+    }
+
+    /// 确认与订阅相符的 pending 记录，其余状态保持不变
+    async fn confirm_existing(
+        db: &DatabaseConnection,
+        existing: unlock_record::Model,
+        resume: &resume::Model,
+        subscriber: &str,
+        block_time: Option<i64>,
+    ) -> Result<Option<unlock_record::Model>, String> {
+        if existing.status != UnlockStatus::Pending.as_str() {
+            return Ok(None);
+        }
+        let buyer = Validator::normalize_sui_address(&existing.buyer_wallet).ok();
+        if existing.resume_id != resume.id || buyer.as_deref() != Some(subscriber) {
+            log::warn!(
+                "Unlock record {} (tx {}) does not match the on-chain subscription, leaving it pending",
+                existing.id, existing.transaction_signature
+            );
+            return Ok(None);
+        }
+
+        let txn = db.begin().await.map_err(|e| format!("Failed to start transaction: {}", e))?;
+        let record = UnlockRecordDao::find_by_id_for_update(&txn, existing.id)
+            .await
+            .map_err(|e| format!("Failed to fetch unlock record: {}", e))?
+            .ok_or_else(|| "Unlock record not found".to_string())?;
+        if record.status != UnlockStatus::Pending.as_str() {
+            return Ok(None);
+        }
+        UnlockRecordService::transition(&txn, &record, UnlockStatus::Confirmed).await?;
+        UnlockRecordDao::record_verification(&txn, record.id, None, block_time)
+            .await
+            .map_err(|e| format!("Failed to record verification: {}", e))?;
+        let record = UnlockRecordDao::find_by_id_for_update(&txn, record.id)
+            .await
+            .map_err(|e| format!("Failed to fetch unlock record: {}", e))?
+            .ok_or_else(|| "Unlock record not found".to_string())?;
+        txn.commit().await.map_err(|e| format!("Failed to commit verification: {}", e))?;
+
+        Ok(Some(record))
+    }
+
+    /// 以该服务为策略的简历及对应档位：简历本身为 `full`，否则为策略匹配的档位
+    async fn find_resume(db: &DatabaseConnection, service_id: &str) -> Result<Option<(resume::Model, String)>, String> {
+        if let Some(resume) = ResumeDao::find_by_policy_object_id(db, service_id)
+            .await
+            .map_err(|e| format!("Failed to fetch resume: {}", e))?
+        {
+            return Ok(Some((resume, FULL_TIER.to_string())));
+        }
+
+        let Some(tier) = ResumeTierDao::find_by_policy_object_id(db, service_id)
+            .await
+            .map_err(|e| format!("Failed to fetch resume tier: {}", e))?
+        else {
+            return Ok(None);
+        };
+        let resume = ResumeDao::find_by_id(db, tier.resume_id)
+            .await
+            .map_err(|e| format!("Failed to fetch resume: {}", e))?;
+        Ok(resume.map(|resume| (resume, tier.tier)))
+    }
+
+    fn normalize(address: &str) -> Result<String, String> {
+        Validator::normalize_sui_address(address).map_err(|e| e.to_string())
+    }
+}
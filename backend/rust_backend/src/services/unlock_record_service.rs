@@ -1,46 +1,528 @@
-use sea_orm::DatabaseConnection;
-use crate::dao::UnlockRecordDao;
-use crate::entities::unlock_record;
+use sea_orm::{ConnectionTrait, DatabaseConnection, Set, TransactionTrait};
+use crate::dao::payment_settlement_dao::{SETTLEMENT_PURPOSE_UNLOCK, SETTLEMENT_STATUS_SETTLED};
+use crate::dao::{PaymentSettlementDao, ResumeDao, SellerLedgerDao, UnlockRecordDao, UserDao};
+use crate::services::resume_tier_service::{Tier, FULL_TIER};
+use crate::services::credit_service::CreditOwner;
+use crate::services::unlock_verification_service::VerificationOutcome;
+use crate::services::{
+    CouponService, CreditService, OrganizationService, ReceiptService, ResumeTierService, UnlockVerificationService,
+};
+use crate::entities::{payment_receipt, payment_settlement, resume, unlock_record};
+use crate::models::{CommissionConfig, UnlockStatus};
+use crate::money::{self, Money};
+use crate::sui::SuiRpcClient;
+use crate::utils::receipt_signer::ReceiptSigner;
+use crate::x402::facilitator::SettleResponse;
+use crate::x402::middleware::PaymentError;
+use crate::x402::{Facilitator, VerifiedPayment};
+
+/// 解锁的支付方式
+#[derive(Debug, Clone, Copy)]
+pub enum PaymentSource<'a> {
+    /// x402 支付，应付为 0 时中间件放行，没有待结算的支付
+    X402(Option<&'a VerifiedPayment>),
+    /// 从额度账户扣减
+    Credits(CreditOwner),
+}
+
+/// 付费解锁的结果
+pub struct ResumePurchase {
+    pub resume: resume::Model,
+    /// 购买的档位及其解密凭据
+    pub tier: Tier,
+    /// 新建的解锁记录，之前已拥有该档位时为 None
+    pub record: Option<unlock_record::Model>,
+    /// 结算结果，之前已解锁、免费或用额度支付时为 None
+    pub settlement: Option<SettleResponse>,
+    /// 支付方式: x402 / credits / free，之前已拥有该档位时为 None
+    pub payment_method: Option<&'static str>,
+    /// 优惠码减免的金额
+    pub discount: Option<Money>,
+    /// 服务端签名的收据，只为已结算的购买签发
+    pub receipt: Option<payment_receipt::Model>,
+}
 
 pub struct UnlockRecordService;
 
 impl UnlockRecordService {
     /// 创建解锁记录（支付后调用）
+    ///
+    /// 记录先以 pending 写入，再按交易摘要到链上校验：通过后转为 confirmed，不通过转为 failed；
+    /// 暂时无法校验时保持 pending，之后可以重新校验。
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_unlock_record(
         db: &DatabaseConnection,
+        sui: &dyn SuiRpcClient,
+        package_id: Option<&str>,
         resume_id: i64,
         buyer_id: i64,
         buyer_wallet: String,
+        organization_id: Option<i64>,
         seller_wallet: String,
-        amount: i64,
+        amount: Money,
         transaction_signature: String,
         block_time: Option<i64>,
-    ) -> Result<unlock_record::Model, String> {
-        // 检查是否已存在相同的交易签名（防止重复记录）
-        let exists = UnlockRecordDao::exists_by_signature(db, &transaction_signature)
+    ) -> Result<(unlock_record::Model, VerificationOutcome), String> {
+        // 检查是否已存在相同的交易签名（防止重复记录）；
+        // 链上索引已为同一买家确认的订阅直接返回该记录
+        if let Some(existing) = UnlockRecordDao::find_by_signature(db, &transaction_signature)
             .await
-            .map_err(|e| format!("Failed to check transaction signature: {}", e))?;
-
-        if exists {
+            .map_err(|e| format!("Failed to check transaction signature: {}", e))?
+        {
+            if existing.buyer_id == buyer_id
+                && existing.resume_id == resume_id
+                && existing.status == UnlockStatus::Confirmed.as_str()
+            {
+                return Ok((existing, VerificationOutcome::Confirmed));
+            }
             return Err("Transaction already recorded".to_string());
         }
 
         // 创建解锁记录
-        UnlockRecordDao::create(
+        let record = UnlockRecordDao::create(
             db,
             resume_id,
             buyer_id,
             buyer_wallet,
+            organization_id,
             seller_wallet,
             amount,
+            FULL_TIER.to_string(),
             transaction_signature,
+            UnlockStatus::Pending,
             block_time,
         )
         .await
-        .map_err(|e| format!("Failed to create unlock record: {}", e))
+        .map_err(|e| format!("Failed to create unlock record: {}", e))?;
+
+        UnlockVerificationService::verify_record(db, sui, package_id, record.id).await
+    }
+
+    /// 付费解锁简历的某个档位（x402 支付或额度支付，可使用优惠码）
+    ///
+    /// 锁定简历后确定应付金额：已拥有（本人或所在组织购买过）同级或更高档位时不付款；从低档升级时只付差价。
+    /// 同一档位已有结算成功、尚未写入解锁记录的意向时返回 409，等待对账任务写入，不再收款。
+    /// 额度支付和免费解锁在同一个事务中扣减额度、写入解锁记录、佣金账目和优惠码使用记录并增加解锁次数。
+    /// x402 支付先在短事务中写入结算意向，提交后再调用 facilitator 结算（不持有行锁），
+    /// 最后由 [`Self::complete_settlement`] 写入解锁记录和签名收据；写入失败的意向由后台对账任务重试。
+    #[allow(clippy::too_many_arguments)]
+    pub async fn purchase_resume(
+        db: &DatabaseConnection,
+        facilitator: &dyn Facilitator,
+        signer: &ReceiptSigner,
+        commission: &CommissionConfig,
+        payment: PaymentSource<'_>,
+        resume_id: &str,
+        tier: &str,
+        coupon: Option<&str>,
+        buyer_id: i64,
+        buyer_wallet: &str,
+        organization_id: Option<i64>,
+    ) -> Result<ResumePurchase, PaymentError> {
+        let internal = |context: &str, e: &dyn std::fmt::Display| PaymentError::Internal(format!("{}: {}", context, e));
+
+        let resume = ResumeDao::find_by_resume_id(db, resume_id)
+            .await
+            .map_err(|e| internal("Failed to fetch resume", &e))?
+            .filter(|r| r.status == "active")
+            .ok_or_else(|| PaymentError::NotFound("Resume not found".to_string()))?;
+
+        if resume.owner_id == buyer_id {
+            return Err(PaymentError::Rejected("Cannot unlock your own resume".to_string()));
+        }
+
+        let organization_ids = OrganizationService::organization_ids_of(db, buyer_id)
+            .await
+            .map_err(PaymentError::Internal)?;
+
+        let txn = db.begin().await.map_err(|e| internal("Failed to start transaction", &e))?;
+
+        // 锁定简历行，同一简历的并发购买在此串行化
+        let resume = ResumeDao::find_by_resume_id_for_update(&txn, resume_id)
+            .await
+            .map_err(|e| internal("Failed to lock resume", &e))?
+            .filter(|r| r.status == "active")
+            .ok_or_else(|| PaymentError::NotFound("Resume not found".to_string()))?;
+
+        let tiers = ResumeTierService::load(&txn, &resume).await.map_err(PaymentError::Internal)?;
+        let target = tiers
+            .iter()
+            .find(|t| t.name == tier)
+            .cloned()
+            .ok_or_else(|| PaymentError::NotFound(format!("Tier '{}' not found", tier)))?;
+
+        let records = UnlockRecordDao::find_all_by_resume_for_buyer(&txn, resume.id, buyer_id, &organization_ids)
+            .await
+            .map_err(|e| internal("Failed to check unlock status", &e))?;
+        let owned = ResumeTierService::owned(&tiers, &records);
+        if owned.is_some_and(|owned| owned.level >= target.level) {
+            return Ok(ResumePurchase {
+                resume,
+                tier: target,
+                record: None,
+                settlement: None,
+                payment_method: None,
+                discount: None,
+                receipt: None,
+            });
+        }
+        Self::ensure_no_settled_purchase(&txn, buyer_id, resume.id, &target.name).await?;
+        let is_upgrade = owned.is_some();
+        let due = ResumeTierService::upgrade_price(&target, owned);
+
+        let coupon = match coupon {
+            Some(code) => CouponService::reserve(&txn, code, buyer_id, &due).await?,
+            None => None,
+        };
+        let discount = coupon.as_ref().map(|(_, discount)| discount.clone());
+        let due = Money { amount: due.amount - discount.as_ref().map_or(0, |d| d.amount), ..due };
+
+        // 应付为 0 时支付中间件直接放行，没有待结算的支付；
+        // 中间件报价之后价格或已拥有的档位发生变化时不结算，让客户端重新获取报价
+        let account = match payment {
+            PaymentSource::X402(Some(payment)) if payment.requirements.max_amount_required == due.amount.to_string() => {
+                let settlement = PaymentSettlementDao::create(
+                    &txn,
+                    payment_settlement::ActiveModel {
+                        purpose: Set(SETTLEMENT_PURPOSE_UNLOCK.to_string()),
+                        user_id: Set(buyer_id),
+                        wallet: Set(buyer_wallet.to_string()),
+                        organization_id: Set(organization_id),
+                        resume_id: Set(Some(resume.id)),
+                        tier: Set(Some(target.name.clone())),
+                        upgrade: Set(is_upgrade),
+                        coupon_id: Set(coupon.as_ref().map(|(coupon, _)| coupon.id)),
+                        discount: Set(discount.as_ref().map_or(0, |d| d.amount)),
+                        amount: Set(due.amount),
+                        asset: Set(due.asset.clone()),
+                        pay_asset: Set(payment.requirements.asset.clone()),
+                        network: Set(payment.requirements.network.clone()),
+                        ..Default::default()
+                    },
+                )
+                .await
+                .map_err(|e| internal("Failed to record settlement", &e))?;
+                txn.commit().await.map_err(|e| internal("Failed to commit settlement", &e))?;
+
+                return Self::settle_purchase(db, facilitator, signer, commission, payment, settlement, resume, target, discount)
+                    .await;
+            }
+            PaymentSource::X402(None) | PaymentSource::Credits(_) if due.amount <= 0 => None,
+            PaymentSource::X402(_) => {
+                return Err(PaymentError::Rejected("Resume price changed, please retry".to_string()));
+            }
+            PaymentSource::Credits(owner) => {
+                let account = CreditService::lock_account(&txn, owner, &due.asset)
+                    .await
+                    .map_err(PaymentError::Internal)?;
+                if account.balance < due.amount {
+                    return Err(PaymentError::Rejected(format!(
+                        "Insufficient credits: {} required, {} available",
+                        due,
+                        Money { amount: account.balance, ..due.clone() }
+                    )));
+                }
+                Some(account)
+            }
+        };
+
+        let (amount, transaction_signature, payment_method) = match &account {
+            Some(_) => (due, format!("credits:{}", uuid::Uuid::new_v4()), "credits"),
+            None => (
+                Money { amount: 0, ..due },
+                // 退款后同一买家可以再次免费解锁，签名不能只由简历、买家和档位决定
+                format!("free:{}:{}:{}:{}", resume.id, buyer_id, target.name, uuid::Uuid::new_v4()),
+                "free",
+            ),
+        };
+
+        let record = UnlockRecordDao::create(
+            &txn,
+            resume.id,
+            buyer_id,
+            buyer_wallet.to_string(),
+            organization_id,
+            resume.owner_wallet.clone(),
+            amount.clone(),
+            target.name.clone(),
+            transaction_signature.clone(),
+            UnlockStatus::Confirmed,
+            None,
+        )
+        .await
+        .map_err(|e| internal("Failed to create unlock record", &e))?;
+
+        // 升级不算新的解锁
+        if !is_upgrade {
+            ResumeDao::increment_unlock_count(&txn, resume_id)
+                .await
+                .map_err(|e| internal("Failed to update unlock count", &e))?;
+        }
+
+        if let Some(account) = &account {
+            CreditService::debit_unlock(&txn, account, amount.amount, record.id, buyer_id)
+                .await
+                .map_err(PaymentError::Internal)?;
+        }
+        if let Some((coupon, discount)) = &coupon
+            && discount.amount > 0
+        {
+            CouponService::redeem(&txn, coupon.id, buyer_id, record.id, discount.clone())
+                .await
+                .map_err(PaymentError::Internal)?;
+        }
+        Self::record_sale(&txn, commission, &record, &resume)
+            .await
+            .map_err(PaymentError::Internal)?;
+
+        txn.commit().await.map_err(|e| internal("Failed to commit unlock", &e))?;
+
+        log::info!(
+            "Resume {} tier {} unlocked by user {} for {} (tx {})",
+            resume_id, target.name, buyer_id, amount, transaction_signature
+        );
+        Ok(ResumePurchase {
+            resume,
+            tier: target,
+            record: Some(record),
+            settlement: None,
+            payment_method: Some(payment_method),
+            discount: discount.filter(|d| d.amount > 0),
+            receipt: None,
+        })
+    }
+
+    /// 买家已有同一简历同一档位、结算成功但尚未写入解锁记录的意向时返回 409，不再重新报价收款
+    pub async fn ensure_no_settled_purchase<C: ConnectionTrait>(
+        db: &C,
+        buyer_id: i64,
+        resume_id: i64,
+        tier: &str,
+    ) -> Result<(), PaymentError> {
+        let settled = PaymentSettlementDao::has_settled_unlock(db, buyer_id, resume_id, tier)
+            .await
+            .map_err(|e| PaymentError::Internal(format!("Failed to check pending settlements: {}", e)))?;
+        if settled {
+            return Err(PaymentError::Conflict(
+                "A payment for this resume tier was already settled and is being recorded, please retry later".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+        /// 在不持有行锁的情况下结算 x402 支付，再写入解锁记录
+    ///
+    /// facilitator 明确拒绝时意向转为 failed；超时等结果未知的错误只记录原因，意向保持 pending。
+    #[allow(clippy::too_many_arguments)]
+    async fn settle_purchase(
+        db: &DatabaseConnection,
+        facilitator: &dyn Facilitator,
+        signer: &ReceiptSigner,
+        commission: &CommissionConfig,
+        payment: &VerifiedPayment,
+        settlement: payment_settlement::Model,
+        resume: resume::Model,
+        tier: Tier,
+        discount: Option<Money>,
+    ) -> Result<ResumePurchase, PaymentError> {
+        let settled = match facilitator.settle(&payment.payment, &payment.requirements).await {
+            Ok(settled) => settled,
+            Err(e) => {
+                let recorded = if e.is_payment_error() {
+                    PaymentSettlementDao::mark_failed(db, settlement.id, &e.to_string()).await
+                } else {
+                    PaymentSettlementDao::record_error(db, settlement.id, &e.to_string()).await
+                };
+                if let Err(db_err) = recorded {
+                    log::warn!("Failed to record settlement {} error: {}", settlement.id, db_err);
+                }
+                // 明确被拒绝时没有扣款，可以用同一个幂等键重新支付
+                let error = PaymentError::from_facilitator(e, &payment.requirements);
+                return Err(if matches!(error, PaymentError::Required { .. }) {
+                    error
+                } else {
+                    error.after_settlement(settlement.id)
+                });
+            }
+        };
+
+        match PaymentSettlementDao::mark_settled(db, settlement.id, &settled.transaction, settled.payer.clone()).await {
+            Ok(true) => {}
+            result => {
+                log::error!(
+                    "Payment {} for settlement {} was settled but could not be recorded: {:?}",
+                    settled.transaction, settlement.id, result
+                );
+                return Err(PaymentError::Internal("Payment was settled but could not be recorded".to_string())
+                    .after_settlement(settlement.id));
+            }
+        }
+
+        let (record, receipt) = match Self::complete_settlement(db, signer, commission, settlement.id).await {
+            Ok(completed) => completed,
+            Err(e) => {
+                log::error!(
+                    "Payment {} for resume {} by user {} was settled but not recorded, settlement {} will be retried: {}",
+                    settled.transaction, resume.resume_id, settlement.user_id, settlement.id, e
+                );
+                if let Err(db_err) = PaymentSettlementDao::record_error(db, settlement.id, &e).await {
+                    log::warn!("Failed to record settlement {} error: {}", settlement.id, db_err);
+                }
+                return Err(PaymentError::Internal(format!(
+                    "Payment was settled but the unlock could not be recorded yet, it will be retried: {}",
+                    e
+                ))
+                .after_settlement(settlement.id));
+            }
+        };
+
+        log::info!(
+            "Resume {} tier {} unlocked by user {} for {} (tx {})",
+            resume.resume_id, tier.name, settlement.user_id, settlement.amount, settled.transaction
+        );
+        Ok(ResumePurchase {
+            resume,
+            tier,
+            record: Some(record),
+            settlement: Some(settled),
+            payment_method: Some("x402"),
+            discount: discount.filter(|d| d.amount > 0),
+            receipt: Some(receipt),
+        })
+    }
+
+    /// 为已结算的意向写入解锁记录、佣金账目、优惠码使用记录和签名收据并增加解锁次数
+    ///
+    /// 在同一个事务中完成并把意向转为 completed；意向不是 settled 状态时返回错误。
+    /// 购买请求结算后直接调用，失败时由对账任务重试。
+    pub async fn complete_settlement(
+        db: &DatabaseConnection,
+        signer: &ReceiptSigner,
+        commission: &CommissionConfig,
+        settlement_id: i64,
+    ) -> Result<(unlock_record::Model, payment_receipt::Model), String> {
+        let txn = db.begin().await.map_err(|e| format!("Failed to start transaction: {}", e))?;
+        let settlement = PaymentSettlementDao::find_by_id_for_update(&txn, settlement_id)
+            .await
+            .map_err(|e| format!("Failed to fetch settlement: {}", e))?
+            .ok_or_else(|| format!("Settlement {} not found", settlement_id))?;
+        if settlement.status != SETTLEMENT_STATUS_SETTLED {
+            return Err(format!("Settlement {} is {}", settlement.id, settlement.status));
+        }
+        let (Some(resume_id), Some(tier), Some(transaction)) =
+            (settlement.resume_id, settlement.tier.clone(), settlement.transaction.clone())
+        else {
+            return Err(format!("Settlement {} is missing its resume, tier or transaction", settlement.id));
+        };
+
+        // 已付款的解锁即使简历之后被删除也要记录
+        let resume = ResumeDao::find_by_id(db, resume_id)
+            .await
+            .map_err(|e| format!("Failed to fetch resume: {}", e))?
+            .ok_or_else(|| format!("Resume {} not found", resume_id))?;
+
+        let record = UnlockRecordDao::create(
+            &txn,
+            resume.id,
+            settlement.user_id,
+            settlement.wallet.clone(),
+            settlement.organization_id,
+            resume.owner_wallet.clone(),
+            money::registry().stored(settlement.amount, &settlement.asset),
+            tier,
+            transaction.clone(),
+            UnlockStatus::Confirmed,
+            None,
+        )
+        .await
+        .map_err(|e| format!("Failed to create unlock record: {}", e))?;
+
+        // 升级不算新的解锁
+        if !settlement.upgrade {
+            ResumeDao::increment_unlock_count(&txn, &resume.resume_id)
+                .await
+                .map_err(|e| format!("Failed to update unlock count: {}", e))?;
+        }
+        if let Some(coupon_id) = settlement.coupon_id
+            && settlement.discount > 0
+        {
+            let discount = money::registry().stored(settlement.discount, &settlement.asset);
+            CouponService::redeem(&txn, coupon_id, settlement.user_id, record.id, discount).await?;
+        }
+        Self::record_sale(&txn, commission, &record, &resume).await?;
+
+        let settled = SettleResponse {
+            success: true,
+            error_reason: None,
+            transaction,
+            network: settlement.network.clone(),
+            payer: settlement.payer.clone(),
+        };
+        let receipt = ReceiptService::issue(&txn, signer, &record, &resume, &settlement.pay_asset, &settled).await?;
+
+        PaymentSettlementDao::mark_completed(&txn, settlement.id, Some(record.id), None)
+            .await
+            .map_err(|e| format!("Failed to complete settlement: {}", e))?;
+        txn.commit().await.map_err(|e| format!("Failed to commit unlock: {}", e))?;
+
+        Ok((record, receipt))
+    }
+
+    /// 款项已在支付或充值额度时进入平台收款地址，按佣金规则记入卖家账本，结算批次打款后到账
+    async fn record_sale<C: ConnectionTrait>(
+        db: &C,
+        commission: &CommissionConfig,
+        record: &unlock_record::Model,
+        resume: &resume::Model,
+    ) -> Result<(), String> {
+        if record.amount <= 0 {
+            return Ok(());
+        }
+
+        let (fee, net) = commission.split(record.amount);
+        SellerLedgerDao::create(
+            db,
+            record.id,
+            resume.owner_id,
+            resume.owner_wallet.clone(),
+            resume.id,
+            record.asset.clone(),
+            record.amount,
+            fee,
+            net,
+        )
+        .await
+        .map_err(|e| format!("Failed to record seller ledger entry: {}", e))?;
+        Ok(())
+    }
+
+    /// 按状态机转换解锁记录状态，不允许的转换或并发修改返回错误
+    pub async fn transition<C: ConnectionTrait>(
+        db: &C,
+        record: &unlock_record::Model,
+        next: UnlockStatus,
+    ) -> Result<(), String> {
+        let current = UnlockStatus::parse(&record.status)
+            .ok_or_else(|| format!("Unlock record {} has unknown status '{}'", record.id, record.status))?;
+        if !current.can_transition_to(next) {
+            return Err(format!(
+                "Unlock record in status '{}' cannot become '{}'",
+                current.as_str(),
+                next.as_str()
+            ));
+        }
+
+        let updated = UnlockRecordDao::update_status(db, record.id, current, next)
+            .await
+            .map_err(|e| format!("Failed to update unlock record status: {}", e))?;
+        if !updated {
+            return Err(format!("Unlock record {} was modified concurrently, please retry", record.id));
+        }
+
+        Ok(())
     }
 
-    /// 检查用户是否已解锁某简历
+    /// 检查用户是否已解锁某简历（本人购买或所在组织的成员购买均算）
     pub async fn has_unlocked(
         db: &DatabaseConnection,
         resume_id: i64,
@@ -50,17 +532,32 @@ impl UnlockRecordService {
             .await
             .map_err(|e| format!("Failed to check unlock status: {}", e))?;
 
-        Ok(record.is_some())
+        if record.is_some() {
+            return Ok(true);
+        }
+
+        let organization_ids = OrganizationService::organization_ids_of(db, buyer_id).await?;
+        let org_record = UnlockRecordDao::find_by_resume_and_organizations(db, resume_id, &organization_ids)
+            .await
+            .map_err(|e| format!("Failed to check organization unlock status: {}", e))?;
+
+        Ok(org_record.is_some())
     }
 
-    /// 获取用户已解锁的所有简历
+    /// 获取用户已解锁的所有简历（`buyer_wallet` 可以是用户的任一关联钱包）
     pub async fn get_unlocked_resumes(
         db: &DatabaseConnection,
         buyer_wallet: &str,
     ) -> Result<Vec<unlock_record::Model>, String> {
-        UnlockRecordDao::find_unlocked_resumes_by_buyer(db, buyer_wallet)
+        let buyer = UserDao::find_by_wallet(db, buyer_wallet)
             .await
-            .map_err(|e| format!("Failed to get unlocked resumes: {}", e))
+            .map_err(|e| format!("Failed to fetch user: {}", e))?;
+
+        match buyer {
+            Some(buyer) => UnlockRecordDao::find_unlocked_resumes_by_buyer_id(db, buyer.id).await,
+            None => UnlockRecordDao::find_unlocked_resumes_by_buyer(db, buyer_wallet).await,
+        }
+        .map_err(|e| format!("Failed to get unlocked resumes: {}", e))
     }
 
     /// 获取简历的所有解锁记录（简历所有者可查看）
@@ -0,0 +1,325 @@
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use crate::dao::{ResumeDao, UnlockRecordDao, UserWalletDao};
+use crate::entities::unlock_record;
+use crate::models::UnlockStatus;
+use crate::money::{self, Money};
+use crate::services::UnlockRecordService;
+use crate::sui::{normalize_type, SuiRpcClient, SuiTransaction};
+use crate::utils::validator::Validator;
+
+/// 解锁交易应满足的条件
+#[derive(Debug, Clone)]
+pub struct UnlockExpectation {
+    /// 买家钱包，必须是交易发送方
+    pub buyer: String,
+    /// 简历所有者的 Sui 钱包，收款方为其中之一即可
+    pub sellers: Vec<String>,
+    /// 简历价格
+    pub price: Money,
+    /// 价格资产在 Sui 上的代币类型，不是 Sui 资产时为 None（只能通过订阅校验）
+    pub coin_type: Option<String>,
+    /// 简历的订阅服务 ID（Seal subscription 模式的 policy_object_id）
+    pub service_id: Option<String>,
+    /// 合约包 ID，设置后只认可该包的 Subscription
+    pub package_id: Option<String>,
+}
+
+/// 一次链上校验的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerificationOutcome {
+    /// 校验通过，记录转为 confirmed
+    Confirmed,
+    /// 暂时无法确认（交易尚未查到或 RPC 不可用），记录保持 pending
+    Pending(String),
+    /// 校验不通过，记录转为 failed
+    Failed(String),
+}
+
+/// 解锁交易的链上校验服务
+pub struct UnlockVerificationService;
+
+impl UnlockVerificationService {
+    /// 校验 pending 状态的解锁记录，返回更新后的记录和校验结果
+    pub async fn verify_record(
+        db: &DatabaseConnection,
+        sui: &dyn SuiRpcClient,
+        package_id: Option<&str>,
+        record_id: i64,
+    ) -> Result<(unlock_record::Model, VerificationOutcome), String> {
+        let record = UnlockRecordDao::find_by_id(db, record_id)
+            .await
+            .map_err(|e| format!("Failed to fetch unlock record: {}", e))?
+            .ok_or_else(|| "Unlock record not found".to_string())?;
+        if record.status != UnlockStatus::Pending.as_str() {
+            return Err(format!("Unlock record {} is already {}", record.id, record.status));
+        }
+
+        let (outcome, block_time) = match Self::expectation(db, &record, package_id).await? {
+            Err(reason) => (VerificationOutcome::Failed(reason), None),
+            Ok(expected) => match sui.get_transaction(&record.transaction_signature).await {
+                Err(e) => {
+                    log::warn!("Could not verify unlock record {}: {}", record.id, e);
+                    (VerificationOutcome::Pending(e.to_string()), None)
+                }
+                Ok(None) => (
+                    VerificationOutcome::Pending("Transaction was not found on chain yet".to_string()),
+                    None,
+                ),
+                Ok(Some(tx)) => {
+                    let outcome = match Self::check(&tx, &expected) {
+                        Ok(paid) if paid < record.amount => VerificationOutcome::Failed(format!(
+                            "Claimed amount {} exceeds the {} paid on chain",
+                            record.amount, paid
+                        )),
+                        Ok(_) => VerificationOutcome::Confirmed,
+                        Err(reason) => VerificationOutcome::Failed(reason),
+                    };
+                    (outcome, tx.timestamp_ms.map(|ms| ms / 1000))
+                }
+            },
+        };
+
+        let (next, failure_reason) = match &outcome {
+            VerificationOutcome::Pending(_) => return Ok((record, outcome)),
+            VerificationOutcome::Confirmed => (UnlockStatus::Confirmed, None),
+            VerificationOutcome::Failed(reason) => (UnlockStatus::Failed, Some(reason.clone())),
+        };
+
+        let txn = db.begin().await.map_err(|e| format!("Failed to start transaction: {}", e))?;
+        let record = UnlockRecordDao::find_by_id_for_update(&txn, record.id)
+            .await
+            .map_err(|e| format!("Failed to fetch unlock record: {}", e))?
+            .ok_or_else(|| "Unlock record not found".to_string())?;
+        UnlockRecordService::transition(&txn, &record, next).await?;
+        UnlockRecordDao::record_verification(&txn, record.id, failure_reason, block_time)
+            .await
+            .map_err(|e| format!("Failed to record verification: {}", e))?;
+        let record = UnlockRecordDao::find_by_id_for_update(&txn, record.id)
+            .await
+            .map_err(|e| format!("Failed to fetch unlock record: {}", e))?
+            .ok_or_else(|| "Unlock record not found".to_string())?;
+        txn.commit().await.map_err(|e| format!("Failed to commit verification: {}", e))?;
+
+        log::info!(
+            "Unlock record {} (tx {}) verified as {}",
+            record.id, record.transaction_signature, record.status
+        );
+        Ok((record, outcome))
+    }
+
+    /// 由简历和所有者钱包得出交易应满足的条件；记录本身与简历不符时返回内层 Err（直接判定失败）
+    async fn expectation(
+        db: &DatabaseConnection,
+        record: &unlock_record::Model,
+        package_id: Option<&str>,
+    ) -> Result<Result<UnlockExpectation, String>, String> {
+        let resume = ResumeDao::find_by_id(db, record.resume_id)
+            .await
+            .map_err(|e| format!("Failed to fetch resume: {}", e))?
+            .ok_or_else(|| "Resume not found".to_string())?;
+
+        let mut sellers = vec![resume.owner_wallet.clone()];
+        sellers.extend(
+            UserWalletDao::find_by_user(db, resume.owner_id)
+                .await
+                .map_err(|e| format!("Failed to fetch seller wallets: {}", e))?
+                .into_iter()
+                .map(|wallet| wallet.address),
+        );
+        if !sellers.iter().any(|seller| same_address(seller, &record.seller_wallet)) {
+            return Ok(Err("seller_wallet is not a wallet of the resume owner".to_string()));
+        }
+        if !record.asset.eq_ignore_ascii_case(&resume.price_asset) {
+            return Ok(Err(format!(
+                "Unlock was recorded in {} but the resume is priced in {}",
+                record.asset, resume.price_asset
+            )));
+        }
+
+        let registry = money::registry();
+        Ok(Ok(UnlockExpectation {
+            buyer: record.buyer_wallet.clone(),
+            sellers,
+            price: registry.stored(resume.price, &resume.price_asset),
+            coin_type: registry
+                .get(&resume.price_asset)
+                .filter(|asset| asset.chain == "sui")
+                .map(|asset| asset.address.clone()),
+            service_id: resume.policy_object_id.filter(|id| !id.is_empty()),
+            package_id: package_id.map(str::to_string),
+        }))
+    }
+
+    /// 检查交易：执行成功、由买家发出，并且向卖家转账不少于简历价格，或为简历的服务创建了 Subscription
+    ///
+    /// 通过时返回链上实际支付的金额（转给卖家的金额或订阅费）。
+    pub fn check(tx: &SuiTransaction, expected: &UnlockExpectation) -> Result<i64, String> {
+        if !tx.success {
+            return Err(format!(
+                "Transaction failed on chain: {}",
+                tx.error.as_deref().unwrap_or("unknown error")
+            ));
+        }
+        if !same_address(&tx.sender, &expected.buyer) {
+            return Err("Transaction was not sent by the buyer".to_string());
+        }
+
+        let received: i128 = match &expected.coin_type {
+            Some(coin_type) => tx
+                .balance_changes
+                .iter()
+                .filter(|change| change.amount > 0)
+                .filter(|change| normalize_type(&change.coin_type) == normalize_type(coin_type))
+                .filter(|change| expected.sellers.iter().any(|seller| same_address(seller, &change.owner)))
+                .map(|change| change.amount)
+                .sum(),
+            None => 0,
+        };
+        if received > 0 && received >= expected.price.amount as i128 {
+            return Ok(i64::try_from(received).unwrap_or(i64::MAX));
+        }
+
+        if let Some(fee) = expected
+            .service_id
+            .as_deref()
+            .and_then(|service_id| Self::subscription_fee(tx, expected, service_id))
+        {
+            return Ok(fee);
+        }
+
+        if received > 0 {
+            let paid = Money { amount: i64::try_from(received).unwrap_or(i64::MAX), ..expected.price.clone() };
+            return Err(format!("Transaction paid the seller {}, less than the price {}", paid, expected.price));
+        }
+        Err("Transaction neither paid the seller nor created a subscription for this resume".to_string())
+    }
+
+    /// 交易为买家创建了指定服务的 Subscription 时返回订阅费
+    fn subscription_fee(tx: &SuiTransaction, expected: &UnlockExpectation, service_id: &str) -> Option<i64> {
+        tx.events.iter().find_map(|event| {
+            if !is_subscription_type(&event.event_type, "SubscriptionCreated", expected.package_id.as_deref()) {
+                return None;
+            }
+            let fields = &event.parsed_json;
+            let service = fields.get("service_id")?.as_str()?;
+            let subscriber = fields.get("subscriber")?.as_str()?;
+            let subscription_id = fields.get("subscription_id")?.as_str()?;
+            if !same_address(service, service_id) || !same_address(subscriber, &expected.buyer) {
+                return None;
+            }
+
+            let created = tx.created_objects.iter().any(|object| {
+                same_address(&object.object_id, subscription_id)
+                    && is_subscription_type(&object.object_type, "Subscription", expected.package_id.as_deref())
+            });
+            if !created {
+                return None;
+            }
+
+            // u64 在 JSON 中以字符串表示
+            let fee = fields.get("fee_paid")?;
+            fee.as_str().and_then(|fee| fee.parse().ok()).or_else(|| fee.as_i64())
+        })
+    }
+}
+
+/// `subscription` 模块中的类型；指定包 ID 时必须来自该包
+fn is_subscription_type(type_tag: &str, name: &str, package_id: Option<&str>) -> bool {
+    let type_tag = normalize_type(type_tag);
+    match package_id {
+        Some(package_id) => type_tag == normalize_type(&format!("{}::subscription::{}", package_id, name)),
+        None => type_tag.ends_with(&format!("::subscription::{}", name)),
+    }
+}
+
+/// 按规范化后的 Sui 地址（或对象 ID）比较
+fn same_address(a: &str, b: &str) -> bool {
+    match (Validator::normalize_sui_address(a), Validator::normalize_sui_address(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sui::{BalanceChange, CreatedObject, SuiEvent};
+
+    const BUYER: &str = "0x00000000000000000000000000000000000000000000000000000000000000b1";
+    const SELLER: &str = "0x00000000000000000000000000000000000000000000000000000000000000c2";
+    const SERVICE: &str = "0x00000000000000000000000000000000000000000000000000000000000000d3";
+    const SUBSCRIPTION: &str = "0x00000000000000000000000000000000000000000000000000000000000000e4";
+
+    fn expected() -> UnlockExpectation {
+        UnlockExpectation {
+            buyer: BUYER.to_string(),
+            sellers: vec![SELLER.to_string()],
+            price: Money { amount: 1_000, asset: "SUI".to_string(), decimals: 9 },
+            coin_type: Some("0x2::sui::SUI".to_string()),
+            service_id: Some(SERVICE.to_string()),
+            package_id: None,
+        }
+    }
+
+    fn transfer(amount: i128) -> SuiTransaction {
+        SuiTransaction {
+            digest: "digest".to_string(),
+            sender: BUYER.to_string(),
+            success: true,
+            error: None,
+            timestamp_ms: Some(1_700_000_000_000),
+            balance_changes: vec![
+                BalanceChange { owner: BUYER.to_string(), coin_type: "0x2::sui::SUI".to_string(), amount: -amount },
+                BalanceChange {
+                    owner: SELLER.to_string(),
+                    coin_type: "0x0000000000000000000000000000000000000000000000000000000000000002::sui::SUI".to_string(),
+                    amount,
+                },
+            ],
+            created_objects: Vec::new(),
+            events: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_check_unlock_transaction() {
+        let expected = expected();
+
+        assert_eq!(UnlockVerificationService::check(&transfer(1_500), &expected), Ok(1_500));
+        assert!(UnlockVerificationService::check(&transfer(999), &expected).is_err());
+
+        let failed = SuiTransaction { success: false, error: Some("InsufficientGas".to_string()), ..transfer(1_500) };
+        assert!(UnlockVerificationService::check(&failed, &expected).is_err());
+        let other_sender = SuiTransaction { sender: SELLER.to_string(), ..transfer(1_500) };
+        assert!(UnlockVerificationService::check(&other_sender, &expected).is_err());
+
+        // 订阅：事件和创建的 Subscription 对象都要对应简历的服务和买家
+        let subscribed = SuiTransaction {
+            balance_changes: Vec::new(),
+            created_objects: vec![CreatedObject {
+                object_id: SUBSCRIPTION.to_string(),
+                object_type: "0xabc::subscription::Subscription".to_string(),
+            }],
+            events: vec![SuiEvent {
+                event_type: "0xabc::subscription::SubscriptionCreated".to_string(),
+                parsed_json: serde_json::json!({
+                    "subscription_id": SUBSCRIPTION,
+                    "service_id": SERVICE,
+                    "subscriber": BUYER,
+                    "fee_paid": "800",
+                    "created_at": "1700000000000",
+                }),
+            }],
+            ..transfer(0)
+        };
+        assert_eq!(UnlockVerificationService::check(&subscribed, &expected), Ok(800));
+
+        let other_service = UnlockExpectation {
+            service_id: Some(SELLER.to_string()),
+            ..expected.clone()
+        };
+        assert!(UnlockVerificationService::check(&subscribed, &other_service).is_err());
+        let other_package = UnlockExpectation { package_id: Some("0xdef".to_string()), ..expected };
+        assert!(UnlockVerificationService::check(&subscribed, &other_package).is_err());
+    }
+}
@@ -1,5 +1,7 @@
+use crate::auth::{permissions, AuthError};
 use crate::dao::UserDao;
 use crate::entities::user;
+use crate::utils::validator::Validator;
 use sea_orm::{DatabaseConnection, DbErr};
 
 pub struct UserService;
@@ -12,10 +14,9 @@ impl UserService {
         db: &DatabaseConnection,
         wallet_address: String,
     ) -> Result<i64, DbErr> {
-        // 验证钱包地址格式(基本验证)
-        if wallet_address.is_empty() {
-            return Err(DbErr::Custom("Wallet address cannot be empty".to_string()));
-        }
+        // 验证并规范化钱包地址
+        let wallet_address = Validator::normalize_sui_address(&wallet_address)
+            .map_err(|e| DbErr::Custom(e.to_string()))?;
 
         UserDao::create_or_get(db, wallet_address).await
             .map_err(|e| DbErr::Custom(e.to_string()))
@@ -30,6 +31,17 @@ impl UserService {
             .map_err(|e| DbErr::Custom(e.to_string()))
     }
 
+    /// 钱包（任一关联钱包）所属用户的 ID，未注册时返回 None
+    pub async fn user_id_of_wallet(
+        db: &DatabaseConnection,
+        wallet_address: &str,
+    ) -> Result<Option<i64>, AuthError> {
+        UserDao::find_by_wallet(db, wallet_address)
+            .await
+            .map(|user| user.map(|u| u.id))
+            .map_err(|e| AuthError::Internal(format!("Failed to fetch user: {}", e)))
+    }
+
     /// 通过ID获取用户
     pub async fn get_user_by_id(
         db: &DatabaseConnection,
@@ -49,6 +61,23 @@ impl UserService {
             .map_err(|e| DbErr::Custom(e.to_string()))
     }
 
+    /// 设置用户角色: job_seeker / recruiter / both
+    pub async fn set_user_type(
+        db: &DatabaseConnection,
+        user_id: i64,
+        user_type: &str,
+    ) -> Result<user::Model, DbErr> {
+        if !permissions::is_valid_user_type(user_type) {
+            return Err(DbErr::Custom(format!(
+                "Invalid user type '{}', expected job_seeker, recruiter or both",
+                user_type
+            )));
+        }
+
+        UserDao::update_user_type(db, user_id, user_type).await
+            .map_err(|e| DbErr::Custom(e.to_string()))
+    }
+
     /// 验证用户是否存在
     pub async fn user_exists(
         db: &DatabaseConnection,
@@ -0,0 +1,163 @@
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use crate::dao::{AuthNonceDao, AuthSessionDao, UserDao, UserWalletDao};
+use crate::entities::user_wallet;
+use crate::models::{AuthConfig, ChallengeResponse};
+use crate::services::auth_service::{AuthService, NONCE_PURPOSE_LINK_WALLET};
+use crate::utils::solana_signature::SolanaSignature;
+use crate::utils::sui_signature::SuiSignature;
+use crate::utils::validator::Chain;
+
+/// 多链钱包关联服务
+pub struct UserWalletService;
+
+impl UserWalletService {
+    /// 签发关联钱包挑战，`address` 需已按 `chain` 规范化
+    pub async fn issue_link_challenge(
+        db: &DatabaseConnection,
+        config: &AuthConfig,
+        user_id: i64,
+        chain: Chain,
+        address: String,
+    ) -> Result<ChallengeResponse, String> {
+        Self::ensure_not_linked(db, user_id, &address).await?;
+
+        let nonce = AuthService::random_hex(16);
+        let now = chrono::Utc::now();
+        let expires_at = now + chrono::Duration::seconds(config.nonce_ttl_secs);
+
+        let message = format!(
+            "ResumeVault wants to link this {} wallet to account #{}:\n{}\n\nNonce: {}\nIssued At: {}\nExpiration Time: {}",
+            chain.as_str(),
+            user_id,
+            address,
+            nonce,
+            now.to_rfc3339(),
+            expires_at.to_rfc3339(),
+        );
+
+        AuthNonceDao::create(
+            db,
+            address,
+            nonce.clone(),
+            message.clone(),
+            NONCE_PURPOSE_LINK_WALLET,
+            chain.as_str(),
+            Some(user_id),
+            expires_at.naive_utc(),
+        )
+        .await
+        .map_err(|e| format!("Failed to create link challenge: {}", e))?;
+
+        Ok(ChallengeResponse {
+            nonce,
+            message,
+            expires_at: expires_at.to_rfc3339(),
+        })
+    }
+
+    /// 验证待关联钱包的签名并关联到用户
+    pub async fn link_wallet(
+        db: &DatabaseConnection,
+        user_id: i64,
+        nonce: &str,
+        signature: &str,
+    ) -> Result<user_wallet::Model, String> {
+        // 1. 查找挑战，必须是本用户发起的关联挑战
+        let challenge = AuthNonceDao::find_by_nonce(db, nonce)
+            .await
+            .map_err(|e| format!("Failed to fetch link challenge: {}", e))?
+            .filter(|c| c.purpose == NONCE_PURPOSE_LINK_WALLET && c.user_id == Some(user_id))
+            .ok_or_else(|| "Unknown link challenge".to_string())?;
+
+        // 2. 按链验证签名
+        let chain = Chain::parse(&challenge.chain).map_err(|e| e.to_string())?;
+        match chain {
+            Chain::Sui => {
+                let signer = SuiSignature::verify_personal_message(challenge.message.as_bytes(), signature)?;
+                if signer != challenge.wallet_address {
+                    return Err("Signature does not match wallet address".to_string());
+                }
+            }
+            Chain::Solana => {
+                SolanaSignature::verify_message(challenge.message.as_bytes(), &challenge.wallet_address, signature)?;
+            }
+        }
+
+        // 3. 消费 nonce（防止重放）
+        let consumed = AuthNonceDao::consume(db, nonce)
+            .await
+            .map_err(|e| format!("Failed to consume link challenge: {}", e))?;
+        if !consumed {
+            return Err("Link challenge expired or already used".to_string());
+        }
+
+        // 4. 挑战签发后地址可能已被其他账户关联
+        Self::ensure_not_linked(db, user_id, &challenge.wallet_address).await?;
+
+        let wallet = UserWalletDao::create(db, user_id, chain.as_str(), challenge.wallet_address, false)
+            .await
+            .map_err(|e| format!("Failed to link wallet: {}", e))?;
+
+        log::info!("User {} linked {} wallet {}", user_id, wallet.chain, wallet.address);
+
+        Ok(wallet)
+    }
+
+    /// 获取用户关联的所有钱包
+    pub async fn list_wallets(
+        db: &DatabaseConnection,
+        user_id: i64,
+    ) -> Result<Vec<user_wallet::Model>, String> {
+        UserWalletDao::find_by_user(db, user_id)
+            .await
+            .map_err(|e| format!("Failed to fetch wallets: {}", e))
+    }
+
+    /// 取消关联钱包（主钱包不能取消），同时注销用该钱包登录的会话
+    pub async fn unlink_wallet(
+        db: &DatabaseConnection,
+        user_id: i64,
+        wallet_id: i64,
+    ) -> Result<(), String> {
+        let wallet = UserWalletDao::find_by_id(db, wallet_id)
+            .await
+            .map_err(|e| format!("Failed to fetch wallet: {}", e))?
+            .filter(|w| w.user_id == user_id)
+            .ok_or_else(|| "Wallet not found".to_string())?;
+
+        if wallet.is_primary {
+            return Err("Cannot unlink the primary wallet".to_string());
+        }
+
+        let txn = db.begin().await.map_err(|e| format!("Failed to start transaction: {}", e))?;
+        UserWalletDao::delete(&txn, wallet.id)
+            .await
+            .map_err(|e| format!("Failed to unlink wallet: {}", e))?;
+        let revoked = AuthSessionDao::revoke_for_wallet(&txn, user_id, &wallet.address)
+            .await
+            .map_err(|e| format!("Failed to revoke wallet sessions: {}", e))?;
+        txn.commit().await.map_err(|e| format!("Failed to unlink wallet: {}", e))?;
+
+        log::info!(
+            "User {} unlinked {} wallet {} and revoked {} session(s)",
+            user_id, wallet.chain, wallet.address, revoked
+        );
+        Ok(())
+    }
+
+    async fn ensure_not_linked(
+        db: &DatabaseConnection,
+        user_id: i64,
+        address: &str,
+    ) -> Result<(), String> {
+        let owner = UserDao::find_by_wallet(db, address)
+            .await
+            .map_err(|e| format!("Failed to fetch wallet owner: {}", e))?;
+
+        match owner {
+            Some(owner) if owner.id == user_id => Err("Wallet is already linked to your account".to_string()),
+            Some(_) => Err("Wallet is already linked to another account".to_string()),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+
+use crate::sui::{
+    BalanceChange, ChainEvent, CreatedObject, EventId, EventPage, SuiEvent, SuiRpcClient, SuiRpcError, SuiTransaction,
+};
+
+/// 通过 JSON-RPC 调用 Sui 全节点（`SUI_RPC_URL`）
+pub struct HttpSuiClient {
+    url: String,
+    client: reqwest::Client,
+}
+
+impl HttpSuiClient {
+    pub fn new(url: &str, timeout: Duration) -> Result<Self, String> {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|e| format!("Failed to build Sui RPC client: {}", e))?;
+
+        Ok(Self {
+            url: url.trim_end_matches('/').to_string(),
+            client,
+        })
+    }
+}
+
+impl HttpSuiClient {
+    /// 发送一次 JSON-RPC 请求，传输层错误和非 2xx 响应转换为 [`SuiRpcError`]
+    async fn call<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<RpcResponse<T>, SuiRpcError> {
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        let response = self.client.post(&self.url).json(&body).send().await.map_err(|e| {
+            if e.is_timeout() {
+                SuiRpcError::Timeout
+            } else {
+                SuiRpcError::Unavailable(e.to_string())
+            }
+        })?;
+        let status = response.status();
+        let text = response
+            .text()
+            .await
+            .map_err(|e| SuiRpcError::Unavailable(e.to_string()))?;
+        if status.is_server_error() {
+            return Err(SuiRpcError::Unavailable(format!("{}: {}", status, text)));
+        }
+        if !status.is_success() {
+            return Err(SuiRpcError::BadResponse(format!("{}: {}", status, text)));
+        }
+
+        serde_json::from_str(&text).map_err(|e| SuiRpcError::BadResponse(format!("{}: {}", e, text)))
+    }
+}
+
+#[async_trait]
+impl SuiRpcClient for HttpSuiClient {
+    async fn get_transaction(&self, digest: &str) -> Result<Option<SuiTransaction>, SuiRpcError> {
+        let params = serde_json::json!([digest, {
+            "showInput": true,
+            "showEffects": true,
+            "showEvents": true,
+            "showBalanceChanges": true,
+            "showObjectChanges": true,
+        }]);
+
+        let response: RpcResponse<TransactionBlock> = self.call("sui_getTransactionBlock", params).await?;
+        if let Some(error) = response.error {
+            // 不存在的摘要（或节点尚未索引到的交易）以 RPC 错误返回
+            if error.message.contains("Could not find the referenced transaction") {
+                return Ok(None);
+            }
+            return Err(SuiRpcError::BadResponse(format!("{} ({})", error.message, error.code)));
+        }
+
+        response
+            .result
+            .map(|block| block.into_transaction())
+            .transpose()
+            .map_err(SuiRpcError::BadResponse)
+    }
+
+    async fn query_module_events(
+        &self,
+        package_id: &str,
+        module: &str,
+        cursor: Option<&EventId>,
+        limit: usize,
+    ) -> Result<EventPage, SuiRpcError> {
+        let cursor = cursor.map(|c| serde_json::json!({ "txDigest": c.tx_digest, "eventSeq": c.event_seq }));
+        let params = serde_json::json!([
+            { "MoveEventModule": { "package": package_id, "module": module } },
+            cursor,
+            limit,
+            false,
+        ]);
+
+        let response: RpcResponse<RawEventPage> = self.call("suix_queryEvents", params).await?;
+        if let Some(error) = response.error {
+            return Err(SuiRpcError::BadResponse(format!("{} ({})", error.message, error.code)));
+        }
+        let page = response
+            .result
+            .ok_or_else(|| SuiRpcError::BadResponse("suix_queryEvents returned no result".to_string()))?;
+
+        Ok(EventPage {
+            events: page
+                .data
+                .into_iter()
+                .map(|e| ChainEvent {
+                    id: e.id.into(),
+                    event_type: e.event_type,
+                    sender: e.sender,
+                    parsed_json: e.parsed_json,
+                    timestamp_ms: e.timestamp_ms.and_then(|t| t.parse().ok()),
+                })
+                .collect(),
+            next_cursor: page.next_cursor.map(Into::into),
+            has_next_page: page.has_next_page,
+        })
+    }
+
+    async fn get_checkpoint_timestamp(&self, sequence: u64) -> Result<Option<i64>, SuiRpcError> {
+        let params = serde_json::json!([sequence.to_string()]);
+
+        let response: RpcResponse<RawCheckpoint> = self.call("sui_getCheckpoint", params).await?;
+        if let Some(error) = response.error {
+            if error.message.to_ascii_lowercase().contains("not found") {
+                return Ok(None);
+            }
+            return Err(SuiRpcError::BadResponse(format!("{} ({})", error.message, error.code)));
+        }
+
+        response
+            .result
+            .map(|checkpoint| checkpoint.timestamp_ms.parse())
+            .transpose()
+            .map_err(|e| SuiRpcError::BadResponse(format!("Invalid checkpoint timestamp: {}", e)))
+    }
+}
+
+#[derive(Deserialize)]
+struct RpcResponse<T> {
+    result: Option<T>,
+    error: Option<RpcError>,
+}
+
+#[derive(Deserialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionBlock {
+    digest: String,
+    transaction: Option<serde_json::Value>,
+    effects: Option<serde_json::Value>,
+    #[serde(default)]
+    events: Vec<RawEvent>,
+    #[serde(default)]
+    balance_changes: Vec<RawBalanceChange>,
+    #[serde(default)]
+    object_changes: Vec<serde_json::Value>,
+    timestamp_ms: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawEvent {
+    #[serde(rename = "type")]
+    event_type: String,
+    #[serde(default)]
+    parsed_json: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawEventPage {
+    #[serde(default)]
+    data: Vec<RawChainEvent>,
+    next_cursor: Option<RawEventId>,
+    #[serde(default)]
+    has_next_page: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawChainEvent {
+    id: RawEventId,
+    #[serde(rename = "type")]
+    event_type: String,
+    sender: String,
+    #[serde(default)]
+    parsed_json: serde_json::Value,
+    timestamp_ms: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawEventId {
+    tx_digest: String,
+    event_seq: String,
+}
+
+impl From<RawEventId> for EventId {
+    fn from(id: RawEventId) -> Self {
+        EventId { tx_digest: id.tx_digest, event_seq: id.event_seq }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawCheckpoint {
+    timestamp_ms: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawBalanceChange {
+    owner: serde_json::Value,
+    coin_type: String,
+    amount: String,
+}
+
+impl TransactionBlock {
+    fn into_transaction(self) -> Result<SuiTransaction, String> {
+        let sender = self
+            .transaction
+            .as_ref()
+            .and_then(|t| t.pointer("/data/sender"))
+            .and_then(|s| s.as_str())
+            .ok_or_else(|| format!("Transaction {} has no sender", self.digest))?
+            .to_string();
+        let status = self.effects.as_ref().and_then(|e| e.get("status"));
+        let success = status.and_then(|s| s.get("status")).and_then(|s| s.as_str()) == Some("success");
+        let error = status
+            .and_then(|s| s.get("error"))
+            .and_then(|e| e.as_str())
+            .map(|e| e.to_string());
+
+        let balance_changes = self
+            .balance_changes
+            .into_iter()
+            .filter_map(|change| {
+                let owner = change.owner.get("AddressOwner")?.as_str()?.to_string();
+                let amount = change.amount.parse().ok()?;
+                Some(BalanceChange { owner, coin_type: change.coin_type, amount })
+            })
+            .collect();
+
+        let created_objects = self
+            .object_changes
+            .iter()
+            .filter(|change| change.get("type").and_then(|t| t.as_str()) == Some("created"))
+            .filter_map(|change| {
+                Some(CreatedObject {
+                    object_id: change.get("objectId")?.as_str()?.to_string(),
+                    object_type: change.get("objectType")?.as_str()?.to_string(),
+                })
+            })
+            .collect();
+
+        Ok(SuiTransaction {
+            digest: self.digest,
+            sender,
+            success,
+            error,
+            timestamp_ms: self.timestamp_ms.and_then(|t| t.parse().ok()),
+            balance_changes,
+            created_objects,
+            events: self
+                .events
+                .into_iter()
+                .map(|e| SuiEvent { event_type: e.event_type, parsed_json: e.parsed_json })
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,130 @@
+pub mod http;
+pub mod stub;
+
+use async_trait::async_trait;
+
+pub use http::HttpSuiClient;
+pub use stub::StubSuiClient;
+
+/// Sui RPC 调用失败的原因
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SuiRpcError {
+    /// 请求超时
+    #[error("Sui RPC timed out")]
+    Timeout,
+
+    /// 连接失败或 5xx
+    #[error("Sui RPC unavailable: {0}")]
+    Unavailable(String),
+
+    /// RPC 返回错误或响应无法解析
+    #[error("Unexpected Sui RPC response: {0}")]
+    BadResponse(String),
+}
+
+/// 交易中某个地址某种代币的余额变化
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalanceChange {
+    /// 地址（对象持有的余额变化不计入）
+    pub owner: String,
+    /// 代币类型，如 `0x2::sui::SUI`
+    pub coin_type: String,
+    /// 变化量（代币最小单位），减少为负
+    pub amount: i128,
+}
+
+/// 交易创建的对象
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatedObject {
+    pub object_id: String,
+    /// Move 类型，如 `0x...::subscription::Subscription`
+    pub object_type: String,
+}
+
+/// 交易发出的 Move 事件
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuiEvent {
+    /// 事件类型，如 `0x...::subscription::SubscriptionCreated`
+    pub event_type: String,
+    /// 事件字段
+    pub parsed_json: serde_json::Value,
+}
+
+/// 按摘要查询到的交易（只保留校验解锁需要的字段）
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuiTransaction {
+    pub digest: String,
+    /// 交易发送方
+    pub sender: String,
+    /// 交易执行是否成功
+    pub success: bool,
+    /// 执行失败的原因
+    pub error: Option<String>,
+    /// 交易所在检查点的时间戳（毫秒）
+    pub timestamp_ms: Option<i64>,
+    pub balance_changes: Vec<BalanceChange>,
+    pub created_objects: Vec<CreatedObject>,
+    pub events: Vec<SuiEvent>,
+}
+
+/// 事件在链上的位置，同时作为分页游标
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EventId {
+    pub tx_digest: String,
+    /// 事件在交易中的序号
+    pub event_seq: String,
+}
+
+/// 按模块查询到的链上事件
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainEvent {
+    pub id: EventId,
+    /// 事件类型，如 `0x...::allowlist::AccountAdded`
+    pub event_type: String,
+    /// 发出事件的交易的发送方
+    pub sender: String,
+    /// 事件字段
+    pub parsed_json: serde_json::Value,
+    /// 交易所在检查点的时间戳（毫秒）
+    pub timestamp_ms: Option<i64>,
+}
+
+/// 一页事件（按链上顺序升序）
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventPage {
+    pub events: Vec<ChainEvent>,
+    /// 下一页的游标，没有新事件时为本页最后一个事件或调用方传入的游标
+    pub next_cursor: Option<EventId>,
+    pub has_next_page: bool,
+}
+
+/// Sui 全节点 RPC 客户端
+#[async_trait]
+pub trait SuiRpcClient: Send + Sync {
+    /// 按摘要查询交易，交易不存在（或节点尚未索引到）时返回 None
+    async fn get_transaction(&self, digest: &str) -> Result<Option<SuiTransaction>, SuiRpcError>;
+
+    /// 查询某个包中某个模块发出的事件，从 `cursor` 之后开始（不含 `cursor`）
+    async fn query_module_events(
+        &self,
+        package_id: &str,
+        module: &str,
+        cursor: Option<&EventId>,
+        limit: usize,
+    ) -> Result<EventPage, SuiRpcError>;
+
+    /// 查询检查点的时间戳（毫秒），检查点不存在时返回 None
+    async fn get_checkpoint_timestamp(&self, sequence: u64) -> Result<Option<i64>, SuiRpcError>;
+}
+
+/// 规范化 Move 类型中的包地址，使 `0x2::sui::SUI` 与 `0x000…002::sui::SUI` 相等
+pub fn normalize_type(type_tag: &str) -> String {
+    let type_tag = type_tag.trim();
+    match type_tag.split_once("::") {
+        Some((address, rest)) => {
+            let address = address.trim_start_matches("0x").trim_start_matches("0X").trim_start_matches('0');
+            format!("0x{}::{}", address.to_ascii_lowercase(), rest)
+        }
+        None => type_tag.to_string(),
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::sui::{ChainEvent, EventId, EventPage, SuiRpcClient, SuiRpcError, SuiTransaction};
+
+/// 进程内的 Sui RPC 替身，供测试和本地开发使用，不访问网络
+///
+/// 只能查到通过 [`StubSuiClient::insert`] 放入的交易、[`StubSuiClient::push_event`] 放入的事件
+/// 和 [`StubSuiClient::insert_checkpoint`] 放入的检查点，其余都视为不存在。
+#[derive(Default)]
+pub struct StubSuiClient {
+    transactions: Mutex<HashMap<String, SuiTransaction>>,
+    /// (包地址, 模块, 事件)，按放入顺序排列
+    events: Mutex<Vec<(String, String, ChainEvent)>>,
+    /// 检查点序号 -> 时间戳（毫秒）
+    checkpoints: Mutex<HashMap<u64, i64>>,
+    failure: Option<SuiRpcError>,
+}
+
+impl StubSuiClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 所有调用都返回指定错误，用于测试错误处理
+    #[allow(dead_code)]
+    pub fn failing(error: SuiRpcError) -> Self {
+        Self {
+            failure: Some(error),
+            ..Self::default()
+        }
+    }
+
+    /// 放入一笔交易，之后可以按摘要查到
+    #[allow(dead_code)]
+    pub fn insert(&self, transaction: SuiTransaction) {
+        self.transactions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(transaction.digest.clone(), transaction);
+    }
+
+    /// 追加一个由 `package_id::module` 发出的事件
+    #[allow(dead_code)]
+    pub fn push_event(&self, package_id: &str, module: &str, event: ChainEvent) {
+        self.events
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push((package_id.to_string(), module.to_string(), event));
+    }
+
+    /// 放入一个检查点
+    #[allow(dead_code)]
+    pub fn insert_checkpoint(&self, sequence: u64, timestamp_ms: i64) {
+        self.checkpoints
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(sequence, timestamp_ms);
+    }
+}
+
+#[async_trait]
+impl SuiRpcClient for StubSuiClient {
+    async fn get_transaction(&self, digest: &str) -> Result<Option<SuiTransaction>, SuiRpcError> {
+        if let Some(error) = &self.failure {
+            return Err(error.clone());
+        }
+
+        Ok(self
+            .transactions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(digest)
+            .cloned())
+    }
+
+    async fn query_module_events(
+        &self,
+        package_id: &str,
+        module: &str,
+        cursor: Option<&EventId>,
+        limit: usize,
+    ) -> Result<EventPage, SuiRpcError> {
+        if let Some(error) = &self.failure {
+            return Err(error.clone());
+        }
+
+        let events = self.events.lock().unwrap_or_else(|e| e.into_inner());
+        let matching: Vec<&ChainEvent> = events
+            .iter()
+            .filter(|(package, m, _)| package == package_id && m == module)
+            .map(|(_, _, event)| event)
+            .collect();
+        let start = match cursor {
+            Some(cursor) => matching
+                .iter()
+                .position(|event| &event.id == cursor)
+                .map(|i| i + 1)
+                .unwrap_or(0),
+            None => 0,
+        };
+
+        let page: Vec<ChainEvent> = matching.iter().skip(start).take(limit).map(|e| (*e).clone()).collect();
+        let has_next_page = start + page.len() < matching.len();
+        let next_cursor = page.last().map(|e| e.id.clone()).or_else(|| cursor.cloned());
+
+        Ok(EventPage { events: page, next_cursor, has_next_page })
+    }
+
+    async fn get_checkpoint_timestamp(&self, sequence: u64) -> Result<Option<i64>, SuiRpcError> {
+        if let Some(error) = &self.failure {
+            return Err(error.clone());
+        }
+
+        Ok(self
+            .checkpoints
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&sequence)
+            .copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(digest: &str) -> ChainEvent {
+        ChainEvent {
+            id: EventId { tx_digest: digest.to_string(), event_seq: "0".to_string() },
+            event_type: "0x1::allowlist::AccountAdded".to_string(),
+            sender: "0xa".to_string(),
+            parsed_json: serde_json::Value::Null,
+            timestamp_ms: None,
+        }
+    }
+
+    #[actix_web::test]
+    async fn query_module_events_pages_after_cursor() {
+        let stub = StubSuiClient::new();
+        stub.push_event("0x1", "allowlist", event("a"));
+        stub.push_event("0x1", "subscription", event("x"));
+        stub.push_event("0x1", "allowlist", event("b"));
+        stub.push_event("0x1", "allowlist", event("c"));
+
+        let first = stub.query_module_events("0x1", "allowlist", None, 2).await.unwrap();
+        assert_eq!(first.events.iter().map(|e| e.id.tx_digest.as_str()).collect::<Vec<_>>(), ["a", "b"]);
+        assert!(first.has_next_page);
+
+        let second = stub
+            .query_module_events("0x1", "allowlist", first.next_cursor.as_ref(), 2)
+            .await
+            .unwrap();
+        assert_eq!(second.events.len(), 1);
+        assert_eq!(second.events[0].id.tx_digest, "c");
+        assert!(!second.has_next_page);
+
+        let empty = stub
+            .query_module_events("0x1", "allowlist", second.next_cursor.as_ref(), 2)
+            .await
+            .unwrap();
+        assert!(empty.events.is_empty());
+        assert_eq!(empty.next_cursor, second.next_cursor);
+    }
+}
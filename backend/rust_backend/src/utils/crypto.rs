@@ -123,7 +123,7 @@ impl CryptoUtil {
             return "***".to_string();
         }
         let chars: Vec<char> = name.chars().collect();
-        if chars.len() > 0 {
+        if !chars.is_empty() {
             format!("{}**", chars[0])
         } else {
             "***".to_string()
@@ -1,3 +1,6 @@
 pub mod crypto;
 pub mod validator;
 pub mod database;
+pub mod sui_signature;
+pub mod solana_signature;
+pub mod receipt_signer;
@@ -0,0 +1,150 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+/// 收据签名算法
+pub const RECEIPT_ALGORITHM: &str = "ed25519";
+
+/// 付费收据的服务端签名密钥（Ed25519）
+pub struct ReceiptSigner {
+    signing_key: SigningKey,
+    key_id: String,
+    /// 轮换前使用过的公钥 (key_id, 十六进制公钥)，旧收据仍可按 key_id 找到公钥验证
+    retired: Vec<(String, String)>,
+}
+
+impl ReceiptSigner {
+    /// 从 32 字节十六进制种子创建
+    pub fn from_seed_hex(seed: &str) -> Result<Self, String> {
+        let seed: [u8; 32] = hex::decode(seed.trim())
+            .map_err(|e| format!("Invalid receipt signing key: {}", e))?
+            .try_into()
+            .map_err(|_| "Receipt signing key must be 32 bytes".to_string())?;
+
+        Ok(Self::new(SigningKey::from_bytes(&seed)))
+    }
+
+    /// 读取 `RECEIPT_SIGNING_KEY` 和 `RECEIPT_RETIRED_PUBLIC_KEYS`（逗号分隔的十六进制公钥）
+    ///
+    /// 签名密钥必须配置，否则每次重启公钥都会变化、旧收据无法离线验证；
+    /// 只有 `allow_ephemeral`（本地 mock 模式）时才生成临时密钥。
+    pub fn from_env(allow_ephemeral: bool) -> Result<Self, String> {
+        let signer = match std::env::var("RECEIPT_SIGNING_KEY") {
+            Ok(seed) if !seed.trim().is_empty() => Self::from_seed_hex(&seed)?,
+            _ if allow_ephemeral => {
+                log::warn!("RECEIPT_SIGNING_KEY is not set, using an ephemeral receipt signing key");
+                Self::generate()
+            }
+            _ => return Err("RECEIPT_SIGNING_KEY must be set to a 32-byte hex seed".to_string()),
+        };
+
+        match std::env::var("RECEIPT_RETIRED_PUBLIC_KEYS") {
+            Ok(keys) => signer.with_retired_keys(keys.split(',').map(str::trim).filter(|k| !k.is_empty())),
+            Err(_) => Ok(signer),
+        }
+    }
+
+    /// 添加轮换前的公钥，之后仍可通过 [`Self::public_key`] 按 key_id 查询
+    pub fn with_retired_keys<'a, I: IntoIterator<Item = &'a str>>(mut self, public_keys: I) -> Result<Self, String> {
+        for public_key_hex in public_keys {
+            let public_key = Self::parse_public_key(public_key_hex)?;
+            let key_id = Self::key_id_of(&public_key);
+            if key_id != self.key_id && !self.retired.iter().any(|(id, _)| *id == key_id) {
+                self.retired.push((key_id, hex::encode(public_key.as_bytes())));
+            }
+        }
+        Ok(self)
+    }
+
+    /// 生成随机密钥
+    pub fn generate() -> Self {
+        let mut seed = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut seed);
+        Self::new(SigningKey::from_bytes(&seed))
+    }
+
+    fn new(signing_key: SigningKey) -> Self {
+        let key_id = Self::key_id_of(&signing_key.verifying_key());
+        Self { signing_key, key_id, retired: Vec::new() }
+    }
+
+    fn key_id_of(public_key: &VerifyingKey) -> String {
+        hex::encode(Sha256::digest(public_key.as_bytes()))[..16].to_string()
+    }
+
+    fn parse_public_key(public_key_hex: &str) -> Result<VerifyingKey, String> {
+        let public_key: [u8; 32] = hex::decode(public_key_hex)
+            .map_err(|e| format!("Invalid public key: {}", e))?
+            .try_into()
+            .map_err(|_| "Invalid Ed25519 public key length".to_string())?;
+        VerifyingKey::from_bytes(&public_key).map_err(|e| format!("Invalid public key: {}", e))
+    }
+
+    /// 公钥 ID（公钥 SHA-256 的前 16 个十六进制字符），写入每张收据
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    /// 十六进制编码的公钥
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(self.signing_key.verifying_key().as_bytes())
+    }
+
+    /// 按 key_id 查询当前或已轮换的公钥（十六进制），未知的 key_id 返回 None
+    pub fn public_key(&self, key_id: &str) -> Option<String> {
+        if key_id == self.key_id {
+            return Some(self.public_key_hex());
+        }
+        self.retired
+            .iter()
+            .find(|(id, _)| id == key_id)
+            .map(|(_, public_key)| public_key.clone())
+    }
+
+    /// 对收据原文签名，返回 base64 编码的签名
+    pub fn sign(&self, payload: &[u8]) -> String {
+        STANDARD.encode(self.signing_key.sign(payload).to_bytes())
+    }
+
+    /// 用公布的公钥验证收据签名（离线验证的参考实现）
+    #[allow(dead_code)]
+    pub fn verify(public_key_hex: &str, payload: &[u8], signature_b64: &str) -> Result<(), String> {
+        let public_key = Self::parse_public_key(public_key_hex)?;
+        let signature: [u8; 64] = STANDARD
+            .decode(signature_b64)
+            .map_err(|e| format!("Invalid signature encoding: {}", e))?
+            .try_into()
+            .map_err(|_| "Invalid Ed25519 signature length".to_string())?;
+
+        public_key
+            .verify(payload, &Signature::from_bytes(&signature))
+            .map_err(|_| "Receipt signature verification failed".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_receipt_signature_roundtrip() {
+        let signer = ReceiptSigner::from_seed_hex(&"11".repeat(32)).unwrap();
+        let payload = br#"{"receipt_id":"r-1","amount":1000}"#;
+        let signature = signer.sign(payload);
+
+        assert!(ReceiptSigner::verify(&signer.public_key_hex(), payload, &signature).is_ok());
+        assert!(ReceiptSigner::verify(&signer.public_key_hex(), br#"{"receipt_id":"r-1","amount":1}"#, &signature).is_err());
+        assert_eq!(signer.key_id().len(), 16);
+
+        // 轮换后旧收据仍能按 key_id 找到旧公钥验证
+        let rotated = ReceiptSigner::from_seed_hex(&"22".repeat(32))
+            .unwrap()
+            .with_retired_keys([signer.public_key_hex().as_str()])
+            .unwrap();
+        let old_key = rotated.public_key(signer.key_id()).unwrap();
+        assert!(ReceiptSigner::verify(&old_key, payload, &signature).is_ok());
+        assert_eq!(rotated.public_key(rotated.key_id()), Some(rotated.public_key_hex()));
+        assert_eq!(rotated.public_key("unknown"), None);
+    }
+}
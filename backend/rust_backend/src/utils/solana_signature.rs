@@ -0,0 +1,49 @@
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+/// Solana 钱包签名验证工具
+pub struct SolanaSignature;
+
+impl SolanaSignature {
+    /// 验证 Solana `signMessage` 签名
+    ///
+    /// # 参数
+    /// - `message`: 原始消息字节（Solana 钱包直接对消息签名，没有前缀）
+    /// - `address`: base58 编码的钱包地址（即 Ed25519 公钥）
+    /// - `signature_b58`: base58 编码的 64 字节签名
+    pub fn verify_message(message: &[u8], address: &str, signature_b58: &str) -> Result<(), String> {
+        let pk_array: [u8; 32] = bs58::decode(address.trim())
+            .into_vec()
+            .map_err(|e| format!("Invalid Solana address: {}", e))?
+            .try_into()
+            .map_err(|_| "Invalid Solana public key length".to_string())?;
+        let sig_array: [u8; 64] = bs58::decode(signature_b58.trim())
+            .into_vec()
+            .map_err(|e| format!("Invalid signature encoding: {}", e))?
+            .try_into()
+            .map_err(|_| "Invalid Ed25519 signature length".to_string())?;
+
+        let public_key = VerifyingKey::from_bytes(&pk_array)
+            .map_err(|e| format!("Invalid public key: {}", e))?;
+
+        public_key
+            .verify(message, &Signature::from_bytes(&sig_array))
+            .map_err(|_| "Signature verification failed".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    #[test]
+    fn test_verify_solana_message() {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let address = bs58::encode(signing_key.verifying_key().to_bytes()).into_string();
+        let message = b"link wallet";
+        let signature = bs58::encode(signing_key.sign(message).to_bytes()).into_string();
+
+        assert!(SolanaSignature::verify_message(message, &address, &signature).is_ok());
+        assert!(SolanaSignature::verify_message(b"other message", &address, &signature).is_err());
+    }
+}
@@ -0,0 +1,128 @@
+use base64::{Engine as _, engine::general_purpose};
+use blake2::{Blake2b, Digest, digest::consts::U32};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+type Blake2b256 = Blake2b<U32>;
+
+/// Sui 签名方案标识（序列化签名的第一个字节）
+const ED25519_FLAG: u8 = 0x00;
+
+/// PersonalMessage 的 intent 前缀: scope=3, version=0, app_id=0
+const PERSONAL_MESSAGE_INTENT: [u8; 3] = [3, 0, 0];
+
+/// Sui 钱包签名验证工具
+pub struct SuiSignature;
+
+impl SuiSignature {
+    /// 验证 Sui personal message 签名，成功时返回签名者地址
+    ///
+    /// # 参数
+    /// - `message`: 原始消息（钱包 signPersonalMessage 时传入的字节）
+    /// - `signature_b64`: 钱包返回的 base64 序列化签名 (flag || sig || pubkey)
+    ///
+    /// # 返回
+    /// - `Ok(String)`: 签名者的 Sui 地址（0x 开头，小写）
+    /// - `Err(String)`: 错误信息
+    pub fn verify_personal_message(message: &[u8], signature_b64: &str) -> Result<String, String> {
+        let bytes = general_purpose::STANDARD
+            .decode(signature_b64.trim())
+            .map_err(|e| format!("Invalid signature encoding: {}", e))?;
+
+        let (flag, rest) = bytes
+            .split_first()
+            .ok_or_else(|| "Empty signature".to_string())?;
+
+        if *flag != ED25519_FLAG {
+            return Err(format!("Unsupported signature scheme flag: {}", flag));
+        }
+
+        if rest.len() != 64 + 32 {
+            return Err("Invalid Ed25519 signature length".to_string());
+        }
+
+        let (sig_bytes, pk_bytes) = rest.split_at(64);
+        let sig_array: [u8; 64] = sig_bytes
+            .try_into()
+            .map_err(|_| "Invalid signature length".to_string())?;
+        let pk_array: [u8; 32] = pk_bytes
+            .try_into()
+            .map_err(|_| "Invalid public key length".to_string())?;
+
+        let public_key = VerifyingKey::from_bytes(&pk_array)
+            .map_err(|e| format!("Invalid public key: {}", e))?;
+        let signature = Signature::from_bytes(&sig_array);
+
+        let digest = Self::personal_message_digest(message);
+        public_key
+            .verify(&digest, &signature)
+            .map_err(|_| "Signature verification failed".to_string())?;
+
+        Ok(Self::ed25519_address(&pk_array))
+    }
+
+    /// 计算 personal message 的签名摘要: blake2b256(intent || bcs(message))
+    pub fn personal_message_digest(message: &[u8]) -> [u8; 32] {
+        let mut hasher = Blake2b256::new();
+        hasher.update(PERSONAL_MESSAGE_INTENT);
+        hasher.update(Self::uleb128(message.len()));
+        hasher.update(message);
+        hasher.finalize().into()
+    }
+
+    /// 由 Ed25519 公钥推导 Sui 地址: blake2b256(flag || pubkey)
+    pub fn ed25519_address(public_key: &[u8; 32]) -> String {
+        let mut hasher = Blake2b256::new();
+        hasher.update([ED25519_FLAG]);
+        hasher.update(public_key);
+        format!("0x{}", hex::encode(hasher.finalize()))
+    }
+
+    /// BCS 向量长度前缀 (ULEB128)
+    fn uleb128(mut value: usize) -> Vec<u8> {
+        let mut out = Vec::new();
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                out.push(byte);
+                return out;
+            }
+            out.push(byte | 0x80);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn sign(key: &SigningKey, message: &[u8]) -> String {
+        let digest = SuiSignature::personal_message_digest(message);
+        let signature = key.sign(&digest);
+        let mut bytes = vec![ED25519_FLAG];
+        bytes.extend_from_slice(&signature.to_bytes());
+        bytes.extend_from_slice(key.verifying_key().as_bytes());
+        general_purpose::STANDARD.encode(bytes)
+    }
+
+    #[test]
+    fn test_verify_personal_message() {
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let message = b"ResumeVault login nonce";
+        let signature = sign(&key, message);
+
+        let address = SuiSignature::verify_personal_message(message, &signature).unwrap();
+        assert_eq!(address, SuiSignature::ed25519_address(key.verifying_key().as_bytes()));
+        assert_eq!(address.len(), 66);
+    }
+
+    #[test]
+    fn test_reject_tampered_message() {
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let signature = sign(&key, b"original");
+
+        assert!(SuiSignature::verify_personal_message(b"tampered", &signature).is_err());
+        assert!(SuiSignature::verify_personal_message(b"original", "not-base64!").is_err());
+    }
+}
@@ -1,4 +1,75 @@
+use actix_web::{http::StatusCode, HttpResponse, ResponseError};
 use regex::Regex;
+use serde::Serialize;
+
+use crate::models::ApiResponse;
+
+/// 钱包所在链
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chain {
+    Sui,
+    Solana,
+}
+
+impl Chain {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Chain::Sui => "sui",
+            Chain::Solana => "solana",
+        }
+    }
+
+    /// 解析链名称（不区分大小写）
+    pub fn parse(value: &str) -> Result<Self, ValidationError> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "sui" => Ok(Chain::Sui),
+            "solana" => Ok(Chain::Solana),
+            _ => Err(ValidationError::new(
+                "chain",
+                "unsupported_chain",
+                format!("Unsupported chain '{}', expected sui or solana", value),
+            )),
+        }
+    }
+}
+
+/// 结构化的参数校验错误 (400)，`data` 中返回出错字段和错误码
+#[derive(Debug, Clone, Serialize, thiserror::Error)]
+#[error("{message}")]
+pub struct ValidationError {
+    /// 出错的字段
+    pub field: &'static str,
+    /// 机器可读的错误码，如 `invalid_format`
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl ValidationError {
+    pub fn new(field: &'static str, code: &'static str, message: impl Into<String>) -> Self {
+        Self { field, code, message: message.into() }
+    }
+
+    /// 替换出错字段名
+    pub fn for_field(mut self, field: &'static str) -> Self {
+        self.field = field;
+        self
+    }
+}
+
+impl ResponseError for ValidationError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(ApiResponse {
+            success: false,
+            data: Some(self),
+            message: None,
+            error: Some(self.message.clone()),
+        })
+    }
+}
 
 /// 验证工具
 #[allow(dead_code)]
@@ -19,16 +90,78 @@ impl Validator {
         phone_regex.is_match(phone)
     }
 
-    /// 验证钱包地址（支持 Sui 地址格式）
-    pub fn is_valid_wallet_address(address: &str) -> bool {
-        // Sui 地址是 0x 开头的 64 位十六进制字符串（总共 66 个字符）
-        if address.len() == 66 && address.starts_with("0x") {
-            // 检查是否只包含十六进制字符
-            return address[2..].chars().all(|c| c.is_ascii_hexdigit());
+    /// 验证钱包地址（按链检查格式）
+    pub fn is_valid_wallet_address(chain: Chain, address: &str) -> bool {
+        Self::normalize_wallet_address(chain, address).is_ok()
+    }
+
+    /// 将钱包地址规范化为唯一形式
+    ///
+    /// - Sui: 小写、`0x` 前缀、左侧补零到 64 位十六进制（与 Sui SDK 的 `normalizeSuiAddress` 一致）
+    /// - Solana: base58 编码的 32 字节公钥，原样保留（base58 区分大小写）
+    pub fn normalize_wallet_address(chain: Chain, address: &str) -> Result<String, ValidationError> {
+        const FIELD: &str = "wallet_address";
+
+        let address = address.trim();
+        if address.is_empty() {
+            return Err(ValidationError::new(FIELD, "empty", "Wallet address cannot be empty"));
+        }
+
+        match chain {
+            Chain::Sui => {
+                let hex = address
+                    .strip_prefix("0x")
+                    .or_else(|| address.strip_prefix("0X"))
+                    .unwrap_or(address);
+
+                if hex.is_empty() || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return Err(ValidationError::new(
+                        FIELD,
+                        "invalid_format",
+                        format!("Invalid Sui address '{}', expected 0x followed by hex digits", address),
+                    ));
+                }
+                if hex.len() > 64 {
+                    return Err(ValidationError::new(
+                        FIELD,
+                        "too_long",
+                        format!("Invalid Sui address '{}', expected at most 64 hex digits", address),
+                    ));
+                }
+
+                Ok(format!("0x{:0>64}", hex.to_ascii_lowercase()))
+            }
+            Chain::Solana => match bs58::decode(address).into_vec() {
+                Ok(bytes) if bytes.len() == 32 => Ok(address.to_string()),
+                Ok(_) => Err(ValidationError::new(
+                    FIELD,
+                    "invalid_length",
+                    format!("Invalid Solana address '{}', expected a 32-byte public key", address),
+                )),
+                Err(_) => Err(ValidationError::new(
+                    FIELD,
+                    "invalid_format",
+                    format!("Invalid Solana address '{}', expected base58", address),
+                )),
+            },
+        }
+    }
+
+    /// 规范化 Sui 钱包地址
+    pub fn normalize_sui_address(address: &str) -> Result<String, ValidationError> {
+        Self::normalize_wallet_address(Chain::Sui, address)
+    }
+
+    /// 识别地址所在链并规范化：`0x` 开头为 Sui，否则先按 Solana 解析，再按无前缀的 Sui 地址解析
+    pub fn detect_wallet_address(address: &str) -> Result<(Chain, String), ValidationError> {
+        let trimmed = address.trim();
+        if trimmed.starts_with("0x") || trimmed.starts_with("0X") {
+            return Ok((Chain::Sui, Self::normalize_sui_address(trimmed)?));
         }
-        
-        // 也可以接受其他长度的地址（向后兼容）
-        !address.is_empty()
+
+        Self::normalize_wallet_address(Chain::Solana, trimmed)
+            .map(|address| (Chain::Solana, address))
+            .or_else(|_| Self::normalize_sui_address(trimmed).map(|address| (Chain::Sui, address)))
     }
 
     /// 验证价格
@@ -52,4 +185,26 @@ mod tests {
         assert!(Validator::is_valid_phone("13800138000"));
         assert!(!Validator::is_valid_phone("12345678901"));
     }
+
+    #[test]
+    fn test_wallet_address_normalization() {
+        let canonical = format!("0x{}", "ab".repeat(32));
+        assert_eq!(Validator::normalize_sui_address(&format!("0X{}", "AB".repeat(32))).unwrap(), canonical);
+        assert_eq!(Validator::normalize_sui_address(&"ab".repeat(32)).unwrap(), canonical);
+        assert_eq!(Validator::normalize_sui_address(" 0x2 ").unwrap(), format!("0x{:0>64}", "2"));
+
+        assert_eq!(Validator::normalize_sui_address("").unwrap_err().code, "empty");
+        assert_eq!(Validator::normalize_sui_address("0x").unwrap_err().code, "invalid_format");
+        assert_eq!(Validator::normalize_sui_address("wallet").unwrap_err().code, "invalid_format");
+        assert_eq!(Validator::normalize_sui_address(&format!("0x{}", "a".repeat(65))).unwrap_err().code, "too_long");
+
+        assert!(Validator::is_valid_wallet_address(Chain::Solana, "11111111111111111111111111111111"));
+        assert!(!Validator::is_valid_wallet_address(Chain::Solana, "0x2"));
+        assert!(!Validator::is_valid_wallet_address(Chain::Solana, "1111"));
+
+        let solana = "11111111111111111111111111111111";
+        assert_eq!(Validator::detect_wallet_address(solana).unwrap(), (Chain::Solana, solana.to_string()));
+        assert_eq!(Validator::detect_wallet_address("0x2").unwrap().0, Chain::Sui);
+        assert!(Validator::detect_wallet_address("not a wallet").is_err());
+    }
 }
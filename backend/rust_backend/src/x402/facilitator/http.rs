@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+
+use crate::x402::facilitator::{
+    check_settle, check_verify, Facilitator, FacilitatorError, FacilitatorRequest, SettleResponse,
+    VerifyResponse,
+};
+use crate::x402::types::{PaymentPayload, PaymentRequirements};
+
+/// 第一次重试前的等待时间，之后每次翻倍
+const RETRY_BACKOFF_MS: u64 = 200;
+
+/// 通过 HTTP 调用远程 facilitator（`FACILITATOR_URL`）
+pub struct HttpFacilitator {
+    base_url: String,
+    client: reqwest::Client,
+    max_retries: u32,
+}
+
+impl HttpFacilitator {
+    pub fn new(base_url: &str, timeout: Duration, max_retries: u32) -> Result<Self, String> {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|e| format!("Failed to build facilitator client: {}", e))?;
+
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            client,
+            max_retries,
+        })
+    }
+
+    /// 调用 facilitator 接口，超时、连接失败和 5xx 按指数退避重试
+    ///
+    /// `/settle` 只在请求确定没有发出（连接失败）时重试，避免重复提交交易。
+    async fn post<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &FacilitatorRequest<'_>,
+        idempotent: bool,
+    ) -> Result<T, FacilitatorError> {
+        let url = format!("{}/{}", self.base_url, endpoint);
+        let mut attempt = 0;
+
+        loop {
+            let error = match self.client.post(&url).json(body).send().await {
+                Ok(response) => match Self::read(response).await {
+                    Ok(value) => return Ok(value),
+                    Err(e @ FacilitatorError::Unavailable(_)) if idempotent => e,
+                    Err(e) => return Err(e),
+                },
+                Err(e) if e.is_connect() => FacilitatorError::Unavailable(e.to_string()),
+                Err(e) if e.is_timeout() && idempotent => FacilitatorError::Timeout,
+                Err(e) if e.is_timeout() => return Err(FacilitatorError::Timeout),
+                Err(e) => return Err(FacilitatorError::Unavailable(e.to_string())),
+            };
+
+            if attempt >= self.max_retries {
+                return Err(error);
+            }
+            attempt += 1;
+            log::warn!("Facilitator {} failed ({}), retry {}/{}", endpoint, error, attempt, self.max_retries);
+            tokio::time::sleep(Duration::from_millis(RETRY_BACKOFF_MS << (attempt - 1))).await;
+        }
+    }
+
+    /// 按状态码解析响应
+    async fn read<T: DeserializeOwned>(response: reqwest::Response) -> Result<T, FacilitatorError> {
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| FacilitatorError::Unavailable(e.to_string()))?;
+
+        match status {
+            s if s.is_success() => serde_json::from_str(&body)
+                .map_err(|e| FacilitatorError::BadResponse(format!("{}: {}", e, body))),
+            // 支付本身无效时部分 facilitator 以 400 返回同样的响应体
+            StatusCode::BAD_REQUEST => serde_json::from_str(&body)
+                .map_err(|_| FacilitatorError::BadResponse(format!("{}: {}", status, body))),
+            s if s.is_server_error() => Err(FacilitatorError::Unavailable(format!("{}: {}", status, body))),
+            _ => Err(FacilitatorError::BadResponse(format!("{}: {}", status, body))),
+        }
+    }
+}
+
+#[async_trait]
+impl Facilitator for HttpFacilitator {
+    async fn verify(
+        &self,
+        payment: &PaymentPayload,
+        requirements: &PaymentRequirements,
+    ) -> Result<VerifyResponse, FacilitatorError> {
+        let response = self.post("verify", &FacilitatorRequest::new(payment, requirements), true).await?;
+        check_verify(response)
+    }
+
+    async fn settle(
+        &self,
+        payment: &PaymentPayload,
+        requirements: &PaymentRequirements,
+    ) -> Result<SettleResponse, FacilitatorError> {
+        let response = self.post("settle", &FacilitatorRequest::new(payment, requirements), false).await?;
+        check_settle(response)
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+
+use crate::x402::facilitator::{Facilitator, FacilitatorError, SettleResponse, VerifyResponse};
+use crate::x402::types::{PaymentPayload, PaymentRequirements};
+
+/// 进程内的模拟 facilitator，供测试和本地开发使用，不访问网络
+///
+/// 接受任何带 `transaction` 字段且与支付要求匹配的支付，结算时用交易内容的哈希作为交易摘要；
+/// 同一笔交易只能结算一次。
+#[derive(Default)]
+pub struct MockFacilitator {
+    settled: Mutex<HashSet<String>>,
+    failure: Option<FacilitatorError>,
+}
+
+impl MockFacilitator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 所有调用都返回指定错误，用于测试错误处理
+    #[allow(dead_code)]
+    pub fn failing(error: FacilitatorError) -> Self {
+        Self {
+            failure: Some(error),
+            ..Self::default()
+        }
+    }
+
+    fn check(
+        &self,
+        payment: &PaymentPayload,
+        requirements: &PaymentRequirements,
+    ) -> Result<(String, Option<String>), FacilitatorError> {
+        if let Some(error) = &self.failure {
+            return Err(error.clone());
+        }
+
+        payment
+            .check_against(requirements)
+            .map_err(FacilitatorError::InvalidPayment)?;
+
+        let transaction = payment
+            .payload
+            .get("transaction")
+            .and_then(|t| t.as_str())
+            .filter(|t| !t.is_empty())
+            .ok_or_else(|| FacilitatorError::InvalidPayment("missing transaction".to_string()))?;
+        let payer = payment.payload.get("payer").and_then(|p| p.as_str()).map(|p| p.to_string());
+
+        Ok((transaction.to_string(), payer))
+    }
+}
+
+#[async_trait]
+impl Facilitator for MockFacilitator {
+    async fn verify(
+        &self,
+        payment: &PaymentPayload,
+        requirements: &PaymentRequirements,
+    ) -> Result<VerifyResponse, FacilitatorError> {
+        let (_, payer) = self.check(payment, requirements)?;
+
+        Ok(VerifyResponse {
+            is_valid: true,
+            invalid_reason: None,
+            payer,
+        })
+    }
+
+    async fn settle(
+        &self,
+        payment: &PaymentPayload,
+        requirements: &PaymentRequirements,
+    ) -> Result<SettleResponse, FacilitatorError> {
+        let (transaction, payer) = self.check(payment, requirements)?;
+
+        let digest = hex::encode(Sha256::digest(transaction.as_bytes()));
+        if !self.settled.lock().unwrap().insert(digest.clone()) {
+            return Err(FacilitatorError::SettlementFailed("transaction already settled".to_string()));
+        }
+
+        Ok(SettleResponse {
+            success: true,
+            error_reason: None,
+            transaction: digest,
+            network: requirements.network.clone(),
+            payer,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::x402::types::{SCHEME_EXACT, X402_VERSION};
+
+    #[actix_web::test]
+    async fn test_mock_settles_each_transaction_once() {
+        let requirements = PaymentRequirements {
+            scheme: SCHEME_EXACT.to_string(),
+            network: "solana-devnet".to_string(),
+            max_amount_required: "1000".to_string(),
+            resource: String::new(),
+            description: String::new(),
+            mime_type: "application/json".to_string(),
+            pay_to: "payee".to_string(),
+            max_timeout_seconds: 60,
+            asset: "mint".to_string(),
+            extra: None,
+        };
+        let payment = PaymentPayload {
+            x402_version: X402_VERSION,
+            scheme: SCHEME_EXACT.to_string(),
+            network: "solana-devnet".to_string(),
+            payload: serde_json::json!({ "transaction": "AQID" }),
+        };
+        let facilitator = MockFacilitator::new();
+
+        assert!(facilitator.verify(&payment, &requirements).await.is_ok());
+        assert!(facilitator.settle(&payment, &requirements).await.is_ok());
+        assert_eq!(
+            facilitator.settle(&payment, &requirements).await.unwrap_err(),
+            FacilitatorError::SettlementFailed("transaction already settled".to_string())
+        );
+
+        let failing = MockFacilitator::failing(FacilitatorError::Timeout);
+        assert_eq!(failing.verify(&payment, &requirements).await.unwrap_err(), FacilitatorError::Timeout);
+    }
+}
@@ -0,0 +1,136 @@
+pub mod http;
+pub mod mock;
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+
+use crate::x402::types::{PaymentPayload, PaymentRequirements, X402_VERSION};
+
+pub use http::HttpFacilitator;
+pub use mock::MockFacilitator;
+
+/// Facilitator 调用失败的原因
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FacilitatorError {
+    /// 支付签名无效（facilitator 的 `invalidReason`）
+    #[error("Invalid payment: {0}")]
+    InvalidPayment(String),
+
+    /// 链上结算失败（facilitator 的 `errorReason`）
+    #[error("Settlement failed: {0}")]
+    SettlementFailed(String),
+
+    /// 重试后仍超时
+    #[error("Facilitator timed out")]
+    Timeout,
+
+    /// 连接失败或 5xx，重试后仍不可用
+    #[error("Facilitator unavailable: {0}")]
+    Unavailable(String),
+
+    /// 请求被拒绝（4xx）或响应无法解析
+    #[error("Unexpected facilitator response: {0}")]
+    BadResponse(String),
+}
+
+impl FacilitatorError {
+    /// 是否是付款方的问题（应返回 402 让客户端重新支付）
+    pub fn is_payment_error(&self) -> bool {
+        matches!(self, FacilitatorError::InvalidPayment(_) | FacilitatorError::SettlementFailed(_))
+    }
+}
+
+/// `/verify` 和 `/settle` 的请求体
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FacilitatorRequest<'a> {
+    pub x402_version: u32,
+    pub payment_payload: &'a PaymentPayload,
+    pub payment_requirements: &'a PaymentRequirements,
+}
+
+impl<'a> FacilitatorRequest<'a> {
+    pub fn new(payment: &'a PaymentPayload, requirements: &'a PaymentRequirements) -> Self {
+        Self {
+            x402_version: X402_VERSION,
+            payment_payload: payment,
+            payment_requirements: requirements,
+        }
+    }
+}
+
+/// `/verify` 响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyResponse {
+    pub is_valid: bool,
+    #[serde(default)]
+    pub invalid_reason: Option<String>,
+    #[serde(default)]
+    pub payer: Option<String>,
+}
+
+/// `/settle` 响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettleResponse {
+    pub success: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_reason: Option<String>,
+    /// 结算交易签名/摘要
+    #[serde(default)]
+    pub transaction: String,
+    pub network: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payer: Option<String>,
+}
+
+impl SettleResponse {
+    /// 编码为 `X-PAYMENT-RESPONSE` 头（base64 编码的 JSON）
+    pub fn to_header(&self) -> String {
+        STANDARD.encode(serde_json::to_vec(self).unwrap_or_default())
+    }
+}
+
+/// x402 facilitator：代为验证支付签名并上链结算
+///
+/// 返回 `Ok` 时支付一定有效/已结算，facilitator 报告的失败原因映射为 [`FacilitatorError`]。
+#[async_trait]
+pub trait Facilitator: Send + Sync {
+    /// 验证支付签名（不上链）
+    async fn verify(
+        &self,
+        payment: &PaymentPayload,
+        requirements: &PaymentRequirements,
+    ) -> Result<VerifyResponse, FacilitatorError>;
+
+    /// 提交支付并等待结算
+    async fn settle(
+        &self,
+        payment: &PaymentPayload,
+        requirements: &PaymentRequirements,
+    ) -> Result<SettleResponse, FacilitatorError>;
+}
+
+/// 把 `/verify` 响应中的失败转换为错误
+pub(crate) fn check_verify(response: VerifyResponse) -> Result<VerifyResponse, FacilitatorError> {
+    if response.is_valid {
+        Ok(response)
+    } else {
+        Err(FacilitatorError::InvalidPayment(
+            response.invalid_reason.unwrap_or_else(|| "unknown".to_string()),
+        ))
+    }
+}
+
+/// 把 `/settle` 响应中的失败转换为错误
+pub(crate) fn check_settle(response: SettleResponse) -> Result<SettleResponse, FacilitatorError> {
+    if response.success {
+        Ok(response)
+    } else {
+        Err(FacilitatorError::SettlementFailed(
+            response.error_reason.unwrap_or_else(|| "unknown".to_string()),
+        ))
+    }
+}
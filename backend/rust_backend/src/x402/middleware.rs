@@ -0,0 +1,222 @@
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::StatusCode,
+    middleware::Next,
+    web, Error, HttpMessage, HttpResponse, ResponseError,
+};
+use sea_orm::DatabaseConnection;
+
+use crate::auth::policy::find_policy;
+use crate::models::{ApiResponse, X402Config};
+use crate::money::Money;
+use crate::x402::facilitator::{Facilitator, FacilitatorError};
+use crate::x402::types::{
+    PaymentPayload, PaymentRequiredResponse, PaymentRequirements, VerifiedPayment, PAYMENT_HEADER,
+    SCHEME_EXACT, X402_VERSION,
+};
+
+/// 支付错误
+#[derive(Debug, thiserror::Error)]
+pub enum PaymentError {
+    /// 未支付或支付无效 (402)，响应体为 x402 支付要求
+    #[error("{reason}")]
+    Required {
+        reason: String,
+        requirements: Box<PaymentRequirements>,
+    },
+
+    /// facilitator 超时 (504) 或不可用 (502)
+    #[error("{0}")]
+    Facilitator(FacilitatorError),
+
+    /// 购买的资源不存在 (404)
+    #[error("{0}")]
+    NotFound(String),
+
+    /// 不允许购买，如购买自己的简历 (400)
+    #[error("{0}")]
+    Rejected(String),
+
+    /// 已有结算成功、正在写入的相同购买 (409)
+    #[error("{0}")]
+    Conflict(String),
+
+    /// 支付配置或定价失败 (500)
+    #[error("{0}")]
+    Internal(String),
+
+    /// 写入结算意向之后的错误（状态码同 `error`），支付可能已经扣款，
+    /// 响应带上 [`SettlementRecorded`] 扩展，幂等中间件据此保存响应而不是释放幂等键
+    #[error("{error}")]
+    Settlement {
+        settlement_id: i64,
+        error: Box<PaymentError>,
+    },
+}
+
+/// 响应扩展：本次请求已写入结算意向 ID
+#[derive(Debug, Clone, Copy)]
+pub struct SettlementRecorded(pub i64);
+
+impl PaymentError {
+    /// facilitator 错误：付款方的问题返回 402，其余按网关错误处理
+    pub fn from_facilitator(error: FacilitatorError, requirements: &PaymentRequirements) -> Self {
+        if error.is_payment_error() {
+            PaymentError::Required {
+                reason: error.to_string(),
+                requirements: Box::new(requirements.clone()),
+            }
+        } else {
+            PaymentError::Facilitator(error)
+        }
+    }
+
+    /// 标记错误发生在写入结算意向之后
+    pub fn after_settlement(self, settlement_id: i64) -> Self {
+        PaymentError::Settlement { settlement_id, error: Box::new(self) }
+    }
+}
+
+impl ResponseError for PaymentError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            PaymentError::Required { .. } => StatusCode::PAYMENT_REQUIRED,
+            PaymentError::Facilitator(FacilitatorError::Timeout) => StatusCode::GATEWAY_TIMEOUT,
+            PaymentError::Facilitator(_) => StatusCode::BAD_GATEWAY,
+            PaymentError::NotFound(_) => StatusCode::NOT_FOUND,
+            PaymentError::Rejected(_) => StatusCode::BAD_REQUEST,
+            PaymentError::Conflict(_) => StatusCode::CONFLICT,
+            PaymentError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            PaymentError::Settlement { error, .. } => error.status_code(),
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        match self {
+            PaymentError::Required { reason, requirements } => {
+                HttpResponse::build(self.status_code()).json(PaymentRequiredResponse {
+                    x402_version: X402_VERSION,
+                    accepts: vec![requirements.as_ref().clone()],
+                    error: reason.clone(),
+                })
+            }
+            PaymentError::Settlement { settlement_id, error } => {
+                let mut response = error.error_response();
+                response.extensions_mut().insert(SettlementRecorded(*settlement_id));
+                response
+            }
+            _ => {
+                HttpResponse::build(self.status_code()).json(ApiResponse::<()>::error(self.to_string()))
+            }
+        }
+    }
+}
+
+/// 按路由策略表中的定价收费
+///
+/// 没有 `X-PAYMENT` 头、支付签名与支付要求不符或 facilitator 验证失败时返回 402 和 x402 支付要求；
+/// 验证通过的支付以 [`VerifiedPayment`] 写入请求扩展，由 handler 负责结算。
+/// 价格为 0 或资源不存在时直接放行。
+pub async fn require_payment(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let Some(pricing) = req
+        .match_pattern()
+        .and_then(|pattern| find_policy(req.method(), &pattern))
+        .and_then(|p| p.price)
+    else {
+        return next.call(req).await;
+    };
+
+    let config = req
+        .app_data::<web::Data<X402Config>>()
+        .cloned()
+        .ok_or_else(|| PaymentError::Internal("Payments are not configured".to_string()))?;
+    let facilitator = req
+        .app_data::<web::Data<dyn Facilitator>>()
+        .cloned()
+        .ok_or_else(|| PaymentError::Internal("Payment facilitator is not configured".to_string()))?;
+    if config.pay_to.is_empty() {
+        return Err(PaymentError::Internal("Payment receiver address is not configured".to_string()).into());
+    }
+
+    let db = req.app_data::<web::Data<DatabaseConnection>>().cloned();
+    let price = pricing
+        .resolve(db.as_ref().map(|db| db.get_ref()), &req)
+        .await?;
+    let price = match price {
+        Some(price) if price.amount > 0 => price,
+        _ => return next.call(req).await,
+    };
+
+    let requirements = build_requirements(&config, &req, &price)?;
+
+    let header = req
+        .headers()
+        .get(PAYMENT_HEADER)
+        .and_then(|h| h.to_str().ok())
+        .map(|s| s.to_string());
+    let Some(header) = header else {
+        return Err(PaymentError::Required {
+            reason: "X-PAYMENT header is required".to_string(),
+            requirements: Box::new(requirements),
+        }
+        .into());
+    };
+
+    let payment = match PaymentPayload::from_header(&header).and_then(|p| p.check_against(&requirements).map(|_| p)) {
+        Ok(payment) => payment,
+        Err(reason) => {
+            log::warn!("Rejected payment for {} {}: {}", req.method(), req.path(), reason);
+            return Err(PaymentError::Required {
+                reason,
+                requirements: Box::new(requirements),
+            }
+            .into());
+        }
+    };
+
+    let verified = match facilitator.verify(&payment, &requirements).await {
+        Ok(verified) => verified,
+        Err(e) => {
+            log::warn!("Facilitator rejected payment for {} {}: {}", req.method(), req.path(), e);
+            return Err(PaymentError::from_facilitator(e, &requirements).into());
+        }
+    };
+
+    req.extensions_mut().insert(VerifiedPayment {
+        payment,
+        requirements,
+        payer: verified.payer,
+    });
+    next.call(req).await
+}
+
+/// 根据配置和价格生成本次请求的支付要求；价格资产不是 x402 收款资产时无法收款
+fn build_requirements(config: &X402Config, req: &ServiceRequest, price: &Money) -> Result<PaymentRequirements, PaymentError> {
+    if !price.asset.eq_ignore_ascii_case(&config.asset_name) || price.decimals != config.asset_decimals {
+        return Err(PaymentError::Rejected(format!(
+            "Priced in {}, but only {} can be paid on {}",
+            price.asset, config.asset_name, config.network
+        )));
+    }
+
+    let connection = req.connection_info();
+    Ok(PaymentRequirements {
+        scheme: SCHEME_EXACT.to_string(),
+        network: config.network.clone(),
+        max_amount_required: price.amount.to_string(),
+        resource: format!("{}://{}{}", connection.scheme(), connection.host(), req.uri()),
+        description: format!("Access to {}", req.path()),
+        mime_type: "application/json".to_string(),
+        pay_to: config.pay_to.clone(),
+        max_timeout_seconds: config.max_timeout_secs,
+        asset: config.asset.clone(),
+        extra: Some(serde_json::json!({
+            "name": config.asset_name,
+            "decimals": config.asset_decimals,
+        })),
+    })
+}
@@ -0,0 +1,8 @@
+pub mod facilitator;
+pub mod middleware;
+pub mod pricing;
+pub mod types;
+
+pub use facilitator::{Facilitator, HttpFacilitator, MockFacilitator};
+pub use pricing::Pricing;
+pub use types::VerifiedPayment;
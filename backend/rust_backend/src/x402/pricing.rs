@@ -0,0 +1,93 @@
+use actix_web::{dev::ServiceRequest, web, HttpMessage};
+use sea_orm::DatabaseConnection;
+
+use crate::auth::AuthenticatedWallet;
+use crate::dao::ResumeDao;
+use crate::models::UnlockResumeQuery;
+use crate::money::{self, Money};
+use crate::services::resume_tier_service::FULL_TIER;
+use crate::services::{CouponService, CreditService, ResumeTierService, UnlockRecordService};
+use crate::x402::middleware::PaymentError;
+
+/// 付费路由的定价方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pricing {
+    /// 固定价格（x402 收款资产的最小单位）
+    Fixed(u64),
+    /// 路径参数 `param` 指定的简历中 `?tier=` 档位（默认 `full`）的价格，
+    /// 已登录的买家已拥有低档位时只收差价，`?coupon=` 优惠码再从中减免
+    Resume { param: &'static str },
+    /// 路径参数 `param` 指定的额度套餐的售价
+    CreditBundle { param: &'static str },
+}
+
+impl Pricing {
+    /// 解析本次请求的价格；返回 None 表示资源不存在（或已下架），由 handler 自行处理。
+    /// 优惠码不可用时返回 [`PaymentError::Rejected`]，买家已有结算成功、正在写入的相同购买时返回
+    /// [`PaymentError::Conflict`]
+    pub async fn resolve(
+        &self,
+        db: Option<&DatabaseConnection>,
+        req: &ServiceRequest,
+    ) -> Result<Option<Money>, PaymentError> {
+        let path_param = |param: &str| {
+            req.match_info()
+                .get(param)
+                .map(str::to_string)
+                .ok_or_else(|| PaymentError::Internal(format!("Missing path parameter '{}'", param)))
+        };
+        let db = || db.ok_or_else(|| PaymentError::Internal("Database not configured".to_string()));
+
+        match *self {
+            Pricing::Fixed(amount) => {
+                let amount = i64::try_from(amount)
+                    .map_err(|_| PaymentError::Internal(format!("Price {} is too large", amount)))?;
+                Ok(Some(money::registry().payment_asset().money(amount)))
+            }
+            Pricing::Resume { param } => {
+                let resume_id = path_param(param)?;
+                let db = db()?;
+
+                let Some(resume) = ResumeDao::find_by_resume_id(db, &resume_id)
+                    .await
+                    .map_err(|e| PaymentError::Internal(format!("Failed to fetch resume: {}", e)))?
+                    .filter(|r| r.status == "active")
+                else {
+                    return Ok(None);
+                };
+
+                let query = web::Query::<UnlockResumeQuery>::from_query(req.query_string())
+                    .map(web::Query::into_inner)
+                    .unwrap_or(UnlockResumeQuery { tier: None, coupon: None });
+                let tier = query.tier.unwrap_or_else(|| FULL_TIER.to_string());
+                // 认证中间件先于支付中间件执行，已登录时会话已在请求扩展中
+                let buyer_id = req.extensions().get::<AuthenticatedWallet>().map(|w| w.user_id());
+
+                if let Some(buyer_id) = buyer_id {
+                    UnlockRecordService::ensure_no_settled_purchase(db, buyer_id, resume.id, &tier).await?;
+                }
+
+                let Some(due) = ResumeTierService::quote(db, &resume, &tier, buyer_id)
+                    .await
+                    .map_err(PaymentError::Internal)?
+                else {
+                    return Ok(None);
+                };
+                match query.coupon {
+                    Some(code) => CouponService::quote(db, &code, buyer_id, due).await.map(Some),
+                    None => Ok(Some(due)),
+                }
+            }
+            Pricing::CreditBundle { param } => {
+                let Ok(bundle_id) = path_param(param)?.parse::<i64>() else {
+                    return Ok(None);
+                };
+
+                let bundle = CreditService::find_active_bundle(db()?, bundle_id)
+                    .await
+                    .map_err(PaymentError::Internal)?;
+                Ok(bundle.map(|b| money::registry().stored(b.price, &b.asset)))
+            }
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+
+/// 支持的 x402 协议版本
+pub const X402_VERSION: u32 = 1;
+
+/// 唯一支持的支付方案：按精确金额转账
+pub const SCHEME_EXACT: &str = "exact";
+
+/// 携带支付签名的请求头
+pub const PAYMENT_HEADER: &str = "x-payment";
+
+/// 返回结算结果的响应头
+pub const PAYMENT_RESPONSE_HEADER: &str = "X-PAYMENT-RESPONSE";
+
+/// 某个资源的支付要求（x402 `PaymentRequirements`）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentRequirements {
+    pub scheme: String,
+    pub network: String,
+    /// 代币最小单位的金额（十进制字符串）
+    pub max_amount_required: String,
+    /// 被购买资源的完整 URL
+    pub resource: String,
+    pub description: String,
+    pub mime_type: String,
+    pub pay_to: String,
+    pub max_timeout_seconds: u64,
+    /// 代币 mint 地址
+    pub asset: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extra: Option<serde_json::Value>,
+}
+
+/// 402 响应体，x402 客户端直接解析，不包在 `ApiResponse` 中
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentRequiredResponse {
+    pub x402_version: u32,
+    pub accepts: Vec<PaymentRequirements>,
+    pub error: String,
+}
+
+/// `X-PAYMENT` 头中的支付签名（base64 编码的 JSON）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentPayload {
+    pub x402_version: u32,
+    pub scheme: String,
+    pub network: String,
+    /// 方案相关的内容，如 Solana 的已签名交易
+    pub payload: serde_json::Value,
+}
+
+impl PaymentPayload {
+    /// 解码 `X-PAYMENT` 头
+    pub fn from_header(value: &str) -> Result<Self, String> {
+        let bytes = STANDARD
+            .decode(value.trim())
+            .map_err(|_| "X-PAYMENT header is not valid base64".to_string())?;
+        serde_json::from_slice(&bytes).map_err(|e| format!("X-PAYMENT header is not a valid payment payload: {}", e))
+    }
+
+    /// 编码为 `X-PAYMENT` 头
+    #[allow(dead_code)]
+    pub fn to_header(&self) -> String {
+        STANDARD.encode(serde_json::to_vec(self).unwrap_or_default())
+    }
+
+    /// 检查支付签名是否针对这份支付要求
+    pub fn check_against(&self, requirements: &PaymentRequirements) -> Result<(), String> {
+        if self.x402_version != X402_VERSION {
+            return Err(format!("Unsupported x402 version {}", self.x402_version));
+        }
+        if self.scheme != requirements.scheme {
+            return Err(format!("Unsupported payment scheme '{}'", self.scheme));
+        }
+        if self.network != requirements.network {
+            return Err(format!(
+                "Payment network '{}' does not match required '{}'",
+                self.network, requirements.network
+            ));
+        }
+        let empty = match &self.payload {
+            serde_json::Value::Object(map) => map.is_empty(),
+            serde_json::Value::Null => true,
+            _ => false,
+        };
+        if empty {
+            return Err("Payment payload is empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// 已通过校验的支付，由支付中间件写入请求扩展供 handler 使用
+#[derive(Debug, Clone)]
+pub struct VerifiedPayment {
+    pub payment: PaymentPayload,
+    pub requirements: PaymentRequirements,
+    /// facilitator 识别出的付款地址
+    pub payer: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn requirements() -> PaymentRequirements {
+        PaymentRequirements {
+            scheme: SCHEME_EXACT.to_string(),
+            network: "solana-devnet".to_string(),
+            max_amount_required: "1000000".to_string(),
+            resource: "http://localhost/api/premium/content".to_string(),
+            description: String::new(),
+            mime_type: "application/json".to_string(),
+            pay_to: "payee".to_string(),
+            max_timeout_seconds: 60,
+            asset: "mint".to_string(),
+            extra: None,
+        }
+    }
+
+    #[test]
+    fn test_payment_header_roundtrip() {
+        let payment = PaymentPayload {
+            x402_version: X402_VERSION,
+            scheme: SCHEME_EXACT.to_string(),
+            network: "solana-devnet".to_string(),
+            payload: serde_json::json!({ "transaction": "AQID" }),
+        };
+
+        let decoded = PaymentPayload::from_header(&payment.to_header()).unwrap();
+        assert!(decoded.check_against(&requirements()).is_ok());
+
+        let wrong_network = PaymentPayload { network: "solana".to_string(), ..decoded };
+        assert!(wrong_network.check_against(&requirements()).is_err());
+        assert!(PaymentPayload::from_header("not base64!").is_err());
+    }
+}
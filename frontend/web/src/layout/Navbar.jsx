@@ -1,12 +1,22 @@
-import React, { useState } from 'react';
+import React, { useEffect, useState } from 'react';
 import { useNavigate, useLocation } from 'react-router-dom';
-import { useCurrentAccount, ConnectButton } from '@mysten/dapp-kit';
+import { useCurrentAccount, useSignPersonalMessage, ConnectButton } from '@mysten/dapp-kit';
+import { authService } from '../services';
 
 const Navbar = () => {
   const [isMenuOpen, setIsMenuOpen] = useState(false);
   const navigate = useNavigate();
   const location = useLocation();
   const currentAccount = useCurrentAccount();
+  const { mutateAsync: signPersonalMessage } = useSignPersonalMessage();
+
+  // 连接或切换钱包后签名登录，之后的请求带上会话 token
+  useEffect(() => {
+    if (!currentAccount?.address) return;
+    authService.ensureLogin(currentAccount.address, signPersonalMessage).catch((err) => {
+      console.error('Wallet sign-in failed:', err);
+    });
+  }, [currentAccount?.address]);
 
   const formatAddress = (address) => {
     if (!address) return '';
@@ -716,7 +716,7 @@ export default function ResumeBrowse() {
           <DialogTitle id="purchase-dialog-title">Confirm Purchase</DialogTitle>
           <DialogContent>
             <Typography>
-              Pay {resumeToPurchase ? (parseInt(resumeToPurchase.priceRaw || 0) / 1000000000).toFixed(9) : '0'} SUI to access this resume.
+              Pay {resumeToPurchase ? resumeToPurchase.price : '-'} to access this resume.
             </Typography>
             <Typography sx={{ mt: 2, color: 'text.secondary', fontSize: '0.875rem' }}>
               You will get permanent access after payment.
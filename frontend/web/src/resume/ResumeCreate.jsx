@@ -1,6 +1,6 @@
 import React, { useState } from 'react';
 import { useNavigate } from 'react-router-dom';
-import { useCurrentAccount, useSignAndExecuteTransaction, useSuiClient } from '@mysten/dapp-kit';
+import { useCurrentAccount, useSignAndExecuteTransaction, useSignPersonalMessage, useSuiClient } from '@mysten/dapp-kit';
 import PageLayout from '../layout/PageLayout';
 import PersonalInfo from './sections/PersonalInfo';
 import Skills from './sections/Skills';
@@ -11,7 +11,7 @@ import Education from './sections/Education';
 import Certificates from './sections/Certificates';
 import ResumePreview from './ResumePreview';
 import EncryptionModeSelector from '../components/EncryptionModeSelector';
-import { resumeService, userService } from '../services';
+import { authService, resumeService, userService } from '../services';
 import { transformResumeData, validateResumeData } from '../services/resume.transform';
 import { createSubscriptionServiceTx } from '../utils/subscription';
 import { usdcToMicroUnits } from '../config/subscription.config';
@@ -21,6 +21,7 @@ export default function ResumeCreate() {
   const currentAccount = useCurrentAccount();
   const suiClient = useSuiClient();
   const { mutate: signAndExecute } = useSignAndExecuteTransaction();
+  const { mutateAsync: signPersonalMessage } = useSignPersonalMessage();
   const connected = !!currentAccount;
   const publicKey = currentAccount?.address;
   const [activeSection, setActiveSection] = useState('personal');
@@ -124,9 +125,10 @@ export default function ResumeCreate() {
     try {
       const walletAddress = publicKey;
       
-      // 1. Ensure user is registered
+      // 1. Sign in with the wallet and ensure user is registered
       console.log('Registering/getting user info...');
-      await userService.registerOrGetUser(walletAddress);
+      await authService.ensureLogin(walletAddress, signPersonalMessage);
+      await userService.registerOrGetUser();
       
       // 2. Transform form data to API format
       const apiData = transformResumeData(formData, walletAddress);
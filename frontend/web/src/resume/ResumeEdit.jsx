@@ -10,7 +10,7 @@ import ProjectExperience from './sections/ProjectExperience';
 import Education from './sections/Education';
 import Certificates from './sections/Certificates';
 import ResumePreview from './ResumePreview';
-import { resumeService } from '../services';
+import { authService, resumeService } from '../services';
 import { transformResumeData, validateResumeData } from '../services/resume.transform';
 import { encryptWithSeal, decryptWithSeal } from '../utils/seal';
 import { uploadToWalrus, downloadFromWalrus } from '../utils/walrus';
@@ -80,8 +80,8 @@ export default function ResumeEdit() {
     setLoadError(null);
 
     try {
-      const owner = publicKey;
-      const resume = await resumeService.getResumeDetail(id, owner);
+      await authService.ensureLogin(publicKey, signPersonalMessage);
+      const resume = await resumeService.getResumeDetail(id);
       
       console.log('加载的简历数据:', resume);
       
@@ -268,8 +268,8 @@ export default function ResumeEdit() {
     }
     
     try {
-      const owner = publicKey;
-      const resume = await resumeService.getResumeDetail(id, owner);
+      await authService.ensureLogin(publicKey, signPersonalMessage);
+      const resume = await resumeService.getResumeDetail(id);
       const ipfsCid = resume.ipfs_cid || resume.cid;
       
       await decryptAndLoadResume(ipfsCid, encryptionKey.trim());
@@ -415,6 +415,7 @@ export default function ResumeEdit() {
       console.log('更新简历数据:', updateData);
       
       // 调用更新简历 API
+      await authService.ensureLogin(walletAddress, signPersonalMessage);
       await resumeService.updateResume(id, updateData);
       
       console.log('简历更新成功');
@@ -1,6 +1,6 @@
 import React, { useState, useEffect } from 'react';
 import { Link } from 'react-router-dom';
-import { useCurrentAccount } from '@mysten/dapp-kit';
+import { useCurrentAccount, useSignPersonalMessage } from '@mysten/dapp-kit';
 import { 
   Dialog, 
   DialogTitle, 
@@ -10,10 +10,12 @@ import {
   TextField 
 } from '@mui/material';
 import PageLayout from '../layout/PageLayout';
-import { resumeService } from '../services';
+import { formatMoney, multiplyMoney, sumMoney, isValidAmount } from '../utils/money';
+import { authService, resumeService } from '../services';
 
 export default function ResumeList() {
   const currentAccount = useCurrentAccount();
+  const { mutateAsync: signPersonalMessage } = useSignPersonalMessage();
   const connected = !!currentAccount;
   const publicKey = currentAccount?.address;
   const [resumes, setResumes] = useState([]);
@@ -61,9 +63,9 @@ export default function ResumeList() {
           views: resume.view_count || 0,
           unlocks: resume.unlock_count || 0,
           encryptionMode, // Encryption mode
-          price: resume.price || 0, // Price (SUI MIST, 9 decimals)
-          priceSUI: isSubscription ? ((resume.price || 0) / 1_000_000_000).toFixed(9) + ' SUI' : null, // Show price only for subscription mode
-          earnings: isSubscription ? (((resume.price || 0) * (resume.unlock_count || 0)) / 1_000_000_000).toFixed(9) + ' SUI' : null, // Show earnings only for subscription mode
+          price: resume.price, // Money: { amount, asset, decimals, display }
+          priceDisplay: isSubscription ? formatMoney(resume.price) : null, // Show price only for subscription mode
+          earnings: isSubscription ? multiplyMoney(resume.price, resume.unlock_count || 0) : null, // Show earnings only for subscription mode
           status: resume.status || 'active',
           rawData: resume, // Save raw data
         };
@@ -84,8 +86,8 @@ export default function ResumeList() {
     }
 
     try {
-      const walletAddress = publicKey;
-      await resumeService.deleteResume(id, walletAddress);
+      await authService.ensureLogin(publicKey, signPersonalMessage);
+      await resumeService.deleteResume(id);
       
       // Remove from list
       setResumes(resumes.filter(r => r.id !== id));
@@ -99,7 +101,7 @@ export default function ResumeList() {
   const handleSetPrice = (id) => {
     // Find current resume and display its current price
     const resume = resumes.find(r => r.id === id);
-    const currentPrice = resume ? ((resume.price || 0) / 1_000_000_000).toFixed(9) : '0.000000000';
+    const currentPrice = resume?.price ? resume.price.display : '0';
     
     setPriceResumeId(id);
     setPriceValue(currentPrice);
@@ -107,16 +109,18 @@ export default function ResumeList() {
   };
 
   const handlePriceSubmit = async () => {
-    const priceFloat = parseFloat(priceValue);
-    if (isNaN(priceFloat) || priceFloat < 0) {
-      alert('Please enter a valid price');
+    const resume = resumes.find(r => r.id === priceResumeId);
+    const asset = resume?.price?.asset || 'USDC';
+    const decimals = resume?.price?.decimals ?? 6;
+    if (!isValidAmount(priceValue, decimals)) {
+      alert(`Please enter a valid price (up to ${decimals} decimals)`);
       return;
     }
 
     setSettingPrice(true);
     try {
-      const walletAddress = publicKey;
-      await resumeService.setResumePrice(priceResumeId, walletAddress, priceFloat);
+      await authService.ensureLogin(publicKey, signPersonalMessage);
+      await resumeService.setResumePrice(priceResumeId, priceValue.trim(), asset);
       
       setOpenPriceDialog(false);
       loadMyResumes();
@@ -143,8 +147,8 @@ export default function ResumeList() {
 
     setRenaming(true);
     try {
-      const walletAddress = publicKey;
-      await resumeService.updateResumeName(renameResumeId, walletAddress, renameValue);
+      await authService.ensureLogin(publicKey, signPersonalMessage);
+      await resumeService.updateResumeName(renameResumeId, renameValue);
       
       setOpenRenameDialog(false);
       loadMyResumes();
@@ -266,10 +270,9 @@ export default function ResumeList() {
               <div>
                 <p className="text-sm text-yellow-600 font-medium">Total Earnings</p>
                 <p className="text-2xl font-bold text-yellow-900 mt-1">
-                  {resumes
-                    .filter(r => r.encryptionMode === 'subscription')
-                    .reduce((acc, r) => acc + parseFloat(r.earnings || 0), 0)
-                    .toFixed(9)} SUI
+                  {sumMoney(resumes.filter(r => r.earnings).map(r => r.earnings))
+                    .map(formatMoney)
+                    .join(' + ') || '0'}
                 </p>
               </div>
               <div className="text-4xl">💰</div>
@@ -339,10 +342,10 @@ export default function ResumeList() {
                       {resume.encryptionMode === 'subscription' && (
                         <>
                           <span className="flex items-center gap-1 font-semibold text-purple-600">
-                            💎 Price: {resume.priceSUI}
+                            💎 Price: {resume.priceDisplay}
                           </span>
                           <span className="flex items-center gap-1 font-semibold text-green-600">
-                            💰 Earnings: {resume.earnings}
+                            💰 Earnings: {formatMoney(resume.earnings)}
                           </span>
                         </>
                       )}
@@ -404,21 +407,18 @@ export default function ResumeList() {
           <TextField
             autoFocus
             margin="dense"
-            label="Price (SUI)"
-            type="number"
+            label={`Price (${resumes.find(r => r.id === priceResumeId)?.price?.asset || 'USDC'})`}
+            type="text"
             fullWidth
             variant="outlined"
             value={priceValue}
             onChange={(e) => setPriceValue(e.target.value)}
-            inputProps={{ 
-              step: "0.000000001",
-              min: "0"
-            }}
+            inputProps={{ inputMode: 'decimal' }}
             sx={{ mt: 1 }}
           />
           <p className="text-sm text-gray-500 mt-2">
             Set the price for others to unlock your resume. 
-            (Precision: 9 decimals)
+            (Precision: {resumes.find(r => r.id === priceResumeId)?.price?.decimals ?? 6} decimals)
           </p>
         </DialogContent>
         <DialogActions>
@@ -3,7 +3,7 @@ import { useNavigate, useParams } from 'react-router-dom';
 import { useCurrentAccount, useSignAndExecuteTransaction, useSignPersonalMessage } from '@mysten/dapp-kit';
 import PageLayout from '../layout/PageLayout';
 import ResumePreview from './ResumePreview';
-import { resumeService } from '../services';
+import { authService, resumeService } from '../services';
 import { getSealClient, downloadAndDecryptResume } from '../utils/sealClient';
 import { decryptWithSeal } from '../utils/seal';
 import { downloadFromWalrus } from '../utils/walrus';
@@ -41,8 +41,8 @@ export default function ResumePreviewPage() {
     setError(null);
 
     try {
-      const owner = publicKey;
-      const resume = await resumeService.getResumeDetail(id, owner);
+      await authService.ensureLogin(publicKey, signPersonalMessage);
+      const resume = await resumeService.getResumeDetail(id);
       
       console.log('加载的简历数据:', resume);
       
@@ -226,8 +226,8 @@ export default function ResumePreviewPage() {
     }
     
     try {
-      const owner = publicKey;
-      const resume = await resumeService.getResumeDetail(id, owner);
+      await authService.ensureLogin(publicKey, signPersonalMessage);
+      const resume = await resumeService.getResumeDetail(id);
       const storageId = resume.blob_id || resume.ipfs_cid || resume.cid;
       
       await decryptAndLoadResume(storageId, encryptionKey.trim());
@@ -4,6 +4,7 @@ import accessLogService from '../services/accessLog.service';
 import { downloadAndDecryptResume } from '../utils/sealClient';
 import { decryptWithSeal } from '../utils/seal';
 import { downloadFromWalrus } from '../utils/walrus';
+import { formatMoney } from '../utils/money';
 import { 
   purchaseSubscriptionTx, 
   getUserSubscriptions, 
@@ -81,8 +82,8 @@ export const loadResumeSummaries = async () => {
         salary: isSealed ? '-' : '-',
         skills: isSealed ? ['-'] : [],
         highlights: highlightsText,
-        price: ((resume.price || 0) / 1_000_000_000).toFixed(2) + ' SUI',
-        priceRaw: resume.price || 0,
+        price: formatMoney(resume.price),
+        priceRaw: resume.price?.amount || '0',
         isLocked: true,
         avatar: '/default-avatar.png',
         viewCount: 0,
@@ -222,6 +223,7 @@ export const handleUnlock = async ({
                   buyer_wallet: publicKey,
                   seller_wallet: resume.owner,
                   amount: parseInt(serviceFee),
+                  asset: 'SUI',
                   transaction_signature: transactionDigest,
                   block_time: result.timestamp ? parseInt(result.timestamp) : null,
                 };
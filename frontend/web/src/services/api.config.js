@@ -5,8 +5,23 @@
 // 从环境变量或配置文件读取 API 基础 URL
 export const API_BASE_URL = import.meta.env.VITE_API_BASE_URL || 'http://127.0.0.1:4021';
 
+// 拼接查询参数，忽略空值
+const withQuery = (path, params = {}) => {
+  const query = new URLSearchParams(
+    Object.entries(params).filter(([, value]) => value !== undefined && value !== null && value !== '')
+  ).toString();
+  return query ? `${path}?${query}` : path;
+};
+
 // API 端点
 export const API_ENDPOINTS = {
+  // 登录认证相关
+  auth: {
+    challenge: '/api/auth/challenge',
+    login: '/api/auth/login',
+    logout: '/api/auth/logout',
+  },
+
   // 用户相关
   users: {
     register: '/api/users/register',
@@ -20,9 +35,26 @@ export const API_ENDPOINTS = {
     create: '/api/resumes',
     getSummaries: '/api/resumes/summaries',
     getMyResumes: (owner) => `/api/resumes/my/${owner}`,
+    detail: (resumeId) => `/api/resumes/detail/${resumeId}`,
     update: (resumeId) => `/api/resumes/${resumeId}`,
-    delete: (resumeId, owner) => `/api/resumes/${resumeId}/${owner}`,
-    unlock: '/api/resumes/unlock',
+    delete: (resumeId) => `/api/resumes/${resumeId}`,
+    setPrice: '/api/resumes/price',
+    updateName: '/api/resumes/name',
+    tiers: (resumeId) => `/api/resumes/${resumeId}/tiers`,
+    allowlistMembers: (resumeId) => `/api/resumes/${resumeId}/allowlist-members`,
+    allowlistMember: (resumeId, memberAddress, txDigest) =>
+      withQuery(`/api/resumes/${resumeId}/allowlist-members/${memberAddress}`, { tx_digest: txDigest }),
+    unlock: (resumeId, params) => withQuery(`/api/resumes/${resumeId}/unlock`, params),
+    unlockWithCredits: (resumeId, params) => withQuery(`/api/resumes/${resumeId}/unlock/credits`, params),
+  },
+
+  // 预付额度相关
+  credits: {
+    balance: (organizationId) => withQuery('/api/credits/balance', { organization_id: organizationId }),
+    ledger: (organizationId) => withQuery('/api/credits/ledger', { organization_id: organizationId }),
+    bundles: '/api/credits/bundles',
+    purchaseBundle: (bundleId, organizationId) =>
+      withQuery(`/api/credits/bundles/${bundleId}/purchase`, { organization_id: organizationId }),
   },
 
   // 解锁记录相关
@@ -31,6 +63,8 @@ export const API_ENDPOINTS = {
     checkUnlock: (resumeId, buyerId) => `/api/unlock-records/check/${resumeId}/${buyerId}`,
     getUnlockedByBuyer: (buyerWallet) => `/api/unlock-records/buyer/${buyerWallet}`,
     getByResume: (resumeId) => `/api/unlock-records/resume/${resumeId}`,
+    dispute: (recordId) => `/api/unlock-records/${recordId}/dispute`,
+    verify: (recordId) => `/api/unlock-records/${recordId}/verify`,
   },
 
   // 访问记录相关
@@ -47,5 +81,8 @@ export const DEFAULT_HEADERS = {
   'Content-Type': 'application/json',
 };
 
+// 登录会话在 localStorage 中的键
+export const AUTH_SESSION_STORAGE_KEY = 'authSession';
+
 // 请求超时时间 (毫秒)
 export const REQUEST_TIMEOUT = 30000;
@@ -0,0 +1,108 @@
+/**
+ * 登录认证相关 API 服务
+ */
+import { httpClient } from './http.client';
+import { API_ENDPOINTS } from './api.config';
+
+/**
+ * 登录认证服务类
+ *
+ * 登录流程：申请挑战 → 钱包签名挑战消息 → 提交签名换取会话 token。
+ * token 由 httpClient 保存，之后的请求自动带上 `Authorization: Bearer <token>`。
+ */
+class AuthService {
+  constructor() {
+    // 进行中的登录，避免同一钱包重复弹出签名请求
+    this.pendingLogin = null;
+  }
+
+  /**
+   * 当前钱包是否已登录
+   * @param {string} walletAddress - 钱包地址
+   * @returns {boolean}
+   */
+  isLoggedIn(walletAddress) {
+    const session = httpClient.getSession();
+    return !!session && session.wallet_address === walletAddress;
+  }
+
+  /**
+   * 钱包签名登录
+   * @param {string} walletAddress - 钱包地址
+   * @param {Function} signPersonalMessage - dapp-kit useSignPersonalMessage 的 mutateAsync
+   * @returns {Promise<object>} 登录结果 { token, user_id, wallet_address, expires_at }
+   */
+  async login(walletAddress, signPersonalMessage) {
+    try {
+      // 1. 申请登录挑战
+      const challenge = await httpClient.post(API_ENDPOINTS.auth.challenge, {
+        wallet_address: walletAddress,
+      });
+      if (!challenge.success) {
+        throw new Error(challenge.error || '申请登录挑战失败');
+      }
+
+      // 2. 钱包签名挑战消息
+      const { signature } = await signPersonalMessage({
+        message: new TextEncoder().encode(challenge.data.message),
+      });
+
+      // 3. 提交签名换取会话 token
+      const response = await httpClient.post(API_ENDPOINTS.auth.login, {
+        nonce: challenge.data.nonce,
+        signature,
+        device_label: navigator.userAgent.slice(0, 100),
+      });
+      if (!response.success) {
+        throw new Error(response.error || '登录失败');
+      }
+
+      httpClient.setSession(response.data);
+      return response.data;
+    } catch (error) {
+      console.error('登录失败:', error);
+      throw error;
+    }
+  }
+
+  /**
+   * 确保当前钱包已登录，未登录或已切换钱包时发起签名登录
+   * @param {string} walletAddress - 钱包地址
+   * @param {Function} signPersonalMessage - dapp-kit useSignPersonalMessage 的 mutateAsync
+   * @returns {Promise<void>}
+   */
+  async ensureLogin(walletAddress, signPersonalMessage) {
+    if (this.isLoggedIn(walletAddress)) {
+      return;
+    }
+
+    if (!this.pendingLogin || this.pendingLogin.walletAddress !== walletAddress) {
+      const promise = this.login(walletAddress, signPersonalMessage).finally(() => {
+        if (this.pendingLogin?.promise === promise) {
+          this.pendingLogin = null;
+        }
+      });
+      this.pendingLogin = { walletAddress, promise };
+    }
+    await this.pendingLogin.promise;
+  }
+
+  /**
+   * 注销当前会话
+   * @returns {Promise<void>}
+   */
+  async logout() {
+    try {
+      if (httpClient.getSession()) {
+        await httpClient.post(API_ENDPOINTS.auth.logout);
+      }
+    } catch (error) {
+      console.error('注销失败:', error);
+    } finally {
+      httpClient.clearSession();
+    }
+  }
+}
+
+// 导出单例
+export const authService = new AuthService();
@@ -0,0 +1,65 @@
+import { httpClient } from './http.client';
+import { API_ENDPOINTS } from './api.config';
+
+/**
+ * 预付额度服务
+ */
+class CreditService {
+  /**
+   * 获取额度余额
+   * @param {number} [organizationId] - 组织 ID，不传为个人账户
+   * @returns {Promise<Object>} { owner_type, owner_id, balances }
+   */
+  async getBalance(organizationId) {
+    const response = await httpClient.get(API_ENDPOINTS.credits.balance(organizationId));
+    if (!response.success) {
+      throw new Error(response.error || '获取额度余额失败');
+    }
+    return response.data;
+  }
+
+  /**
+   * 获取额度流水（充值、解锁扣减、退款退回）
+   * @param {number} [organizationId] - 组织 ID，不传为个人账户
+   * @returns {Promise<Array>} 流水列表，最新的在前
+   */
+  async getLedger(organizationId) {
+    try {
+      const response = await httpClient.get(API_ENDPOINTS.credits.ledger(organizationId));
+      return response.data || [];
+    } catch (error) {
+      console.error('获取额度流水失败:', error);
+      return [];
+    }
+  }
+
+  /**
+   * 获取在售的额度套餐
+   * @returns {Promise<Array>} 套餐列表
+   */
+  async getBundles() {
+    try {
+      const response = await httpClient.get(API_ENDPOINTS.credits.bundles);
+      return response.data || [];
+    } catch (error) {
+      console.error('获取额度套餐失败:', error);
+      return [];
+    }
+  }
+
+  /**
+   * 购买额度套餐（需要 x402 支付）
+   * @param {number} bundleId - 套餐 ID
+   * @param {number} [organizationId] - 充值到组织账户
+   * @returns {Promise<Object>} { credited, balance, transaction, network }
+   */
+  async purchaseBundle(bundleId, organizationId) {
+    const response = await httpClient.post(API_ENDPOINTS.credits.purchaseBundle(bundleId, organizationId));
+    if (!response.success) {
+      throw new Error(response.error || '购买额度套餐失败');
+    }
+    return response.data;
+  }
+}
+
+export const creditService = new CreditService();
@@ -1,7 +1,7 @@
 /**
  * HTTP 请求工具类
  */
-import { API_BASE_URL, DEFAULT_HEADERS, REQUEST_TIMEOUT } from './api.config';
+import { API_BASE_URL, DEFAULT_HEADERS, REQUEST_TIMEOUT, AUTH_SESSION_STORAGE_KEY } from './api.config';
 
 /**
  * HTTP 请求类
@@ -11,6 +11,39 @@ class HttpClient {
     this.baseURL = baseURL;
   }
 
+  /**
+   * 获取本地保存的登录会话
+   *
+   * 后端在会话被使用时会延长有效期，这里不按登录时的过期时间判断，会话失效以 401 为准。
+   * @returns {object|null} { token, wallet_address }，不存在时为 null
+   */
+  getSession() {
+    try {
+      const session = JSON.parse(localStorage.getItem(AUTH_SESSION_STORAGE_KEY) || 'null');
+      return session?.token ? session : null;
+    } catch {
+      return null;
+    }
+  }
+
+  /**
+   * 保存登录会话，之后的请求会带上 Authorization 头
+   * @param {object} session - 登录接口返回的 { token, wallet_address }
+   */
+  setSession(session) {
+    localStorage.setItem(AUTH_SESSION_STORAGE_KEY, JSON.stringify({
+      token: session.token,
+      wallet_address: session.wallet_address,
+    }));
+  }
+
+  /**
+   * 清除登录会话
+   */
+  clearSession() {
+    localStorage.removeItem(AUTH_SESSION_STORAGE_KEY);
+  }
+
   /**
    * 发送 HTTP 请求
    * @param {string} endpoint - API 端点
@@ -20,10 +53,12 @@ class HttpClient {
   async request(endpoint, options = {}) {
     const url = `${this.baseURL}${endpoint}`;
     
+    const session = this.getSession();
     const config = {
       ...options,
       headers: {
         ...DEFAULT_HEADERS,
+        ...(session ? { Authorization: `Bearer ${session.token}` } : {}),
         ...options.headers,
       },
     };
@@ -40,6 +75,11 @@ class HttpClient {
       // 解析响应
       const data = await response.json();
 
+      // 会话已失效（过期、被注销或被撤销），清除后需要重新登录
+      if (response.status === 401 && session) {
+        this.clearSession();
+      }
+
       // 检查响应状态
       if (!response.ok) {
         throw new Error(data.error || `HTTP ${response.status}: ${response.statusText}`);
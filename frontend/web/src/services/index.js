@@ -2,7 +2,9 @@
  * API 服务统一导出
  */
 
+export { authService } from './auth.service';
 export { resumeService } from './resume.service';
 export { userService } from './user.service';
+export { creditService } from './credit.service';
 export { httpClient } from './http.client';
 export { API_BASE_URL, API_ENDPOINTS } from './api.config';
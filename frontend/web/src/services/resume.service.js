@@ -12,14 +12,22 @@ import {
   createAddToAllowlistTransaction 
 } from '../utils/sealClient';
 
+/**
+ * 去掉创建请求中的 owner，后端以当前登录钱包为所有者
+ * @param {object} resumeData - 简历数据
+ * @returns {object} 创建请求数据
+ */
+const toCreateRequest = ({ owner: _owner, ...request }) => request;
+
 /**
  * 简历 API 服务类
  */
 class ResumeService {
   /**
    * 创建简历(带加密和 Walrus 上传)
+   * 简历所有者为当前登录的钱包，需先登录
    * @param {object} resumeData - 简历数据
-   * @param {string} resumeData.owner - 钱包地址
+   * @param {string} resumeData.owner - 钱包地址（不发送给后端，后端以登录钱包为所有者）
    * @param {object} resumeData.personal - 个人信息
    * @param {string} resumeData.skills - 个人优势
    * @param {object} resumeData.desired_position - 期望职位
@@ -57,7 +65,7 @@ class ResumeService {
       
       // 3. 调用后端 API，传递 Blob ID
       const response = await httpClient.post(API_ENDPOINTS.resumes.create, {
-        ...resumeData,
+        ...toCreateRequest(resumeData),
         blob_id: blobId,           // 使用 blob_id
         encryption_type: 'simple', // 明确标记为简单加密
         encryption_key: null,      // 密钥不存储在后端，由前端管理
@@ -110,7 +118,7 @@ class ResumeService {
    */
   async getMyResumes(walletAddress) {
     try {
-      const response = await httpClient.get(`/api/resumes/my/${walletAddress}`);
+      const response = await httpClient.get(API_ENDPOINTS.resumes.getMyResumes(walletAddress));
       
       if (response.success) {
         return response.data || [];
@@ -124,14 +132,13 @@ class ResumeService {
   }
 
   /**
-   * 获取简历详情 (需要 owner 验证)
+   * 获取简历详情 (需要登录，仅限所有者)
    * @param {string} resumeId - 简历 ID
-   * @param {string} owner - 所有者钱包地址
    * @returns {Promise<Object>} 简历详情
    */
-  async getResumeDetail(resumeId, owner) {
+  async getResumeDetail(resumeId) {
     try {
-      const response = await httpClient.get(`/api/resumes/detail/${resumeId}/${owner}`);
+      const response = await httpClient.get(API_ENDPOINTS.resumes.detail(resumeId));
       
       if (response.success) {
         return response.data;
@@ -145,7 +152,7 @@ class ResumeService {
   }
 
   /**
-   * 更新简历
+   * 更新简历 (需要登录，仅限所有者)
    * @param {string} resumeId - 简历 ID
    * @param {object} resumeData - 简历数据
    * @returns {Promise<object>} 更新结果
@@ -154,7 +161,7 @@ class ResumeService {
     try {
       const response = await httpClient.put(
         API_ENDPOINTS.resumes.update(resumeId),
-        resumeData
+        toCreateRequest(resumeData)
       );
       
       if (response.success) {
@@ -172,17 +179,15 @@ class ResumeService {
   }
 
   /**
-   * 更新简历名称
+   * 更新简历名称 (需要登录，仅限所有者)
    * @param {string} resumeId - 简历 ID
-   * @param {string} owner - 所有者钱包地址
    * @param {string} name - 新的简历名称
    * @returns {Promise<object>} 更新结果
    */
-  async updateResumeName(resumeId, owner, name) {
+  async updateResumeName(resumeId, name) {
     try {
-      const response = await httpClient.put('/api/resumes/name', {
+      const response = await httpClient.put(API_ENDPOINTS.resumes.updateName, {
         resume_id: resumeId,
-        owner: owner,
         name: name,
       });
       
@@ -201,15 +206,14 @@ class ResumeService {
   }
 
   /**
-   * 删除简历
+   * 删除简历 (需要登录，仅限所有者)
    * @param {string} resumeId - 简历 ID
-   * @param {string} owner - 所有者钱包地址
    * @returns {Promise<object>} 删除结果
    */
-  async deleteResume(resumeId, owner) {
+  async deleteResume(resumeId) {
     try {
       const response = await httpClient.delete(
-        API_ENDPOINTS.resumes.delete(resumeId, owner)
+        API_ENDPOINTS.resumes.delete(resumeId)
       );
       
       if (response.success) {
@@ -227,22 +231,20 @@ class ResumeService {
   }
 
   /**
-   * 解锁简历 (需要 x402 支付)
+   * 解锁简历 (需要 x402 支付，买家为当前登录钱包)
    * @param {string} resumeId - 简历 ID
-   * @param {string} buyerWallet - 买家钱包地址
-   * @returns {Promise<object>} 解锁结果
+   * @param {string} [tier] - 价格档位，默认 full；已拥有低档位时只需支付差价
+   * @param {string} [coupon] - 优惠码
+   * @returns {Promise<object>} 解锁结果，包含该档位的解密凭据
    */
-  async unlockResume(resumeId, buyerWallet) {
+  async unlockResume(resumeId, tier, coupon) {
     try {
-      const response = await httpClient.post(API_ENDPOINTS.resumes.unlock, {
-        resume_id: resumeId,
-        buyer_wallet: buyerWallet,
-      });
+      const response = await httpClient.post(API_ENDPOINTS.resumes.unlock(resumeId, { tier, coupon }));
       
       if (response.success) {
         return {
           success: true,
-          resume: response.data.resume,
+          entitlement: response.data,
           message: '简历解锁成功',
         };
       } else {
@@ -253,29 +255,55 @@ class ResumeService {
       throw error;
     }
   }
+  /**
+   * 用预付额度解锁简历（默认从个人额度扣减，与额度余额、购买套餐一致）
+   * @param {string} resumeId - 简历 ID
+   * @param {string} [tier] - 价格档位，默认 full
+   * @param {string} [coupon] - 优惠码
+   * @param {number} [organizationId] - 从该组织的额度扣减，购买归属该组织
+   * @returns {Promise<object>} 解锁结果，包含该档位的解密凭据
+   */
+  async unlockResumeWithCredits(resumeId, tier, coupon, organizationId) {
+    try {
+      const response = await httpClient.post(
+        API_ENDPOINTS.resumes.unlockWithCredits(resumeId, { tier, coupon, organization_id: organizationId })
+      );
+
+      if (response.success) {
+        return {
+          success: true,
+          entitlement: response.data,
+          message: '简历解锁成功',
+        };
+      } else {
+        throw new Error(response.error || '额度解锁失败');
+      }
+    } catch (error) {
+      console.error('额度解锁失败:', error);
+      throw error;
+    }
+  }
+
 
   /**
-   * 设置简历价格
+   * 设置简历价格 (需要登录，仅限所有者)
    * @param {string} resumeId - 简历 ID
-   * @param {string} owner - 所有者钱包地址
-   * @param {number} priceInSUI - 价格（SUI）
+   * @param {string} price - 十进制价格字符串，如 "5.25"（由后端按资产精度精确换算）
+   * @param {string} [asset] - 资产符号，默认为后端的 x402 收款资产
    * @returns {Promise<object>} 设置结果
    */
-  async setResumePrice(resumeId, owner, priceInSUI) {
+  async setResumePrice(resumeId, price, asset) {
     try {
-      // 将 SUI 转换为 MIST (1 SUI = 1,000,000,000 MIST, 9 decimals)
-      const priceInUnits = Math.floor(priceInSUI * 1_000_000_000);
-
-      const response = await httpClient.put('/api/resumes/price', {
+      const response = await httpClient.put(API_ENDPOINTS.resumes.setPrice, {
         resume_id: resumeId,
-        owner: owner,
-        price: priceInUnits,
+        price: String(price),
+        asset,
       });
       
       if (response.success) {
         return {
           success: true,
-          message: `Resume price set to ${priceInSUI} SUI`,
+          message: response.message || `Resume price set to ${price} ${asset || ''}`.trim(),
         };
       } else {
         throw new Error(response.error || 'Failed to set resume price');
@@ -334,7 +362,7 @@ class ResumeService {
       
       // 2. 调用后端 API
       const response = await httpClient.post(API_ENDPOINTS.resumes.create, {
-        ...resumeData,
+        ...toCreateRequest(resumeData),
         blob_id: blobId,           // 使用 blob_id 而不是 ipfs_cid
         encryption_id: encryptionId,
         policy_object_id: policyObjectId,
@@ -456,6 +484,77 @@ class ResumeService {
       throw error;
     }
   }
+
+  /**
+   * 获取简历访问白名单成员（仅限所有者）
+   * @param {string} resumeId - 简历 ID
+   * @returns {Promise<array>} 成员列表，source 为 purchase（付费解锁）或 manual（手动添加）
+   */
+  async getAllowlistMembers(resumeId) {
+    try {
+      const response = await httpClient.get(API_ENDPOINTS.resumes.allowlistMembers(resumeId));
+
+      if (response.success) {
+        return response.data;
+      } else {
+        throw new Error(response.error || '获取白名单成员失败');
+      }
+    } catch (error) {
+      console.error('获取白名单成员失败:', error);
+      throw error;
+    }
+  }
+
+  /**
+   * 记录已提交的白名单添加交易（链上确认前为 pending）
+   * @param {string} resumeId - 简历 ID
+   * @param {string} memberAddress - 成员地址
+   * @param {string} txDigest - allowlist::add 交易摘要
+   * @param {string} [policyObjectId] - 目标 allowlist，默认为简历本身的策略对象
+   * @returns {Promise<object>} 成员记录
+   */
+  async recordAllowlistMember(resumeId, memberAddress, txDigest, policyObjectId) {
+    try {
+      const response = await httpClient.post(API_ENDPOINTS.resumes.allowlistMembers(resumeId), {
+        member_address: memberAddress,
+        tx_digest: txDigest,
+        policy_object_id: policyObjectId,
+      });
+
+      if (response.success) {
+        return response.data;
+      } else {
+        throw new Error(response.error || '记录白名单成员失败');
+      }
+    } catch (error) {
+      console.error('记录白名单成员失败:', error);
+      throw error;
+    }
+  }
+
+  /**
+   * 标记白名单成员已移除
+   * @param {string} resumeId - 简历 ID
+   * @param {string} memberAddress - 成员地址
+   * @param {string} [txDigest] - allowlist::remove 交易摘要
+   * @returns {Promise<object>} 移除结果
+   */
+  async removeAllowlistMember(resumeId, memberAddress, txDigest) {
+    try {
+      const response = await httpClient.delete(
+        API_ENDPOINTS.resumes.allowlistMember(resumeId, memberAddress, txDigest)
+      );
+
+      if (response.success) {
+        return response.data;
+      } else {
+        throw new Error(response.error || '移除白名单成员失败');
+      }
+    } catch (error) {
+      console.error('移除白名单成员失败:', error);
+      throw error;
+    }
+  }
 }
 
 // 导出单例
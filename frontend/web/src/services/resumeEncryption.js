@@ -110,15 +110,11 @@ export async function unlockAndDecryptResume(cid, encryptionKey) {
 export async function getResumeCID(resumeId, buyerWallet) {
   try {
     // 调用后端解锁接口
-    const response = await fetch('/api/resumes/unlock', {
+    const response = await fetch(`/api/resumes/${resumeId}/unlock`, {
       method: 'POST',
       headers: {
         'Content-Type': 'application/json',
       },
-      body: JSON.stringify({
-        resume_id: resumeId,
-        buyer_wallet: buyerWallet,
-      }),
     });
 
     if (!response.ok) {
@@ -128,11 +124,11 @@ export async function getResumeCID(resumeId, buyerWallet) {
 
     const result = await response.json();
     
-    if (!result.data?.ipfs_cid) {
+    if (!result.data?.blob_id) {
       throw new Error('No CID returned from backend');
     }
 
-    return result.data.ipfs_cid;
+    return result.data.blob_id;
   } catch (error) {
     console.error('❌ Get resume CID failed:', error);
     throw error;
@@ -12,14 +12,18 @@ class UnlockRecordService {
    * @param {number} data.buyer_id - 购买者用户 ID
    * @param {string} data.buyer_wallet - 购买者钱包地址
    * @param {string} data.seller_wallet - 卖家钱包地址
-   * @param {number} data.amount - 支付金额
+   * @param {number} data.amount - 支付金额（资产最小单位）
+   * @param {string} [data.asset] - 支付资产符号，如 SUI（默认为后端的 x402 收款资产）
    * @param {string} data.transaction_signature - 交易签名（Sui transaction digest）
    * @param {number} [data.block_time] - 区块时间戳（可选）
-   * @returns {Promise<Object>} 创建的解锁记录
+   * @returns {Promise<Object>} 创建的解锁记录，链上交易尚未确认时 status 为 pending
    */
   async createUnlockRecord(data) {
     try {
-      const response = await httpClient.post(API_ENDPOINTS.unlockRecords.create, data);
+      // 以交易签名作为幂等键，重试时后端重放第一次的响应
+      const response = await httpClient.post(API_ENDPOINTS.unlockRecords.create, data, {
+        headers: { 'Idempotency-Key': `unlock-${data.transaction_signature}` },
+      });
       return response.data;
     } catch (error) {
       console.error('创建解锁记录失败:', error);
@@ -78,6 +82,52 @@ class UnlockRecordService {
       return [];
     }
   }
+
+  /**
+   * 重新校验 pending 状态的解锁记录（链上交易确认后转为 confirmed）
+   * @param {number} recordId - 解锁记录 ID
+   * @returns {Promise<Object>} 校验后的解锁记录
+   */
+  async verifyUnlockRecord(recordId) {
+    const response = await httpClient.post(API_ENDPOINTS.unlockRecords.verify(recordId));
+    if (!response.success) {
+      throw new Error(response.error || '校验解锁记录失败');
+    }
+    return response.data;
+  }
+
+  /**
+   * 对解锁记录发起争议（购买者）
+   * @param {number} recordId - 解锁记录 ID
+   * @param {string} reason - 争议原因
+   * @param {string[]} [evidence] - 证据链接或说明
+   * @returns {Promise<object>} 争议详情
+   */
+  async openDispute(recordId, reason, evidence = []) {
+    const response = await httpClient.post(API_ENDPOINTS.unlockRecords.dispute(recordId), {
+      reason,
+      evidence,
+    });
+    if (!response.success) {
+      throw new Error(response.error || '发起争议失败');
+    }
+    return response.data;
+  }
+
+  /**
+   * 获取解锁记录的争议（购买者或卖家）
+   * @param {number} recordId - 解锁记录 ID
+   * @returns {Promise<object|null>} 争议详情，没有争议时为 null
+   */
+  async getDispute(recordId) {
+    try {
+      const response = await httpClient.get(API_ENDPOINTS.unlockRecords.dispute(recordId));
+      return response.data || null;
+    } catch (error) {
+      console.error('获取争议失败:', error);
+      return null;
+    }
+  }
 }
 
 export default new UnlockRecordService();
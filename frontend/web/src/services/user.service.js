@@ -9,14 +9,14 @@ import { API_ENDPOINTS } from './api.config';
  */
 class UserService {
   /**
-   * 注册或获取用户
-   * @param {string} walletAddress - 钱包地址
+   * 注册或获取当前登录钱包的用户 (需要登录)
+   * @param {string} [userType] - 角色: job_seeker / recruiter / both，不传则保持当前角色
    * @returns {Promise<object>} 用户信息
    */
-  async registerOrGetUser(walletAddress) {
+  async registerOrGetUser(userType) {
     try {
       const response = await httpClient.post(API_ENDPOINTS.users.register, {
-        wallet_address: walletAddress,
+        user_type: userType,
       });
       
       if (response.success) {
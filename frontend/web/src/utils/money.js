@@ -0,0 +1,63 @@
+/**
+ * 金额工具函数
+ * 后端金额统一为 { amount, asset, decimals, display }，amount 为最小单位的整数字符串，
+ * 这里用 BigInt 和字符串换算，避免浮点误差
+ */
+
+/**
+ * 格式化金额，如 "5.000000 USDC"
+ * @param {{display: string, asset: string}} money - 后端返回的金额
+ * @returns {string}
+ */
+export function formatMoney(money) {
+  if (!money) return '-';
+  return `${money.display} ${money.asset}`;
+}
+
+/**
+ * 最小单位整数转十进制字符串
+ * @param {bigint} amount - 最小单位金额
+ * @param {number} decimals - 精度
+ * @returns {string}
+ */
+function toDecimalString(amount, decimals) {
+  const digits = amount.toString().padStart(decimals + 1, '0');
+  if (decimals === 0) return digits;
+  return `${digits.slice(0, -decimals)}.${digits.slice(-decimals)}`;
+}
+
+/**
+ * 金额乘以整数（如单价 × 解锁次数）
+ * @param {object} money - 后端返回的金额
+ * @param {number} times - 倍数
+ * @returns {object} 新的金额
+ */
+export function multiplyMoney(money, times) {
+  const amount = BigInt(money.amount) * BigInt(times);
+  return { ...money, amount: amount.toString(), display: toDecimalString(amount, money.decimals) };
+}
+
+/**
+ * 按资产分组求和
+ * @param {object[]} items - 金额列表
+ * @returns {object[]} 每种资产一项
+ */
+export function sumMoney(items) {
+  const totals = new Map();
+  for (const money of items) {
+    const current = totals.get(money.asset);
+    const amount = BigInt(money.amount) + (current ? BigInt(current.amount) : 0n);
+    totals.set(money.asset, { ...money, amount: amount.toString(), display: toDecimalString(amount, money.decimals) });
+  }
+  return [...totals.values()];
+}
+
+/**
+ * 校验十进制金额字符串（不超过资产精度）
+ * @param {string} value - 用户输入
+ * @param {number} decimals - 精度
+ * @returns {boolean}
+ */
+export function isValidAmount(value, decimals) {
+  return new RegExp(`^\\d+(\\.\\d{0,${decimals}})?$`).test(String(value).trim());
+}